@@ -7,8 +7,12 @@ use metadata_bilateral_exchange::storage::contract_info::ContractInfoV2;
 use metadata_bilateral_exchange::types::core::msg::{
     ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg,
 };
+use metadata_bilateral_exchange::types::core::quote_denom_allowlist::QuoteDenomAllowlist;
 use metadata_bilateral_exchange::types::request::ask_types::ask_order::AskOrder;
 use metadata_bilateral_exchange::types::request::bid_types::bid_order::BidOrder;
+use metadata_bilateral_exchange::types::request::match_batch::ExecuteMatchesResponse;
+use metadata_bilateral_exchange::types::request::match_candidates::MatchCandidatesResponse;
+use metadata_bilateral_exchange::types::request::match_record::MatchRecord;
 use metadata_bilateral_exchange::types::request::match_report::MatchReport;
 
 fn main() {
@@ -18,8 +22,12 @@ fn main() {
     remove_schemas(&out_dir).unwrap();
     export_schema(&schema_for!(AskOrder), &out_dir);
     export_schema(&schema_for!(BidOrder), &out_dir);
+    export_schema(&schema_for!(ExecuteMatchesResponse), &out_dir);
+    export_schema(&schema_for!(MatchCandidatesResponse), &out_dir);
+    export_schema(&schema_for!(MatchRecord), &out_dir);
     export_schema(&schema_for!(MatchReport), &out_dir);
     export_schema(&schema_for!(ContractInfoV2), &out_dir);
+    export_schema(&schema_for!(QuoteDenomAllowlist), &out_dir);
     export_schema(&schema_for!(ExecuteMsg), &out_dir);
     export_schema(&schema_for!(InstantiateMsg), &out_dir);
     export_schema(&schema_for!(QueryMsg), &out_dir);
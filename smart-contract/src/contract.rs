@@ -1,8 +1,20 @@
+use crate::execute::accept_admin::accept_admin;
+use crate::execute::add_quote_denoms::add_quote_denoms;
+use crate::execute::block_addresses::block_addresses;
+use crate::execute::cancel_admin_proposal::cancel_admin_proposal;
 use crate::execute::cancel_ask::cancel_ask;
 use crate::execute::cancel_bid::cancel_bid;
 use crate::execute::create_ask::create_ask;
 use crate::execute::create_bid::create_bid;
 use crate::execute::execute_match::execute_match;
+use crate::execute::execute_matches::execute_matches;
+use crate::execute::expire_orders::expire_orders;
+use crate::execute::grant_role::grant_role;
+use crate::execute::propose_admin::propose_admin;
+use crate::execute::register_scope_stake::register_scope_stake;
+use crate::execute::remove_quote_denoms::remove_quote_denoms;
+use crate::execute::revoke_role::revoke_role;
+use crate::execute::unblock_addresses::unblock_addresses;
 use crate::execute::update_ask::update_ask;
 use crate::execute::update_bid::update_bid;
 use crate::execute::update_settings::update_settings;
@@ -11,10 +23,16 @@ use crate::migrate::migrate_contract::migrate_contract;
 use crate::query::get_ask::query_ask;
 use crate::query::get_asks_by_collateral_id::query_asks_by_collateral_id;
 use crate::query::get_bid::query_bid;
+use crate::query::get_bids_by_collateral_id::query_bids_by_collateral_id;
 use crate::query::get_contract_info::query_contract_info;
+use crate::query::get_match_candidates::get_match_candidates;
+use crate::query::get_match_history::query_match_history;
 use crate::query::get_match_report::get_match_report;
+use crate::query::get_quote_denom_allowlist::query_quote_denom_allowlist;
+use crate::query::get_roles::query_roles;
 use crate::query::search_asks::search_asks;
 use crate::query::search_bids::search_bids;
+use crate::query::search_matches::search_matches;
 use crate::types::core::error::ContractError;
 use crate::types::core::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
 use cosmwasm_std::{entry_point, Binary, Deps, DepsMut, Env, MessageInfo, Response};
@@ -51,7 +69,29 @@ pub fn execute(
             bid_id,
             admin_match_options,
         } => execute_match(deps, env, info, ask_id, bid_id, admin_match_options),
+        ExecuteMsg::ExecuteMatches { matches, mode } => {
+            execute_matches(deps, env, info, matches, mode)
+        }
         ExecuteMsg::UpdateSettings { update } => update_settings(deps, info, update),
+        ExecuteMsg::ExpireOrders { limit } => expire_orders(deps, env, info, limit),
+        ExecuteMsg::GrantRole { address, role } => grant_role(deps, info, address, role),
+        ExecuteMsg::RevokeRole { address, role } => revoke_role(deps, info, address, role),
+        ExecuteMsg::ProposeAdmin { address } => propose_admin(deps, info, address),
+        ExecuteMsg::AcceptAdmin {} => accept_admin(deps, info),
+        ExecuteMsg::CancelAdminProposal {} => cancel_admin_proposal(deps, info),
+        ExecuteMsg::AddQuoteDenoms {
+            denoms,
+            request_type,
+        } => add_quote_denoms(deps, info, denoms, request_type),
+        ExecuteMsg::RemoveQuoteDenoms {
+            denoms,
+            request_type,
+        } => remove_quote_denoms(deps, info, denoms, request_type),
+        ExecuteMsg::BlockAddresses { addresses } => block_addresses(deps, info, addresses),
+        ExecuteMsg::UnblockAddresses { addresses } => unblock_addresses(deps, info, addresses),
+        ExecuteMsg::RegisterScopeStake { scope_address } => {
+            register_scope_stake(deps, info, scope_address)
+        }
     }
 }
 
@@ -59,7 +99,7 @@ pub fn execute(
 #[entry_point]
 pub fn query(
     deps: Deps<ProvenanceQuery>,
-    _env: Env,
+    env: Env,
     msg: QueryMsg,
 ) -> Result<Binary, ContractError> {
     match msg {
@@ -68,14 +108,31 @@ pub fn query(
             query_asks_by_collateral_id(deps, collateral_id)
         }
         QueryMsg::GetBid { id } => query_bid(deps, id),
+        QueryMsg::GetBidsByCollateralId {
+            collateral_id,
+            page_size,
+            start_after,
+        } => query_bids_by_collateral_id(deps, collateral_id, page_size, start_after),
         QueryMsg::GetMatchReport {
             ask_id,
             bid_id,
             admin_match_options,
-        } => get_match_report(deps, ask_id, bid_id, admin_match_options),
+        } => get_match_report(deps, env, ask_id, bid_id, admin_match_options),
+        QueryMsg::GetMatchCandidates {
+            ask_id,
+            bid_id,
+            options,
+        } => get_match_candidates(deps, env, ask_id, bid_id, options),
         QueryMsg::GetContractInfo {} => query_contract_info(deps),
-        QueryMsg::SearchAsks { search } => search_asks(deps, search),
-        QueryMsg::SearchBids { search } => search_bids(deps, search),
+        QueryMsg::SearchAsks { search } => search_asks(deps, env, search),
+        QueryMsg::SearchBids { search } => search_bids(deps, env, search),
+        QueryMsg::GetMatchHistory {
+            page_size,
+            page_number,
+        } => query_match_history(deps, page_size, page_number),
+        QueryMsg::SearchMatches { search } => search_matches(deps, search),
+        QueryMsg::GetRoles { address } => query_roles(deps, address),
+        QueryMsg::GetQuoteDenomAllowlist {} => query_quote_denom_allowlist(deps),
     }
 }
 
@@ -0,0 +1,147 @@
+use crate::storage::contract_info::{get_contract_info, set_contract_info};
+use crate::types::core::error::ContractError;
+use crate::util::extensions::ResultExtensions;
+use cosmwasm_std::{DepsMut, MessageInfo, Response};
+use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
+
+// accept admin entrypoint
+pub fn accept_admin(
+    deps: DepsMut<ProvenanceQuery>,
+    info: MessageInfo,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    let mut contract_info = get_contract_info(deps.storage)?;
+    let pending_admin = if let Some(pending_admin) = contract_info.pending_admin.to_owned() {
+        pending_admin
+    } else {
+        return ContractError::InvalidRequest {
+            message: "no admin proposal is pending".to_string(),
+        }
+        .to_err();
+    };
+    // Only the proposed account may accept, proving that its key is controlled by the new admin
+    if info.sender != pending_admin {
+        return ContractError::Unauthorized.to_err();
+    }
+    if !info.funds.is_empty() {
+        return ContractError::InvalidFundsProvided {
+            message: "funds cannot be provided when accepting the admin role".to_string(),
+        }
+        .to_err();
+    }
+    let previous_admin = contract_info.admin.to_owned();
+    contract_info.admin = pending_admin;
+    contract_info.pending_admin = None;
+    set_contract_info(deps.storage, &contract_info)?;
+    Response::new()
+        .add_attribute("action", "accept_admin")
+        .add_attribute("previous_admin", previous_admin.as_str())
+        .add_attribute("new_admin", contract_info.admin.as_str())
+        .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::execute::accept_admin::accept_admin;
+    use crate::execute::propose_admin::propose_admin;
+    use crate::execute::update_settings::update_settings;
+    use crate::storage::contract_info::get_contract_info;
+    use crate::test::cosmos_type_helpers::single_attribute_for_key;
+    use crate::test::mock_instantiate::{default_instantiate, DEFAULT_ADMIN_ADDRESS};
+    use crate::types::core::error::ContractError;
+    use crate::types::request::settings_update::SettingsUpdate;
+    use crate::util::constants::NHASH;
+    use cosmwasm_std::testing::mock_info;
+    use cosmwasm_std::{coins, Uint128};
+    use provwasm_mocks::mock_dependencies;
+
+    #[test]
+    fn test_accept_admin_with_invalid_data() {
+        let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
+        let err = accept_admin(deps.as_mut(), mock_info("new_admin", &[]))
+            .expect_err("an error should occur when no proposal is pending");
+        match err {
+            ContractError::InvalidRequest { message } => assert_eq!(
+                "no admin proposal is pending", message,
+                "the correct error message should be produced",
+            ),
+            e => panic!("unexpected error: {:?}", e),
+        };
+        propose_admin(
+            deps.as_mut(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+            "new_admin".to_string(),
+        )
+        .expect("the proposal should succeed");
+        let err = accept_admin(deps.as_mut(), mock_info(DEFAULT_ADMIN_ADDRESS, &[]))
+            .expect_err("the current admin should not be able to accept the proposal");
+        assert!(
+            matches!(err, ContractError::Unauthorized),
+            "an unauthorized error should be returned, but got: {:?}",
+            err,
+        );
+        let err = accept_admin(deps.as_mut(), mock_info("new_admin", &coins(100, NHASH)))
+            .expect_err("an error should occur when funds are provided");
+        assert!(
+            matches!(err, ContractError::InvalidFundsProvided { .. }),
+            "an invalid funds error should be returned, but got: {:?}",
+            err,
+        );
+    }
+
+    #[test]
+    fn test_accept_admin_transfers_admin() {
+        let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
+        propose_admin(
+            deps.as_mut(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+            "new_admin".to_string(),
+        )
+        .expect("the proposal should succeed");
+        let response = accept_admin(deps.as_mut(), mock_info("new_admin", &[]))
+            .expect("the proposed admin should be able to accept");
+        assert_eq!(
+            "accept_admin",
+            single_attribute_for_key(&response, "action"),
+            "the correct action attribute should be emitted",
+        );
+        assert_eq!(
+            DEFAULT_ADMIN_ADDRESS,
+            single_attribute_for_key(&response, "previous_admin"),
+            "the correct previous_admin attribute should be emitted",
+        );
+        assert_eq!(
+            "new_admin",
+            single_attribute_for_key(&response, "new_admin"),
+            "the correct new_admin attribute should be emitted",
+        );
+        let contract_info =
+            get_contract_info(deps.as_ref().storage).expect("contract info should load");
+        assert_eq!(
+            "new_admin",
+            contract_info.admin.as_str(),
+            "the new admin should be set in the contract info",
+        );
+        assert!(
+            contract_info.pending_admin.is_none(),
+            "the pending admin should be cleared after acceptance",
+        );
+        let settings_update = SettingsUpdate {
+            new_create_ask_nhash_fee: Some(Uint128::new(100)),
+            new_create_bid_nhash_fee: None,
+            new_settlement_fees: None,
+            new_fee_collector_address: None,
+            new_contract_status: None,
+            new_required_trade_attributes: None,
+        };
+        update_settings(
+            deps.as_mut(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+            settings_update.clone(),
+        )
+        .expect_err("the previous admin should lose access to admin functions");
+        update_settings(deps.as_mut(), mock_info("new_admin", &[]), settings_update)
+            .expect("the new admin should have access to admin functions");
+    }
+}
@@ -0,0 +1,170 @@
+use crate::storage::contract_info::get_contract_info;
+use crate::storage::contract_role_storage::address_has_role;
+use crate::storage::quote_denom_allowlist_storage::{
+    get_quote_denom_allowlist, set_quote_denom_allowlist,
+};
+use crate::types::core::contract_role::ContractRole;
+use crate::types::core::error::ContractError;
+use crate::types::core::quote_denom_allowlist::get_allowlist_target_name;
+use crate::types::request::request_type::RequestType;
+use crate::util::extensions::ResultExtensions;
+use crate::validation::validation_handler::ValidationHandler;
+use cosmwasm_std::{DepsMut, MessageInfo, Response};
+use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
+
+// add quote denoms entrypoint
+pub fn add_quote_denoms(
+    deps: DepsMut<ProvenanceQuery>,
+    info: MessageInfo,
+    denoms: Vec<String>,
+    request_type: Option<RequestType>,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    let handler = ValidationHandler::new();
+    if denoms.is_empty() {
+        handler.push("at least one denom must be provided");
+    }
+    if denoms.iter().any(|denom| denom.is_empty()) {
+        handler.push("denoms must not be empty");
+    }
+    handler.handle()?;
+    if !address_has_role(
+        deps.storage,
+        &get_contract_info(deps.storage)?,
+        &info.sender,
+        &ContractRole::SettingsAdmin,
+    ) {
+        return ContractError::Unauthorized.to_err();
+    }
+    if !info.funds.is_empty() {
+        return ContractError::InvalidFundsProvided {
+            message: "funds cannot be provided when adding quote denoms".to_string(),
+        }
+        .to_err();
+    }
+    let mut allowlist = get_quote_denom_allowlist(deps.storage)?;
+    let allowed_denoms = allowlist.get_denoms_mut(&request_type);
+    for denom in denoms.iter() {
+        if !allowed_denoms.contains(denom) {
+            allowed_denoms.push(denom.to_owned());
+        }
+    }
+    set_quote_denom_allowlist(deps.storage, &allowlist)?;
+    Response::new()
+        .add_attribute("action", "add_quote_denoms")
+        .add_attribute("request_type", get_allowlist_target_name(&request_type))
+        .add_attribute("denoms", denoms.join(","))
+        .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::execute::add_quote_denoms::add_quote_denoms;
+    use crate::execute::create_bid::create_bid;
+    use crate::storage::quote_denom_allowlist_storage::get_quote_denom_allowlist;
+    use crate::test::cosmos_type_helpers::single_attribute_for_key;
+    use crate::test::error_helpers::assert_validation_error_message;
+    use crate::test::mock_instantiate::{default_instantiate, DEFAULT_ADMIN_ADDRESS};
+    use crate::types::core::error::ContractError;
+    use crate::types::request::bid_types::bid::Bid;
+    use crate::types::request::request_type::RequestType;
+    use crate::util::constants::NHASH;
+    use cosmwasm_std::coins;
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use provwasm_mocks::mock_dependencies;
+
+    #[test]
+    fn test_add_quote_denoms_with_invalid_data() {
+        let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
+        let err = add_quote_denoms(
+            deps.as_mut(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+            vec![],
+            None,
+        )
+        .expect_err("an error should occur when no denoms are provided");
+        assert_validation_error_message(err, "at least one denom must be provided");
+        let err = add_quote_denoms(
+            deps.as_mut(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+            vec![String::new()],
+            None,
+        )
+        .expect_err("an error should occur when an empty denom is provided");
+        assert_validation_error_message(err, "denoms must not be empty");
+        let err = add_quote_denoms(
+            deps.as_mut(),
+            mock_info("not_admin", &[]),
+            vec![NHASH.to_string()],
+            None,
+        )
+        .expect_err("an error should occur when the sender is not a settings admin");
+        assert!(
+            matches!(err, ContractError::Unauthorized),
+            "an unauthorized error should be returned, but got: {:?}",
+            err,
+        );
+        let err = add_quote_denoms(
+            deps.as_mut(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &coins(100, NHASH)),
+            vec![NHASH.to_string()],
+            None,
+        )
+        .expect_err("an error should occur when funds are provided");
+        assert!(
+            matches!(err, ContractError::InvalidFundsProvided { .. }),
+            "an invalid funds error should be returned, but got: {:?}",
+            err,
+        );
+    }
+
+    #[test]
+    fn test_add_quote_denoms_restricts_order_creation() {
+        let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
+        let response = add_quote_denoms(
+            deps.as_mut(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+            vec!["usd".to_string(), NHASH.to_string(), "usd".to_string()],
+            Some(RequestType::CoinTrade),
+        )
+        .expect("the denoms should be added");
+        assert_eq!(
+            "coin_trade",
+            single_attribute_for_key(&response, "request_type"),
+            "the correct request_type attribute should be emitted",
+        );
+        assert_eq!(
+            "usd,nhash,usd",
+            single_attribute_for_key(&response, "denoms"),
+            "the correct denoms attribute should be emitted",
+        );
+        assert_eq!(
+            vec!["usd".to_string(), NHASH.to_string()],
+            get_quote_denom_allowlist(deps.as_ref().storage)
+                .expect("the allowlist should load")
+                .coin_trade,
+            "each denom should only be added once",
+        );
+        let err = create_bid(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder", &coins(100, "spamcoin")),
+            Bid::new_coin_trade("bid_id", &coins(100, "base")),
+            None,
+        )
+        .expect_err("a bid with a disallowed quote denom should be rejected");
+        assert_validation_error_message(
+            err,
+            "BidOrder [bid_id] quote denom [spamcoin] is not allowed for request type [coin_trade]",
+        );
+        create_bid(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder", &coins(100, "usd")),
+            Bid::new_coin_trade("bid_id", &coins(100, "base")),
+            None,
+        )
+        .expect("a bid with an allowed quote denom should be created");
+    }
+}
@@ -0,0 +1,190 @@
+use crate::storage::address_blocklist_storage::block_address;
+use crate::storage::contract_info::get_contract_info;
+use crate::storage::contract_role_storage::address_has_role;
+use crate::types::core::contract_role::ContractRole;
+use crate::types::core::error::ContractError;
+use crate::util::extensions::ResultExtensions;
+use cosmwasm_std::{Addr, DepsMut, MessageInfo, Response};
+use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
+
+// block addresses entrypoint
+pub fn block_addresses(
+    deps: DepsMut<ProvenanceQuery>,
+    info: MessageInfo,
+    addresses: Vec<String>,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    if addresses.is_empty() {
+        return ContractError::ValidationError {
+            messages: vec!["at least one address must be provided".to_string()],
+        }
+        .to_err();
+    }
+    if !address_has_role(
+        deps.storage,
+        &get_contract_info(deps.storage)?,
+        &info.sender,
+        &ContractRole::SettingsAdmin,
+    ) {
+        return ContractError::Unauthorized.to_err();
+    }
+    if !info.funds.is_empty() {
+        return ContractError::InvalidFundsProvided {
+            message: "funds cannot be provided when blocking addresses".to_string(),
+        }
+        .to_err();
+    }
+    let addresses = addresses
+        .iter()
+        .map(|address| deps.api.addr_validate(address))
+        .collect::<Result<Vec<Addr>, _>>()?;
+    for address in addresses.iter() {
+        block_address(deps.storage, address)?;
+    }
+    Response::new()
+        .add_attribute("action", "block_addresses")
+        .add_attribute(
+            "addresses",
+            addresses
+                .iter()
+                .map(|address| address.as_str())
+                .collect::<Vec<&str>>()
+                .join(","),
+        )
+        .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::execute::block_addresses::block_addresses;
+    use crate::execute::cancel_ask::cancel_ask;
+    use crate::execute::create_ask::create_ask;
+    use crate::execute::create_bid::create_bid;
+    use crate::execute::execute_match::execute_match;
+    use crate::execute::update_ask::update_ask;
+    use crate::test::cosmos_type_helpers::single_attribute_for_key;
+    use crate::test::error_helpers::assert_validation_error_message;
+    use crate::test::mock_instantiate::{default_instantiate, DEFAULT_ADMIN_ADDRESS};
+    use crate::types::core::error::ContractError;
+    use crate::types::request::ask_types::ask::Ask;
+    use crate::types::request::bid_types::bid::Bid;
+    use crate::util::constants::NHASH;
+    use cosmwasm_std::coins;
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use provwasm_mocks::mock_dependencies;
+
+    #[test]
+    fn test_block_addresses_with_invalid_data() {
+        let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
+        let err = block_addresses(deps.as_mut(), mock_info(DEFAULT_ADMIN_ADDRESS, &[]), vec![])
+            .expect_err("an error should occur when no addresses are provided");
+        assert_validation_error_message(err, "at least one address must be provided");
+        let err = block_addresses(
+            deps.as_mut(),
+            mock_info("not_admin", &[]),
+            vec!["sanctioned".to_string()],
+        )
+        .expect_err("an error should occur when the sender is not a settings admin");
+        assert!(
+            matches!(err, ContractError::Unauthorized),
+            "an unauthorized error should be returned, but got: {:?}",
+            err,
+        );
+        let err = block_addresses(
+            deps.as_mut(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &coins(100, NHASH)),
+            vec!["sanctioned".to_string()],
+        )
+        .expect_err("an error should occur when funds are provided");
+        assert!(
+            matches!(err, ContractError::InvalidFundsProvided { .. }),
+            "an invalid funds error should be returned, but got: {:?}",
+            err,
+        );
+    }
+
+    #[test]
+    fn test_blocked_address_restrictions() {
+        let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
+        create_ask(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &coins(100, "base")),
+            Ask::new_coin_trade("ask_id", &coins(100, "quote")),
+            None,
+        )
+        .expect("the ask should be created before the asker is blocked");
+        create_bid(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder", &coins(100, "quote")),
+            Bid::new_coin_trade("bid_id", &coins(100, "base")),
+            None,
+        )
+        .expect("the bid should be created");
+        let response = block_addresses(
+            deps.as_mut(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+            vec!["asker".to_string()],
+        )
+        .expect("the asker should be blocked");
+        assert_eq!(
+            "block_addresses",
+            single_attribute_for_key(&response, "action"),
+            "the correct action attribute should be emitted",
+        );
+        assert_eq!(
+            "asker",
+            single_attribute_for_key(&response, "addresses"),
+            "the correct addresses attribute should be emitted",
+        );
+        let expect_blocked_error = |err: ContractError| match err {
+            ContractError::InvalidRequest { message } => assert_eq!(
+                "address [asker] is blocked", message,
+                "the correct error message should be produced",
+            ),
+            e => panic!("unexpected error: {:?}", e),
+        };
+        expect_blocked_error(
+            create_ask(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("asker", &coins(100, "base")),
+                Ask::new_coin_trade("ask_id_2", &coins(100, "quote")),
+                None,
+            )
+            .expect_err("a blocked address should not create an ask"),
+        );
+        expect_blocked_error(
+            update_ask(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("asker", &coins(100, "base")),
+                Ask::new_coin_trade("ask_id", &coins(200, "quote")),
+                None,
+            )
+            .expect_err("a blocked address should not update an ask"),
+        );
+        let err = execute_match(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+            "ask_id".to_string(),
+            "bid_id".to_string(),
+            None,
+        )
+        .expect_err("an ask owned by a blocked address should not be matched");
+        assert_validation_error_message(
+            err,
+            "Match Validation for AskOrder [ask_id] and BidOrder [bid_id]: AskOrder owner [asker] is blocked",
+        );
+        cancel_ask(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &[]),
+            "ask_id".to_string(),
+        )
+        .expect("a blocked address should be able to cancel its ask and recover its collateral");
+    }
+}
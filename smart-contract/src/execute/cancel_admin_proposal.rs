@@ -0,0 +1,108 @@
+use crate::storage::contract_info::{get_contract_info, set_contract_info};
+use crate::types::core::error::ContractError;
+use crate::util::extensions::ResultExtensions;
+use cosmwasm_std::{DepsMut, MessageInfo, Response};
+use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
+
+// cancel admin proposal entrypoint
+pub fn cancel_admin_proposal(
+    deps: DepsMut<ProvenanceQuery>,
+    info: MessageInfo,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    let mut contract_info = get_contract_info(deps.storage)?;
+    if info.sender != contract_info.admin {
+        return ContractError::Unauthorized.to_err();
+    }
+    if !info.funds.is_empty() {
+        return ContractError::InvalidFundsProvided {
+            message: "funds cannot be provided when cancelling an admin proposal".to_string(),
+        }
+        .to_err();
+    }
+    let cancelled_admin = if let Some(pending_admin) = contract_info.pending_admin.to_owned() {
+        pending_admin
+    } else {
+        return ContractError::InvalidRequest {
+            message: "no admin proposal is pending".to_string(),
+        }
+        .to_err();
+    };
+    contract_info.pending_admin = None;
+    set_contract_info(deps.storage, &contract_info)?;
+    Response::new()
+        .add_attribute("action", "cancel_admin_proposal")
+        .add_attribute("cancelled_admin", cancelled_admin.as_str())
+        .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::execute::accept_admin::accept_admin;
+    use crate::execute::cancel_admin_proposal::cancel_admin_proposal;
+    use crate::execute::propose_admin::propose_admin;
+    use crate::storage::contract_info::get_contract_info;
+    use crate::test::cosmos_type_helpers::single_attribute_for_key;
+    use crate::test::mock_instantiate::{default_instantiate, DEFAULT_ADMIN_ADDRESS};
+    use crate::types::core::error::ContractError;
+    use cosmwasm_std::testing::mock_info;
+    use provwasm_mocks::mock_dependencies;
+
+    #[test]
+    fn test_cancel_admin_proposal_with_invalid_data() {
+        let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
+        let err = cancel_admin_proposal(deps.as_mut(), mock_info(DEFAULT_ADMIN_ADDRESS, &[]))
+            .expect_err("an error should occur when no proposal is pending");
+        assert!(
+            matches!(err, ContractError::InvalidRequest { .. }),
+            "an invalid request error should be returned, but got: {:?}",
+            err,
+        );
+        propose_admin(
+            deps.as_mut(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+            "new_admin".to_string(),
+        )
+        .expect("the proposal should succeed");
+        let err = cancel_admin_proposal(deps.as_mut(), mock_info("new_admin", &[]))
+            .expect_err("the proposed admin should not be able to cancel the proposal");
+        assert!(
+            matches!(err, ContractError::Unauthorized),
+            "an unauthorized error should be returned, but got: {:?}",
+            err,
+        );
+    }
+
+    #[test]
+    fn test_cancel_admin_proposal() {
+        let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
+        propose_admin(
+            deps.as_mut(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+            "typo_admin".to_string(),
+        )
+        .expect("the proposal should succeed");
+        let response = cancel_admin_proposal(deps.as_mut(), mock_info(DEFAULT_ADMIN_ADDRESS, &[]))
+            .expect("the admin should be able to cancel the proposal");
+        assert_eq!(
+            "cancel_admin_proposal",
+            single_attribute_for_key(&response, "action"),
+            "the correct action attribute should be emitted",
+        );
+        assert_eq!(
+            "typo_admin",
+            single_attribute_for_key(&response, "cancelled_admin"),
+            "the correct cancelled_admin attribute should be emitted",
+        );
+        assert!(
+            get_contract_info(deps.as_ref().storage)
+                .expect("contract info should load")
+                .pending_admin
+                .is_none(),
+            "the pending admin should be cleared",
+        );
+        accept_admin(deps.as_mut(), mock_info("typo_admin", &[]))
+            .expect_err("a cancelled proposal should not be accepted");
+    }
+}
@@ -1,17 +1,16 @@
-use crate::storage::ask_order_storage::{
-    delete_ask_order_by_id, get_ask_order_by_id, get_ask_orders_by_collateral_id,
-};
+use crate::storage::ask_order_storage::get_ask_order_by_id;
 use crate::storage::contract_info::get_contract_info;
+use crate::storage::contract_role_storage::address_has_role;
+use crate::types::core::contract_role::ContractRole;
 use crate::types::core::error::ContractError;
-use crate::types::request::ask_types::ask_collateral::AskCollateral;
+use crate::util::cancel_order_utilities::cancel_ask_order;
 use crate::util::extensions::ResultExtensions;
-use crate::util::provenance_utilities::{release_marker_from_contract, replace_scope_owner};
-use cosmwasm_std::{to_binary, BankMsg, CosmosMsg, DepsMut, Env, MessageInfo, Response};
-use provwasm_std::{write_scope, ProvenanceMsg, ProvenanceQuerier, ProvenanceQuery};
+use cosmwasm_std::{to_binary, DepsMut, Env, MessageInfo, Response};
+use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
 
 // cancel ask entrypoint
 pub fn cancel_ask(
-    deps: DepsMut<ProvenanceQuery>,
+    mut deps: DepsMut<ProvenanceQuery>,
     env: Env,
     info: MessageInfo,
     id: String,
@@ -31,60 +30,26 @@ pub fn cancel_ask(
         .to_err();
     }
     let ask_order = get_ask_order_by_id(deps.storage, &id)?;
-    // Only the owner of the ask and the admin can cancel an ask
-    if info.sender != ask_order.owner && info.sender != get_contract_info(deps.storage)?.admin {
+    // Only the owner of the ask and order cancellers can cancel an ask
+    if info.sender != ask_order.owner
+        && !address_has_role(
+            deps.storage,
+            &get_contract_info(deps.storage)?,
+            &info.sender,
+            &ContractRole::OrderCanceller,
+        )
+    {
         return ContractError::Unauthorized.to_err();
     }
-    let mut messages: Vec<CosmosMsg<ProvenanceMsg>> = vec![];
-    let collateral_released = match &ask_order.collateral {
-        AskCollateral::CoinTrade(collateral) => {
-            messages.push(CosmosMsg::Bank(BankMsg::Send {
-                to_address: ask_order.owner.to_string(),
-                amount: collateral.base.to_owned(),
-            }));
-            true
-        }
-        AskCollateral::MarkerTrade(collateral) => {
-            messages.append(&mut release_marker_from_contract(
-                &collateral.marker_denom,
-                &env.contract.address,
-                &collateral.removed_permissions,
-            )?);
-            true
-        }
-        AskCollateral::MarkerShareSale(collateral) => {
-            // Only release the marker if this is the final remaining ask for the given marker.
-            // Multiple marker share sales can be created for a single marker while it is held by
-            // the contract, so this check ensures that the marker is only relinquished when the
-            // final sale is cancelled.
-            if get_ask_orders_by_collateral_id(deps.storage, collateral.marker_address.as_str())
-                .len()
-                <= 1
-            {
-                messages.append(&mut release_marker_from_contract(
-                    &collateral.marker_denom,
-                    &env.contract.address,
-                    &collateral.removed_permissions,
-                )?);
-                true
-            } else {
-                false
-            }
-        }
-        AskCollateral::ScopeTrade(collateral) => {
-            let mut scope =
-                ProvenanceQuerier::new(&deps.querier).get_scope(&collateral.scope_address)?;
-            scope = replace_scope_owner(scope, ask_order.owner.to_owned());
-            messages.push(write_scope(scope, vec![env.contract.address])?);
-            true
-        }
-    };
-    delete_ask_order_by_id(deps.storage, &ask_order.id)?;
+    let cancellation_result = cancel_ask_order(&mut deps, &env, &ask_order)?;
     Response::new()
-        .add_messages(messages)
+        .add_messages(cancellation_result.messages)
         .add_attribute("action", "cancel_ask")
         .add_attribute("ask_id", &ask_order.id)
-        .add_attribute("collateral_released", collateral_released.to_string())
+        .add_attribute(
+            "collateral_released",
+            cancellation_result.collateral_released.to_string(),
+        )
         .set_data(to_binary(&ask_order)?)
         .to_ok()
 }
@@ -94,21 +59,26 @@ mod tests {
     use super::*;
     use crate::contract::execute;
     use crate::execute::create_ask::create_ask;
+    use crate::storage::ask_order_storage::get_ask_orders_by_collateral_id;
     use crate::storage::ask_order_storage::insert_ask_order;
+    use crate::storage::scope_stake_storage::register_scope_stake;
     use crate::test::cosmos_type_helpers::single_attribute_for_key;
     use crate::test::mock_instantiate::{default_instantiate, DEFAULT_ADMIN_ADDRESS};
     use crate::test::mock_marker::{MockMarker, DEFAULT_MARKER_DENOM, DEFAULT_MARKER_HOLDINGS};
     use crate::test::mock_scope::{MockScope, DEFAULT_SCOPE_ADDR};
+    use crate::test::mock_scopes_querier::mock_dependencies_with_scopes;
     use crate::test::request_helpers::mock_ask_order;
     use crate::types::core::msg::ExecuteMsg;
     use crate::types::request::ask_types::ask::Ask;
+    use crate::types::request::ask_types::ask_collateral::AskCollateral;
     use crate::types::request::ask_types::ask_order::AskOrder;
     use crate::types::request::share_sale_type::ShareSaleType;
     use crate::util::constants::NHASH;
     use cosmwasm_std::testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR};
-    use cosmwasm_std::{coins, from_binary, CosmosMsg, Storage};
+    use cosmwasm_std::{coins, from_binary, Addr, BankMsg, CosmosMsg, Storage};
     use provwasm_mocks::mock_dependencies;
-    use provwasm_std::{MarkerMsgParams, MetadataMsgParams, PartyType, ProvenanceMsgParams};
+    use provwasm_std::ProvenanceMsg;
+    use provwasm_std::{MarkerMsgParams, MetadataMsgParams, Party, PartyType, ProvenanceMsgParams};
 
     #[test]
     fn cancel_coin_ask_as_asker() {
@@ -120,6 +90,41 @@ mod tests {
         do_coin_trade_cancel_ask(DEFAULT_ADMIN_ADDRESS);
     }
 
+    #[test]
+    fn test_cancel_partially_filled_coin_trade_ask_refunds_remaining_base() {
+        let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
+        insert_ask_order(
+            deps.as_mut().storage,
+            &mock_ask_order(AskCollateral::partial_fill_coin_trade(
+                &coins(100, "base"),
+                &coins(200, "quote"),
+                25,
+            )),
+        )
+        .expect("expected the ask order to be inserted");
+        let response = cancel_ask(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &[]),
+            "ask_id".to_string(),
+        )
+        .expect("the partially filled ask should be cancelled without issue");
+        assert_cancel_ask_succeeded(deps.as_ref().storage, &response, "ask_id", true);
+        assert_eq!(
+            vec![CosmosMsg::Bank(BankMsg::Send {
+                to_address: "asker".to_string(),
+                amount: coins(25, "base"),
+            })],
+            response
+                .messages
+                .iter()
+                .map(|msg| msg.msg.to_owned())
+                .collect::<Vec<CosmosMsg<ProvenanceMsg>>>(),
+            "only the remaining base should be refunded to the asker",
+        );
+    }
+
     #[test]
     fn test_cancel_marker_trade_ask_as_asker() {
         do_marker_trade_cancel_ask("asker");
@@ -378,7 +383,7 @@ mod tests {
             None,
         )
         .expect("marker trade ask should be created without issue");
-        get_ask_order_by_id(&mut deps.storage, &ask_id)
+        get_ask_order_by_id(&deps.storage, &ask_id)
             .expect("an ask order should be available in storage");
         let response = cancel_ask(
             deps.as_mut(),
@@ -542,4 +547,146 @@ mod tests {
             msg => panic!("unexpected message when cancelling scope trade: {:?}", msg),
         };
     }
+
+    #[test]
+    fn test_cancel_scope_bundle_trade_returns_all_scopes() {
+        let mut deps = mock_dependencies_with_scopes(&["scope1", "scope2"], MOCK_CONTRACT_ADDR);
+        default_instantiate(deps.as_mut());
+        create_ask(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &[]),
+            Ask::new_scope_bundle_trade("ask_id", &["scope1", "scope2"], &coins(500, "quote")),
+            None,
+        )
+        .expect("the bundle ask should be created successfully");
+        let response = cancel_ask(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &[]),
+            "ask_id".to_string(),
+        )
+        .expect("the bundle ask should be cancelled successfully");
+        assert_eq!(
+            2,
+            response.messages.len(),
+            "a write should be produced for each scope in the bundle",
+        );
+        response.messages.iter().for_each(|msg| match &msg.msg {
+            CosmosMsg::Custom(ProvenanceMsg {
+                params: ProvenanceMsgParams::Metadata(MetadataMsgParams::WriteScope { scope, .. }),
+                ..
+            }) => {
+                assert_eq!(
+                    "asker",
+                    scope.value_owner_address.as_str(),
+                    "the asker should be returned value ownership of each scope",
+                );
+                assert!(
+                    scope
+                        .owners
+                        .iter()
+                        .any(|owner| owner.address.as_str() == "asker"
+                            && owner.role == PartyType::Owner),
+                    "the asker should be returned ownership of each scope",
+                );
+            }
+            msg => panic!("unexpected message: {:?}", msg),
+        });
+        assert!(
+            get_ask_orders_by_collateral_id(deps.as_ref().storage, "scope1").is_empty(),
+            "the bundled scopes should no longer be tracked after the cancellation",
+        );
+    }
+
+    #[test]
+    fn test_cancel_co_owned_scope_trade_returns_only_sold_stakes() {
+        let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
+        deps.querier.with_scope(
+            MockScope {
+                owners: vec![
+                    Party {
+                        address: Addr::unchecked(MOCK_CONTRACT_ADDR),
+                        role: PartyType::Owner,
+                    },
+                    Party {
+                        address: Addr::unchecked("co_owner"),
+                        role: PartyType::Owner,
+                    },
+                ],
+                value_owner_address: Addr::unchecked(MOCK_CONTRACT_ADDR),
+                ..MockScope::default()
+            }
+            .to_scope(),
+        );
+        register_scope_stake(
+            deps.as_mut().storage,
+            DEFAULT_SCOPE_ADDR,
+            &Addr::unchecked("partner"),
+        )
+        .expect("the stake should be registered");
+        register_scope_stake(
+            deps.as_mut().storage,
+            DEFAULT_SCOPE_ADDR,
+            &Addr::unchecked("asker"),
+        )
+        .expect("the stake should be registered");
+        create_ask(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &[]),
+            Ask::new_co_owned_scope_trade(
+                "ask_id",
+                DEFAULT_SCOPE_ADDR,
+                &coins(100, NHASH),
+                &["partner", "asker"],
+            ),
+            None,
+        )
+        .expect("the co-owned scope ask should be created successfully");
+        let response = cancel_ask(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &[]),
+            "ask_id".to_string(),
+        )
+        .expect("the co-owned scope ask should be cancelled successfully");
+        assert_eq!(
+            1,
+            response.messages.len(),
+            "a single write scope message should be produced",
+        );
+        match &response.messages.first().unwrap().msg {
+            CosmosMsg::Custom(ProvenanceMsg {
+                params: ProvenanceMsgParams::Metadata(MetadataMsgParams::WriteScope { scope, .. }),
+                ..
+            }) => {
+                assert_eq!(
+                    vec![
+                        Party {
+                            address: Addr::unchecked("co_owner"),
+                            role: PartyType::Owner,
+                        },
+                        Party {
+                            address: Addr::unchecked("partner"),
+                            role: PartyType::Owner,
+                        },
+                        Party {
+                            address: Addr::unchecked("asker"),
+                            role: PartyType::Owner,
+                        },
+                    ],
+                    scope.owners,
+                    "the sold stakes should be returned and the other owner should be unchanged",
+                );
+                assert_eq!(
+                    "partner",
+                    scope.value_owner_address.as_str(),
+                    "value ownership should be returned to the first sold owner rather than the asker",
+                );
+            }
+            msg => panic!("unexpected message: {:?}", msg),
+        }
+    }
 }
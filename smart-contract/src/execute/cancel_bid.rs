@@ -1,8 +1,11 @@
-use crate::storage::bid_order_storage::{delete_bid_order_by_id, get_bid_order_by_id};
+use crate::storage::bid_order_storage::get_bid_order_by_id;
 use crate::storage::contract_info::get_contract_info;
+use crate::storage::contract_role_storage::address_has_role;
+use crate::types::core::contract_role::ContractRole;
 use crate::types::core::error::ContractError;
+use crate::util::cancel_order_utilities::cancel_bid_order;
 use crate::util::extensions::ResultExtensions;
-use cosmwasm_std::{to_binary, BankMsg, DepsMut, MessageInfo, Response};
+use cosmwasm_std::{to_binary, DepsMut, MessageInfo, Response};
 use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
 
 // cancel bid entrypoint
@@ -26,18 +29,21 @@ pub fn cancel_bid(
         .to_err();
     }
     let bid_order = get_bid_order_by_id(deps.storage, &id)?;
-    // Only the owner of the bid and the admin can cancel a bid
-    if info.sender != bid_order.owner && info.sender != get_contract_info(deps.storage)?.admin {
+    // Only the owner of the bid and order cancellers can cancel a bid
+    if info.sender != bid_order.owner
+        && !address_has_role(
+            deps.storage,
+            &get_contract_info(deps.storage)?,
+            &info.sender,
+            &ContractRole::OrderCanceller,
+        )
+    {
         return ContractError::Unauthorized.to_err();
     }
-    let coin_to_send = bid_order.collateral.get_quote();
     // Remove the bid order from storage now that it is no longer needed
-    delete_bid_order_by_id(deps.storage, &id)?;
+    let refund_message = cancel_bid_order(deps.storage, &bid_order)?;
     Response::new()
-        .add_message(BankMsg::Send {
-            to_address: bid_order.owner.to_string(),
-            amount: coin_to_send,
-        })
+        .add_message(refund_message)
         .add_attribute("action", "cancel_bid")
         .add_attribute("bid_id", &bid_order.id)
         .set_data(to_binary(&bid_order)?)
@@ -48,12 +54,14 @@ pub fn cancel_bid(
 mod tests {
     use crate::execute::cancel_bid::cancel_bid;
     use crate::execute::create_bid::create_bid;
+    use crate::execute::grant_role::grant_role;
     use crate::storage::bid_order_storage::{get_bid_order_by_id, insert_bid_order};
     use crate::test::cosmos_type_helpers::single_attribute_for_key;
     use crate::test::mock_instantiate::{default_instantiate, DEFAULT_ADMIN_ADDRESS};
     use crate::test::mock_marker::{MockMarker, DEFAULT_MARKER_DENOM};
     use crate::test::mock_scope::DEFAULT_SCOPE_ADDR;
     use crate::test::request_helpers::mock_bid_order;
+    use crate::types::core::contract_role::ContractRole;
     use crate::types::core::error::ContractError;
     use crate::types::request::bid_types::bid::Bid;
     use crate::types::request::bid_types::bid_collateral::BidCollateral;
@@ -74,6 +82,50 @@ mod tests {
         do_coin_trade_cancel_bid(DEFAULT_ADMIN_ADDRESS);
     }
 
+    #[test]
+    fn test_cancel_coin_trade_as_order_canceller() {
+        let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
+        for (address, role) in [
+            ("canceller", ContractRole::OrderCanceller),
+            ("match_bot", ContractRole::Matcher),
+        ] {
+            grant_role(
+                deps.as_mut(),
+                mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+                address.to_string(),
+                role,
+            )
+            .expect("the role should be granted");
+        }
+        create_bid(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder", &coins(150, "quote")),
+            Bid::new_coin_trade("bid_id", &coins(100, "base")),
+            None,
+        )
+        .expect("expected bid creation to succeed");
+        let err = cancel_bid(
+            deps.as_mut(),
+            mock_info("match_bot", &[]),
+            "bid_id".to_string(),
+        )
+        .expect_err("an account without the order canceller role should not cancel the bid");
+        assert!(
+            matches!(err, ContractError::Unauthorized),
+            "an unauthorized error should be returned, but got: {:?}",
+            err,
+        );
+        let response = cancel_bid(
+            deps.as_mut(),
+            mock_info("canceller", &[]),
+            "bid_id".to_string(),
+        )
+        .expect("an order canceller should be able to cancel the bid");
+        assert_cancel_bid_succeeded(deps.as_ref().storage, &response, &coins(150, "quote"));
+    }
+
     #[test]
     fn test_cancel_marker_trade_as_bidder() {
         do_marker_trade_cancel_bid("bidder");
@@ -1,6 +1,8 @@
 use crate::storage::ask_order_storage::{get_ask_order_by_id, insert_ask_order};
+use crate::storage::scope_stake_storage::remove_scope_stake_registration;
 use crate::types::core::error::ContractError;
 use crate::types::request::ask_types::ask::Ask;
+use crate::types::request::ask_types::ask_collateral::{AskCollateral, ScopeTradeAskCollateral};
 use crate::types::request::request_descriptor::RequestDescriptor;
 use crate::util::create_ask_order_utilities::{
     create_ask_order, AskCreationType, AskOrderCreationResponse,
@@ -32,6 +34,17 @@ pub fn create_ask(
         ask_fee_msg,
     } = create_ask_order(&deps, &env, &info, ask, descriptor, AskCreationType::New)?;
     insert_ask_order(deps.storage, &ask_order)?;
+    // The stake registrations of a co-owned scope are consumed by the ask that lists them
+    if let AskCollateral::ScopeTrade(ScopeTradeAskCollateral {
+        scope_address,
+        sold_owner_addresses: Some(sold_owner_addresses),
+        ..
+    }) = &ask_order.collateral
+    {
+        for sold_owner_address in sold_owner_addresses {
+            remove_scope_stake_registration(deps.storage, scope_address, sold_owner_address)?;
+        }
+    }
     let mut response = Response::new()
         .add_messages(messages)
         .add_attribute("action", "create_ask")
@@ -52,8 +65,12 @@ pub fn create_ask(
 mod tests {
     use crate::contract::execute;
     use crate::execute::create_ask::create_ask;
+    use crate::execute::register_scope_stake::register_scope_stake;
+    use crate::execute::update_settings::update_settings;
     use crate::storage::ask_order_storage::{get_ask_order_by_id, insert_ask_order};
+    use crate::storage::scope_stake_storage::is_scope_stake_registered;
     use crate::test::cosmos_type_helpers::single_attribute_for_key;
+    use crate::test::error_helpers::assert_validation_error_message;
     use crate::test::mock_instantiate::{
         default_instantiate, test_instantiate, TestInstantiate, DEFAULT_ADMIN_ADDRESS,
     };
@@ -61,6 +78,8 @@ mod tests {
         MockMarker, DEFAULT_MARKER_ADDRESS, DEFAULT_MARKER_DENOM, DEFAULT_MARKER_HOLDINGS,
     };
     use crate::test::mock_scope::{MockScope, DEFAULT_SCOPE_ADDR};
+    use crate::test::mock_scopes_querier::mock_dependencies_with_scopes;
+    use crate::types::core::contract_status::ContractStatus;
     use crate::types::core::error::ContractError;
     use crate::types::core::msg::ExecuteMsg;
     use crate::types::request::ask_types::ask::Ask;
@@ -68,12 +87,15 @@ mod tests {
     use crate::types::request::ask_types::ask_order::AskOrder;
     use crate::types::request::request_descriptor::{AttributeRequirement, RequestDescriptor};
     use crate::types::request::request_type::RequestType;
+    use crate::types::request::settings_update::SettingsUpdate;
     use crate::types::request::share_sale_type::ShareSaleType;
     use crate::util::constants::NHASH;
     use cosmwasm_std::testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR};
     use cosmwasm_std::{coin, coins, from_binary, Addr, CosmosMsg, Response, Storage, Uint128};
     use provwasm_mocks::mock_dependencies;
-    use provwasm_std::{MarkerMsgParams, MsgFeesMsgParams, ProvenanceMsg, ProvenanceMsgParams};
+    use provwasm_std::{
+        MarkerMsgParams, MsgFeesMsgParams, Party, PartyType, ProvenanceMsg, ProvenanceMsgParams,
+    };
 
     #[test]
     fn test_coin_trade_with_valid_data() {
@@ -325,6 +347,69 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_new_ask_is_rejected_when_creation_is_halted() {
+        let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
+        update_settings(
+            deps.as_mut(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+            SettingsUpdate {
+                new_create_ask_nhash_fee: None,
+                new_create_bid_nhash_fee: None,
+                new_settlement_fees: None,
+                new_fee_collector_address: None,
+                new_contract_status: Some(ContractStatus::CreationHalted),
+                new_required_trade_attributes: None,
+            },
+        )
+        .expect("the contract status should be updated");
+        let err = create_ask(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &coins(100, "base")),
+            Ask::new_coin_trade("ask_id", &coins(100, "quote")),
+            None,
+        )
+        .expect_err("an ask should not be created while creation is halted");
+        match err {
+            ContractError::InvalidRequest { message } => assert_eq!(
+                "asks and bids cannot be created or updated while the contract status is [creation_halted]",
+                message,
+                "the correct error message should be produced",
+            ),
+            e => panic!("unexpected error encountered: {:?}", e),
+        };
+        assert!(
+            get_ask_order_by_id(deps.as_ref().storage, "ask_id").is_err(),
+            "the ask should not be stored",
+        );
+    }
+
+    #[test]
+    fn test_new_ask_is_rejected_for_past_expiration_time() {
+        let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
+        let env = mock_env();
+        let err = create_ask(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("asker", &coins(100, "base")),
+            Ask::new_coin_trade("ask_id", &coins(100, "quote")),
+            Some(RequestDescriptor::basic("expired").with_expiration_time(env.block.time)),
+        )
+        .expect_err("expected an error to be returned when the ask was already expired");
+        assert_validation_error_message(
+            err,
+            format!(
+                "AskOrder [ask_id] specified expiration time [{}], which is not after the current block time [{}]",
+                env.block.time, env.block.time,
+            ),
+        );
+        get_ask_order_by_id(deps.as_ref().storage, "ask_id")
+            .expect_err("the ask order should not be stored when validation fails");
+    }
+
     #[test]
     fn test_coin_trade_with_invalid_data() {
         let mut deps = mock_dependencies(&[]);
@@ -756,6 +841,101 @@ mod tests {
         };
     }
 
+    #[test]
+    fn test_co_owned_scope_trade_requires_a_registered_sold_owner() {
+        let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
+        let co_owned_scope = |escrowed_owner: &str| {
+            MockScope {
+                owners: vec![
+                    Party {
+                        address: Addr::unchecked(escrowed_owner),
+                        role: PartyType::Owner,
+                    },
+                    Party {
+                        address: Addr::unchecked("co_owner"),
+                        role: PartyType::Owner,
+                    },
+                ],
+                value_owner_address: Addr::unchecked(MOCK_CONTRACT_ADDR),
+                ..MockScope::default()
+            }
+            .to_scope()
+        };
+        // The seller registers its stake while it still owns it, and then escrows it
+        deps.querier.with_scope(co_owned_scope("seller"));
+        register_scope_stake(
+            deps.as_mut(),
+            mock_info("seller", &[]),
+            DEFAULT_SCOPE_ADDR.to_string(),
+        )
+        .expect("the seller should be able to register its stake");
+        deps.querier.with_scope(co_owned_scope(MOCK_CONTRACT_ADDR));
+        for sender in ["stranger", "co_owner"] {
+            let err = create_ask(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(sender, &[]),
+                Ask::new_co_owned_scope_trade(
+                    "ask_id",
+                    DEFAULT_SCOPE_ADDR,
+                    &coins(100, NHASH),
+                    &["seller"],
+                ),
+                None,
+            )
+            .expect_err("an account that did not sell its stake should not list it");
+            assert!(
+                matches!(err, ContractError::Unauthorized),
+                "[{}]: an unauthorized error should be returned, but got: {:?}",
+                sender,
+                err,
+            );
+        }
+        let err = create_ask(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("stranger", &[]),
+            Ask::new_co_owned_scope_trade(
+                "ask_id",
+                DEFAULT_SCOPE_ADDR,
+                &coins(100, NHASH),
+                &["seller", "stranger"],
+            ),
+            None,
+        )
+        .expect_err("an account that never held a stake should not list itself as a sold owner");
+        match err {
+            ContractError::InvalidScopeOwner { explanation, .. } => assert_eq!(
+                "sold owner [stranger] did not register its stake before it was escrowed",
+                explanation,
+                "the correct error explanation should be produced",
+            ),
+            e => panic!("unexpected error: {:?}", e),
+        };
+        create_ask(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("seller", &[]),
+            Ask::new_co_owned_scope_trade(
+                "ask_id",
+                DEFAULT_SCOPE_ADDR,
+                &coins(100, NHASH),
+                &["seller"],
+            ),
+            None,
+        )
+        .expect("the owner that sold the stake should be able to list it");
+        assert!(
+            !is_scope_stake_registered(
+                deps.as_ref().storage,
+                DEFAULT_SCOPE_ADDR,
+                &Addr::unchecked("seller"),
+            ),
+            "the seller's registration should be consumed by the ask",
+        );
+    }
+
     fn assert_valid_response<S: Into<String>>(
         storage: &dyn Storage,
         response: &Response<ProvenanceMsg>,
@@ -809,7 +989,7 @@ mod tests {
         test_instantiate(
             deps.as_mut(),
             TestInstantiate {
-                create_ask_nhash_fee: ask_fee.map(Uint128::new).clone(),
+                create_ask_nhash_fee: ask_fee.map(Uint128::new),
                 ..TestInstantiate::default()
             },
         );
@@ -893,7 +1073,7 @@ mod tests {
         test_instantiate(
             deps.as_mut(),
             TestInstantiate {
-                create_ask_nhash_fee: ask_fee.map(Uint128::new).clone(),
+                create_ask_nhash_fee: ask_fee.map(Uint128::new),
                 ..TestInstantiate::default()
             },
         );
@@ -1020,7 +1200,7 @@ mod tests {
         test_instantiate(
             deps.as_mut(),
             TestInstantiate {
-                create_ask_nhash_fee: ask_fee.map(Uint128::new).clone(),
+                create_ask_nhash_fee: ask_fee.map(Uint128::new),
                 ..TestInstantiate::default()
             },
         );
@@ -1179,10 +1359,7 @@ mod tests {
             "all the correct permissions should be revoked, but some were not",
         );
         assert!(
-            matches!(
-                collateral.sale_type,
-                ShareSaleType::SingleTransaction { .. }
-            ),
+            matches!(collateral.sale_type, ShareSaleType::SingleTransaction),
             "the share sale type should be properly copied into the ask order from the request",
         );
     }
@@ -1192,7 +1369,7 @@ mod tests {
         test_instantiate(
             deps.as_mut(),
             TestInstantiate {
-                create_ask_nhash_fee: ask_fee.map(Uint128::new).clone(),
+                create_ask_nhash_fee: ask_fee.map(Uint128::new),
                 ..TestInstantiate::default()
             },
         );
@@ -1293,4 +1470,84 @@ mod tests {
             "the quote should be properly copied into the ask order's collateral",
         );
     }
+
+    #[test]
+    fn test_create_scope_bundle_trade_rejects_scopes_held_by_other_asks() {
+        let mut deps =
+            mock_dependencies_with_scopes(&["scope1", "scope2", "scope3"], MOCK_CONTRACT_ADDR);
+        default_instantiate(deps.as_mut());
+        create_ask(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &[]),
+            Ask::new_scope_bundle_trade("bundle_id", &["scope1", "scope2"], &coins(500, "quote")),
+            None,
+        )
+        .expect("the bundle ask should be created successfully");
+        let ask_order = get_ask_order_by_id(deps.as_ref().storage, "bundle_id")
+            .expect("the bundle ask should be stored");
+        assert_eq!(RequestType::ScopeBundleTrade, ask_order.ask_type);
+        assert_eq!(
+            AskCollateral::scope_bundle_trade(&["scope1", "scope2"], &coins(500, "quote")),
+            ask_order.collateral,
+            "the bundle collateral should include every scope",
+        );
+        let expect_duplicate_error = |err: ContractError, scope_address: &str| match err {
+            ContractError::InvalidRequest { message } => assert_eq!(
+                format!(
+                    "only one scope trade can exist at a time for scope [{}]",
+                    scope_address
+                ),
+                message,
+                "the correct error message should be produced",
+            ),
+            e => panic!("unexpected error: {:?}", e),
+        };
+        expect_duplicate_error(
+            create_ask(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("asker", &[]),
+                Ask::new_scope_bundle_trade(
+                    "bundle_id_2",
+                    &["scope3", "scope2"],
+                    &coins(500, "quote"),
+                ),
+                None,
+            )
+            .expect_err("a scope already in a bundle should not be bundled again"),
+            "scope2",
+        );
+        expect_duplicate_error(
+            create_ask(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("asker", &[]),
+                Ask::new_scope_trade("scope_ask_id", "scope1", &coins(500, "quote")),
+                None,
+            )
+            .expect_err("a scope already in a bundle should not be sold alone"),
+            "scope1",
+        );
+        create_ask(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &[]),
+            Ask::new_scope_bundle_trade("bundle_id", &["scope2", "scope1"], &coins(600, "quote")),
+            None,
+        )
+        .expect_err("a new ask cannot reuse an existing ask id");
+        let err = create_ask(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &[]),
+            Ask::new_scope_bundle_trade("bundle_id_3", &["scope3", "scope3"], &coins(500, "quote")),
+            None,
+        )
+        .expect_err("duplicate scopes in a bundle should be rejected");
+        assert_validation_error_message(
+            err,
+            "AskOrder [bundle_id_3] of type scope bundle trade must not include duplicate scope addresses",
+        );
+    }
 }
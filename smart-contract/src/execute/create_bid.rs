@@ -51,6 +51,7 @@ mod tests {
     use crate::execute::create_bid::create_bid;
     use crate::storage::bid_order_storage::{get_bid_order_by_id, insert_bid_order};
     use crate::test::cosmos_type_helpers::single_attribute_for_key;
+    use crate::test::error_helpers::assert_validation_error_message;
     use crate::test::mock_instantiate::{
         default_instantiate, test_instantiate, TestInstantiate, DEFAULT_ADMIN_ADDRESS,
     };
@@ -139,6 +140,31 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_new_bid_is_rejected_for_past_expiration_time() {
+        let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
+        let env = mock_env();
+        let expiration_time = env.block.time.minus_seconds(60);
+        let err = create_bid(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("bidder", &coins(100, "quote")),
+            Bid::new_coin_trade("bid_id", &coins(100, "base")),
+            Some(RequestDescriptor::basic("expired").with_expiration_time(expiration_time)),
+        )
+        .expect_err("expected an error to be returned when the bid was already expired");
+        assert_validation_error_message(
+            err,
+            format!(
+                "BidOrder [bid_id] specified expiration time [{}], which is not after the current block time [{}]",
+                expiration_time, env.block.time,
+            ),
+        );
+        get_bid_order_by_id(deps.as_ref().storage, "bid_id")
+            .expect_err("the bid order should not be stored when validation fails");
+    }
+
     #[test]
     fn test_coin_trade_with_invalid_data() {
         let mut deps = mock_dependencies(&[]);
@@ -658,7 +684,7 @@ mod tests {
         test_instantiate(
             deps.as_mut(),
             TestInstantiate {
-                create_bid_nhash_fee: bid_fee.map(Uint128::new).clone(),
+                create_bid_nhash_fee: bid_fee.map(Uint128::new),
                 ..TestInstantiate::default()
             },
         );
@@ -711,7 +737,7 @@ mod tests {
         test_instantiate(
             deps.as_mut(),
             TestInstantiate {
-                create_bid_nhash_fee: bid_fee.map(Uint128::new).clone(),
+                create_bid_nhash_fee: bid_fee.map(Uint128::new),
                 ..TestInstantiate::default()
             },
         );
@@ -757,7 +783,7 @@ mod tests {
         test_instantiate(
             deps.as_mut(),
             TestInstantiate {
-                create_bid_nhash_fee: bid_fee.map(Uint128::new).clone(),
+                create_bid_nhash_fee: bid_fee.map(Uint128::new),
                 ..TestInstantiate::default()
             },
         );
@@ -809,7 +835,7 @@ mod tests {
         test_instantiate(
             deps.as_mut(),
             TestInstantiate {
-                create_bid_nhash_fee: bid_fee.map(Uint128::new).clone(),
+                create_bid_nhash_fee: bid_fee.map(Uint128::new),
                 ..TestInstantiate::default()
             },
         );
@@ -1,38 +1,33 @@
 use crate::storage::ask_order_storage::{
-    delete_ask_order_by_id, get_ask_order_by_id, get_ask_orders_by_collateral_id, update_ask_order,
+    delete_ask_order_by_id, get_ask_order_by_id, update_ask_order,
 };
 use crate::storage::bid_order_storage::{
     delete_bid_order_by_id, get_bid_order_by_id, update_bid_order,
 };
-use crate::storage::contract_info::get_contract_info;
+use crate::storage::contract_info::{get_contract_info, ContractInfoV2};
+use crate::storage::contract_role_storage::address_has_role;
+use crate::storage::match_record_storage::insert_match_record;
+use crate::types::core::contract_role::ContractRole;
 use crate::types::core::error::ContractError;
-use crate::types::request::admin_match_options::{AdminMatchOptions, OverrideQuoteSource};
-use crate::types::request::ask_types::ask_collateral::{
-    AskCollateral, CoinTradeAskCollateral, MarkerShareSaleAskCollateral, MarkerTradeAskCollateral,
-    ScopeTradeAskCollateral,
-};
+use crate::types::request::admin_match_options::AdminMatchOptions;
 use crate::types::request::ask_types::ask_order::AskOrder;
-use crate::types::request::bid_types::bid_collateral::{
-    BidCollateral, CoinTradeBidCollateral, MarkerShareSaleBidCollateral, MarkerTradeBidCollateral,
-    ScopeTradeBidCollateral,
-};
 use crate::types::request::bid_types::bid_order::BidOrder;
-use crate::types::request::share_sale_type::ShareSaleType;
-use crate::util::coin_utilities::{
-    calculate_marker_share_sale_bid_totals, multiply_coins_by_amount, MSSBidTotalsCalc,
-};
+use crate::types::request::match_record::MatchRecord;
 use crate::util::extensions::ResultExtensions;
-use crate::util::provenance_utilities::{release_marker_from_contract, replace_scope_owner};
+use crate::util::provenance_utilities::format_coin_display;
+use crate::util::settlement_fee_utilities::get_quote_payment;
+use crate::util::settlement_utilities::{plan_settlement, OrderUpdate, SettlementPlan};
+use crate::validation::contract_status_validation::validate_matching_allowed;
 use crate::validation::execute_match_validation::validate_match;
 use crate::validation::validation_handler::ValidationHandler;
-use cosmwasm_std::{BankMsg, CosmosMsg, DepsMut, Env, MessageInfo, Response, Uint128};
-use provwasm_std::{
-    withdraw_coins, write_scope, ProvenanceMsg, ProvenanceQuerier, ProvenanceQuery,
+use cosmwasm_std::{
+    Addr, Coin, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Response, Storage, Uint128,
 };
+use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
 
 // match and execute an ask and bid order
 pub fn execute_match(
-    deps: DepsMut<ProvenanceQuery>,
+    mut deps: DepsMut<ProvenanceQuery>,
     env: Env,
     info: MessageInfo,
     ask_id: String,
@@ -56,344 +51,211 @@ pub fn execute_match(
         }
         .to_err();
     }
-    let ask_order = get_ask_order_by_id(deps.storage, &ask_id)?;
-    let bid_order = get_bid_order_by_id(deps.storage, &bid_id)?;
-    // only the admin or the asker may execute matches
-    if info.sender != ask_order.owner && info.sender != contract_info.admin {
-        return ContractError::Unauthorized.to_err();
-    }
-    // Ensure match is viable before trying to actually execute the match
-    validate_match(&deps.as_ref(), &ask_order, &bid_order, &admin_match_options)?;
-    let execute_result = match &ask_order.collateral {
-        AskCollateral::CoinTrade(collateral) => execute_coin_trade(
-            deps,
-            &ask_order,
-            &bid_order,
-            collateral,
-            bid_order.collateral.get_coin_trade()?,
-        )?,
-        AskCollateral::MarkerTrade(collateral) => execute_marker_trade(
-            deps,
-            &env,
-            &ask_order,
-            &bid_order,
-            collateral,
-            bid_order.collateral.get_marker_trade()?,
-        )?,
-        AskCollateral::MarkerShareSale(collateral) => execute_marker_share_sale(
-            deps,
-            &env,
-            &ask_order,
-            &bid_order,
-            collateral,
-            bid_order.collateral.get_marker_share_sale()?,
-            admin_match_options.and_then(|opt| match opt {
-                AdminMatchOptions::MarkerShareSale {
-                    override_quote_source,
-                } => override_quote_source,
-                _ => None,
-            }),
-        )?,
-        AskCollateral::ScopeTrade(collateral) => execute_scope_trade(
-            deps,
-            &env,
-            &ask_order,
-            &bid_order,
-            collateral,
-            bid_order.collateral.get_scope_trade()?,
-        )?,
-    };
+    validate_matching_allowed(&contract_info)?;
+    let match_result = match_orders(
+        deps.branch(),
+        &env,
+        &info.sender,
+        &contract_info,
+        &ask_id,
+        &bid_id,
+        admin_match_options,
+    )?;
     Response::new()
-        .add_messages(execute_result.messages)
+        .add_messages(match_result.messages)
         .add_attribute("action", "execute")
-        .add_attribute("ask_id", &ask_order.id)
-        .add_attribute("bid_id", &bid_order.id)
-        .add_attribute("ask_deleted", execute_result.ask_deleted.to_string())
-        .add_attribute("bid_deleted", execute_result.bid_deleted.to_string())
+        .add_attribute("ask_id", &ask_id)
+        .add_attribute("bid_id", &bid_id)
+        .add_attribute("ask_deleted", match_result.ask_deleted.to_string())
+        .add_attribute("bid_deleted", match_result.bid_deleted.to_string())
         .add_attribute(
             "collateral_released",
-            execute_result.collateral_released.to_string(),
+            match_result.collateral_released.to_string(),
+        )
+        .add_attribute("match_record_id", match_result.match_record_id.to_string())
+        .add_attribute(
+            "settlement_fee",
+            format_coin_display(&match_result.settlement_fee),
         )
         .to_ok()
 }
 
-struct ExecuteResults {
+/// The outcome of a successfully executed match, before it is converted to a response.
+pub struct MatchOrdersResult {
     pub messages: Vec<CosmosMsg<ProvenanceMsg>>,
     pub ask_deleted: bool,
     pub bid_deleted: bool,
     pub collateral_released: bool,
+    pub match_record_id: Uint128,
+    pub settlement_fee: Vec<Coin>,
 }
 
-fn execute_coin_trade(
+/// Loads, authorizes, validates and settles a single ask and bid pair, storing a match record when
+/// the settlement succeeds.
+pub fn match_orders(
     deps: DepsMut<ProvenanceQuery>,
-    ask_order: &AskOrder,
-    bid_order: &BidOrder,
-    ask_collateral: &CoinTradeAskCollateral,
-    bid_collateral: &CoinTradeBidCollateral,
-) -> Result<ExecuteResults, ContractError> {
-    // Remove ask and bid - this transaction has concluded
-    delete_ask_order_by_id(deps.storage, &ask_order.id)?;
-    delete_bid_order_by_id(deps.storage, &bid_order.id)?;
-    ExecuteResults {
-        messages: vec![
-            CosmosMsg::Bank(BankMsg::Send {
-                to_address: ask_order.owner.to_string(),
-                amount: bid_collateral.quote.to_owned(),
-            }),
-            CosmosMsg::Bank(BankMsg::Send {
-                to_address: bid_order.owner.to_string(),
-                amount: ask_collateral.base.to_owned(),
-            }),
-        ],
-        ask_deleted: true,
-        bid_deleted: true,
-        collateral_released: true,
-    }
-    .to_ok()
+    env: &Env,
+    sender: &Addr,
+    contract_info: &ContractInfoV2,
+    ask_id: &str,
+    bid_id: &str,
+    admin_match_options: Option<AdminMatchOptions>,
+) -> Result<MatchOrdersResult, ContractError> {
+    let plan = plan_match_orders(
+        &deps.as_ref(),
+        env,
+        sender,
+        contract_info,
+        ask_id,
+        bid_id,
+        admin_match_options,
+    )?;
+    settle_match_orders(deps.storage, env, sender, contract_info, plan)
 }
 
-fn execute_marker_trade(
-    deps: DepsMut<ProvenanceQuery>,
+/// A validated ask and bid pair, along with the settlement that matching them will produce.
+pub struct MatchOrdersPlan {
+    pub ask_order: AskOrder,
+    pub bid_order: BidOrder,
+    pub admin_match_options: Option<AdminMatchOptions>,
+    pub settlement: SettlementPlan,
+}
+
+/// Loads, authorizes and validates a single ask and bid pair, and calculates the settlement of
+/// their match.  No state is modified, so an error returned while planning leaves the ask and bid
+/// untouched.
+pub fn plan_match_orders(
+    deps: &Deps<ProvenanceQuery>,
     env: &Env,
-    ask_order: &AskOrder,
-    bid_order: &BidOrder,
-    ask_collateral: &MarkerTradeAskCollateral,
-    bid_collateral: &MarkerTradeBidCollateral,
-) -> Result<ExecuteResults, ContractError> {
-    let mut messages = vec![];
-    // Only transfer marker shares to the bidder if the bidder explicitly requested it with a Some(true)
-    // value for their withdraw_shares_after_match param during BidOrder creation
-    if bid_collateral.withdraw_shares_after_match.unwrap_or(false) {
-        messages.push(withdraw_coins(
-            &ask_collateral.marker_denom,
-            // Withdraw all remaining shares in the marker to the bidder's account upon marker
-            // trade completion.  This will cause them to immediately show up in the bidder's wallet.
-            ask_collateral.share_count.u128(),
-            &ask_collateral.marker_denom,
-            bid_order.owner.to_owned(),
-        )?);
-    }
-    if let Some(asker_permissions) = ask_collateral
-        .removed_permissions
-        .iter()
-        .find(|perm| perm.address == ask_order.owner)
+    sender: &Addr,
+    contract_info: &ContractInfoV2,
+    ask_id: &str,
+    bid_id: &str,
+    admin_match_options: Option<AdminMatchOptions>,
+) -> Result<MatchOrdersPlan, ContractError> {
+    let ask_order = get_ask_order_by_id(deps.storage, ask_id)?;
+    let bid_order = get_bid_order_by_id(deps.storage, bid_id)?;
+    // only matchers or the asker may execute matches
+    if *sender != ask_order.owner
+        && !address_has_role(deps.storage, contract_info, sender, &ContractRole::Matcher)
     {
-        // Now that the match has been made, grant all permissions on the marker to the bidder that
-        // the asker once had.  The validation code has already ensured that the asker was an admin
-        // of the marker, so the bidder at very least has the permission on the marker to grant
-        // themselves any remaining permissions they desire.
-        let mut bidder_permissions = asker_permissions.to_owned();
-        bidder_permissions.address = bid_order.owner.to_owned();
-        messages.append(&mut release_marker_from_contract(
-            &ask_collateral.marker_denom,
-            &env.contract.address,
-            &[bidder_permissions],
-        )?);
-    } else {
-        return ContractError::ValidationError {
-            messages: vec![
-                "failed to find access permissions in the revoked permissions for the asker"
-                    .to_string(),
-            ],
-        }
-        .to_err();
+        return ContractError::Unauthorized.to_err();
     }
-    // Send the entirety of the quote to the asker. They have just effectively sold their
-    // marker to the bidder.
-    messages.push(CosmosMsg::Bank(BankMsg::Send {
-        to_address: ask_order.owner.to_string(),
-        amount: bid_collateral.quote.to_owned(),
-    }));
-    // Remove ask and bid - this transaction has concluded
-    delete_ask_order_by_id(deps.storage, &ask_order.id)?;
-    delete_bid_order_by_id(deps.storage, &bid_order.id)?;
-    ExecuteResults {
-        messages,
-        ask_deleted: true,
-        bid_deleted: true,
-        collateral_released: true,
+    // Ensure match is viable before trying to actually execute the match
+    validate_match(deps, env, &ask_order, &bid_order, &admin_match_options)?;
+    let settlement = plan_settlement(deps, env, &ask_order, &bid_order, &admin_match_options)?;
+    MatchOrdersPlan {
+        ask_order,
+        bid_order,
+        admin_match_options,
+        settlement,
     }
     .to_ok()
 }
 
-fn execute_marker_share_sale(
-    deps: DepsMut<ProvenanceQuery>,
+/// Applies a planned settlement to the ask and bid, and stores a match record for it.  Every
+/// fallible calculation is made while planning, but storage is modified as soon as settlement
+/// begins, so an error returned from settlement must fail the entire transaction.
+pub fn settle_match_orders(
+    storage: &mut dyn Storage,
     env: &Env,
-    ask_order: &AskOrder,
-    bid_order: &BidOrder,
-    ask_collateral: &MarkerShareSaleAskCollateral,
-    bid_collateral: &MarkerShareSaleBidCollateral,
-    override_quote_source: Option<OverrideQuoteSource>,
-) -> Result<ExecuteResults, ContractError> {
-    let bid_overage_shares = bid_collateral
-        .share_count
-        .checked_sub(ask_collateral.remaining_shares_in_sale)
-        .unwrap_or(Uint128::zero())
-        .u128();
-    let shares_purchased = bid_collateral.share_count.u128() - bid_overage_shares;
-    let quote_paid = if let Some(OverrideQuoteSource::Bid) = override_quote_source {
-        multiply_coins_by_amount(&bid_collateral.get_quote_per_share(), shares_purchased)
-    } else {
-        multiply_coins_by_amount(&ask_collateral.quote_per_share, shares_purchased)
-    };
-    // Asker gets the quote that the bidder provided from escrow
-    // Bidder gets their X marker coins withdrawn to them from the contract-controlled marker
-    let mut messages = vec![
-        CosmosMsg::Bank(BankMsg::Send {
-            to_address: ask_order.owner.to_string(),
-            amount: quote_paid.to_owned(),
-        }),
-        withdraw_coins(
-            &ask_collateral.marker_denom,
-            shares_purchased,
-            &ask_collateral.marker_denom,
-            bid_order.owner.to_owned(),
-        )?,
-    ];
-    let mut collateral_released = false;
-    let mut terminate_sale = || -> Result<(), ContractError> {
-        // Only release the marker if this is the final remaining ask for the given marker.
-        // Multiple marker share sales can be created for a single marker while it is held by
-        // the contract, so this check ensures that the marker is only relinquished when the
-        // final sale is completed.
-        if get_ask_orders_by_collateral_id(deps.storage, ask_collateral.marker_address.as_str())
-            .len()
-            <= 1
-        {
-            // Marker gets released to the asker.  The sale is effectively over.
-            messages.append(&mut release_marker_from_contract(
-                &ask_collateral.marker_denom,
-                &env.contract.address,
-                &ask_collateral.removed_permissions,
-            )?);
-            collateral_released = true;
-        }
-        delete_ask_order_by_id(deps.storage, &ask_order.id)?;
-        ().to_ok()
-    };
-    let ask_deleted = match ask_collateral.sale_type {
-        // Single transaction sales should always terminate immediately after the sale completes
-        ShareSaleType::SingleTransaction => {
-            terminate_sale()?;
-            true
-        }
-        ShareSaleType::MultipleTransactions => {
-            // Validation will prevent this value from ever becoming less than zero from the sale,
-            // so this is a safe operation
-            let shares_remaining_after_sale =
-                ask_collateral.remaining_shares_in_sale.u128() - shares_purchased;
-            // If all listed shares are now sold, terminate the sale
-            if shares_remaining_after_sale == 0 {
-                terminate_sale()?;
-                true
-            } else {
-                let mut ask_order = ask_order.to_owned();
-                let mut ask_collateral = ask_collateral.to_owned();
-                ask_collateral.remaining_shares_in_sale = Uint128::new(shares_remaining_after_sale);
-                ask_order.collateral = AskCollateral::MarkerShareSale(ask_collateral);
-                // Replace the ask order in storage with an updated remaining_shares value
-                update_ask_order(deps.storage, &ask_order)?;
-                false
-            }
-        }
+    sender: &Addr,
+    contract_info: &ContractInfoV2,
+    plan: MatchOrdersPlan,
+) -> Result<MatchOrdersResult, ContractError> {
+    let MatchOrdersPlan {
+        ask_order,
+        bid_order,
+        admin_match_options,
+        settlement,
+    } = plan;
+    let ask_deleted = settlement.is_ask_deleted();
+    let bid_deleted = settlement.is_bid_deleted();
+    match &settlement.ask_update {
+        OrderUpdate::Delete => delete_ask_order_by_id(storage, &ask_order.id)?,
+        OrderUpdate::Replace(updated_ask_order) => update_ask_order(storage, updated_ask_order)?,
     };
-    let MSSBidTotalsCalc {
-        expected_remaining_bidder_coin,
-        bidder_refund,
-        ..
-    } = calculate_marker_share_sale_bid_totals(bid_collateral, &quote_paid, bid_overage_shares)?;
-    // Subtract coins will not add coin values of zero into the vector.  This ensures that a vector
-    // that would be the result of perfect subtraction and cause zeroed out values will be empty,
-    // allowing this check to ensure a refund message is only populated when actual coin needs to
-    // be sent
-    if !bidder_refund.is_empty() {
-        messages.push(CosmosMsg::Bank(BankMsg::Send {
-            to_address: bid_order.owner.to_string(),
-            amount: bidder_refund,
-        }));
-    }
-    // If a bid overage occurred, then the bid should remain open with a reduced number of shares
-    // and a reset quote.
-    let bid_deleted = if bid_overage_shares > 0 {
-        let mut updated_bidder_collateral = bid_collateral.to_owned();
-        updated_bidder_collateral.share_count = Uint128::new(bid_overage_shares);
-        updated_bidder_collateral.quote = expected_remaining_bidder_coin;
-        let mut updated_bid_order = bid_order.to_owned();
-        updated_bid_order.collateral = BidCollateral::MarkerShareSale(updated_bidder_collateral);
-        update_bid_order(deps.storage, &updated_bid_order)?;
-        // False = the bid was not deleted because it still wants additional shares
-        false
-    } else {
-        // If no bid overage occurred, then all shares were purchased at the expected amount and the
-        // bid should be closed.
-        delete_bid_order_by_id(deps.storage, &bid_order.id)?;
-        true
+    match &settlement.bid_update {
+        OrderUpdate::Delete => delete_bid_order_by_id(storage, &bid_order.id)?,
+        OrderUpdate::Replace(updated_bid_order) => update_bid_order(storage, updated_bid_order)?,
     };
-    ExecuteResults {
+    // The quote is paid out separately from the rest of the settlement to ensure that settlement
+    // fees are always taken from it, regardless of trade type
+    let quote_payment = get_quote_payment(contract_info, &ask_order, &settlement.quote_paid);
+    // Keep a permanent record of the trade now that the orders have been settled
+    let match_record = insert_match_record(
+        storage,
+        &MatchRecord {
+            id: Uint128::zero(),
+            ask_id: ask_order.id.to_owned(),
+            bid_id: bid_order.id.to_owned(),
+            asker: ask_order.owner.to_owned(),
+            bidder: bid_order.owner.to_owned(),
+            request_type: ask_order.ask_type.to_owned(),
+            quote_paid: settlement.quote_paid,
+            collateral_transferred: settlement.collateral_transferred,
+            settlement_fee: quote_payment.settlement_fee.to_owned(),
+            admin_match_options,
+            executed_by: sender.to_owned(),
+            block_height: env.block.height,
+            block_time: env.block.time,
+        },
+    )?;
+    let mut messages = quote_payment.messages;
+    messages.extend(settlement.messages);
+    MatchOrdersResult {
         messages,
         ask_deleted,
         bid_deleted,
-        collateral_released,
-    }
-    .to_ok()
-}
-
-fn execute_scope_trade(
-    deps: DepsMut<ProvenanceQuery>,
-    env: &Env,
-    ask_order: &AskOrder,
-    bid_order: &BidOrder,
-    ask_collateral: &ScopeTradeAskCollateral,
-    bid_collateral: &ScopeTradeBidCollateral,
-) -> Result<ExecuteResults, ContractError> {
-    // Asker gets the quote that the bidder provided from escrow
-    let mut messages = vec![CosmosMsg::Bank(BankMsg::Send {
-        to_address: ask_order.owner.to_string(),
-        amount: bid_collateral.quote.to_owned(),
-    })];
-    let scope = ProvenanceQuerier::new(&deps.querier).get_scope(&ask_collateral.scope_address)?;
-    // Bidder gets the scope transferred to them
-    messages.push(write_scope(
-        replace_scope_owner(scope, bid_order.owner.to_owned()),
-        vec![env.contract.address.to_owned()],
-    )?);
-    // Remove the ask and bid orders now that the trade has been finalized
-    delete_ask_order_by_id(deps.storage, &ask_order.id)?;
-    delete_bid_order_by_id(deps.storage, &bid_order.id)?;
-    ExecuteResults {
-        messages,
-        ask_deleted: true,
-        bid_deleted: true,
-        collateral_released: true,
+        collateral_released: settlement.collateral_released,
+        match_record_id: match_record.id,
+        settlement_fee: quote_payment.settlement_fee,
     }
     .to_ok()
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::execute::cancel_ask::cancel_ask;
+    use crate::execute::cancel_bid::cancel_bid;
     use crate::execute::create_ask::create_ask;
     use crate::execute::create_bid::create_bid;
     use crate::execute::execute_match::execute_match;
+    use crate::execute::grant_role::grant_role;
+    use crate::execute::update_settings::update_settings;
+    use crate::storage::ask_order_storage::get_ask_orders_by_collateral_id;
     use crate::storage::ask_order_storage::{get_ask_order_by_id, insert_ask_order};
     use crate::storage::bid_order_storage::{get_bid_order_by_id, insert_bid_order};
+    use crate::storage::match_record_storage::get_match_record_by_id;
+    use crate::storage::scope_stake_storage::register_scope_stake;
     use crate::test::cosmos_type_helpers::single_attribute_for_key;
     use crate::test::error_helpers::assert_validation_error_message;
-    use crate::test::mock_instantiate::{default_instantiate, DEFAULT_ADMIN_ADDRESS};
+    use crate::test::mock_instantiate::{
+        default_instantiate, test_instantiate, TestInstantiate, DEFAULT_ADMIN_ADDRESS,
+    };
     use crate::test::mock_marker::{MockMarker, DEFAULT_MARKER_DENOM, DEFAULT_MARKER_HOLDINGS};
-    use crate::test::mock_scope::{MockScope, DEFAULT_SCOPE_ADDR};
+    use crate::test::mock_scope::{MockScope, DEFAULT_SCOPE_ADDR, DEFAULT_SCOPE_SPEC_ID};
+    use crate::test::mock_scopes_querier::mock_dependencies_with_scopes;
     use crate::test::request_helpers::{mock_ask_order, mock_bid_order, mock_bid_scope_trade};
+    use crate::types::core::contract_role::ContractRole;
+    use crate::types::core::contract_status::ContractStatus;
     use crate::types::core::error::ContractError;
     use crate::types::request::admin_match_options::{AdminMatchOptions, OverrideQuoteSource};
     use crate::types::request::ask_types::ask::Ask;
     use crate::types::request::ask_types::ask_collateral::AskCollateral;
     use crate::types::request::bid_types::bid::Bid;
+    use crate::types::request::match_record::{MatchRecord, MatchedCollateral};
+    use crate::types::request::request_type::RequestType;
+    use crate::types::request::scope_transfer_policy::{DataAccessPolicy, ScopeTransferPolicy};
+    use crate::types::request::settings_update::SettingsUpdate;
+    use crate::types::request::settlement_fees::SettlementFees;
     use crate::types::request::share_sale_type::ShareSaleType;
     use crate::util::constants::NHASH;
     use cosmwasm_std::testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR};
-    use cosmwasm_std::{coin, coins, BankMsg, Coin, CosmosMsg, Response, Storage, Uint128};
+    use cosmwasm_std::{coin, coins, Addr, BankMsg, Coin, CosmosMsg, Response, Storage, Uint128};
     use provwasm_mocks::mock_dependencies;
     use provwasm_std::{
-        MarkerMsgParams, MetadataMsgParams, PartyType, ProvenanceMsg, ProvenanceMsgParams,
+        MarkerMsgParams, MetadataMsgParams, Party, PartyType, ProvenanceMsg, ProvenanceMsgParams,
     };
 
     #[test]
@@ -534,6 +396,422 @@ mod tests {
         do_coin_trade_test("asker", false);
     }
 
+    #[test]
+    fn test_execute_match_from_matcher() {
+        let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
+        grant_role(
+            deps.as_mut(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+            "match_bot".to_string(),
+            ContractRole::Matcher,
+        )
+        .expect("the matcher role should be granted");
+        create_ask(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &coins(100, "base")),
+            Ask::new_coin_trade("ask_id", &coins(100, "quote")),
+            None,
+        )
+        .expect("the ask should be created successfully");
+        create_bid(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder", &coins(100, "quote")),
+            Bid::new_coin_trade("bid_id", &coins(100, "base")),
+            None,
+        )
+        .expect("the bid should be created successfully");
+        let err = execute_match(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("order_canceller", &[]),
+            "ask_id".to_string(),
+            "bid_id".to_string(),
+            None,
+        )
+        .expect_err("an account without the matcher role should not be able to execute a match");
+        assert!(
+            matches!(err, ContractError::Unauthorized),
+            "an unauthorized error should be returned, but got: {:?}",
+            err,
+        );
+        let response = execute_match(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("match_bot", &[]),
+            "ask_id".to_string(),
+            "bid_id".to_string(),
+            None,
+        )
+        .expect("an account with the matcher role should be able to execute a match");
+        assert_match_produced_correct_results(deps.as_ref().storage, &response, true);
+    }
+
+    #[test]
+    fn test_execute_match_when_contract_is_paused() {
+        let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
+        create_ask(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &coins(100, "base")),
+            Ask::new_coin_trade("ask_id", &coins(100, "quote")),
+            None,
+        )
+        .expect("the ask should be created successfully");
+        create_bid(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder", &coins(100, "quote")),
+            Bid::new_coin_trade("bid_id", &coins(100, "base")),
+            None,
+        )
+        .expect("the bid should be created successfully");
+        update_settings(
+            deps.as_mut(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+            SettingsUpdate {
+                new_create_ask_nhash_fee: None,
+                new_create_bid_nhash_fee: None,
+                new_settlement_fees: None,
+                new_fee_collector_address: None,
+                new_contract_status: Some(ContractStatus::FullyPaused),
+                new_required_trade_attributes: None,
+            },
+        )
+        .expect("the contract should be paused");
+        let err = execute_match(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+            "ask_id".to_string(),
+            "bid_id".to_string(),
+            None,
+        )
+        .expect_err("a match should not execute while the contract is paused");
+        match err {
+            ContractError::InvalidRequest { message } => assert_eq!(
+                "asks and bids cannot be matched while the contract status is [fully_paused]",
+                message,
+                "the correct error message should be produced",
+            ),
+            e => panic!("unexpected error: {:?}", e),
+        };
+        get_ask_order_by_id(deps.as_ref().storage, "ask_id")
+            .expect("the ask should remain in storage after the rejected match");
+        get_bid_order_by_id(deps.as_ref().storage, "bid_id")
+            .expect("the bid should remain in storage after the rejected match");
+        // Cancellations are always allowed so that collateral can be reclaimed
+        cancel_ask(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &[]),
+            "ask_id".to_string(),
+        )
+        .expect("the asker should be able to cancel while the contract is paused");
+        cancel_bid(
+            deps.as_mut(),
+            mock_info("bidder", &[]),
+            "bid_id".to_string(),
+        )
+        .expect("the bidder should be able to cancel while the contract is paused");
+    }
+
+    #[test]
+    fn test_execute_match_stores_match_record() {
+        let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
+        create_ask(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &coins(100, "base")),
+            Ask::new_coin_trade("ask_id", &coins(100, "quote")),
+            None,
+        )
+        .expect("the ask should be created successfully");
+        create_bid(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder", &coins(90, "quote")),
+            Bid::new_coin_trade("bid_id", &coins(100, "base")),
+            None,
+        )
+        .expect("the bid should be created successfully");
+        let response = execute_match(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+            "ask_id".to_string(),
+            "bid_id".to_string(),
+            Some(AdminMatchOptions::coin_trade_options(true)),
+        )
+        .expect("the match should execute successfully");
+        assert_eq!(
+            "1",
+            single_attribute_for_key(&response, "match_record_id"),
+            "the first match record should be assigned the first id",
+        );
+        let env = mock_env();
+        assert_eq!(
+            MatchRecord {
+                id: Uint128::new(1),
+                ask_id: "ask_id".to_string(),
+                bid_id: "bid_id".to_string(),
+                asker: Addr::unchecked("asker"),
+                bidder: Addr::unchecked("bidder"),
+                request_type: RequestType::CoinTrade,
+                quote_paid: coins(90, "quote"),
+                settlement_fee: vec![],
+                collateral_transferred: MatchedCollateral::CoinTrade {
+                    base: coins(100, "base"),
+                },
+                admin_match_options: Some(AdminMatchOptions::coin_trade_options(true)),
+                executed_by: Addr::unchecked(DEFAULT_ADMIN_ADDRESS),
+                block_height: env.block.height,
+                block_time: env.block.time,
+            },
+            get_match_record_by_id(deps.as_ref().storage, 1)
+                .expect("the match record should be stored"),
+            "the match record should contain the details of the executed match",
+        );
+    }
+
+    #[test]
+    fn test_execute_match_charges_settlement_fee() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(
+            deps.as_mut(),
+            TestInstantiate {
+                settlement_fees: Some(SettlementFees {
+                    coin_trade_bps: Some(Uint128::new(250)),
+                    ..SettlementFees::default()
+                }),
+                fee_collector_address: Some("fee_collector".to_string()),
+                ..TestInstantiate::default()
+            },
+        );
+        create_ask(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &coins(100, "base")),
+            Ask::new_coin_trade("ask_id", &coins(1000, "quote")),
+            None,
+        )
+        .expect("the ask should be created successfully");
+        create_bid(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder", &coins(1000, "quote")),
+            Bid::new_coin_trade("bid_id", &coins(100, "base")),
+            None,
+        )
+        .expect("the bid should be created successfully");
+        let response = execute_match(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+            "ask_id".to_string(),
+            "bid_id".to_string(),
+            None,
+        )
+        .expect("the match should execute successfully");
+        assert_match_produced_correct_results(deps.as_ref().storage, &response, true);
+        assert_eq!(
+            "25quote",
+            single_attribute_for_key(&response, "settlement_fee"),
+            "the settlement fee should be displayed in the response attributes",
+        );
+        assert_eq!(
+            vec![
+                CosmosMsg::Bank(BankMsg::Send {
+                    to_address: "asker".to_string(),
+                    amount: coins(975, "quote"),
+                }),
+                CosmosMsg::Bank(BankMsg::Send {
+                    to_address: "fee_collector".to_string(),
+                    amount: coins(25, "quote"),
+                }),
+                CosmosMsg::Bank(BankMsg::Send {
+                    to_address: "bidder".to_string(),
+                    amount: coins(100, "base"),
+                }),
+            ],
+            response
+                .messages
+                .iter()
+                .map(|msg| msg.msg.to_owned())
+                .collect::<Vec<CosmosMsg<ProvenanceMsg>>>(),
+            "the fee should be taken from the quote and sent to the fee collector",
+        );
+        let match_record = get_match_record_by_id(deps.as_ref().storage, 1)
+            .expect("the match record should be stored");
+        assert_eq!(
+            coins(1000, "quote"),
+            match_record.quote_paid,
+            "the match record should include the full quote paid",
+        );
+        assert_eq!(
+            coins(25, "quote"),
+            match_record.settlement_fee,
+            "the match record should include the settlement fee",
+        );
+    }
+
+    #[test]
+    fn test_execute_coin_trade_partial_fills() {
+        let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
+        create_ask(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &coins(100, "base")),
+            Ask::new_partial_fill_coin_trade("ask_id", &coins(200, "quote")),
+            None,
+        )
+        .expect("the partial fill ask should be created successfully");
+        create_bid(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder", &coins(80, "quote")),
+            Bid::new_coin_trade("bid_id", &coins(40, "base")),
+            None,
+        )
+        .expect("the first bid should be created successfully");
+        let response = execute_match(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+            "ask_id".to_string(),
+            "bid_id".to_string(),
+            None,
+        )
+        .expect("the first partial fill should execute successfully");
+        assert_match_produced_correct_results_with_extras(
+            deps.as_ref().storage,
+            &response,
+            false,
+            true,
+            false,
+            "ask_id",
+            "bid_id",
+        );
+        assert_eq!(
+            vec![
+                CosmosMsg::Bank(BankMsg::Send {
+                    to_address: "asker".to_string(),
+                    amount: coins(80, "quote"),
+                }),
+                CosmosMsg::Bank(BankMsg::Send {
+                    to_address: "bidder".to_string(),
+                    amount: coins(40, "base"),
+                }),
+            ],
+            response
+                .messages
+                .iter()
+                .map(|msg| msg.msg.to_owned())
+                .collect::<Vec<CosmosMsg<ProvenanceMsg>>>(),
+            "the asker should receive the bid quote and the bidder should receive only the base purchased",
+        );
+        let ask_order = get_ask_order_by_id(deps.as_ref().storage, "ask_id")
+            .expect("the ask should remain in storage after a partial fill");
+        let collateral = ask_order
+            .collateral
+            .get_coin_trade()
+            .expect("the ask should be a coin trade");
+        assert_eq!(
+            Some(Uint128::new(60)),
+            collateral.remaining_base,
+            "the remaining base should be reduced by the amount purchased",
+        );
+        assert_eq!(
+            coins(100, "base"),
+            collateral.base,
+            "the original base should be retained to preserve the price ratio",
+        );
+        create_bid(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder2", &coins(120, "quote")),
+            Bid::new_coin_trade("bid_id_2", &coins(60, "base")),
+            None,
+        )
+        .expect("the second bid should be created successfully");
+        let response = execute_match(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+            "ask_id".to_string(),
+            "bid_id_2".to_string(),
+            None,
+        )
+        .expect("the final partial fill should execute successfully");
+        assert_match_produced_correct_results_with_extras(
+            deps.as_ref().storage,
+            &response,
+            true,
+            false,
+            false,
+            "ask_id",
+            "bid_id_2",
+        );
+        let match_record = get_match_record_by_id(deps.as_ref().storage, 2)
+            .expect("the second match record should be stored");
+        assert_eq!(
+            MatchedCollateral::CoinTrade {
+                base: coins(60, "base"),
+            },
+            match_record.collateral_transferred,
+            "the match record should only include the base purchased by the bid",
+        );
+    }
+
+    #[test]
+    fn test_execute_coin_trade_partial_fill_rejects_mispriced_bid() {
+        let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
+        create_ask(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &coins(100, "base")),
+            Ask::new_partial_fill_coin_trade("ask_id", &coins(200, "quote")),
+            None,
+        )
+        .expect("the partial fill ask should be created successfully");
+        create_bid(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder", &coins(79, "quote")),
+            Bid::new_coin_trade("bid_id", &coins(40, "base")),
+            None,
+        )
+        .expect("the bid should be created successfully");
+        let err = execute_match(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+            "ask_id".to_string(),
+            "bid_id".to_string(),
+            None,
+        )
+        .expect_err("a bid that does not pay the proportional quote should be rejected");
+        assert_validation_error_message(
+            err,
+            "COIN TRADE Match Validation for AskOrder [ask_id] and BidOrder [bid_id]: Ask quote [80quote] for bid base [40base] does not match bid quote [79quote]",
+        );
+        execute_match(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+            "ask_id".to_string(),
+            "bid_id".to_string(),
+            Some(AdminMatchOptions::coin_trade_options(true)),
+        )
+        .expect("the admin should be able to accept a mismatched quote on a partial fill");
+    }
+
     #[test]
     fn test_execute_marker_trade_from_admin_matching_quote() {
         do_marker_trade_test(DEFAULT_ADMIN_ADDRESS, false, None);
@@ -806,7 +1084,7 @@ mod tests {
 
     #[test]
     fn test_execute_marker_share_sale_excess_bid_shares_leftover_no_admin_options() {
-        for share_sale_type in vec![
+        for share_sale_type in [
             ShareSaleType::SingleTransaction,
             ShareSaleType::MultipleTransactions,
         ] {
@@ -968,7 +1246,7 @@ mod tests {
 
     #[test]
     fn test_execute_marker_share_sale_use_lower_ask_amount_full_sale() {
-        for share_sale_type in vec![
+        for share_sale_type in [
             ShareSaleType::SingleTransaction,
             ShareSaleType::MultipleTransactions,
         ] {
@@ -1118,7 +1396,7 @@ mod tests {
 
     #[test]
     fn test_execute_marker_share_sale_use_lower_ask_amount_bidder_shares_leftover() {
-        for share_sale_type in vec![
+        for share_sale_type in [
             ShareSaleType::SingleTransaction,
             ShareSaleType::MultipleTransactions,
         ] {
@@ -1293,7 +1571,7 @@ mod tests {
 
     #[test]
     fn test_execute_marker_share_sale_single_tx_use_higher_bid_amount_bidder_shares_leftover() {
-        for share_sale_type in vec![
+        for share_sale_type in [
             ShareSaleType::SingleTransaction,
             ShareSaleType::MultipleTransactions,
         ] {
@@ -1458,7 +1736,7 @@ mod tests {
 
     #[test]
     fn test_execute_marker_share_sale_use_higher_bid_amount_full_sale() {
-        for share_sale_type in vec![
+        for share_sale_type in [
             ShareSaleType::SingleTransaction,
             ShareSaleType::MultipleTransactions,
         ] {
@@ -1639,7 +1917,7 @@ mod tests {
         let ask_id = ask_id.into();
         let bid_id = bid_id.into();
         assert_eq!(
-            6,
+            8,
             response.attributes.len(),
             "the correct number of attributes should be produced in the response",
         );
@@ -1673,6 +1951,21 @@ mod tests {
             single_attribute_for_key(response, "collateral_released"),
             "the correct collateral_released value should be produced",
         );
+        let match_record = get_match_record_by_id(
+            storage,
+            single_attribute_for_key(response, "match_record_id")
+                .parse::<u64>()
+                .expect("the match_record_id attribute should be a number"),
+        )
+        .expect("a match record should be stored for the execution");
+        assert_eq!(
+            ask_id, match_record.ask_id,
+            "the match record should reference the correct ask",
+        );
+        assert_eq!(
+            bid_id, match_record.bid_id,
+            "the match record should reference the correct bid",
+        );
         let ask_order_result = get_ask_order_by_id(storage, &ask_id);
         if expect_ask_to_remain_in_storage {
             ask_order_result.expect("ask should remain in storage");
@@ -2426,4 +2719,331 @@ mod tests {
             msg => panic!("unexpected message: {:?}", msg),
         })
     }
+
+    #[test]
+    fn test_execute_scope_trade_with_transfer_policy() {
+        let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
+        deps.querier
+            .with_scope(MockScope::new_with_owner(MOCK_CONTRACT_ADDR));
+        create_ask(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &[]),
+            Ask::new_scope_trade_with_transfer_policy(
+                "ask_id",
+                DEFAULT_SCOPE_ADDR,
+                &coins(420, "quote"),
+                ScopeTransferPolicy::new()
+                    .with_data_access(DataAccessPolicy::AddBidder)
+                    .with_additional_bidder_roles(&[PartyType::Investor])
+                    .with_retained_owner_address("servicer"),
+            ),
+            None,
+        )
+        .expect("the ask should be created successfully");
+        create_bid(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder", &coins(420, "quote")),
+            Bid::new_scope_trade("bid_id", DEFAULT_SCOPE_ADDR),
+            None,
+        )
+        .expect("the bid should be created successfully");
+        let response = execute_match(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+            "ask_id".to_string(),
+            "bid_id".to_string(),
+            None,
+        )
+        .expect("the match should execute successfully");
+        let scope = response
+            .messages
+            .iter()
+            .find_map(|msg| match &msg.msg {
+                CosmosMsg::Custom(ProvenanceMsg {
+                    params:
+                        ProvenanceMsgParams::Metadata(MetadataMsgParams::WriteScope { scope, .. }),
+                    ..
+                }) => Some(scope),
+                _ => None,
+            })
+            .expect("a write scope message should be produced");
+        assert_eq!(
+            "bidder",
+            scope.value_owner_address.as_str(),
+            "the bidder should be the new value owner of the scope",
+        );
+        assert_eq!(
+            vec![
+                Party {
+                    address: Addr::unchecked("servicer"),
+                    role: PartyType::Owner,
+                },
+                Party {
+                    address: Addr::unchecked("bidder"),
+                    role: PartyType::Investor,
+                },
+            ],
+            scope.owners,
+            "the retained owner should remain the owner and the bidder should be added as an investor",
+        );
+        assert!(
+            scope.data_access.contains(&Addr::unchecked("bidder")),
+            "the bidder should be granted data access to the scope",
+        );
+    }
+
+    #[test]
+    fn test_execute_co_owned_scope_trade() {
+        let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
+        deps.querier.with_scope(
+            MockScope {
+                owners: vec![
+                    Party {
+                        address: Addr::unchecked(MOCK_CONTRACT_ADDR),
+                        role: PartyType::Owner,
+                    },
+                    Party {
+                        address: Addr::unchecked("co_owner"),
+                        role: PartyType::Owner,
+                    },
+                ],
+                value_owner_address: Addr::unchecked(MOCK_CONTRACT_ADDR),
+                ..MockScope::default()
+            }
+            .to_scope(),
+        );
+        register_scope_stake(
+            deps.as_mut().storage,
+            DEFAULT_SCOPE_ADDR,
+            &Addr::unchecked("asker"),
+        )
+        .expect("the stake should be registered");
+        create_ask(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &[]),
+            Ask::new_co_owned_scope_trade(
+                "ask_id",
+                DEFAULT_SCOPE_ADDR,
+                &coins(420, "quote"),
+                &["asker"],
+            ),
+            None,
+        )
+        .expect("the co-owned scope ask should be created successfully");
+        create_bid(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder", &coins(420, "quote")),
+            Bid::new_scope_trade("bid_id", DEFAULT_SCOPE_ADDR),
+            None,
+        )
+        .expect("the bid should be created successfully");
+        let response = execute_match(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+            "ask_id".to_string(),
+            "bid_id".to_string(),
+            None,
+        )
+        .expect("the match should execute successfully");
+        let scope = response
+            .messages
+            .iter()
+            .find_map(|msg| match &msg.msg {
+                CosmosMsg::Custom(ProvenanceMsg {
+                    params:
+                        ProvenanceMsgParams::Metadata(MetadataMsgParams::WriteScope { scope, .. }),
+                    ..
+                }) => Some(scope),
+                _ => None,
+            })
+            .expect("a write scope message should be produced");
+        assert_eq!(
+            vec![
+                Party {
+                    address: Addr::unchecked("co_owner"),
+                    role: PartyType::Owner,
+                },
+                Party {
+                    address: Addr::unchecked("bidder"),
+                    role: PartyType::Owner,
+                },
+            ],
+            scope.owners,
+            "the bidder should replace the contract's stake and the other owner should be unchanged",
+        );
+        assert_eq!(
+            "bidder",
+            scope.value_owner_address.as_str(),
+            "the bidder should receive the value ownership held by the contract",
+        );
+    }
+
+    #[test]
+    fn test_execute_scope_spec_trade_refunds_unspent_quote() {
+        let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
+        deps.querier
+            .with_scope(MockScope::new_with_owner(MOCK_CONTRACT_ADDR));
+        create_ask(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &[]),
+            Ask::new_scope_trade("ask_id", DEFAULT_SCOPE_ADDR, &coins(100, "quote")),
+            None,
+        )
+        .expect("the ask should be created successfully");
+        create_bid(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder", &coins(150, "quote")),
+            Bid::new_scope_spec_trade("bid_id", DEFAULT_SCOPE_SPEC_ID, None, Some(true)),
+            None,
+        )
+        .expect("the scope spec bid should be created successfully");
+        let bid_order =
+            get_bid_order_by_id(deps.as_ref().storage, "bid_id").expect("bid order should exist");
+        assert_eq!(
+            RequestType::ScopeTrade,
+            bid_order.bid_type,
+            "scope spec bids should be stored as scope trade bids",
+        );
+        let response = execute_match(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+            "ask_id".to_string(),
+            "bid_id".to_string(),
+            None,
+        )
+        .expect("the match should execute successfully");
+        assert_match_produced_correct_results(deps.as_ref().storage, &response, true);
+        assert_eq!(
+            3,
+            response.messages.len(),
+            "the scope write, asker payment and bidder refund should be produced",
+        );
+        response.messages.iter().for_each(|msg| match &msg.msg {
+            CosmosMsg::Bank(BankMsg::Send { to_address, amount }) => match to_address.as_str() {
+                "asker" => assert_eq!(
+                    &coins(100, "quote"),
+                    amount,
+                    "the asker should only receive the ask quote",
+                ),
+                "bidder" => assert_eq!(
+                    &coins(50, "quote"),
+                    amount,
+                    "the bidder should be refunded the quote that was not spent",
+                ),
+                address => panic!("unexpected bank send recipient: {}", address),
+            },
+            CosmosMsg::Custom(ProvenanceMsg {
+                params: ProvenanceMsgParams::Metadata(MetadataMsgParams::WriteScope { scope, .. }),
+                ..
+            }) => assert_eq!(
+                "bidder",
+                scope.value_owner_address.as_str(),
+                "the bidder should be the new value owner of the scope",
+            ),
+            msg => panic!("unexpected message: {:?}", msg),
+        });
+        let match_record = get_match_record_by_id(deps.as_ref().storage, 1)
+            .expect("a match record should be stored");
+        assert_eq!(
+            coins(100, "quote"),
+            match_record.quote_paid,
+            "the match record should reflect the quote that was actually paid",
+        );
+    }
+
+    #[test]
+    fn test_execute_scope_bundle_trade() {
+        let mut deps = mock_dependencies_with_scopes(&["scope1", "scope2"], MOCK_CONTRACT_ADDR);
+        default_instantiate(deps.as_mut());
+        create_ask(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &[]),
+            Ask::new_scope_bundle_trade("ask_id", &["scope1", "scope2"], &coins(500, "quote")),
+            None,
+        )
+        .expect("the bundle ask should be created successfully");
+        assert_eq!(
+            "ask_id",
+            get_ask_orders_by_collateral_id(deps.as_ref().storage, "scope2")
+                .first()
+                .expect("the bundle ask should be found by one of its scopes")
+                .id,
+            "the bundle ask should be indexed by each of its scopes",
+        );
+        create_bid(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder", &coins(500, "quote")),
+            Bid::new_scope_bundle_trade("bid_id", &["scope2", "scope1"]),
+            None,
+        )
+        .expect("the bundle bid should be created successfully");
+        let response = execute_match(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &[]),
+            "ask_id".to_string(),
+            "bid_id".to_string(),
+            None,
+        )
+        .expect("the bundle match should execute successfully");
+        assert_eq!(
+            3,
+            response.messages.len(),
+            "a payment and a write for each scope should be produced",
+        );
+        let mut written_scopes = vec![];
+        response.messages.iter().for_each(|msg| match &msg.msg {
+            CosmosMsg::Bank(BankMsg::Send { to_address, amount }) => {
+                assert_eq!("asker", to_address, "the asker should be paid");
+                assert_eq!(
+                    &coins(500, "quote"),
+                    amount,
+                    "the asker should receive the quote"
+                );
+            }
+            CosmosMsg::Custom(ProvenanceMsg {
+                params: ProvenanceMsgParams::Metadata(MetadataMsgParams::WriteScope { scope, .. }),
+                ..
+            }) => {
+                assert_eq!(
+                    "bidder",
+                    scope.value_owner_address.as_str(),
+                    "the bidder should be the new value owner of each scope",
+                );
+                assert!(
+                    scope
+                        .owners
+                        .iter()
+                        .all(|owner| owner.address.as_str() == "bidder"),
+                    "the bidder should be the sole owner of each scope",
+                );
+                written_scopes.push(scope.scope_id.to_owned());
+            }
+            msg => panic!("unexpected message: {:?}", msg),
+        });
+        written_scopes.sort();
+        assert_eq!(
+            vec!["scope1".to_string(), "scope2".to_string()],
+            written_scopes,
+            "every scope in the bundle should be transferred",
+        );
+        assert!(
+            get_ask_orders_by_collateral_id(deps.as_ref().storage, "scope1").is_empty(),
+            "the bundled scopes should no longer be tracked after the match",
+        );
+    }
 }
@@ -0,0 +1,580 @@
+use crate::execute::execute_match::{plan_match_orders, settle_match_orders};
+use crate::storage::contract_info::get_contract_info;
+use crate::types::core::constants::MAX_EXECUTE_MATCHES_BATCH_SIZE;
+use crate::types::core::error::ContractError;
+use crate::types::request::match_batch::{
+    BatchMatchMode, ExecuteMatchesResponse, MatchPair, MatchPairResult,
+};
+use crate::util::extensions::ResultExtensions;
+use crate::validation::contract_status_validation::validate_matching_allowed;
+use crate::validation::validation_handler::ValidationHandler;
+use cosmwasm_std::{to_binary, CosmosMsg, DepsMut, Env, MessageInfo, Response};
+use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
+
+// execute matches entrypoint
+pub fn execute_matches(
+    deps: DepsMut<ProvenanceQuery>,
+    env: Env,
+    info: MessageInfo,
+    matches: Vec<MatchPair>,
+    mode: Option<BatchMatchMode>,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    let handler = ValidationHandler::new();
+    if matches.is_empty() {
+        handler.push("at least one match must be provided");
+    }
+    if matches.len() > MAX_EXECUTE_MATCHES_BATCH_SIZE {
+        handler.push(format!(
+            "no more than [{}] matches may be provided, but found [{}]",
+            MAX_EXECUTE_MATCHES_BATCH_SIZE,
+            matches.len(),
+        ));
+    }
+    for (index, pair) in matches.iter().enumerate() {
+        if pair.ask_id.is_empty() {
+            handler.push(format!("match [{}]: ask id must not be empty", index));
+        }
+        if pair.bid_id.is_empty() {
+            handler.push(format!("match [{}]: bid id must not be empty", index));
+        }
+    }
+    let contract_info = get_contract_info(deps.storage)?;
+    // return error if the batch or any of its ids are badly formed
+    handler.handle()?;
+    // return error if funds sent
+    if !info.funds.is_empty() {
+        return ContractError::InvalidFundsProvided {
+            message: "funds should not be provided during match execution".to_string(),
+        }
+        .to_err();
+    }
+    validate_matching_allowed(&contract_info)?;
+    let mode = mode.unwrap_or(BatchMatchMode::AllOrNothing);
+    let mut messages: Vec<CosmosMsg<ProvenanceMsg>> = vec![];
+    let mut results: Vec<MatchPairResult> = vec![];
+    // Pairs are executed sequentially, so each match observes the storage changes made by the
+    // matches before it.  This allows many bids to be settled against a single ask.
+    for pair in matches.into_iter() {
+        match plan_match_orders(
+            &deps.as_ref(),
+            &env,
+            &info.sender,
+            &contract_info,
+            &pair.ask_id,
+            &pair.bid_id,
+            pair.admin_match_options,
+        ) {
+            Ok(plan) => {
+                // Settlement modifies storage before it can fail, and the contract cannot revert a
+                // single pair's changes, so settlement errors always fail the entire batch
+                let mut match_result =
+                    settle_match_orders(deps.storage, &env, &info.sender, &contract_info, plan)
+                        .map_err(|e| ContractError::GenericError {
+                            message: format!(
+                        "match for AskOrder [{}] and BidOrder [{}] failed during settlement: {}",
+                        &pair.ask_id, &pair.bid_id, e,
+                    ),
+                        })?;
+                messages.append(&mut match_result.messages);
+                results.push(MatchPairResult {
+                    ask_id: pair.ask_id,
+                    bid_id: pair.bid_id,
+                    success: true,
+                    ask_deleted: match_result.ask_deleted,
+                    bid_deleted: match_result.bid_deleted,
+                    collateral_released: match_result.collateral_released,
+                    match_record_id: Some(match_result.match_record_id),
+                    settlement_fee: match_result.settlement_fee,
+                    error_message: None,
+                });
+            }
+            Err(e) => match mode {
+                // Returning an error reverts every match already executed in the batch
+                BatchMatchMode::AllOrNothing => {
+                    return ContractError::GenericError {
+                        message: format!(
+                            "match for AskOrder [{}] and BidOrder [{}] failed: {}",
+                            &pair.ask_id, &pair.bid_id, e,
+                        ),
+                    }
+                    .to_err();
+                }
+                BatchMatchMode::BestEffort => results.push(MatchPairResult {
+                    ask_id: pair.ask_id,
+                    bid_id: pair.bid_id,
+                    success: false,
+                    ask_deleted: false,
+                    bid_deleted: false,
+                    collateral_released: false,
+                    match_record_id: None,
+                    settlement_fee: vec![],
+                    error_message: Some(e.to_string()),
+                }),
+            },
+        }
+    }
+    let successful_results = results
+        .iter()
+        .filter(|result| result.success)
+        .collect::<Vec<&MatchPairResult>>();
+    Response::new()
+        .add_messages(messages)
+        .add_attribute("action", "execute_matches")
+        .add_attribute("match_count", results.len().to_string())
+        .add_attribute(
+            "successful_match_count",
+            successful_results.len().to_string(),
+        )
+        .add_attribute(
+            "failed_match_count",
+            (results.len() - successful_results.len()).to_string(),
+        )
+        .add_attribute(
+            "match_record_ids",
+            successful_results
+                .iter()
+                .filter_map(|result| result.match_record_id.map(|id| id.to_string()))
+                .collect::<Vec<String>>()
+                .join(","),
+        )
+        .set_data(to_binary(&ExecuteMatchesResponse { results })?)
+        .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::execute::create_ask::create_ask;
+    use crate::execute::create_bid::create_bid;
+    use crate::execute::execute_matches::execute_matches;
+    use crate::storage::ask_order_storage::get_ask_order_by_id;
+    use crate::storage::ask_order_storage::update_ask_order;
+    use crate::storage::bid_order_storage::get_bid_order_by_id;
+    use crate::storage::match_record_storage::get_match_record_by_id;
+    use crate::test::cosmos_type_helpers::{single_attribute_for_key, MockOwnedDeps};
+    use crate::test::error_helpers::assert_validation_error_message;
+    use crate::test::mock_instantiate::{default_instantiate, DEFAULT_ADMIN_ADDRESS};
+    use crate::test::mock_marker::{MockMarker, DEFAULT_MARKER_DENOM};
+    use crate::types::core::constants::MAX_EXECUTE_MATCHES_BATCH_SIZE;
+    use crate::types::core::error::ContractError;
+    use crate::types::request::ask_types::ask::Ask;
+    use crate::types::request::ask_types::ask_collateral::AskCollateral;
+    use crate::types::request::bid_types::bid::Bid;
+    use crate::types::request::match_batch::{
+        BatchMatchMode, ExecuteMatchesResponse, MatchPair, MatchPairResult,
+    };
+    use crate::types::request::share_sale_type::ShareSaleType;
+    use crate::util::constants::NHASH;
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{coins, from_binary, Addr, Binary, Uint128};
+    use provwasm_mocks::mock_dependencies;
+    use provwasm_std::{AccessGrant, MarkerAccess};
+
+    #[test]
+    fn test_execute_matches_with_invalid_data() {
+        let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
+        let err = execute_matches(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+            vec![],
+            None,
+        )
+        .expect_err("an error should occur when no matches are provided");
+        assert_validation_error_message(err, "at least one match must be provided");
+        let err = execute_matches(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+            vec![
+                MatchPair::new("ask_id", "bid_id", None),
+                MatchPair::new("", "bid_id", None),
+            ],
+            None,
+        )
+        .expect_err("an error should occur when a match includes an empty ask id");
+        assert_validation_error_message(err, "match [1]: ask id must not be empty");
+        let err = execute_matches(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+            vec![MatchPair::new("ask_id", "", None)],
+            None,
+        )
+        .expect_err("an error should occur when a match includes an empty bid id");
+        assert_validation_error_message(err, "match [0]: bid id must not be empty");
+        let err = execute_matches(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+            (0..=MAX_EXECUTE_MATCHES_BATCH_SIZE)
+                .map(|index| MatchPair::new("ask_id", format!("bid_id_{}", index), None))
+                .collect(),
+            None,
+        )
+        .expect_err("an error should occur when too many matches are provided");
+        assert_validation_error_message(
+            err,
+            format!(
+                "no more than [{}] matches may be provided, but found [{}]",
+                MAX_EXECUTE_MATCHES_BATCH_SIZE,
+                MAX_EXECUTE_MATCHES_BATCH_SIZE + 1,
+            ),
+        );
+        let err = execute_matches(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &coins(100, NHASH)),
+            vec![MatchPair::new("ask_id", "bid_id", None)],
+            None,
+        )
+        .expect_err("an error should occur when funds are provided");
+        assert!(
+            matches!(err, ContractError::InvalidFundsProvided { .. }),
+            "an invalid funds error should be returned when funds are provided, but got: {:?}",
+            err,
+        );
+    }
+
+    #[test]
+    fn test_execute_matches_all_or_nothing_settles_many_bids_against_one_ask() {
+        let mut deps = mock_dependencies(&[]);
+        setup_partial_fill_ask_and_bids(&mut deps);
+        let response = execute_matches(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+            vec![
+                MatchPair::new("ask_id", "bid_id_1", None),
+                MatchPair::new("ask_id", "bid_id_2", None),
+            ],
+            None,
+        )
+        .expect("all matches should execute successfully");
+        assert_eq!(
+            "2",
+            single_attribute_for_key(&response, "successful_match_count"),
+            "both matches should be counted as successful",
+        );
+        assert_eq!(
+            "0",
+            single_attribute_for_key(&response, "failed_match_count"),
+            "no matches should be counted as failed",
+        );
+        assert_eq!(
+            "1,2",
+            single_attribute_for_key(&response, "match_record_ids"),
+            "the match record ids should be listed in execution order",
+        );
+        assert_eq!(
+            4,
+            response.messages.len(),
+            "each match should produce a payment to the asker and the bidder",
+        );
+        let results = deserialize_results(&response.data);
+        assert_eq!(
+            vec![
+                successful_result("bid_id_1", false, 1),
+                successful_result("bid_id_2", false, 2),
+            ],
+            results,
+            "a successful result should be produced for each pair",
+        );
+        let remaining_base = get_ask_order_by_id(deps.as_ref().storage, "ask_id")
+            .expect("the ask should remain in storage with base left to sell")
+            .collateral
+            .get_coin_trade()
+            .expect("the ask should be a coin trade")
+            .remaining_base;
+        assert_eq!(
+            Some(Uint128::new(50)),
+            remaining_base,
+            "the remaining base should reflect both matches",
+        );
+    }
+
+    #[test]
+    fn test_execute_matches_all_or_nothing_rejects_batch_with_failing_pair() {
+        let mut deps = mock_dependencies(&[]);
+        setup_partial_fill_ask_and_bids(&mut deps);
+        let err = execute_matches(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+            vec![
+                MatchPair::new("ask_id", "bid_id_1", None),
+                MatchPair::new("ask_id", "mispriced_bid", None),
+            ],
+            Some(BatchMatchMode::AllOrNothing),
+        )
+        .expect_err("the batch should fail when any pair fails");
+        match err {
+            ContractError::GenericError { message } => assert!(
+                message.starts_with(
+                    "match for AskOrder [ask_id] and BidOrder [mispriced_bid] failed:"
+                ),
+                "the error should identify the failing pair, but got: {}",
+                message,
+            ),
+            e => panic!("unexpected error: {:?}", e),
+        };
+    }
+
+    #[test]
+    fn test_execute_matches_best_effort_skips_failing_pairs() {
+        let mut deps = mock_dependencies(&[]);
+        setup_partial_fill_ask_and_bids(&mut deps);
+        let response = execute_matches(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+            vec![
+                MatchPair::new("ask_id", "bid_id_1", None),
+                MatchPair::new("ask_id", "mispriced_bid", None),
+                MatchPair::new("ask_id", "missing_bid", None),
+                MatchPair::new("ask_id", "bid_id_2", None),
+            ],
+            Some(BatchMatchMode::BestEffort),
+        )
+        .expect("the batch should succeed when some pairs fail in best effort mode");
+        assert_eq!(
+            "4",
+            single_attribute_for_key(&response, "match_count"),
+            "every pair should be counted",
+        );
+        assert_eq!(
+            "2",
+            single_attribute_for_key(&response, "successful_match_count"),
+            "only the valid pairs should be counted as successful",
+        );
+        assert_eq!(
+            "2",
+            single_attribute_for_key(&response, "failed_match_count"),
+            "the invalid pairs should be counted as failed",
+        );
+        let results = deserialize_results(&response.data);
+        assert_eq!(
+            4,
+            results.len(),
+            "a result should be produced for each pair"
+        );
+        assert_eq!(
+            successful_result("bid_id_1", false, 1),
+            results[0],
+            "the first pair should succeed",
+        );
+        assert_eq!(
+            successful_result("bid_id_2", false, 2),
+            results[3],
+            "the last pair should succeed after the failures are skipped",
+        );
+        for failed_result in [&results[1], &results[2]] {
+            assert!(
+                !failed_result.success && failed_result.match_record_id.is_none(),
+                "the failing pair should not produce a match record: {:?}",
+                failed_result,
+            );
+            assert!(
+                failed_result.error_message.is_some(),
+                "the failing pair should include an error message: {:?}",
+                failed_result,
+            );
+        }
+        assert!(
+            results[1]
+                .error_message
+                .as_ref()
+                .unwrap()
+                .contains("does not match bid quote"),
+            "the mispriced bid should explain the failure, but got: {:?}",
+            results[1].error_message,
+        );
+        get_bid_order_by_id(deps.as_ref().storage, "mispriced_bid")
+            .expect("the failing bid should remain in storage");
+        get_match_record_by_id(deps.as_ref().storage, 3)
+            .expect_err("no match records should be stored for the failing pairs");
+    }
+
+    #[test]
+    fn test_execute_matches_best_effort_leaves_failed_share_sale_untouched() {
+        let mut deps = mock_dependencies(&[]);
+        setup_partial_fill_ask_and_bids(&mut deps);
+        deps.querier
+            .with_markers(vec![MockMarker::new_owned_marker("asker")]);
+        create_ask(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &[]),
+            Ask::new_marker_share_sale(
+                "share_ask",
+                DEFAULT_MARKER_DENOM,
+                10,
+                &coins(2, "quote"),
+                ShareSaleType::SingleTransaction,
+            ),
+            None,
+        )
+        .expect("the marker share sale ask should be created");
+        // Simulate the marker being moved to be owned by the contract
+        deps.querier.with_markers(vec![MockMarker::new_marker()]);
+        create_bid(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("share_bidder", &coins(20, "quote")),
+            Bid::new_marker_share_sale("share_bid", DEFAULT_MARKER_DENOM, 10),
+            None,
+        )
+        .expect("the marker share sale bid should be created");
+        // A permission that cannot be restored causes the share sale's settlement to fail after it
+        // has passed validation, when the marker is released
+        let mut share_ask = get_ask_order_by_id(deps.as_ref().storage, "share_ask")
+            .expect("the share sale ask should be stored");
+        if let AskCollateral::MarkerShareSale(collateral) = &mut share_ask.collateral {
+            collateral.removed_permissions.push(AccessGrant {
+                address: Addr::unchecked(""),
+                permissions: vec![MarkerAccess::Admin],
+            });
+        }
+        update_ask_order(deps.as_mut().storage, &share_ask)
+            .expect("the share sale ask should be updated");
+        let share_bid = get_bid_order_by_id(deps.as_ref().storage, "share_bid")
+            .expect("the share sale bid should be stored");
+        let response = execute_matches(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+            vec![
+                MatchPair::new("ask_id", "bid_id_1", None),
+                MatchPair::new("share_ask", "share_bid", None),
+                MatchPair::new("ask_id", "bid_id_2", None),
+            ],
+            Some(BatchMatchMode::BestEffort),
+        )
+        .expect("the batch should succeed when a share sale fails in best effort mode");
+        let results = deserialize_results(&response.data);
+        assert_eq!(
+            successful_result("bid_id_1", false, 1),
+            results[0],
+            "the pair before the share sale should succeed",
+        );
+        assert_eq!(
+            successful_result("bid_id_2", false, 2),
+            results[2],
+            "the pair after the share sale should succeed",
+        );
+        assert!(
+            !results[1].success
+                && results[1]
+                    .error_message
+                    .as_ref()
+                    .unwrap()
+                    .contains("address must not be empty"),
+            "the share sale should fail while calculating its settlement: {:?}",
+            results[1],
+        );
+        assert_eq!(
+            share_ask,
+            get_ask_order_by_id(deps.as_ref().storage, "share_ask")
+                .expect("the failed share sale ask should remain in storage"),
+            "the failed share sale ask should not be modified",
+        );
+        assert_eq!(
+            share_bid,
+            get_bid_order_by_id(deps.as_ref().storage, "share_bid")
+                .expect("the failed share sale bid should remain in storage"),
+            "the failed share sale bid should not be modified",
+        );
+        assert_eq!(
+            4,
+            response.messages.len(),
+            "only the payment and base transfer of each successful pair should be dispatched",
+        );
+    }
+
+    #[test]
+    fn test_execute_matches_best_effort_authorizes_each_pair() {
+        let mut deps = mock_dependencies(&[]);
+        setup_partial_fill_ask_and_bids(&mut deps);
+        create_ask(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("other_asker", &coins(10, "base")),
+            Ask::new_coin_trade("other_ask", &coins(10, "quote")),
+            None,
+        )
+        .expect("the other ask should be created successfully");
+        let response = execute_matches(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &[]),
+            vec![
+                MatchPair::new("other_ask", "bid_id_1", None),
+                MatchPair::new("ask_id", "bid_id_1", None),
+            ],
+            Some(BatchMatchMode::BestEffort),
+        )
+        .expect("the batch should succeed in best effort mode");
+        let results = deserialize_results(&response.data);
+        assert_eq!(
+            Some("Unauthorized".to_string()),
+            results[0].error_message,
+            "the asker should not be able to match another account's ask",
+        );
+        assert!(
+            results[1].success,
+            "the asker should be able to match their own ask",
+        );
+    }
+
+    fn setup_partial_fill_ask_and_bids(deps: &mut MockOwnedDeps) {
+        default_instantiate(deps.as_mut());
+        create_ask(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &coins(100, "base")),
+            Ask::new_partial_fill_coin_trade("ask_id", &coins(200, "quote")),
+            None,
+        )
+        .expect("the partial fill ask should be created successfully");
+        for (bid_id, bidder, quote_amount, base_amount) in [
+            ("bid_id_1", "bidder_1", 40, 20),
+            ("bid_id_2", "bidder_2", 60, 30),
+            ("mispriced_bid", "bidder_3", 10, 30),
+        ] {
+            create_bid(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(bidder, &coins(quote_amount, "quote")),
+                Bid::new_coin_trade(bid_id, &coins(base_amount, "base")),
+                None,
+            )
+            .expect("the bid should be created successfully");
+        }
+    }
+
+    fn successful_result(
+        bid_id: &str,
+        ask_deleted: bool,
+        match_record_id: u128,
+    ) -> MatchPairResult {
+        MatchPairResult {
+            ask_id: "ask_id".to_string(),
+            bid_id: bid_id.to_string(),
+            success: true,
+            ask_deleted,
+            bid_deleted: true,
+            collateral_released: ask_deleted,
+            match_record_id: Some(Uint128::new(match_record_id)),
+            settlement_fee: vec![],
+            error_message: None,
+        }
+    }
+
+    fn deserialize_results(data: &Option<Binary>) -> Vec<MatchPairResult> {
+        from_binary::<ExecuteMatchesResponse>(
+            data.as_ref().expect("the response should include data"),
+        )
+        .expect("the data should deserialize to an execute matches response")
+        .results
+    }
+}
@@ -0,0 +1,263 @@
+use crate::storage::ask_order_storage::get_expired_ask_orders;
+use crate::storage::bid_order_storage::get_expired_bid_orders;
+use crate::storage::contract_info::get_contract_info;
+use crate::storage::contract_role_storage::address_has_role;
+use crate::types::core::constants::{DEFAULT_EXPIRE_ORDERS_LIMIT, MAX_EXPIRE_ORDERS_LIMIT};
+use crate::types::core::contract_role::ContractRole;
+use crate::types::core::error::ContractError;
+use crate::util::cancel_order_utilities::{cancel_ask_order, cancel_bid_order};
+use crate::util::extensions::ResultExtensions;
+use cosmwasm_std::{CosmosMsg, DepsMut, Env, MessageInfo, Response, Uint128};
+use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
+
+// expire orders entrypoint
+pub fn expire_orders(
+    mut deps: DepsMut<ProvenanceQuery>,
+    env: Env,
+    info: MessageInfo,
+    limit: Option<Uint128>,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    if !address_has_role(
+        deps.storage,
+        &get_contract_info(deps.storage)?,
+        &info.sender,
+        &ContractRole::OrderCanceller,
+    ) {
+        return ContractError::Unauthorized.to_err();
+    }
+    if !info.funds.is_empty() {
+        return ContractError::InvalidFundsProvided {
+            message: "funds should not be provided when expiring orders".to_string(),
+        }
+        .to_err();
+    }
+    let limit = limit
+        .map(|l| l.u128() as usize)
+        .unwrap_or(DEFAULT_EXPIRE_ORDERS_LIMIT)
+        .clamp(1, MAX_EXPIRE_ORDERS_LIMIT);
+    let mut messages: Vec<CosmosMsg<ProvenanceMsg>> = vec![];
+    // Asks are processed first, and any remaining room under the limit is used to process bids
+    let expired_asks = get_expired_ask_orders(deps.storage, &env.block.time, limit);
+    for ask_order in expired_asks.iter() {
+        messages.append(&mut cancel_ask_order(&mut deps, &env, ask_order)?.messages);
+    }
+    let expired_bids =
+        get_expired_bid_orders(deps.storage, &env.block.time, limit - expired_asks.len());
+    for bid_order in expired_bids.iter() {
+        messages.push(cancel_bid_order(deps.storage, bid_order)?);
+    }
+    Response::new()
+        .add_messages(messages)
+        .add_attribute("action", "expire_orders")
+        .add_attribute(
+            "expired_ask_ids",
+            expired_asks
+                .iter()
+                .map(|ask| ask.id.to_owned())
+                .collect::<Vec<String>>()
+                .join(","),
+        )
+        .add_attribute(
+            "expired_bid_ids",
+            expired_bids
+                .iter()
+                .map(|bid| bid.id.to_owned())
+                .collect::<Vec<String>>()
+                .join(","),
+        )
+        .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::execute::create_ask::create_ask;
+    use crate::execute::create_bid::create_bid;
+    use crate::execute::expire_orders::expire_orders;
+    use crate::storage::ask_order_storage::get_ask_order_by_id;
+    use crate::storage::bid_order_storage::get_bid_order_by_id;
+    use crate::test::cosmos_type_helpers::{single_attribute_for_key, MockOwnedDeps};
+    use crate::test::mock_instantiate::{default_instantiate, DEFAULT_ADMIN_ADDRESS};
+    use crate::types::core::error::ContractError;
+    use crate::types::request::ask_types::ask::Ask;
+    use crate::types::request::bid_types::bid::Bid;
+    use crate::types::request::request_descriptor::RequestDescriptor;
+    use crate::util::constants::NHASH;
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{coins, BankMsg, Coin, CosmosMsg, Env, Uint128};
+    use provwasm_mocks::mock_dependencies;
+    use provwasm_std::ProvenanceMsg;
+
+    #[test]
+    fn test_expire_orders_with_invalid_data() {
+        let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
+        let err = expire_orders(deps.as_mut(), mock_env(), mock_info("not_admin", &[]), None)
+            .expect_err("an error should occur when the sender is not the admin");
+        assert!(
+            matches!(err, ContractError::Unauthorized),
+            "an unauthorized error should be produced when the admin is not the sender, but got: {:?}",
+            err,
+        );
+        let err = expire_orders(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &coins(100, NHASH)),
+            None,
+        )
+        .expect_err("an error should occur when funds are provided");
+        assert!(
+            matches!(err, ContractError::InvalidFundsProvided { .. }),
+            "an invalid funds provided error should occur when the sender adds funds, but got: {:?}",
+            err,
+        );
+    }
+
+    #[test]
+    fn test_expire_orders_removes_only_expired_orders() {
+        let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
+        create_expiring_ask(&mut deps, "ask_expiring", 100);
+        create_expiring_ask(&mut deps, "ask_not_expiring", 1000);
+        create_expiring_bid(&mut deps, "bid_expiring", 50);
+        create_expiring_bid(&mut deps, "bid_not_expiring", 5000);
+        let response = expire_orders(
+            deps.as_mut(),
+            env_at_offset(500),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+            None,
+        )
+        .expect("expiring orders as the admin should succeed");
+        assert_eq!(
+            "expire_orders",
+            single_attribute_for_key(&response, "action"),
+            "the correct action attribute should be emitted",
+        );
+        assert_eq!(
+            "ask_expiring",
+            single_attribute_for_key(&response, "expired_ask_ids"),
+            "only the expired ask should be included in the attributes",
+        );
+        assert_eq!(
+            "bid_expiring",
+            single_attribute_for_key(&response, "expired_bid_ids"),
+            "only the expired bid should be included in the attributes",
+        );
+        assert_eq!(
+            2,
+            response.messages.len(),
+            "a message should be sent to return collateral for each expired order",
+        );
+        assert_bank_send(
+            &response.messages[0].msg,
+            "asker",
+            &coins(100, "base"),
+            "the ask's base should be returned to the asker",
+        );
+        assert_bank_send(
+            &response.messages[1].msg,
+            "bidder",
+            &coins(100, "quote"),
+            "the bid's quote should be returned to the bidder",
+        );
+        get_ask_order_by_id(deps.as_ref().storage, "ask_expiring")
+            .expect_err("the expired ask should be removed from storage");
+        get_bid_order_by_id(deps.as_ref().storage, "bid_expiring")
+            .expect_err("the expired bid should be removed from storage");
+        get_ask_order_by_id(deps.as_ref().storage, "ask_not_expiring")
+            .expect("the unexpired ask should remain in storage");
+        get_bid_order_by_id(deps.as_ref().storage, "bid_not_expiring")
+            .expect("the unexpired bid should remain in storage");
+    }
+
+    #[test]
+    fn test_expire_orders_respects_limit() {
+        let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
+        create_expiring_ask(&mut deps, "ask_1", 10);
+        create_expiring_ask(&mut deps, "ask_2", 20);
+        create_expiring_bid(&mut deps, "bid_1", 10);
+        let response = expire_orders(
+            deps.as_mut(),
+            env_at_offset(100),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+            Some(Uint128::new(2)),
+        )
+        .expect("expiring orders with a limit should succeed");
+        assert_eq!(
+            "ask_1,ask_2",
+            single_attribute_for_key(&response, "expired_ask_ids"),
+            "the asks should be processed first, in order of expiration",
+        );
+        assert_eq!(
+            "",
+            single_attribute_for_key(&response, "expired_bid_ids"),
+            "no bids should be processed when the limit is reached by asks",
+        );
+        let response = expire_orders(
+            deps.as_mut(),
+            env_at_offset(100),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+            Some(Uint128::new(2)),
+        )
+        .expect("a subsequent sweep should succeed");
+        assert_eq!(
+            "",
+            single_attribute_for_key(&response, "expired_ask_ids"),
+            "no asks should remain to be expired",
+        );
+        assert_eq!(
+            "bid_1",
+            single_attribute_for_key(&response, "expired_bid_ids"),
+            "the remaining expired bid should be processed",
+        );
+    }
+
+    fn env_at_offset(seconds: u64) -> Env {
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(seconds);
+        env
+    }
+
+    fn create_expiring_ask(deps: &mut MockOwnedDeps, ask_id: &str, expires_in_seconds: u64) {
+        create_ask(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &coins(100, "base")),
+            Ask::new_coin_trade(ask_id, &coins(100, "quote")),
+            Some(
+                RequestDescriptor::basic("expiring ask")
+                    .with_expiration_time(env_at_offset(expires_in_seconds).block.time),
+            ),
+        )
+        .expect("expected the ask to be created successfully");
+    }
+
+    fn create_expiring_bid(deps: &mut MockOwnedDeps, bid_id: &str, expires_in_seconds: u64) {
+        create_bid(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder", &coins(100, "quote")),
+            Bid::new_coin_trade(bid_id, &coins(100, "base")),
+            Some(
+                RequestDescriptor::basic("expiring bid")
+                    .with_expiration_time(env_at_offset(expires_in_seconds).block.time),
+            ),
+        )
+        .expect("expected the bid to be created successfully");
+    }
+
+    fn assert_bank_send(
+        msg: &CosmosMsg<ProvenanceMsg>,
+        expected_recipient: &str,
+        expected_amount: &[Coin],
+        message: &str,
+    ) {
+        match msg {
+            CosmosMsg::Bank(BankMsg::Send { to_address, amount }) => {
+                assert_eq!(expected_recipient, to_address, "{}", message);
+                assert_eq!(expected_amount, amount, "{}", message);
+            }
+            msg => panic!("unexpected message sent: {:?}", msg),
+        }
+    }
+}
@@ -0,0 +1,197 @@
+use crate::storage::contract_info::{get_contract_info, set_contract_info};
+use crate::storage::contract_role_storage::{address_has_role, grant_stored_role};
+use crate::types::core::contract_role::ContractRole;
+use crate::types::core::error::ContractError;
+use crate::util::extensions::ResultExtensions;
+use cosmwasm_std::{DepsMut, MessageInfo, Response};
+use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
+
+// grant role entrypoint
+pub fn grant_role(
+    deps: DepsMut<ProvenanceQuery>,
+    info: MessageInfo,
+    address: String,
+    role: ContractRole,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    let mut contract_info = get_contract_info(deps.storage)?;
+    if !address_has_role(
+        deps.storage,
+        &contract_info,
+        &info.sender,
+        &ContractRole::SettingsAdmin,
+    ) {
+        return ContractError::Unauthorized.to_err();
+    }
+    if !info.funds.is_empty() {
+        return ContractError::InvalidFundsProvided {
+            message: "funds cannot be provided when granting a role".to_string(),
+        }
+        .to_err();
+    }
+    let address = deps.api.addr_validate(&address)?;
+    if address == contract_info.admin {
+        return ContractError::InvalidRequest {
+            message: "the contract admin implicitly holds all roles".to_string(),
+        }
+        .to_err();
+    }
+    match role {
+        // Only one account may collect fees, so granting the role replaces the existing collector
+        ContractRole::FeeCollector => {
+            contract_info.fee_collector_address = Some(address.to_owned());
+            set_contract_info(deps.storage, &contract_info)?;
+        }
+        _ => grant_stored_role(deps.storage, &address, &role)?,
+    };
+    Response::new()
+        .add_attribute("action", "grant_role")
+        .add_attribute("address", address.as_str())
+        .add_attribute("role", role.get_name())
+        .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::execute::grant_role::grant_role;
+    use crate::storage::contract_info::get_contract_info;
+    use crate::storage::contract_role_storage::get_roles_for_address;
+    use crate::test::cosmos_type_helpers::single_attribute_for_key;
+    use crate::test::mock_instantiate::{default_instantiate, DEFAULT_ADMIN_ADDRESS};
+    use crate::types::core::contract_role::ContractRole;
+    use crate::types::core::error::ContractError;
+    use crate::util::constants::NHASH;
+    use cosmwasm_std::testing::mock_info;
+    use cosmwasm_std::{coins, Addr};
+    use provwasm_mocks::mock_dependencies;
+
+    #[test]
+    fn test_grant_role_with_invalid_data() {
+        let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
+        let err = grant_role(
+            deps.as_mut(),
+            mock_info("not_admin", &[]),
+            "match_bot".to_string(),
+            ContractRole::Matcher,
+        )
+        .expect_err("an error should occur when the sender is not a settings admin");
+        assert!(
+            matches!(err, ContractError::Unauthorized),
+            "an unauthorized error should be returned, but got: {:?}",
+            err,
+        );
+        let err = grant_role(
+            deps.as_mut(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &coins(100, NHASH)),
+            "match_bot".to_string(),
+            ContractRole::Matcher,
+        )
+        .expect_err("an error should occur when funds are provided");
+        assert!(
+            matches!(err, ContractError::InvalidFundsProvided { .. }),
+            "an invalid funds error should be returned, but got: {:?}",
+            err,
+        );
+        let err = grant_role(
+            deps.as_mut(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+            DEFAULT_ADMIN_ADDRESS.to_string(),
+            ContractRole::Matcher,
+        )
+        .expect_err("an error should occur when granting a role to the admin");
+        assert!(
+            matches!(err, ContractError::InvalidRequest { .. }),
+            "an invalid request error should be returned, but got: {:?}",
+            err,
+        );
+    }
+
+    #[test]
+    fn test_grant_roles() {
+        let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
+        let response = grant_role(
+            deps.as_mut(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+            "ops".to_string(),
+            ContractRole::SettingsAdmin,
+        )
+        .expect("the admin should be able to grant the settings admin role");
+        assert_eq!(
+            "grant_role",
+            single_attribute_for_key(&response, "action"),
+            "the correct action attribute should be emitted",
+        );
+        assert_eq!(
+            "ops",
+            single_attribute_for_key(&response, "address"),
+            "the correct address attribute should be emitted",
+        );
+        assert_eq!(
+            "settings_admin",
+            single_attribute_for_key(&response, "role"),
+            "the correct role attribute should be emitted",
+        );
+        // Settings admins may grant roles to other accounts
+        grant_role(
+            deps.as_mut(),
+            mock_info("ops", &[]),
+            "match_bot".to_string(),
+            ContractRole::Matcher,
+        )
+        .expect("a settings admin should be able to grant the matcher role");
+        grant_role(
+            deps.as_mut(),
+            mock_info("ops", &[]),
+            "treasury".to_string(),
+            ContractRole::FeeCollector,
+        )
+        .expect("a settings admin should be able to grant the fee collector role");
+        let contract_info =
+            get_contract_info(deps.as_ref().storage).expect("contract info should load");
+        assert_eq!(
+            vec![ContractRole::Matcher],
+            get_roles_for_address(
+                deps.as_ref().storage,
+                &contract_info,
+                &Addr::unchecked("match_bot"),
+            ),
+            "the match bot should only hold the matcher role",
+        );
+        assert_eq!(
+            "treasury",
+            contract_info.get_fee_collector_address().as_str(),
+            "the fee collector should be set in the contract info",
+        );
+        grant_role(
+            deps.as_mut(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+            "new_treasury".to_string(),
+            ContractRole::FeeCollector,
+        )
+        .expect("the fee collector role should be transferable");
+        let contract_info =
+            get_contract_info(deps.as_ref().storage).expect("contract info should load");
+        assert!(
+            get_roles_for_address(
+                deps.as_ref().storage,
+                &contract_info,
+                &Addr::unchecked("treasury"),
+            )
+            .is_empty(),
+            "the previous fee collector should no longer hold the role",
+        );
+        let err = grant_role(
+            deps.as_mut(),
+            mock_info("match_bot", &[]),
+            "match_bot".to_string(),
+            ContractRole::SettingsAdmin,
+        )
+        .expect_err("a matcher should not be able to grant roles");
+        assert!(
+            matches!(err, ContractError::Unauthorized),
+            "an unauthorized error should be returned, but got: {:?}",
+            err,
+        );
+    }
+}
@@ -1,8 +1,20 @@
+pub mod accept_admin;
+pub mod add_quote_denoms;
+pub mod block_addresses;
+pub mod cancel_admin_proposal;
 pub mod cancel_ask;
 pub mod cancel_bid;
 pub mod create_ask;
 pub mod create_bid;
 pub mod execute_match;
+pub mod execute_matches;
+pub mod expire_orders;
+pub mod grant_role;
+pub mod propose_admin;
+pub mod register_scope_stake;
+pub mod remove_quote_denoms;
+pub mod revoke_role;
+pub mod unblock_addresses;
 pub mod update_ask;
 pub mod update_bid;
 pub mod update_settings;
@@ -0,0 +1,166 @@
+use crate::storage::contract_info::{get_contract_info, set_contract_info};
+use crate::types::core::error::ContractError;
+use crate::util::extensions::ResultExtensions;
+use cosmwasm_std::{DepsMut, MessageInfo, Response};
+use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
+
+// propose admin entrypoint
+pub fn propose_admin(
+    deps: DepsMut<ProvenanceQuery>,
+    info: MessageInfo,
+    address: String,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    let mut contract_info = get_contract_info(deps.storage)?;
+    // Only the admin may transfer its own privileges, regardless of the roles held by the sender
+    if info.sender != contract_info.admin {
+        return ContractError::Unauthorized.to_err();
+    }
+    if !info.funds.is_empty() {
+        return ContractError::InvalidFundsProvided {
+            message: "funds cannot be provided when proposing a new admin".to_string(),
+        }
+        .to_err();
+    }
+    if address.is_empty() {
+        return ContractError::ValidationError {
+            messages: vec!["an address must be provided when proposing a new admin".to_string()],
+        }
+        .to_err();
+    }
+    let proposed_admin = deps.api.addr_validate(&address)?;
+    if proposed_admin == contract_info.admin {
+        return ContractError::InvalidRequest {
+            message: format!(
+                "address [{}] is already the contract admin",
+                proposed_admin.as_str()
+            ),
+        }
+        .to_err();
+    }
+    // Any existing proposal is replaced by the new proposal
+    contract_info.pending_admin = Some(proposed_admin.to_owned());
+    set_contract_info(deps.storage, &contract_info)?;
+    Response::new()
+        .add_attribute("action", "propose_admin")
+        .add_attribute("proposed_admin", proposed_admin.as_str())
+        .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::execute::grant_role::grant_role;
+    use crate::execute::propose_admin::propose_admin;
+    use crate::storage::contract_info::get_contract_info;
+    use crate::test::cosmos_type_helpers::single_attribute_for_key;
+    use crate::test::error_helpers::assert_validation_error_message;
+    use crate::test::mock_instantiate::{default_instantiate, DEFAULT_ADMIN_ADDRESS};
+    use crate::types::core::contract_role::ContractRole;
+    use crate::types::core::error::ContractError;
+    use crate::util::constants::NHASH;
+    use cosmwasm_std::testing::mock_info;
+    use cosmwasm_std::{coins, Addr};
+    use provwasm_mocks::mock_dependencies;
+
+    #[test]
+    fn test_propose_admin_with_invalid_data() {
+        let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
+        grant_role(
+            deps.as_mut(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+            "settings_admin".to_string(),
+            ContractRole::SettingsAdmin,
+        )
+        .expect("the role should be granted");
+        let err = propose_admin(
+            deps.as_mut(),
+            mock_info("settings_admin", &[]),
+            "settings_admin".to_string(),
+        )
+        .expect_err("an account other than the admin should not be able to propose an admin");
+        assert!(
+            matches!(err, ContractError::Unauthorized),
+            "an unauthorized error should be returned, but got: {:?}",
+            err,
+        );
+        let err = propose_admin(
+            deps.as_mut(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &coins(100, NHASH)),
+            "new_admin".to_string(),
+        )
+        .expect_err("an error should occur when funds are provided");
+        assert!(
+            matches!(err, ContractError::InvalidFundsProvided { .. }),
+            "an invalid funds error should be returned, but got: {:?}",
+            err,
+        );
+        let err = propose_admin(
+            deps.as_mut(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+            String::new(),
+        )
+        .expect_err("an error should occur when the address is empty");
+        assert_validation_error_message(
+            err,
+            "an address must be provided when proposing a new admin",
+        );
+        let err = propose_admin(
+            deps.as_mut(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+            DEFAULT_ADMIN_ADDRESS.to_string(),
+        )
+        .expect_err("an error should occur when the current admin is proposed");
+        match err {
+            ContractError::InvalidRequest { message } => assert_eq!(
+                "address [contract_admin] is already the contract admin", message,
+                "the correct error message should be produced",
+            ),
+            e => panic!("unexpected error: {:?}", e),
+        };
+    }
+
+    #[test]
+    fn test_propose_admin_replaces_existing_proposal() {
+        let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
+        propose_admin(
+            deps.as_mut(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+            "typo_admin".to_string(),
+        )
+        .expect("the first proposal should succeed");
+        let response = propose_admin(
+            deps.as_mut(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+            "new_admin".to_string(),
+        )
+        .expect("the second proposal should succeed");
+        assert_eq!(
+            2,
+            response.attributes.len(),
+            "the correct number of attributes should be emitted",
+        );
+        assert_eq!(
+            "propose_admin",
+            single_attribute_for_key(&response, "action"),
+            "the correct action attribute should be emitted",
+        );
+        assert_eq!(
+            "new_admin",
+            single_attribute_for_key(&response, "proposed_admin"),
+            "the correct proposed_admin attribute should be emitted",
+        );
+        let contract_info =
+            get_contract_info(deps.as_ref().storage).expect("contract info should load");
+        assert_eq!(
+            Some(Addr::unchecked("new_admin")),
+            contract_info.pending_admin,
+            "the latest proposal should replace the previous one",
+        );
+        assert_eq!(
+            DEFAULT_ADMIN_ADDRESS,
+            contract_info.admin.as_str(),
+            "the admin should not change until the proposal is accepted",
+        );
+    }
+}
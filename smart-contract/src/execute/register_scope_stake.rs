@@ -0,0 +1,118 @@
+use crate::storage::scope_stake_storage::register_scope_stake as save_scope_stake_registration;
+use crate::types::core::error::ContractError;
+use crate::util::extensions::ResultExtensions;
+use cosmwasm_std::{DepsMut, MessageInfo, Response};
+use provwasm_std::{PartyType, ProvenanceMsg, ProvenanceQuerier, ProvenanceQuery};
+
+// register scope stake entrypoint
+pub fn register_scope_stake(
+    deps: DepsMut<ProvenanceQuery>,
+    info: MessageInfo,
+    scope_address: String,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    if !info.funds.is_empty() {
+        return ContractError::InvalidFundsProvided {
+            message: "funds cannot be provided when registering a scope stake".to_string(),
+        }
+        .to_err();
+    }
+    let scope = ProvenanceQuerier::new(&deps.querier).get_scope(&scope_address)?;
+    // The registration must be made while the sender still holds its stake, before the stake is
+    // escrowed with the contract.  This records that the sender's stake is among those that the
+    // contract holds, which a co-owned scope trade ask requires for each of its sold owners
+    if !scope
+        .owners
+        .iter()
+        .any(|owner| owner.role == PartyType::Owner && owner.address == info.sender)
+    {
+        return ContractError::Unauthorized.to_err();
+    }
+    save_scope_stake_registration(deps.storage, &scope_address, &info.sender)?;
+    Response::new()
+        .add_attribute("action", "register_scope_stake")
+        .add_attribute("scope_address", &scope_address)
+        .add_attribute("owner", info.sender.as_str())
+        .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::execute::register_scope_stake::register_scope_stake;
+    use crate::storage::scope_stake_storage::is_scope_stake_registered;
+    use crate::test::cosmos_type_helpers::single_attribute_for_key;
+    use crate::test::mock_instantiate::default_instantiate;
+    use crate::test::mock_scope::{MockScope, DEFAULT_SCOPE_ADDR};
+    use crate::types::core::error::ContractError;
+    use crate::util::constants::NHASH;
+    use cosmwasm_std::testing::mock_info;
+    use cosmwasm_std::{coins, Addr};
+    use provwasm_mocks::mock_dependencies;
+
+    #[test]
+    fn test_register_scope_stake_with_invalid_data() {
+        let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
+        deps.querier.with_scope(MockScope::new_with_owner("owner"));
+        let err = register_scope_stake(
+            deps.as_mut(),
+            mock_info("owner", &coins(100, NHASH)),
+            DEFAULT_SCOPE_ADDR.to_string(),
+        )
+        .expect_err("an error should occur when funds are provided");
+        assert!(
+            matches!(err, ContractError::InvalidFundsProvided { .. }),
+            "an invalid funds error should be returned, but got: {:?}",
+            err,
+        );
+        let err = register_scope_stake(
+            deps.as_mut(),
+            mock_info("stranger", &[]),
+            DEFAULT_SCOPE_ADDR.to_string(),
+        )
+        .expect_err("an error should occur when the sender is not an owner of the scope");
+        assert!(
+            matches!(err, ContractError::Unauthorized),
+            "an unauthorized error should be returned, but got: {:?}",
+            err,
+        );
+        assert!(
+            !is_scope_stake_registered(
+                deps.as_ref().storage,
+                DEFAULT_SCOPE_ADDR,
+                &Addr::unchecked("stranger"),
+            ),
+            "no stake should be registered for the stranger",
+        );
+    }
+
+    #[test]
+    fn test_register_scope_stake_with_valid_data() {
+        let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
+        deps.querier.with_scope(MockScope::new_with_owner("owner"));
+        let response = register_scope_stake(
+            deps.as_mut(),
+            mock_info("owner", &[]),
+            DEFAULT_SCOPE_ADDR.to_string(),
+        )
+        .expect("the owner should be able to register its stake");
+        assert_eq!(
+            "register_scope_stake",
+            single_attribute_for_key(&response, "action"),
+            "the correct action attribute should be emitted",
+        );
+        assert_eq!(
+            DEFAULT_SCOPE_ADDR,
+            single_attribute_for_key(&response, "scope_address"),
+            "the correct scope address attribute should be emitted",
+        );
+        assert!(
+            is_scope_stake_registered(
+                deps.as_ref().storage,
+                DEFAULT_SCOPE_ADDR,
+                &Addr::unchecked("owner"),
+            ),
+            "the owner's stake should be registered",
+        );
+    }
+}
@@ -0,0 +1,190 @@
+use crate::storage::contract_info::get_contract_info;
+use crate::storage::contract_role_storage::address_has_role;
+use crate::storage::quote_denom_allowlist_storage::{
+    get_quote_denom_allowlist, set_quote_denom_allowlist,
+};
+use crate::types::core::contract_role::ContractRole;
+use crate::types::core::error::ContractError;
+use crate::types::core::quote_denom_allowlist::get_allowlist_target_name;
+use crate::types::request::request_type::RequestType;
+use crate::util::extensions::ResultExtensions;
+use crate::validation::validation_handler::ValidationHandler;
+use cosmwasm_std::{DepsMut, MessageInfo, Response};
+use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
+
+// remove quote denoms entrypoint
+pub fn remove_quote_denoms(
+    deps: DepsMut<ProvenanceQuery>,
+    info: MessageInfo,
+    denoms: Vec<String>,
+    request_type: Option<RequestType>,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    let handler = ValidationHandler::new();
+    if denoms.is_empty() {
+        handler.push("at least one denom must be provided");
+    }
+    handler.handle()?;
+    if !address_has_role(
+        deps.storage,
+        &get_contract_info(deps.storage)?,
+        &info.sender,
+        &ContractRole::SettingsAdmin,
+    ) {
+        return ContractError::Unauthorized.to_err();
+    }
+    if !info.funds.is_empty() {
+        return ContractError::InvalidFundsProvided {
+            message: "funds cannot be provided when removing quote denoms".to_string(),
+        }
+        .to_err();
+    }
+    let mut allowlist = get_quote_denom_allowlist(deps.storage)?;
+    let restricted_types = RequestType::iterator()
+        .filter(|restricted_type| allowlist.is_restricted(restricted_type))
+        .collect::<Vec<&RequestType>>();
+    let allowed_denoms = allowlist.get_denoms_mut(&request_type);
+    let handler = ValidationHandler::new();
+    for denom in denoms.iter() {
+        if !allowed_denoms.contains(denom) {
+            handler.push(format!(
+                "denom [{}] is not in the quote denom allowlist for [{}]",
+                denom,
+                get_allowlist_target_name(&request_type),
+            ));
+        }
+    }
+    allowed_denoms.retain(|denom| !denoms.contains(denom));
+    // A request type without any allowed denoms accepts every denom, so removing the last denom
+    // that restricts a request type would silently lift its restriction
+    for restricted_type in restricted_types {
+        if !allowlist.is_restricted(restricted_type) {
+            handler.push(format!(
+                "removing the denoms would leave no allowed quote denoms for [{}], which would allow any denom",
+                restricted_type.get_name(),
+            ));
+        }
+    }
+    handler.handle()?;
+    set_quote_denom_allowlist(deps.storage, &allowlist)?;
+    Response::new()
+        .add_attribute("action", "remove_quote_denoms")
+        .add_attribute("request_type", get_allowlist_target_name(&request_type))
+        .add_attribute("denoms", denoms.join(","))
+        .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::execute::add_quote_denoms::add_quote_denoms;
+    use crate::execute::remove_quote_denoms::remove_quote_denoms;
+    use crate::storage::quote_denom_allowlist_storage::get_quote_denom_allowlist;
+    use crate::test::cosmos_type_helpers::single_attribute_for_key;
+    use crate::test::error_helpers::assert_validation_error_message;
+    use crate::test::mock_instantiate::{default_instantiate, DEFAULT_ADMIN_ADDRESS};
+    use crate::types::core::error::ContractError;
+    use crate::types::core::quote_denom_allowlist::QuoteDenomAllowlist;
+    use crate::types::request::request_type::RequestType;
+    use crate::util::constants::NHASH;
+    use cosmwasm_std::testing::mock_info;
+    use provwasm_mocks::mock_dependencies;
+
+    #[test]
+    fn test_remove_quote_denoms_with_invalid_data() {
+        let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
+        let err = remove_quote_denoms(
+            deps.as_mut(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+            vec![],
+            None,
+        )
+        .expect_err("an error should occur when no denoms are provided");
+        assert_validation_error_message(err, "at least one denom must be provided");
+        let err = remove_quote_denoms(
+            deps.as_mut(),
+            mock_info("not_admin", &[]),
+            vec![NHASH.to_string()],
+            None,
+        )
+        .expect_err("an error should occur when the sender is not a settings admin");
+        assert!(
+            matches!(err, ContractError::Unauthorized),
+            "an unauthorized error should be returned, but got: {:?}",
+            err,
+        );
+        let err = remove_quote_denoms(
+            deps.as_mut(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+            vec![NHASH.to_string()],
+            None,
+        )
+        .expect_err("an error should occur when the denom is not in the allowlist");
+        assert_validation_error_message(
+            err,
+            "denom [nhash] is not in the quote denom allowlist for [all request types]",
+        );
+        add_quote_denoms(
+            deps.as_mut(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+            vec![NHASH.to_string()],
+            Some(RequestType::CoinTrade),
+        )
+        .expect("the denom should be added");
+        let err = remove_quote_denoms(
+            deps.as_mut(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+            vec![NHASH.to_string()],
+            Some(RequestType::CoinTrade),
+        )
+        .expect_err("an error should occur when the last denom for a request type is removed");
+        assert_validation_error_message(
+            err,
+            "removing the denoms would leave no allowed quote denoms for [coin_trade], which would allow any denom",
+        );
+        assert_eq!(
+            &vec![NHASH.to_string()],
+            get_quote_denom_allowlist(deps.as_ref().storage)
+                .expect("the allowlist should load")
+                .get_denoms(&Some(RequestType::CoinTrade)),
+            "the rejected removal should not change the allowlist",
+        );
+    }
+
+    #[test]
+    fn test_remove_quote_denoms() {
+        let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
+        add_quote_denoms(
+            deps.as_mut(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+            vec![NHASH.to_string(), "usd".to_string()],
+            None,
+        )
+        .expect("the denoms should be added");
+        let response = remove_quote_denoms(
+            deps.as_mut(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+            vec!["usd".to_string()],
+            None,
+        )
+        .expect("the denom should be removed");
+        assert_eq!(
+            "remove_quote_denoms",
+            single_attribute_for_key(&response, "action"),
+            "the correct action attribute should be emitted",
+        );
+        assert_eq!(
+            "all request types",
+            single_attribute_for_key(&response, "request_type"),
+            "the correct request_type attribute should be emitted",
+        );
+        assert_eq!(
+            QuoteDenomAllowlist {
+                all_request_types: vec![NHASH.to_string()],
+                ..QuoteDenomAllowlist::default()
+            },
+            get_quote_denom_allowlist(deps.as_ref().storage).expect("the allowlist should load"),
+            "only the removed denom should be removed from the allowlist",
+        );
+    }
+}
@@ -0,0 +1,170 @@
+use crate::storage::contract_info::{get_contract_info, set_contract_info};
+use crate::storage::contract_role_storage::{address_has_role, revoke_stored_role};
+use crate::types::core::contract_role::ContractRole;
+use crate::types::core::error::ContractError;
+use crate::util::extensions::ResultExtensions;
+use cosmwasm_std::{DepsMut, MessageInfo, Response};
+use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
+
+// revoke role entrypoint
+pub fn revoke_role(
+    deps: DepsMut<ProvenanceQuery>,
+    info: MessageInfo,
+    address: String,
+    role: ContractRole,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    let mut contract_info = get_contract_info(deps.storage)?;
+    if !address_has_role(
+        deps.storage,
+        &contract_info,
+        &info.sender,
+        &ContractRole::SettingsAdmin,
+    ) {
+        return ContractError::Unauthorized.to_err();
+    }
+    if !info.funds.is_empty() {
+        return ContractError::InvalidFundsProvided {
+            message: "funds cannot be provided when revoking a role".to_string(),
+        }
+        .to_err();
+    }
+    let address = deps.api.addr_validate(&address)?;
+    if address == contract_info.admin {
+        return ContractError::InvalidRequest {
+            message: "roles cannot be revoked from the contract admin".to_string(),
+        }
+        .to_err();
+    }
+    if !address_has_role(deps.storage, &contract_info, &address, &role) {
+        return ContractError::InvalidRequest {
+            message: format!(
+                "address [{}] does not hold role [{}]",
+                address.as_str(),
+                role.get_name(),
+            ),
+        }
+        .to_err();
+    }
+    match role {
+        // Fees are sent to the contract admin when no fee collector is set
+        ContractRole::FeeCollector => {
+            contract_info.fee_collector_address = None;
+            set_contract_info(deps.storage, &contract_info)?;
+        }
+        _ => revoke_stored_role(deps.storage, &address, &role)?,
+    };
+    Response::new()
+        .add_attribute("action", "revoke_role")
+        .add_attribute("address", address.as_str())
+        .add_attribute("role", role.get_name())
+        .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::execute::grant_role::grant_role;
+    use crate::execute::revoke_role::revoke_role;
+    use crate::storage::contract_info::get_contract_info;
+    use crate::storage::contract_role_storage::address_has_role;
+    use crate::test::cosmos_type_helpers::single_attribute_for_key;
+    use crate::test::mock_instantiate::{default_instantiate, DEFAULT_ADMIN_ADDRESS};
+    use crate::types::core::contract_role::ContractRole;
+    use crate::types::core::error::ContractError;
+    use cosmwasm_std::testing::mock_info;
+    use cosmwasm_std::Addr;
+    use provwasm_mocks::mock_dependencies;
+
+    #[test]
+    fn test_revoke_role_with_invalid_data() {
+        let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
+        let err = revoke_role(
+            deps.as_mut(),
+            mock_info("not_admin", &[]),
+            "match_bot".to_string(),
+            ContractRole::Matcher,
+        )
+        .expect_err("an error should occur when the sender is not a settings admin");
+        assert!(
+            matches!(err, ContractError::Unauthorized),
+            "an unauthorized error should be returned, but got: {:?}",
+            err,
+        );
+        let err = revoke_role(
+            deps.as_mut(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+            DEFAULT_ADMIN_ADDRESS.to_string(),
+            ContractRole::Matcher,
+        )
+        .expect_err("an error should occur when revoking a role from the admin");
+        assert!(
+            matches!(err, ContractError::InvalidRequest { .. }),
+            "an invalid request error should be returned, but got: {:?}",
+            err,
+        );
+        let err = revoke_role(
+            deps.as_mut(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+            "match_bot".to_string(),
+            ContractRole::Matcher,
+        )
+        .expect_err("an error should occur when the address does not hold the role");
+        match err {
+            ContractError::InvalidRequest { message } => assert_eq!(
+                "address [match_bot] does not hold role [matcher]", message,
+                "the correct error message should be produced",
+            ),
+            e => panic!("unexpected error: {:?}", e),
+        };
+    }
+
+    #[test]
+    fn test_revoke_roles() {
+        let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
+        for role in [ContractRole::Matcher, ContractRole::FeeCollector] {
+            grant_role(
+                deps.as_mut(),
+                mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+                "ops".to_string(),
+                role,
+            )
+            .expect("the role should be granted");
+        }
+        let response = revoke_role(
+            deps.as_mut(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+            "ops".to_string(),
+            ContractRole::Matcher,
+        )
+        .expect("the matcher role should be revoked");
+        assert_eq!(
+            "revoke_role",
+            single_attribute_for_key(&response, "action"),
+            "the correct action attribute should be emitted",
+        );
+        revoke_role(
+            deps.as_mut(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+            "ops".to_string(),
+            ContractRole::FeeCollector,
+        )
+        .expect("the fee collector role should be revoked");
+        let contract_info =
+            get_contract_info(deps.as_ref().storage).expect("contract info should load");
+        assert!(
+            !address_has_role(
+                deps.as_ref().storage,
+                &contract_info,
+                &Addr::unchecked("ops"),
+                &ContractRole::Matcher,
+            ),
+            "the matcher role should no longer be held",
+        );
+        assert_eq!(
+            DEFAULT_ADMIN_ADDRESS,
+            contract_info.get_fee_collector_address().as_str(),
+            "fees should return to the admin after the fee collector is revoked",
+        );
+    }
+}
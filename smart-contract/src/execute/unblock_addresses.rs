@@ -0,0 +1,143 @@
+use crate::storage::address_blocklist_storage::{is_address_blocked, unblock_address};
+use crate::storage::contract_info::get_contract_info;
+use crate::storage::contract_role_storage::address_has_role;
+use crate::types::core::contract_role::ContractRole;
+use crate::types::core::error::ContractError;
+use crate::util::extensions::ResultExtensions;
+use crate::validation::validation_handler::ValidationHandler;
+use cosmwasm_std::{Addr, DepsMut, MessageInfo, Response};
+use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
+
+// unblock addresses entrypoint
+pub fn unblock_addresses(
+    deps: DepsMut<ProvenanceQuery>,
+    info: MessageInfo,
+    addresses: Vec<String>,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    if addresses.is_empty() {
+        return ContractError::ValidationError {
+            messages: vec!["at least one address must be provided".to_string()],
+        }
+        .to_err();
+    }
+    if !address_has_role(
+        deps.storage,
+        &get_contract_info(deps.storage)?,
+        &info.sender,
+        &ContractRole::SettingsAdmin,
+    ) {
+        return ContractError::Unauthorized.to_err();
+    }
+    if !info.funds.is_empty() {
+        return ContractError::InvalidFundsProvided {
+            message: "funds cannot be provided when unblocking addresses".to_string(),
+        }
+        .to_err();
+    }
+    let addresses = addresses
+        .iter()
+        .map(|address| deps.api.addr_validate(address))
+        .collect::<Result<Vec<Addr>, _>>()?;
+    let handler = ValidationHandler::new();
+    for address in addresses.iter() {
+        if !is_address_blocked(deps.storage, address) {
+            handler.push(format!("address [{}] is not blocked", address.as_str()));
+        }
+    }
+    handler.handle()?;
+    for address in addresses.iter() {
+        unblock_address(deps.storage, address)?;
+    }
+    Response::new()
+        .add_attribute("action", "unblock_addresses")
+        .add_attribute(
+            "addresses",
+            addresses
+                .iter()
+                .map(|address| address.as_str())
+                .collect::<Vec<&str>>()
+                .join(","),
+        )
+        .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::execute::block_addresses::block_addresses;
+    use crate::execute::create_bid::create_bid;
+    use crate::execute::unblock_addresses::unblock_addresses;
+    use crate::test::cosmos_type_helpers::single_attribute_for_key;
+    use crate::test::error_helpers::assert_validation_error_message;
+    use crate::test::mock_instantiate::{default_instantiate, DEFAULT_ADMIN_ADDRESS};
+    use crate::types::core::error::ContractError;
+    use crate::types::request::bid_types::bid::Bid;
+    use cosmwasm_std::coins;
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use provwasm_mocks::mock_dependencies;
+
+    #[test]
+    fn test_unblock_addresses_with_invalid_data() {
+        let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
+        let err = unblock_addresses(deps.as_mut(), mock_info(DEFAULT_ADMIN_ADDRESS, &[]), vec![])
+            .expect_err("an error should occur when no addresses are provided");
+        assert_validation_error_message(err, "at least one address must be provided");
+        let err = unblock_addresses(
+            deps.as_mut(),
+            mock_info("not_admin", &[]),
+            vec!["sanctioned".to_string()],
+        )
+        .expect_err("an error should occur when the sender is not a settings admin");
+        assert!(
+            matches!(err, ContractError::Unauthorized),
+            "an unauthorized error should be returned, but got: {:?}",
+            err,
+        );
+        let err = unblock_addresses(
+            deps.as_mut(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+            vec!["sanctioned".to_string()],
+        )
+        .expect_err("an error should occur when the address is not blocked");
+        assert_validation_error_message(err, "address [sanctioned] is not blocked");
+    }
+
+    #[test]
+    fn test_unblock_addresses() {
+        let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
+        block_addresses(
+            deps.as_mut(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+            vec!["bidder".to_string()],
+        )
+        .expect("the bidder should be blocked");
+        create_bid(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder", &coins(100, "quote")),
+            Bid::new_coin_trade("bid_id", &coins(100, "base")),
+            None,
+        )
+        .expect_err("a blocked bidder should not create a bid");
+        let response = unblock_addresses(
+            deps.as_mut(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+            vec!["bidder".to_string()],
+        )
+        .expect("the bidder should be unblocked");
+        assert_eq!(
+            "unblock_addresses",
+            single_attribute_for_key(&response, "action"),
+            "the correct action attribute should be emitted",
+        );
+        create_bid(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder", &coins(100, "quote")),
+            Bid::new_coin_trade("bid_id", &coins(100, "base")),
+            None,
+        )
+        .expect("an unblocked bidder should create a bid");
+    }
+}
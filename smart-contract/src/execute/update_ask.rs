@@ -128,9 +128,11 @@ mod tests {
             "description",
             AttributeRequirement::any(&["this.pb", "that.pio"]),
         );
+        let mut update_env = mock_env();
+        update_env.block.time = update_env.block.time.plus_seconds(100);
         let response = update_ask(
             deps.as_mut(),
-            mock_env(),
+            update_env,
             mock_info("asker", &coins(150, "base")),
             Ask::new_coin_trade("ask_id", &coins(150, "quote")),
             Some(descriptor.clone()),
@@ -138,6 +140,11 @@ mod tests {
         .expect("the ask update should be successful");
         let ask_order =
             assert_valid_response(&deps, &response, RequestType::CoinTrade, Some(descriptor));
+        assert_eq!(
+            Some(mock_env().block.time),
+            ask_order.created_time,
+            "the ask should retain its original creation time",
+        );
         assert_eq!(
             1,
             response.messages.len(),
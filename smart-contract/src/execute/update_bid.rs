@@ -19,8 +19,9 @@ pub fn update_bid(
         return ContractError::Unauthorized.to_err();
     }
     let refunded_quote = existing_bid_order.collateral.get_quote();
-    let new_bid_order =
+    let mut new_bid_order =
         create_bid_order(&deps, &env, &info, bid, descriptor, BidCreationType::Update)?.bid_order;
+    new_bid_order.created_time = existing_bid_order.created_time;
     update_bid_order(deps.storage, &new_bid_order)?;
     Response::new()
         .add_attribute("action", "update_bid")
@@ -114,9 +115,11 @@ mod tests {
             "description",
             AttributeRequirement::all(&["my.pb", "attributes.pb"]),
         );
+        let mut update_env = mock_env();
+        update_env.block.time = update_env.block.time.plus_seconds(100);
         let response = update_bid(
             deps.as_mut(),
-            mock_env(),
+            update_env,
             mock_info("bidder", &coins(100, "quote")),
             Bid::new_coin_trade("bid_id", &coins(100, "base")),
             Some(descriptor.clone()),
@@ -140,6 +143,11 @@ mod tests {
             collateral.base,
             "the correct base should be set on the bid",
         );
+        assert_eq!(
+            Some(mock_env().block.time),
+            bid_order.created_time,
+            "the bid should retain its original creation time",
+        );
     }
 
     #[test]
@@ -1,10 +1,12 @@
 use crate::storage::contract_info::{get_contract_info, set_contract_info};
+use crate::storage::contract_role_storage::address_has_role;
+use crate::types::core::contract_role::ContractRole;
 use crate::types::core::error::ContractError;
 use crate::types::request::settings_update::SettingsUpdate;
 use crate::util::constants::NHASH;
 use crate::util::extensions::ResultExtensions;
 use crate::validation::settings_update_validation::validate_settings_update;
-use cosmwasm_std::{DepsMut, MessageInfo, Response};
+use cosmwasm_std::{DepsMut, MessageInfo, Response, Uint128};
 use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
 
 const DISABLED_FEE_DISPLAY: &str = "disabled";
@@ -16,7 +18,12 @@ pub fn update_settings(
 ) -> Result<Response<ProvenanceMsg>, ContractError> {
     validate_settings_update(&update)?;
     let mut contract_info = get_contract_info(deps.storage)?;
-    if info.sender != contract_info.admin {
+    if !address_has_role(
+        deps.storage,
+        &contract_info,
+        &info.sender,
+        &ContractRole::SettingsAdmin,
+    ) {
         return ContractError::Unauthorized.to_err();
     }
     if !info.funds.is_empty() {
@@ -26,10 +33,6 @@ pub fn update_settings(
         .to_err();
     }
     let mut attributes = vec![];
-    if let Some(ref new_admin) = &update.new_admin_address {
-        contract_info.admin = deps.api.addr_validate(new_admin)?;
-        attributes.push(("new_admin_address".to_string(), new_admin.to_string()));
-    }
     if let Some(ref new_ask_fee) = &update.new_create_ask_nhash_fee {
         contract_info.create_ask_nhash_fee = new_ask_fee.to_owned();
         attributes.push((
@@ -52,6 +55,46 @@ pub fn update_settings(
             },
         ));
     }
+    if let Some(ref new_settlement_fees) = &update.new_settlement_fees {
+        contract_info.settlement_fees = Some(new_settlement_fees.to_owned());
+        attributes.push((
+            "new_settlement_fees".to_string(),
+            new_settlement_fees
+                .get_named_fee_bps()
+                .into_iter()
+                .map(|(name, fee_bps)| {
+                    format!("{}={}", name, fee_bps.unwrap_or_else(Uint128::zero).u128())
+                })
+                .collect::<Vec<String>>()
+                .join(","),
+        ));
+    }
+    if let Some(ref new_fee_collector) = &update.new_fee_collector_address {
+        contract_info.fee_collector_address = Some(deps.api.addr_validate(new_fee_collector)?);
+        attributes.push((
+            "new_fee_collector_address".to_string(),
+            new_fee_collector.to_string(),
+        ));
+    }
+    if let Some(ref new_contract_status) = &update.new_contract_status {
+        contract_info.contract_status = Some(new_contract_status.to_owned());
+        attributes.push((
+            "new_contract_status".to_string(),
+            new_contract_status.get_name(),
+        ));
+    }
+    if let Some(ref new_required_trade_attributes) = &update.new_required_trade_attributes {
+        contract_info.required_trade_attributes = Some(new_required_trade_attributes.to_owned())
+            .filter(|requirement| requirement.has_checks());
+        attributes.push((
+            "new_required_trade_attributes".to_string(),
+            contract_info
+                .required_trade_attributes
+                .as_ref()
+                .map(|requirement| requirement.get_description())
+                .unwrap_or_else(|| "none".to_string()),
+        ));
+    }
     // Save changes to the contract information
     set_contract_info(deps.storage, &contract_info)?;
     Response::new()
@@ -62,14 +105,19 @@ pub fn update_settings(
 
 #[cfg(test)]
 mod tests {
+    use crate::execute::grant_role::grant_role;
     use crate::execute::update_settings::{update_settings, DISABLED_FEE_DISPLAY};
     use crate::storage::contract_info::get_contract_info;
     use crate::test::cosmos_type_helpers::single_attribute_for_key;
     use crate::test::mock_instantiate::{
         default_instantiate, test_instantiate, TestInstantiate, DEFAULT_ADMIN_ADDRESS,
     };
+    use crate::types::core::contract_role::ContractRole;
+    use crate::types::core::contract_status::ContractStatus;
     use crate::types::core::error::ContractError;
+    use crate::types::request::request_descriptor::AttributeRequirement;
     use crate::types::request::settings_update::SettingsUpdate;
+    use crate::types::request::settlement_fees::SettlementFees;
     use crate::util::constants::NHASH;
     use cosmwasm_std::testing::mock_info;
     use cosmwasm_std::{coins, Uint128};
@@ -83,9 +131,12 @@ mod tests {
             deps.as_mut(),
             mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
             SettingsUpdate {
-                new_admin_address: Some(String::new()),
                 new_create_ask_nhash_fee: None,
                 new_create_bid_nhash_fee: None,
+                new_settlement_fees: None,
+                new_fee_collector_address: Some(String::new()),
+                new_contract_status: None,
+                new_required_trade_attributes: None,
             },
         )
         .expect_err("an error should occur when invalid data is provided to the settings update");
@@ -97,7 +148,7 @@ mod tests {
                     "a single error message should be produced in the validation error"
                 );
                 assert_eq!(
-                    "new_admin_address was empty",
+                    "new_fee_collector_address was empty",
                     messages.first().unwrap(),
                     "unexpected error message was sent by validation",
                 );
@@ -105,9 +156,12 @@ mod tests {
             e => panic!("unexpected error: {:?}", e),
         };
         let valid_update = SettingsUpdate {
-            new_admin_address: Some("some admin".to_string()),
-            new_create_ask_nhash_fee: None,
+            new_create_ask_nhash_fee: Some(Uint128::new(100)),
             new_create_bid_nhash_fee: None,
+            new_settlement_fees: None,
+            new_fee_collector_address: None,
+            new_contract_status: None,
+            new_required_trade_attributes: None,
         };
         let err = update_settings(
             deps.as_mut(),
@@ -133,6 +187,53 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_update_settings_as_settings_admin() {
+        let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
+        for (address, role) in [
+            ("settings_admin", ContractRole::SettingsAdmin),
+            ("match_bot", ContractRole::Matcher),
+        ] {
+            grant_role(
+                deps.as_mut(),
+                mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+                address.to_string(),
+                role,
+            )
+            .expect("the role should be granted");
+        }
+        let fee_update = SettingsUpdate {
+            new_create_ask_nhash_fee: Some(Uint128::new(100)),
+            new_create_bid_nhash_fee: None,
+            new_settlement_fees: None,
+            new_fee_collector_address: None,
+            new_contract_status: None,
+            new_required_trade_attributes: None,
+        };
+        let err = update_settings(
+            deps.as_mut(),
+            mock_info("match_bot", &[]),
+            fee_update.clone(),
+        )
+        .expect_err("a matcher should not be able to change fees");
+        assert!(
+            matches!(err, ContractError::Unauthorized),
+            "an unauthorized error should be produced for a matcher, but got: {:?}",
+            err,
+        );
+        update_settings(deps.as_mut(), mock_info("settings_admin", &[]), fee_update)
+            .expect("a settings admin should be able to change fees");
+        assert_eq!(
+            100,
+            get_contract_info(deps.as_ref().storage)
+                .expect("expected contract info to load correctly")
+                .create_ask_nhash_fee
+                .u128(),
+            "the ask fee should be updated by the settings admin",
+        );
+    }
+
     #[test]
     fn test_update_settings_with_all_values_set() {
         let mut deps = mock_dependencies(&[]);
@@ -141,9 +242,12 @@ mod tests {
             deps.as_mut(),
             mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
             SettingsUpdate {
-                new_admin_address: Some("new_admin".to_string()),
                 new_create_ask_nhash_fee: Some(Uint128::new(100)),
                 new_create_bid_nhash_fee: Some(Uint128::new(150)),
+                new_settlement_fees: None,
+                new_fee_collector_address: None,
+                new_contract_status: None,
+                new_required_trade_attributes: None,
             },
         )
         .expect("expected a response to be emitted when a valid request is made by the admin");
@@ -152,7 +256,7 @@ mod tests {
             "no messages should be emitted by the settings update",
         );
         assert_eq!(
-            4,
+            3,
             response.attributes.len(),
             "the correct number of attributes should be emitted in the settings update",
         );
@@ -161,11 +265,6 @@ mod tests {
             single_attribute_for_key(&response, "action"),
             "the correct value should be set for the action attribute",
         );
-        assert_eq!(
-            "new_admin",
-            single_attribute_for_key(&response, "new_admin_address"),
-            "the correct value should be set for the new_admin attribute",
-        );
         assert_eq!(
             "100nhash",
             single_attribute_for_key(&response, "new_ask_fee"),
@@ -178,11 +277,6 @@ mod tests {
         );
         let contract_info = get_contract_info(deps.as_ref().storage)
             .expect("expected contract info to load correctly");
-        assert_eq!(
-            "new_admin",
-            contract_info.admin.as_str(),
-            "the correct admin address should be now set in the contract info",
-        );
         assert_eq!(
             100,
             contract_info.create_ask_nhash_fee.u128(),
@@ -196,11 +290,14 @@ mod tests {
         // Ensure that disabling the fees will set the proper text
         let response = update_settings(
             deps.as_mut(),
-            mock_info("new_admin", &[]),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
             SettingsUpdate {
-                new_admin_address: None,
                 new_create_ask_nhash_fee: Some(Uint128::zero()),
                 new_create_bid_nhash_fee: Some(Uint128::zero()),
+                new_settlement_fees: None,
+                new_fee_collector_address: None,
+                new_contract_status: None,
+                new_required_trade_attributes: None,
             },
         )
         .expect("disabling ask and bid fees with a zero amount should succeed");
@@ -238,6 +335,145 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_update_settings_with_settlement_fees() {
+        let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
+        let settlement_fees = SettlementFees {
+            coin_trade_bps: Some(Uint128::new(25)),
+            marker_share_sale_bps: Some(Uint128::new(150)),
+            ..SettlementFees::default()
+        };
+        let response = update_settings(
+            deps.as_mut(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+            SettingsUpdate {
+                new_create_ask_nhash_fee: None,
+                new_create_bid_nhash_fee: None,
+                new_settlement_fees: Some(settlement_fees.to_owned()),
+                new_fee_collector_address: Some("fee_collector".to_string()),
+                new_contract_status: None,
+                new_required_trade_attributes: None,
+            },
+        )
+        .expect("expected the settlement fee update to succeed");
+        assert_eq!(
+            3,
+            response.attributes.len(),
+            "the correct number of attributes should be emitted in the settings update",
+        );
+        assert_eq!(
+            "coin_trade_bps=25,marker_trade_bps=0,marker_share_sale_bps=150,scope_trade_bps=0,scope_bundle_trade_bps=0",
+            single_attribute_for_key(&response, "new_settlement_fees"),
+            "the correct value should be set for the new_settlement_fees attribute",
+        );
+        assert_eq!(
+            "fee_collector",
+            single_attribute_for_key(&response, "new_fee_collector_address"),
+            "the correct value should be set for the new_fee_collector_address attribute",
+        );
+        let contract_info = get_contract_info(deps.as_ref().storage)
+            .expect("expected contract info to load correctly");
+        assert_eq!(
+            Some(settlement_fees),
+            contract_info.settlement_fees,
+            "the new settlement fees should be set in the contract info",
+        );
+        assert_eq!(
+            "fee_collector",
+            contract_info.get_fee_collector_address().as_str(),
+            "the new fee collector should be set in the contract info",
+        );
+    }
+
+    #[test]
+    fn test_update_settings_with_contract_status() {
+        let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
+        assert_eq!(
+            ContractStatus::Active,
+            get_contract_info(deps.as_ref().storage)
+                .expect("expected contract info to load correctly")
+                .get_contract_status(),
+            "sanity check: the contract should be active after instantiation",
+        );
+        let response = update_settings(
+            deps.as_mut(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+            SettingsUpdate {
+                new_create_ask_nhash_fee: None,
+                new_create_bid_nhash_fee: None,
+                new_settlement_fees: None,
+                new_fee_collector_address: None,
+                new_contract_status: Some(ContractStatus::MatchingHalted),
+                new_required_trade_attributes: None,
+            },
+        )
+        .expect("expected the contract status update to succeed");
+        assert_eq!(
+            "matching_halted",
+            single_attribute_for_key(&response, "new_contract_status"),
+            "the correct value should be set for the new_contract_status attribute",
+        );
+        assert_eq!(
+            ContractStatus::MatchingHalted,
+            get_contract_info(deps.as_ref().storage)
+                .expect("expected contract info to load correctly")
+                .get_contract_status(),
+            "the new contract status should be set in the contract info",
+        );
+    }
+
+    #[test]
+    fn test_update_settings_with_required_trade_attributes() {
+        let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
+        let update = |requirement: AttributeRequirement| SettingsUpdate {
+            new_create_ask_nhash_fee: None,
+            new_create_bid_nhash_fee: None,
+            new_settlement_fees: None,
+            new_fee_collector_address: None,
+            new_contract_status: None,
+            new_required_trade_attributes: Some(requirement),
+        };
+        let response = update_settings(
+            deps.as_mut(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+            update(AttributeRequirement::all(&["kyc.pb"])),
+        )
+        .expect("expected the required trade attributes update to succeed");
+        assert_eq!(
+            "all of [\"kyc.pb\"]",
+            single_attribute_for_key(&response, "new_required_trade_attributes"),
+            "the correct value should be set for the new_required_trade_attributes attribute",
+        );
+        assert_eq!(
+            Some(AttributeRequirement::all(&["kyc.pb"])),
+            get_contract_info(deps.as_ref().storage)
+                .expect("expected contract info to load correctly")
+                .required_trade_attributes,
+            "the new required trade attributes should be set in the contract info",
+        );
+        let response = update_settings(
+            deps.as_mut(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+            update(AttributeRequirement::all::<String>(&[])),
+        )
+        .expect("expected the required trade attributes removal to succeed");
+        assert_eq!(
+            "none",
+            single_attribute_for_key(&response, "new_required_trade_attributes"),
+            "the removal should be reflected in the new_required_trade_attributes attribute",
+        );
+        assert_eq!(
+            None,
+            get_contract_info(deps.as_ref().storage)
+                .expect("expected contract info to load correctly")
+                .required_trade_attributes,
+            "an empty requirement should remove the required trade attributes",
+        );
+    }
+
     #[test]
     fn test_update_settings_with_no_changes() {
         let mut deps = mock_dependencies(&[]);
@@ -265,9 +501,12 @@ mod tests {
             deps.as_mut(),
             mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
             SettingsUpdate {
-                new_admin_address: None,
                 new_create_ask_nhash_fee: None,
                 new_create_bid_nhash_fee: None,
+                new_settlement_fees: None,
+                new_fee_collector_address: None,
+                new_contract_status: None,
+                new_required_trade_attributes: None,
             },
         )
         .expect("expected settings update to run with all none values");
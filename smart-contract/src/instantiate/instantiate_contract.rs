@@ -13,6 +13,10 @@ pub fn instantiate_contract(
     msg: InstantiateMsg,
 ) -> Result<Response<ProvenanceMsg>, ContractError> {
     validate_instantiate_msg(&msg)?;
+    let fee_collector_address = match &msg.fee_collector_address {
+        Some(address) => Some(deps.api.addr_validate(address)?),
+        None => None,
+    };
 
     // set contract info
     let contract_info = ContractInfoV2::new(
@@ -21,6 +25,8 @@ pub fn instantiate_contract(
         msg.contract_name,
         msg.create_ask_nhash_fee,
         msg.create_bid_nhash_fee,
+        msg.settlement_fees,
+        fee_collector_address,
     );
     set_contract_info(deps.storage, &contract_info)?;
 
@@ -65,6 +71,8 @@ mod tests {
             contract_name: DEFAULT_CONTRACT_NAME.to_string(),
             create_ask_nhash_fee: Some(Uint128::new(100)),
             create_bid_nhash_fee: Some(Uint128::new(200)),
+            settlement_fees: None,
+            fee_collector_address: None,
         };
 
         // initialize
@@ -94,6 +102,11 @@ mod tests {
                     contract_version: CONTRACT_VERSION.into(),
                     create_ask_nhash_fee: Uint128::new(100),
                     create_bid_nhash_fee: Uint128::new(200),
+                    settlement_fees: None,
+                    fee_collector_address: None,
+                    contract_status: None,
+                    pending_admin: None,
+                    required_trade_attributes: None,
                 };
 
                 assert_eq!(init_response.attributes.len(), 2);
@@ -125,6 +138,8 @@ mod tests {
                 contract_name: DEFAULT_CONTRACT_NAME.to_string(),
                 create_ask_nhash_fee: None,
                 create_bid_nhash_fee: None,
+                settlement_fees: None,
+                fee_collector_address: None,
             },
         )
         .expect("instantiation should succeed without error");
@@ -152,6 +167,8 @@ mod tests {
             contract_name: "contract_name".to_string(),
             create_ask_nhash_fee: Some(Uint128::new(10)),
             create_bid_nhash_fee: Some(Uint128::new(20)),
+            settlement_fees: None,
+            fee_collector_address: None,
         };
 
         // initialize
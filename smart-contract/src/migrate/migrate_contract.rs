@@ -1,3 +1,5 @@
+use crate::storage::ask_order_storage::reindex_ask_orders;
+use crate::storage::bid_order_storage::reindex_bid_orders;
 use crate::storage::contract_info::{
     get_contract_info, set_contract_info, ContractInfoV2, CONTRACT_TYPE, CONTRACT_VERSION,
 };
@@ -7,11 +9,21 @@ use cosmwasm_std::{to_binary, DepsMut, Response};
 use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
 use semver::Version;
 
+/// The first contract version that maintains the order search indices.  Orders stored by any
+/// earlier version are missing their index entries, so they are reindexed when migrating from it.
+const ORDER_INDICES_CONTRACT_VERSION: &str = "1.2.0-rc2";
+
 pub fn migrate_contract(
     deps: DepsMut<ProvenanceQuery>,
 ) -> Result<Response<ProvenanceMsg>, ContractError> {
     let mut contract_info = get_contract_info(deps.storage)?;
     check_valid_migration_target(&contract_info)?;
+    // Reindexing loads every stored order, so it is only done once, when migrating from a version
+    // that stored orders without the indices
+    if stores_unindexed_orders(&contract_info)? {
+        reindex_ask_orders(deps.storage)?;
+        reindex_bid_orders(deps.storage)?;
+    }
     contract_info.contract_version = CONTRACT_VERSION.to_string();
     set_contract_info(deps.storage, &contract_info)?;
     Response::new()
@@ -47,15 +59,35 @@ fn check_valid_migration_target(contract_info: &ContractInfoV2) -> Result<(), Co
     ().to_ok()
 }
 
+fn stores_unindexed_orders(contract_info: &ContractInfoV2) -> Result<bool, ContractError> {
+    (contract_info.contract_version.parse::<Version>()?
+        < ORDER_INDICES_CONTRACT_VERSION.parse::<Version>()?)
+    .to_ok()
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::migrate::migrate_contract::migrate_contract;
+    use crate::migrate::migrate_contract::{migrate_contract, stores_unindexed_orders};
+    use crate::storage::ask_order_storage::ask_orders;
+    use crate::storage::bid_order_storage::bid_orders;
     use crate::storage::contract_info::{
         get_contract_info, set_contract_info, CONTRACT_TYPE, CONTRACT_VERSION,
     };
+    use crate::storage::order_count_index::COUNT_KEY_ALL;
+    use crate::storage::order_search_repository::OrderSearchRepository;
     use crate::test::cosmos_type_helpers::single_attribute_for_key;
     use crate::test::mock_instantiate::default_instantiate;
+    use crate::test::request_helpers::{mock_ask_order, mock_bid_order};
     use crate::types::core::error::ContractError;
+    use crate::types::request::ask_types::ask_collateral::AskCollateral;
+    use crate::types::request::ask_types::ask_order::AskOrder;
+    use crate::types::request::bid_types::bid_collateral::BidCollateral;
+    use crate::types::request::bid_types::bid_order::BidOrder;
+    use crate::types::request::search::Search;
+    use crate::util::constants::NHASH;
+    use cosmwasm_std::coins;
+    use cosmwasm_std::testing::mock_env;
+    use cw_storage_plus::Map;
     use provwasm_mocks::mock_dependencies;
 
     #[test]
@@ -147,4 +179,99 @@ mod tests {
             ),
         };
     }
+
+    #[test]
+    fn test_migrate_populates_order_indices() {
+        let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
+        let mut contract_info =
+            get_contract_info(deps.as_ref().storage).expect("contract info should load");
+        contract_info.contract_version = "0.0.1".to_string();
+        set_contract_info(deps.as_mut().storage, &contract_info)
+            .expect("contract info should be stored");
+        // Simulate orders stored before the indices existed by writing them without any indices
+        Map::<&[u8], AskOrder>::new("ask")
+            .save(
+                deps.as_mut().storage,
+                b"ask_id",
+                &mock_ask_order(AskCollateral::coin_trade(&[], &coins(100, NHASH))),
+            )
+            .expect("the ask order should be stored");
+        Map::<&[u8], BidOrder>::new("bid")
+            .save(
+                deps.as_mut().storage,
+                b"bid_id",
+                &mock_bid_order(BidCollateral::coin_trade(&[], &coins(100, NHASH))),
+            )
+            .expect("the bid order should be stored");
+        assert!(
+            OrderSearchRepository::new(ask_orders())
+                .search(
+                    deps.as_ref().storage,
+                    Search::quote_denom(NHASH, None, None),
+                    &mock_env().block.time
+                )
+                .expect("the search should succeed")
+                .results
+                .is_empty(),
+            "sanity check: the ask order should not be indexed before the migration",
+        );
+        migrate_contract(deps.as_mut()).expect("the migration should succeed");
+        assert_eq!(
+            1,
+            OrderSearchRepository::new(ask_orders())
+                .search(
+                    deps.as_ref().storage,
+                    Search::quote_denom(NHASH, None, None),
+                    &mock_env().block.time
+                )
+                .expect("the search should succeed")
+                .results
+                .len(),
+            "the ask order should be indexed after the migration",
+        );
+        assert_eq!(
+            1,
+            ask_orders()
+                .idx
+                .count_index
+                .count(deps.as_ref().storage, COUNT_KEY_ALL, ""),
+            "the ask order should be counted after the migration",
+        );
+        assert_eq!(
+            1,
+            OrderSearchRepository::new(bid_orders())
+                .search(
+                    deps.as_ref().storage,
+                    Search::quote_denom(NHASH, None, None),
+                    &mock_env().block.time
+                )
+                .expect("the search should succeed")
+                .results
+                .len(),
+            "the bid order should be indexed after the migration",
+        );
+    }
+
+    #[test]
+    fn test_only_versions_without_indices_are_reindexed() {
+        let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
+        let mut contract_info =
+            get_contract_info(deps.as_ref().storage).expect("contract info should load");
+        for (version, expected) in [
+            ("1.1.0", true),
+            ("1.2.0-rc1", true),
+            ("1.2.0-rc2", false),
+            ("1.2.0", false),
+        ] {
+            contract_info.contract_version = version.to_string();
+            assert_eq!(
+                expected,
+                stores_unindexed_orders(&contract_info).expect("the version should parse"),
+                "unexpected reindexing decision for version [{}]",
+                version,
+            );
+        }
+    }
 }
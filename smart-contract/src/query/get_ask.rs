@@ -38,6 +38,7 @@ mod tests {
                 "a very nice description",
                 AttributeRequirement::all(&["some.attribute.pb"]),
             )),
+            created_time: None,
         };
 
         if let Err(error) = insert_ask_order(deps.as_mut().storage, &ask_order) {
@@ -0,0 +1,136 @@
+use crate::storage::bid_order_storage::get_bid_orders_by_collateral_id;
+use crate::types::core::error::ContractError;
+use crate::util::extensions::ResultExtensions;
+use cosmwasm_std::{to_binary, Binary, Deps, Uint128};
+use provwasm_std::ProvenanceQuery;
+
+pub fn query_bids_by_collateral_id(
+    deps: Deps<ProvenanceQuery>,
+    collateral_id: String,
+    page_size: Option<Uint128>,
+    start_after: Option<String>,
+) -> Result<Binary, ContractError> {
+    to_binary(&get_bid_orders_by_collateral_id(
+        deps.storage,
+        collateral_id,
+        &page_size,
+        start_after,
+    ))?
+    .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::query::get_bids_by_collateral_id::query_bids_by_collateral_id;
+    use crate::storage::bid_order_storage::insert_bid_order;
+    use crate::test::mock_marker::{DEFAULT_MARKER_ADDRESS, DEFAULT_MARKER_DENOM};
+    use crate::types::request::bid_types::bid_collateral::BidCollateral;
+    use crate::types::request::bid_types::bid_order::BidOrder;
+    use crate::types::request::search::SearchResult;
+    use crate::util::constants::NHASH;
+    use cosmwasm_std::{coins, from_binary, Addr, Uint128};
+    use provwasm_mocks::mock_dependencies;
+
+    #[test]
+    fn test_get_bids_by_collateral_id_no_results() {
+        let deps = mock_dependencies(&[]);
+        let empty_binary = query_bids_by_collateral_id(
+            deps.as_ref(),
+            DEFAULT_MARKER_ADDRESS.to_string(),
+            None,
+            None,
+        )
+        .expect("the result should be returned");
+        let empty_result = from_binary::<SearchResult<BidOrder>>(&empty_binary)
+            .expect("result should properly deserialize from binary");
+        assert!(
+            empty_result.results.is_empty(),
+            "the result should contain no bids",
+        );
+        assert_eq!(
+            None, empty_result.next_start_after,
+            "no cursor should be returned when no bids are found",
+        );
+    }
+
+    #[test]
+    fn test_get_bids_by_collateral_id_with_results() {
+        let mut deps = mock_dependencies(&[]);
+        for id in ["bid_1", "bid_2", "bid_3"] {
+            insert_bid_order(
+                deps.as_mut().storage,
+                &BidOrder::new_unchecked(
+                    id,
+                    Addr::unchecked("bidder"),
+                    BidCollateral::marker_trade(
+                        Addr::unchecked(DEFAULT_MARKER_ADDRESS),
+                        DEFAULT_MARKER_DENOM,
+                        &coins(100, NHASH),
+                        None,
+                    ),
+                    None,
+                ),
+            )
+            .expect("the bid order should be inserted without error");
+        }
+        insert_bid_order(
+            deps.as_mut().storage,
+            &BidOrder::new_unchecked(
+                "scope_bid",
+                Addr::unchecked("bidder"),
+                BidCollateral::scope_bundle_trade(&["scope_1", "scope_2"], &coins(100, NHASH)),
+                None,
+            ),
+        )
+        .expect("the bid order should be inserted without error");
+        let query = |collateral_id: &str, page_size: u128, start_after: Option<&str>| {
+            from_binary::<SearchResult<BidOrder>>(
+                &query_bids_by_collateral_id(
+                    deps.as_ref(),
+                    collateral_id.to_string(),
+                    Some(Uint128::new(page_size)),
+                    start_after.map(|id| id.to_string()),
+                )
+                .expect("the result should be returned"),
+            )
+            .expect("result should properly deserialize from binary")
+        };
+        let get_ids = |result: &SearchResult<BidOrder>| {
+            result
+                .results
+                .iter()
+                .map(|bid_order| bid_order.id.to_owned())
+                .collect::<Vec<String>>()
+        };
+        let first_page = query(DEFAULT_MARKER_ADDRESS, 2, None);
+        assert_eq!(
+            vec!["bid_1", "bid_2"],
+            get_ids(&first_page),
+            "the first page of marker bids should be returned by marker address",
+        );
+        assert_eq!(
+            2,
+            first_page.total_pages.u128(),
+            "the total pages should reflect all bids for the marker",
+        );
+        assert_eq!(
+            vec!["bid_3"],
+            get_ids(&query(
+                DEFAULT_MARKER_ADDRESS,
+                2,
+                first_page.next_start_after.as_deref(),
+            )),
+            "the cursor should be used to fetch the next page",
+        );
+        assert_eq!(
+            vec!["bid_1", "bid_2", "bid_3"],
+            get_ids(&query(DEFAULT_MARKER_DENOM, 10, None)),
+            "marker bids should be found by marker denom",
+        );
+        assert_eq!(
+            vec!["scope_bid"],
+            get_ids(&query("scope_2", 10, None)),
+            "scope bundle bids should be found by any of their scopes",
+        );
+    }
+}
@@ -0,0 +1,520 @@
+use crate::storage::ask_order_storage::{get_ask_match_candidates, get_ask_order_by_id};
+use crate::storage::bid_order_storage::{get_bid_match_candidates, get_bid_order_by_id};
+use crate::storage::order_search_repository::get_page_size;
+use crate::types::core::constants::{MAX_MATCH_CANDIDATE_EVALUATIONS, MAX_MATCH_CANDIDATE_LOADS};
+use crate::types::core::error::ContractError;
+use crate::types::request::ask_types::ask_order::AskOrder;
+use crate::types::request::bid_types::bid_order::BidOrder;
+use crate::types::request::match_candidates::{
+    MatchCandidate, MatchCandidateOptions, MatchCandidatesResponse,
+};
+use crate::util::extensions::ResultExtensions;
+use crate::util::match_report_utilities::create_match_report;
+use crate::util::settlement_fee_utilities::get_expected_quote_paid;
+use cosmwasm_std::{to_binary, Binary, Coin, Deps, Env, Uint128};
+use provwasm_std::ProvenanceQuery;
+use std::cmp::Reverse;
+
+/// Finds the orders on the other side of the book that can currently be matched with the given
+/// ask or bid.  Candidates are ranked by the quote that would be paid in the ranking denom: the
+/// highest paying bids are listed first for an ask, and the cheapest asks are listed first for a
+/// bid.  Only the first MAX_MATCH_CANDIDATE_LOADS orders found through the collateral indices are
+/// ranked, and validation requires querying the blockchain for each candidate, so at most
+/// MAX_MATCH_CANDIDATE_EVALUATIONS of the best ranked are validated.  When either cap prevents
+/// the limit from being filled, the response is marked as truncated, because matchable orders may
+/// exist beyond it.
+pub fn get_match_candidates(
+    deps: Deps<ProvenanceQuery>,
+    env: Env,
+    ask_id: Option<String>,
+    bid_id: Option<String>,
+    options: Option<MatchCandidateOptions>,
+) -> Result<Binary, ContractError> {
+    let options = options.unwrap_or_default();
+    let (pairs, load_truncated) = match (ask_id, bid_id) {
+        (Some(ask_id), None) => {
+            let ask_order = get_ask_order_by_id(deps.storage, ask_id)?;
+            let ranking_denom = get_ranking_denom(&options, &ask_order.collateral.get_quote());
+            let (bid_orders, load_truncated) =
+                get_bid_match_candidates(deps.storage, &ask_order, MAX_MATCH_CANDIDATE_LOADS);
+            let mut pairs = bid_orders
+                .into_iter()
+                .map(|bid_order| {
+                    let order_id = bid_order.id.to_owned();
+                    CandidatePair::new(&options, &ranking_denom, &ask_order, bid_order, order_id)
+                })
+                .collect::<Vec<CandidatePair>>();
+            // Askers are best served by the bids that pay the most
+            pairs.sort_by_key(|pair| {
+                (
+                    pair.price.is_none(),
+                    Reverse(pair.price),
+                    pair.order_id.to_owned(),
+                )
+            });
+            (pairs, load_truncated)
+        }
+        (None, Some(bid_id)) => {
+            let bid_order = get_bid_order_by_id(deps.storage, bid_id)?;
+            let ranking_denom = get_ranking_denom(&options, &bid_order.collateral.get_quote());
+            let (ask_orders, load_truncated) =
+                get_ask_match_candidates(deps.storage, &bid_order, MAX_MATCH_CANDIDATE_LOADS);
+            let mut pairs = ask_orders
+                .into_iter()
+                .map(|ask_order| {
+                    let order_id = ask_order.id.to_owned();
+                    CandidatePair::new(
+                        &options,
+                        &ranking_denom,
+                        &ask_order,
+                        bid_order.to_owned(),
+                        order_id,
+                    )
+                })
+                .collect::<Vec<CandidatePair>>();
+            // Bidders are best served by the asks that cost the least
+            pairs.sort_by_key(|pair| (pair.price.is_none(), pair.price, pair.order_id.to_owned()));
+            (pairs, load_truncated)
+        }
+        _ => {
+            return ContractError::InvalidRequest {
+                message: "exactly one of ask_id or bid_id must be provided".to_string(),
+            }
+            .to_err();
+        }
+    };
+    let limit = get_page_size(&options.limit);
+    let mut candidates = vec![];
+    let mut unevaluated_pairs = pairs.into_iter();
+    for pair in unevaluated_pairs
+        .by_ref()
+        .take(MAX_MATCH_CANDIDATE_EVALUATIONS)
+    {
+        // Reasons that a candidate cannot be matched are listed in its report, so an error means
+        // that no report could be produced for any candidate
+        let report = create_match_report(
+            &deps,
+            &env,
+            &pair.ask_order,
+            &pair.bid_order,
+            &options.admin_match_options,
+        )?;
+        if report.match_possible {
+            candidates.push(MatchCandidate {
+                order_id: pair.order_id,
+                quote_paid: pair.quote_paid,
+                report,
+            });
+            if candidates.len() >= limit {
+                break;
+            }
+        }
+    }
+    let truncated =
+        candidates.len() < limit && (load_truncated || unevaluated_pairs.next().is_some());
+    to_binary(&MatchCandidatesResponse {
+        candidates,
+        truncated,
+    })?
+    .to_ok()
+}
+
+fn get_ranking_denom(options: &MatchCandidateOptions, quote: &[Coin]) -> Option<String> {
+    options
+        .quote_denom
+        .to_owned()
+        .or_else(|| quote.first().map(|coin| coin.denom.to_owned()))
+}
+
+struct CandidatePair {
+    ask_order: AskOrder,
+    bid_order: BidOrder,
+    order_id: String,
+    quote_paid: Vec<Coin>,
+    /// The amount of the ranking denom in the quote paid.  Candidates that would not pay in the
+    /// ranking denom are ranked last.
+    price: Option<Uint128>,
+}
+impl CandidatePair {
+    fn new(
+        options: &MatchCandidateOptions,
+        ranking_denom: &Option<String>,
+        ask_order: &AskOrder,
+        bid_order: BidOrder,
+        order_id: String,
+    ) -> Self {
+        let quote_paid =
+            get_expected_quote_paid(ask_order, &bid_order, &options.admin_match_options);
+        let price = ranking_denom.as_ref().and_then(|denom| {
+            quote_paid
+                .iter()
+                .find(|coin| &coin.denom == denom)
+                .map(|coin| coin.amount)
+        });
+        Self {
+            ask_order: ask_order.to_owned(),
+            bid_order,
+            order_id,
+            quote_paid,
+            price,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::query::get_match_candidates::get_match_candidates;
+    use crate::storage::ask_order_storage::insert_ask_order;
+    use crate::storage::bid_order_storage::insert_bid_order;
+    use crate::test::cosmos_type_helpers::MockOwnedDeps;
+    use crate::test::mock_instantiate::default_instantiate;
+    use crate::test::mock_scopes_querier::mock_dependencies_with_scopes;
+    use crate::types::core::constants::MAX_MATCH_CANDIDATE_EVALUATIONS;
+    use crate::types::core::error::ContractError;
+    use crate::types::request::admin_match_options::AdminMatchOptions;
+    use crate::types::request::ask_types::ask_collateral::AskCollateral;
+    use crate::types::request::ask_types::ask_order::AskOrder;
+    use crate::types::request::bid_types::bid_collateral::BidCollateral;
+    use crate::types::request::bid_types::bid_order::BidOrder;
+    use crate::types::request::match_candidates::{
+        MatchCandidate, MatchCandidateOptions, MatchCandidatesResponse,
+    };
+    use cosmwasm_std::testing::{mock_env, MOCK_CONTRACT_ADDR};
+    use cosmwasm_std::{coins, from_binary, Addr};
+    use provwasm_mocks::mock_dependencies;
+
+    #[test]
+    fn test_invalid_candidate_requests() {
+        let deps = mock_dependencies(&[]);
+        for (ask_id, bid_id) in [
+            (None, None),
+            (Some("ask_id".to_string()), Some("bid_id".to_string())),
+        ] {
+            let err = get_match_candidates(deps.as_ref(), mock_env(), ask_id, bid_id, None)
+                .expect_err("an error should occur when not exactly one order id is provided");
+            assert!(
+                matches!(err, ContractError::InvalidRequest { .. }),
+                "an invalid request error should be returned, but got: {:?}",
+                err,
+            );
+        }
+        let err = get_match_candidates(
+            deps.as_ref(),
+            mock_env(),
+            Some("ask_id".to_string()),
+            None,
+            None,
+        )
+        .expect_err("an error should occur when the order does not exist");
+        assert!(
+            matches!(err, ContractError::StorageError { .. }),
+            "a storage error should be returned, but got: {:?}",
+            err,
+        );
+    }
+
+    #[test]
+    fn test_bid_candidates_for_ask() {
+        let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
+        insert_ask(&mut deps, "ask_id", 100, 100);
+        insert_bid(&mut deps, "bid_1", 100, 100);
+        insert_bid(&mut deps, "bid_2", 100, 150);
+        insert_bid(&mut deps, "bid_3", 100, 120);
+        insert_bid(&mut deps, "bid_4", 50, 200);
+        insert_bid_order(
+            deps.as_mut().storage,
+            &BidOrder::new_unchecked(
+                "marker_bid",
+                Addr::unchecked("bidder"),
+                BidCollateral::marker_trade(
+                    Addr::unchecked("marker"),
+                    "markerdenom",
+                    &coins(100, "quote"),
+                    None,
+                ),
+                None,
+            ),
+        )
+        .expect("the bid should be inserted");
+        let candidates = query_ask_candidates(&deps, None).candidates;
+        assert_eq!(
+            vec!["bid_1"],
+            get_order_ids(&candidates),
+            "only the bid with a matching base and quote should be a candidate",
+        );
+        let candidate = candidates.first().unwrap();
+        assert_eq!(
+            coins(100, "quote"),
+            candidate.quote_paid,
+            "the quote that the bidder would pay should be included",
+        );
+        assert!(
+            candidate.report.match_possible,
+            "the candidate's report should indicate that the match is possible",
+        );
+        assert_eq!(
+            "bid_1", candidate.report.bid_id,
+            "the candidate's report should refer to the candidate bid",
+        );
+        let admin_options =
+            MatchCandidateOptions::new().with_admin_match_options(AdminMatchOptions::CoinTrade {
+                accept_mismatched_bids: Some(true),
+            });
+        assert_eq!(
+            vec!["bid_2", "bid_3", "bid_1"],
+            get_order_ids(&query_ask_candidates(&deps, Some(admin_options.to_owned())).candidates),
+            "admin match options should allow mismatched quotes, ranked from the highest paying",
+        );
+        assert_eq!(
+            vec!["bid_2", "bid_3"],
+            get_order_ids(
+                &query_ask_candidates(&deps, Some(admin_options.with_limit(2))).candidates
+            ),
+            "the limit should restrict the number of candidates to the best ranked",
+        );
+    }
+
+    #[test]
+    fn test_ask_candidates_for_bid() {
+        let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
+        insert_bid(&mut deps, "bid_id", 100, 100);
+        insert_ask(&mut deps, "ask_1", 100, 100);
+        insert_ask(&mut deps, "ask_2", 100, 100);
+        insert_ask(&mut deps, "ask_3", 100, 120);
+        let response = from_binary::<MatchCandidatesResponse>(
+            &get_match_candidates(
+                deps.as_ref(),
+                mock_env(),
+                None,
+                Some("bid_id".to_string()),
+                None,
+            )
+            .expect("the candidates should be returned"),
+        )
+        .expect("the candidates should deserialize");
+        assert_eq!(
+            vec!["ask_1", "ask_2"],
+            get_order_ids(&response.candidates),
+            "asks with a matching quote should be candidates, with equally priced asks ranked by id",
+        );
+    }
+
+    #[test]
+    fn test_coin_trade_candidates_ranked_by_quote_denom() {
+        let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
+        insert_ask(&mut deps, "ask_id", 100, 100);
+        insert_bid(&mut deps, "bid_1", 100, 100);
+        for (id, amount) in [("bid_2", 10), ("bid_3", 30), ("bid_4", 20)] {
+            insert_bid_order(
+                deps.as_mut().storage,
+                &BidOrder::new_unchecked(
+                    id,
+                    Addr::unchecked("bidder"),
+                    BidCollateral::coin_trade(&coins(100, "base"), &coins(amount, "other")),
+                    None,
+                ),
+            )
+            .expect("the bid should be inserted");
+        }
+        let options = MatchCandidateOptions::new()
+            .with_admin_match_options(AdminMatchOptions::coin_trade_options(true))
+            .with_quote_denom("other");
+        assert_eq!(
+            vec!["bid_3", "bid_4", "bid_2", "bid_1"],
+            get_order_ids(&query_ask_candidates(&deps, Some(options)).candidates),
+            "bids should be ranked from the highest paying in the quote denom, followed by bids that do not pay in it",
+        );
+    }
+
+    #[test]
+    fn test_candidate_evaluation_limit_marks_truncated_responses() {
+        let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
+        insert_ask(&mut deps, "ask_id", 100, 100);
+        // Bids for a different base amount are ranked first by their quotes, but can never be
+        // matched
+        for index in 1..MAX_MATCH_CANDIDATE_EVALUATIONS {
+            insert_bid(&mut deps, &format!("bid_unmatched_{:02}", index), 50, 200);
+        }
+        insert_bid(&mut deps, "bid_matched", 100, 150);
+        let options = MatchCandidateOptions::new()
+            .with_admin_match_options(AdminMatchOptions::coin_trade_options(true))
+            .with_limit(1);
+        let response = query_ask_candidates(&deps, Some(options.to_owned()));
+        assert_eq!(
+            vec!["bid_matched"],
+            get_order_ids(&response.candidates),
+            "candidates that cannot be matched should not count towards the limit",
+        );
+        assert!(
+            !response.truncated,
+            "the response should not be truncated when the limit is filled",
+        );
+        let response = query_ask_candidates(&deps, Some(options.to_owned().with_limit(2)));
+        assert_eq!(
+            vec!["bid_matched"],
+            get_order_ids(&response.candidates),
+            "sanity check: the only matchable bid should be found",
+        );
+        assert!(
+            !response.truncated,
+            "the response should not be truncated when every candidate was evaluated",
+        );
+        insert_bid(&mut deps, "bid_unmatched_max", 50, 200);
+        let response = query_ask_candidates(&deps, Some(options));
+        assert!(
+            response.candidates.is_empty(),
+            "candidates ranked beyond the maximum number of evaluations should not be validated",
+        );
+        assert!(
+            response.truncated,
+            "the response should be truncated when candidates were left unevaluated",
+        );
+    }
+
+    #[test]
+    fn test_coin_trade_candidates_are_found_by_base_denom() {
+        let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
+        insert_ask(&mut deps, "ask_id", 100, 100);
+        // Bids for other base denoms are never loaded, so they cannot exhaust the evaluations
+        for index in 0..MAX_MATCH_CANDIDATE_EVALUATIONS {
+            insert_bid_order(
+                deps.as_mut().storage,
+                &BidOrder::new_unchecked(
+                    format!("bid_other_{:02}", index),
+                    Addr::unchecked("bidder"),
+                    BidCollateral::coin_trade(&coins(100, "other"), &coins(200, "quote")),
+                    None,
+                ),
+            )
+            .expect("the bid should be inserted");
+        }
+        insert_bid(&mut deps, "bid_matched", 100, 100);
+        let response = query_ask_candidates(&deps, None);
+        assert_eq!(
+            vec!["bid_matched"],
+            get_order_ids(&response.candidates),
+            "only the bid with the ask's base denom should be a candidate",
+        );
+        assert!(
+            !response.truncated,
+            "bids for other base denoms should not truncate the response",
+        );
+    }
+
+    #[test]
+    fn test_candidate_report_errors_are_returned() {
+        // Without instantiation, no contract info exists with which to produce any report
+        let mut deps = mock_dependencies(&[]);
+        insert_ask(&mut deps, "ask_id", 100, 100);
+        insert_bid(&mut deps, "bid_id", 100, 100);
+        let err = get_match_candidates(
+            deps.as_ref(),
+            mock_env(),
+            Some("ask_id".to_string()),
+            None,
+            None,
+        )
+        .expect_err("an error should occur when no match report can be produced");
+        assert!(
+            matches!(err, ContractError::StorageError { .. }),
+            "the error should be returned rather than skipping the candidate, but got: {:?}",
+            err,
+        );
+    }
+
+    #[test]
+    fn test_scope_bundle_candidates_are_listed_once() {
+        let mut deps = mock_dependencies_with_scopes(&["scope1", "scope2"], MOCK_CONTRACT_ADDR);
+        default_instantiate(deps.as_mut());
+        insert_ask_order(
+            deps.as_mut().storage,
+            &AskOrder::new_unchecked(
+                "ask_id",
+                Addr::unchecked("asker"),
+                AskCollateral::scope_bundle_trade(&["scope1", "scope2"], &coins(100, "quote")),
+                None,
+            ),
+        )
+        .expect("the bundle ask should be inserted");
+        insert_bid_order(
+            deps.as_mut().storage,
+            &BidOrder::new_unchecked(
+                "bid_id",
+                Addr::unchecked("bidder"),
+                BidCollateral::scope_bundle_trade(&["scope2", "scope1"], &coins(100, "quote")),
+                None,
+            ),
+        )
+        .expect("the bundle bid should be inserted");
+        for (ask_id, bid_id, expected_id) in [
+            (Some("ask_id".to_string()), None, "bid_id"),
+            (None, Some("bid_id".to_string()), "ask_id"),
+        ] {
+            let response = from_binary::<MatchCandidatesResponse>(
+                &get_match_candidates(deps.as_ref(), mock_env(), ask_id, bid_id, None)
+                    .expect("the candidates should be returned"),
+            )
+            .expect("the candidates should deserialize");
+            assert_eq!(
+                vec![expected_id],
+                get_order_ids(&response.candidates),
+                "a bundle found through each of its scopes should only be listed once",
+            );
+        }
+    }
+
+    fn insert_ask(deps: &mut MockOwnedDeps, id: &str, base: u128, quote: u128) {
+        insert_ask_order(
+            deps.as_mut().storage,
+            &AskOrder::new_unchecked(
+                id,
+                Addr::unchecked("asker"),
+                AskCollateral::coin_trade(&coins(base, "base"), &coins(quote, "quote")),
+                None,
+            ),
+        )
+        .expect("the ask should be inserted");
+    }
+
+    fn insert_bid(deps: &mut MockOwnedDeps, id: &str, base: u128, quote: u128) {
+        insert_bid_order(
+            deps.as_mut().storage,
+            &BidOrder::new_unchecked(
+                id,
+                Addr::unchecked("bidder"),
+                BidCollateral::coin_trade(&coins(base, "base"), &coins(quote, "quote")),
+                None,
+            ),
+        )
+        .expect("the bid should be inserted");
+    }
+
+    fn query_ask_candidates(
+        deps: &MockOwnedDeps,
+        options: Option<MatchCandidateOptions>,
+    ) -> MatchCandidatesResponse {
+        from_binary(
+            &get_match_candidates(
+                deps.as_ref(),
+                mock_env(),
+                Some("ask_id".to_string()),
+                None,
+                options,
+            )
+            .expect("the candidates should be returned"),
+        )
+        .expect("the candidates should deserialize")
+    }
+
+    fn get_order_ids(candidates: &[MatchCandidate]) -> Vec<&str> {
+        candidates
+            .iter()
+            .map(|candidate| candidate.order_id.as_str())
+            .collect()
+    }
+}
@@ -0,0 +1,70 @@
+use crate::storage::match_record_storage::search_match_records;
+use crate::types::core::error::ContractError;
+use crate::types::request::search::{MatchSearch, MatchSearchType};
+use crate::util::extensions::ResultExtensions;
+use cosmwasm_std::{to_binary, Binary, Deps, Uint128};
+use provwasm_std::ProvenanceQuery;
+
+pub fn query_match_history(
+    deps: Deps<ProvenanceQuery>,
+    page_size: Option<Uint128>,
+    page_number: Option<Uint128>,
+) -> Result<Binary, ContractError> {
+    to_binary(&search_match_records(
+        deps.storage,
+        &MatchSearch {
+            search_type: MatchSearchType::All,
+            page_size,
+            page_number,
+            start_after: None,
+        },
+    ))?
+    .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::contract::query;
+    use crate::storage::match_record_storage::insert_match_record;
+    use crate::test::request_helpers::mock_match_record;
+    use crate::types::core::msg::QueryMsg;
+    use crate::types::request::match_record::MatchRecord;
+    use crate::types::request::search::SearchResult;
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{from_binary, Uint128};
+    use provwasm_mocks::mock_dependencies;
+
+    #[test]
+    fn test_query_match_history() {
+        let mut deps = mock_dependencies(&[]);
+        for _ in 0..3 {
+            insert_match_record(deps.as_mut().storage, &mock_match_record())
+                .expect("the match record should be inserted");
+        }
+        let binary = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::GetMatchHistory {
+                page_size: Some(Uint128::new(2)),
+                page_number: None,
+            },
+        )
+        .expect("the query should succeed");
+        let result = from_binary::<SearchResult<MatchRecord>>(&binary)
+            .expect("the result should deserialize to a search result");
+        assert_eq!(
+            vec![3, 2],
+            result
+                .results
+                .iter()
+                .map(|record| record.id.u128())
+                .collect::<Vec<u128>>(),
+            "the most recent records should be returned first",
+        );
+        assert_eq!(
+            2,
+            result.total_pages.u128(),
+            "the correct number of pages should be reported",
+        );
+    }
+}
@@ -2,14 +2,17 @@ use crate::storage::ask_order_storage::get_ask_order_by_id;
 use crate::storage::bid_order_storage::get_bid_order_by_id;
 use crate::types::core::error::ContractError;
 use crate::types::request::admin_match_options::AdminMatchOptions;
-use crate::types::request::match_report::MatchReport;
+use crate::types::request::match_report::{
+    MatchFailureCode, MatchReport, MatchSide, MatchValidationFailure,
+};
 use crate::util::extensions::ResultExtensions;
-use crate::validation::execute_match_validation::validate_match;
-use cosmwasm_std::{to_binary, Binary, Deps};
+use crate::util::match_report_utilities::create_match_report;
+use cosmwasm_std::{to_binary, Binary, Deps, Env, Uint128};
 use provwasm_std::ProvenanceQuery;
 
 pub fn get_match_report(
     deps: Deps<ProvenanceQuery>,
+    env: Env,
     ask_id: String,
     bid_id: String,
     admin_match_options: Option<AdminMatchOptions>,
@@ -17,62 +20,87 @@ pub fn get_match_report(
     let ask_order_result = get_ask_order_by_id(deps.storage, &ask_id);
     let bid_order_result = get_bid_order_by_id(deps.storage, &bid_id);
     if ask_order_result.is_err() || bid_order_result.is_err() {
-        let error_message = if ask_order_result.is_ok() {
-            format!("BidOrder [{}] was missing from contract storage", &bid_id)
-        } else if bid_order_result.is_ok() {
-            format!("AskOrder [{}] was missing from contract storage", &ask_id)
-        } else {
-            format!(
-                "AskOrder [{}] and BidOrder [{}] were missing from contract storage",
-                &ask_id, &bid_id
-            )
-        };
+        let mut validation_failures = vec![];
+        if ask_order_result.is_err() {
+            validation_failures.push(MatchValidationFailure::new(
+                MatchFailureCode::OrderMissing,
+                MatchSide::Ask,
+                "id",
+                format!("AskOrder [{}] was missing from contract storage", &ask_id),
+            ));
+        }
+        if bid_order_result.is_err() {
+            validation_failures.push(MatchValidationFailure::new(
+                MatchFailureCode::OrderMissing,
+                MatchSide::Bid,
+                "id",
+                format!("BidOrder [{}] was missing from contract storage", &bid_id),
+            ));
+        }
         return to_binary(&MatchReport {
             ask_id,
             bid_id,
             ask_exists: ask_order_result.is_ok(),
             bid_exists: bid_order_result.is_ok(),
             match_possible: false,
-            error_message: Some(error_message),
+            validation_failures,
+            settlement_fee_bps: Uint128::zero(),
+            settlement_fee: vec![],
+            settlement_preview: None,
         })?
         .to_ok();
     }
-    let ask_order = ask_order_result.unwrap();
-    let bid_order = bid_order_result.unwrap();
-    let match_result = validate_match(&deps, &ask_order, &bid_order, &admin_match_options);
-    to_binary(&MatchReport {
-        ask_id,
-        bid_id,
-        ask_exists: true,
-        bid_exists: true,
-        match_possible: match_result.is_ok(),
-        error_message: if let Err(e) = &match_result {
-            Some(format!("Match fails due to: {:?}", e))
-        } else {
-            None
-        },
-    })?
+    to_binary(&create_match_report(
+        &deps,
+        &env,
+        &ask_order_result?,
+        &bid_order_result?,
+        &admin_match_options,
+    )?)?
     .to_ok()
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::execute::create_ask::create_ask;
+    use crate::execute::create_bid::create_bid;
     use crate::query::get_match_report::get_match_report;
-    use crate::storage::ask_order_storage::insert_ask_order;
+    use crate::storage::ask_order_storage::{
+        get_ask_order_by_id, insert_ask_order, update_ask_order,
+    };
     use crate::storage::bid_order_storage::insert_bid_order;
-    use crate::test::cosmos_type_helpers::MockOwnedDeps;
-    use crate::test::mock_scope::DEFAULT_SCOPE_ADDR;
-    use crate::test::request_helpers::{mock_ask_order, mock_bid_order, mock_bid_scope_trade};
+    use crate::storage::scope_stake_storage::register_scope_stake;
+    use crate::test::mock_instantiate::{default_instantiate, test_instantiate, TestInstantiate};
+    use crate::test::mock_marker::{
+        MockMarker, DEFAULT_MARKER_ADDRESS, DEFAULT_MARKER_DENOM, DEFAULT_MARKER_HOLDINGS,
+    };
+    use crate::test::mock_scope::{MockScope, DEFAULT_SCOPE_ADDR, DEFAULT_SCOPE_SPEC_ID};
+    use crate::test::mock_scopes_querier::mock_dependencies_with_scopes;
+    use crate::test::request_helpers::{
+        mock_ask_marker_share_sale, mock_ask_order, mock_ask_order_with_descriptor,
+        mock_bid_marker_share_sale, mock_bid_order, mock_bid_scope_trade,
+    };
     use crate::types::request::admin_match_options::AdminMatchOptions;
+    use crate::types::request::ask_types::ask::Ask;
     use crate::types::request::ask_types::ask_collateral::AskCollateral;
+    use crate::types::request::bid_types::bid::Bid;
     use crate::types::request::bid_types::bid_collateral::BidCollateral;
-    use crate::types::request::match_report::MatchReport;
-    use cosmwasm_std::{coins, from_binary};
+    use crate::types::request::match_record::MatchedCollateral;
+    use crate::types::request::match_report::{
+        MatchFailureCode, MatchReport, MatchSide, MatchValidationFailure, SettlementPreview,
+    };
+    use crate::types::request::request_descriptor::RequestDescriptor;
+    use crate::types::request::settlement_fees::SettlementFees;
+    use crate::types::request::share_sale_type::ShareSaleType;
+    use cosmwasm_std::testing::{mock_env, mock_info, MockApi, MockStorage, MOCK_CONTRACT_ADDR};
+    use cosmwasm_std::{coins, from_binary, Addr, OwnedDeps, Querier, Uint128};
     use provwasm_mocks::mock_dependencies;
+    use provwasm_std::{AccessGrant, MarkerAccess, Party, PartyType, ProvenanceQuery};
 
     #[test]
     fn test_missing_orders_report() {
         let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
         let missing_both_report = deserialize_report(&deps, "ask_id", "bid_id", None);
         assert!(
             !missing_both_report.ask_exists,
@@ -82,9 +110,13 @@ mod tests {
             !missing_both_report.bid_exists,
             "the bid should not be marked as existing",
         );
-        assert_report_contains_error_message(
-            &missing_both_report,
-            "AskOrder [ask_id] and BidOrder [bid_id] were missing from contract storage",
+        assert_eq!(
+            vec![
+                missing_order_failure(MatchSide::Ask, "AskOrder [ask_id]"),
+                missing_order_failure(MatchSide::Bid, "BidOrder [bid_id]"),
+            ],
+            missing_both_report.validation_failures,
+            "a failure should be reported for each missing order",
         );
         let ask_order = mock_ask_order(AskCollateral::coin_trade(&[], &[]));
         insert_ask_order(deps.as_mut().storage, &ask_order)
@@ -101,9 +133,10 @@ mod tests {
             missing_ask_report.bid_exists,
             "the bid should be marked as existing"
         );
-        assert_report_contains_error_message(
-            &missing_ask_report,
-            "AskOrder [not_ask] was missing from contract storage",
+        assert_eq!(
+            vec![missing_order_failure(MatchSide::Ask, "AskOrder [not_ask]")],
+            missing_ask_report.validation_failures,
+            "only the missing ask should be reported",
         );
         let missing_bid_report = deserialize_report(&deps, "ask_id", "not_bid", None);
         assert!(
@@ -114,15 +147,21 @@ mod tests {
             !missing_bid_report.bid_exists,
             "the bid should not be marked as existing",
         );
-        assert_report_contains_error_message(
-            &missing_bid_report,
-            "BidOrder [not_bid] was missing from contract storage",
+        assert_eq!(
+            vec![missing_order_failure(MatchSide::Bid, "BidOrder [not_bid]")],
+            missing_bid_report.validation_failures,
+            "only the missing bid should be reported",
+        );
+        assert!(
+            missing_bid_report.settlement_preview.is_none(),
+            "no settlement preview should be included when an order is missing",
         );
     }
 
     #[test]
     fn test_match_failure_without_match_options() {
         let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
         let base = coins(100, "base");
         let ask_order = mock_ask_order(AskCollateral::coin_trade(&base, &coins(100, "quote")));
         insert_ask_order(deps.as_mut().storage, &ask_order).expect("ask should be inserted");
@@ -135,16 +174,26 @@ mod tests {
             !report.match_possible,
             "the match possible report param should indicate false",
         );
-        let error_message = report.error_message.expect("an error should be returned");
+        assert_eq!(
+            vec![MatchValidationFailure::new(
+                MatchFailureCode::QuoteMismatch,
+                MatchSide::Both,
+                "collateral.quote",
+                "Ask quote [100quote] does not match bid quote [99quote]",
+            )],
+            report.validation_failures,
+            "the quote mismatch should be the only failure reported",
+        );
         assert!(
-            error_message.contains("Match fails due to:"),
-            "the failing message should contain a match failure prefix",
+            report.settlement_preview.is_none(),
+            "no settlement preview should be included when the match is not possible",
         );
     }
 
     #[test]
     fn test_failure_with_match_options() {
         let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
         let ask_order = mock_ask_order(AskCollateral::coin_trade(
             &coins(100, "base"),
             &coins(100, "quote"),
@@ -167,18 +216,62 @@ mod tests {
             !report.match_possible,
             "the match possible report param should indicate false",
         );
-        let error_message = report
-            .error_message
-            .expect("an error message should be returned");
+        assert_eq!(
+            vec![
+                (MatchFailureCode::RequestTypeMismatch, "request_type"),
+                (MatchFailureCode::CollateralTypeMismatch, "collateral"),
+            ],
+            report
+                .validation_failures
+                .iter()
+                .map(|failure| (failure.code.to_owned(), failure.field.as_str()))
+                .collect::<Vec<(MatchFailureCode, &str)>>(),
+            "the type mismatches should be reported",
+        );
         assert!(
-            error_message.contains("Match fails due to:"),
-            "the failing message should contain a match failure prefix",
+            report
+                .validation_failures
+                .iter()
+                .all(|failure| failure.side == MatchSide::Both),
+            "type mismatches should be attributed to both orders",
+        );
+    }
+
+    #[test]
+    fn test_match_report_explains_inactive_order() {
+        let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
+        let base = coins(100, "base");
+        let quote = coins(100, "quote");
+        let effective_time = mock_env().block.time.plus_seconds(3600);
+        let ask_order = mock_ask_order_with_descriptor(
+            AskCollateral::coin_trade(&base, &quote),
+            RequestDescriptor::basic("scheduled listing").with_effective_time(effective_time),
+        );
+        insert_ask_order(deps.as_mut().storage, &ask_order).expect("ask should be inserted");
+        let bid_order = mock_bid_order(BidCollateral::coin_trade(&base, &quote));
+        insert_bid_order(deps.as_mut().storage, &bid_order).expect("bid should be inserted");
+        let report = deserialize_report(&deps, "ask_id", "bid_id", None);
+        assert!(
+            !report.match_possible,
+            "the report should indicate that a match is not possible before the effective time",
+        );
+        assert_eq!(
+            vec![MatchValidationFailure::new(
+                MatchFailureCode::NotYetEffective,
+                MatchSide::Ask,
+                "descriptor.effective_time",
+                format!("AskOrder is not effective until [{}]", effective_time),
+            )],
+            report.validation_failures,
+            "the failure should explain that the ask is not yet effective",
         );
     }
 
     #[test]
     fn test_successful_match_report() {
         let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
         let base = coins(100, "base");
         let quote = coins(100, "quote");
         let ask_order = mock_ask_order(AskCollateral::coin_trade(&base, &quote));
@@ -198,21 +291,427 @@ mod tests {
             "the report should indicate that a match is possible",
         );
         assert!(
-            report.error_message.is_none(),
-            "the report should not include any error message",
+            report.validation_failures.is_empty(),
+            "the report should not include any validation failures",
+        );
+        assert_eq!(
+            Some(SettlementPreview {
+                quote_paid: quote.to_owned(),
+                asker_proceeds: quote,
+                collateral_transferred: MatchedCollateral::CoinTrade { base },
+                bidder_refund: vec![],
+                shares_withdrawn: Uint128::zero(),
+                ask_deleted: true,
+                bid_deleted: true,
+                collateral_released: true,
+            }),
+            report.settlement_preview,
+            "the settlement preview should describe the coin trade",
+        );
+    }
+
+    #[test]
+    fn test_match_report_includes_settlement_fee() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(
+            deps.as_mut(),
+            TestInstantiate {
+                settlement_fees: Some(SettlementFees {
+                    coin_trade_bps: Some(Uint128::new(250)),
+                    ..SettlementFees::default()
+                }),
+                ..TestInstantiate::default()
+            },
+        );
+        let base = coins(100, "base");
+        let quote = coins(1000, "quote");
+        let ask_order = mock_ask_order(AskCollateral::coin_trade(&base, &quote));
+        insert_ask_order(deps.as_mut().storage, &ask_order).expect("ask should be inserted");
+        let bid_order = mock_bid_order(BidCollateral::coin_trade(&base, &quote));
+        insert_bid_order(deps.as_mut().storage, &bid_order).expect("bid should be inserted");
+        let report = deserialize_report(&deps, "ask_id", "bid_id", None);
+        assert!(
+            report.match_possible,
+            "the report should indicate that a match is possible",
+        );
+        assert_eq!(
+            250,
+            report.settlement_fee_bps.u128(),
+            "the coin trade settlement fee rate should be reported",
+        );
+        assert_eq!(
+            coins(25, "quote"),
+            report.settlement_fee,
+            "the settlement fee should be calculated from the bid quote",
+        );
+        assert_eq!(
+            coins(975, "quote"),
+            report
+                .settlement_preview
+                .expect("a settlement preview should be included")
+                .asker_proceeds,
+            "the asker's proceeds should exclude the settlement fee",
         );
     }
 
-    fn deserialize_report<A: Into<String>, B: Into<String>>(
-        deps: &MockOwnedDeps,
+    #[test]
+    fn test_partial_fill_settlement_preview() {
+        let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
+        let ask_order = mock_ask_order(AskCollateral::partial_fill_coin_trade(
+            &coins(100, "base"),
+            &coins(200, "quote"),
+            100,
+        ));
+        insert_ask_order(deps.as_mut().storage, &ask_order).expect("ask should be inserted");
+        let bid_order = mock_bid_order(BidCollateral::coin_trade(
+            &coins(40, "base"),
+            &coins(80, "quote"),
+        ));
+        insert_bid_order(deps.as_mut().storage, &bid_order).expect("bid should be inserted");
+        let preview = deserialize_report(&deps, "ask_id", "bid_id", None)
+            .settlement_preview
+            .expect("a settlement preview should be included");
+        assert_eq!(
+            MatchedCollateral::CoinTrade {
+                base: coins(40, "base"),
+            },
+            preview.collateral_transferred,
+            "only the portion of the base requested by the bid should be transferred",
+        );
+        assert!(
+            !preview.ask_deleted,
+            "the ask should remain open with its remaining base",
+        );
+        assert!(preview.bid_deleted, "the bid should be deleted");
+        assert!(
+            !preview.collateral_released,
+            "the contract should continue to hold the remaining base",
+        );
+    }
+
+    #[test]
+    fn test_marker_share_sale_settlement_preview() {
+        let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
+        deps.querier
+            .with_markers(vec![MockMarker::new_owned_marker("asker")]);
+        let ask_order = mock_ask_order(mock_ask_marker_share_sale(
+            DEFAULT_MARKER_ADDRESS,
+            DEFAULT_MARKER_DENOM,
+            15,
+            15,
+            &coins(1, "quote"),
+            ShareSaleType::MultipleTransactions,
+        ));
+        insert_ask_order(deps.as_mut().storage, &ask_order).expect("ask should be inserted");
+        let bid_order = mock_bid_order(mock_bid_marker_share_sale(
+            DEFAULT_MARKER_ADDRESS,
+            DEFAULT_MARKER_DENOM,
+            20,
+            &coins(20, "quote"),
+        ));
+        insert_bid_order(deps.as_mut().storage, &bid_order).expect("bid should be inserted");
+        let report = deserialize_report(&deps, "ask_id", "bid_id", None);
+        assert!(
+            report.validation_failures.is_empty(),
+            "no validation failures should be reported, but got: {:?}",
+            report.validation_failures,
+        );
+        assert_eq!(
+            Some(SettlementPreview {
+                quote_paid: coins(15, "quote"),
+                asker_proceeds: coins(15, "quote"),
+                collateral_transferred: MatchedCollateral::MarkerShareSale {
+                    marker_address: Addr::unchecked(DEFAULT_MARKER_ADDRESS),
+                    marker_denom: DEFAULT_MARKER_DENOM.to_string(),
+                    shares_purchased: Uint128::new(15),
+                },
+                bidder_refund: vec![],
+                shares_withdrawn: Uint128::new(15),
+                ask_deleted: true,
+                bid_deleted: false,
+                collateral_released: true,
+            }),
+            report.settlement_preview,
+            "the preview should show the sale completing while the bid remains open for its remaining shares",
+        );
+    }
+
+    #[test]
+    fn test_marker_trade_settlement_preview() {
+        let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
+        deps.querier
+            .with_markers(vec![MockMarker::new_owned_marker("asker")]);
+        create_ask(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &[]),
+            Ask::new_marker_trade("ask_id", DEFAULT_MARKER_DENOM, &coins(1, "quote")),
+            None,
+        )
+        .expect("the ask should be created successfully");
+        create_bid(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder", &coins(DEFAULT_MARKER_HOLDINGS, "quote")),
+            Bid::new_marker_trade("bid_id", DEFAULT_MARKER_DENOM, Some(true)),
+            None,
+        )
+        .expect("the bid should be created successfully");
+        assert_eq!(
+            Some(SettlementPreview {
+                quote_paid: coins(DEFAULT_MARKER_HOLDINGS, "quote"),
+                asker_proceeds: coins(DEFAULT_MARKER_HOLDINGS, "quote"),
+                collateral_transferred: MatchedCollateral::MarkerTrade {
+                    marker_address: Addr::unchecked(DEFAULT_MARKER_ADDRESS),
+                    marker_denom: DEFAULT_MARKER_DENOM.to_string(),
+                    share_count: Uint128::new(DEFAULT_MARKER_HOLDINGS),
+                },
+                bidder_refund: vec![],
+                shares_withdrawn: Uint128::new(DEFAULT_MARKER_HOLDINGS),
+                ask_deleted: true,
+                bid_deleted: true,
+                collateral_released: true,
+            }),
+            deserialize_report(&deps, "ask_id", "bid_id", None).settlement_preview,
+            "the preview should show the marker being released and its shares withdrawn",
+        );
+    }
+
+    #[test]
+    fn test_scope_spec_trade_settlement_preview_includes_refund() {
+        let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
+        deps.querier
+            .with_scope(MockScope::new_with_owner(MOCK_CONTRACT_ADDR));
+        create_ask(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &[]),
+            Ask::new_scope_trade("ask_id", DEFAULT_SCOPE_ADDR, &coins(100, "quote")),
+            None,
+        )
+        .expect("the ask should be created successfully");
+        create_bid(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder", &coins(150, "quote")),
+            Bid::new_scope_spec_trade("bid_id", DEFAULT_SCOPE_SPEC_ID, None, None),
+            None,
+        )
+        .expect("the scope spec bid should be created successfully");
+        assert_eq!(
+            Some(SettlementPreview {
+                quote_paid: coins(100, "quote"),
+                asker_proceeds: coins(100, "quote"),
+                collateral_transferred: MatchedCollateral::ScopeTrade {
+                    scope_address: DEFAULT_SCOPE_ADDR.to_string(),
+                },
+                bidder_refund: coins(50, "quote"),
+                shares_withdrawn: Uint128::zero(),
+                ask_deleted: true,
+                bid_deleted: true,
+                collateral_released: true,
+            }),
+            deserialize_report(&deps, "ask_id", "bid_id", None).settlement_preview,
+            "the preview should refund the portion of the bid's quote that exceeds the ask's quote",
+        );
+    }
+
+    #[test]
+    fn test_scope_bundle_trade_settlement_preview() {
+        let mut deps = mock_dependencies_with_scopes(&["scope1", "scope2"], MOCK_CONTRACT_ADDR);
+        default_instantiate(deps.as_mut());
+        create_ask(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &[]),
+            Ask::new_scope_bundle_trade("ask_id", &["scope1", "scope2"], &coins(500, "quote")),
+            None,
+        )
+        .expect("the bundle ask should be created successfully");
+        create_bid(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder", &coins(500, "quote")),
+            Bid::new_scope_bundle_trade("bid_id", &["scope2", "scope1"]),
+            None,
+        )
+        .expect("the bundle bid should be created successfully");
+        assert_eq!(
+            Some(SettlementPreview {
+                quote_paid: coins(500, "quote"),
+                asker_proceeds: coins(500, "quote"),
+                collateral_transferred: MatchedCollateral::ScopeBundleTrade {
+                    scope_addresses: vec!["scope1".to_string(), "scope2".to_string()],
+                },
+                bidder_refund: vec![],
+                shares_withdrawn: Uint128::zero(),
+                ask_deleted: true,
+                bid_deleted: true,
+                collateral_released: true,
+            }),
+            deserialize_report(&deps, "ask_id", "bid_id", None).settlement_preview,
+            "the preview should show every scope in the bundle being transferred",
+        );
+    }
+
+    #[test]
+    fn test_co_owned_scope_trade_settlement_preview() {
+        let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
+        deps.querier.with_scope(
+            MockScope {
+                owners: vec![
+                    Party {
+                        address: Addr::unchecked(MOCK_CONTRACT_ADDR),
+                        role: PartyType::Owner,
+                    },
+                    Party {
+                        address: Addr::unchecked("co_owner"),
+                        role: PartyType::Owner,
+                    },
+                ],
+                value_owner_address: Addr::unchecked("co_owner"),
+                ..MockScope::default()
+            }
+            .to_scope(),
+        );
+        register_scope_stake(
+            deps.as_mut().storage,
+            DEFAULT_SCOPE_ADDR,
+            &Addr::unchecked("asker"),
+        )
+        .expect("the stake should be registered");
+        create_ask(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &[]),
+            Ask::new_co_owned_scope_trade(
+                "ask_id",
+                DEFAULT_SCOPE_ADDR,
+                &coins(420, "quote"),
+                &["asker"],
+            ),
+            None,
+        )
+        .expect("the co-owned scope ask should be created successfully");
+        create_bid(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder", &coins(420, "quote")),
+            Bid::new_scope_trade("bid_id", DEFAULT_SCOPE_ADDR),
+            None,
+        )
+        .expect("the bid should be created successfully");
+        assert_eq!(
+            Some(SettlementPreview {
+                quote_paid: coins(420, "quote"),
+                asker_proceeds: coins(420, "quote"),
+                collateral_transferred: MatchedCollateral::ScopeTrade {
+                    scope_address: DEFAULT_SCOPE_ADDR.to_string(),
+                },
+                bidder_refund: vec![],
+                shares_withdrawn: Uint128::zero(),
+                ask_deleted: true,
+                bid_deleted: true,
+                collateral_released: true,
+            }),
+            deserialize_report(&deps, "ask_id", "bid_id", None).settlement_preview,
+            "the preview should show the escrowed stake being transferred",
+        );
+    }
+
+    #[test]
+    fn test_settlement_failure_is_reported_as_validation_failure() {
+        let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
+        deps.querier
+            .with_markers(vec![MockMarker::new_owned_marker("asker")]);
+        create_ask(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &[]),
+            Ask::new_marker_share_sale(
+                "ask_id",
+                DEFAULT_MARKER_DENOM,
+                10,
+                &coins(2, "quote"),
+                ShareSaleType::SingleTransaction,
+            ),
+            None,
+        )
+        .expect("the marker share sale ask should be created");
+        // Simulate the marker being moved to be owned by the contract
+        deps.querier.with_markers(vec![MockMarker::new_marker()]);
+        create_bid(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder", &coins(20, "quote")),
+            Bid::new_marker_share_sale("bid_id", DEFAULT_MARKER_DENOM, 10),
+            None,
+        )
+        .expect("the marker share sale bid should be created");
+        // A permission that cannot be restored passes validation, but fails when the marker's
+        // release is calculated
+        let mut ask_order =
+            get_ask_order_by_id(deps.as_ref().storage, "ask_id").expect("the ask should exist");
+        if let AskCollateral::MarkerShareSale(collateral) = &mut ask_order.collateral {
+            collateral.removed_permissions.push(AccessGrant {
+                address: Addr::unchecked(""),
+                permissions: vec![MarkerAccess::Admin],
+            });
+        }
+        update_ask_order(deps.as_mut().storage, &ask_order).expect("the ask should be updated");
+        let report = deserialize_report(&deps, "ask_id", "bid_id", None);
+        assert!(
+            !report.match_possible,
+            "the match should not be possible when its settlement cannot be calculated",
+        );
+        assert!(
+            report.settlement_preview.is_none(),
+            "no settlement preview should be included",
+        );
+        match report.validation_failures.as_slice() {
+            [failure] => {
+                assert_eq!(
+                    MatchFailureCode::SettlementFailed,
+                    failure.code,
+                    "the settlement failure code should be used",
+                );
+                assert_eq!(
+                    MatchSide::Both,
+                    failure.side,
+                    "both orders should be blamed"
+                );
+                assert!(
+                    failure.message.contains("address must not be empty"),
+                    "the settlement error should be included in the message, but got: {}",
+                    failure.message,
+                );
+            }
+            failures => panic!("expected a single failure, but got: {:?}", failures),
+        };
+    }
+
+    fn deserialize_report<A: Into<String>, B: Into<String>, Q: Querier>(
+        deps: &OwnedDeps<MockStorage, MockApi, Q, ProvenanceQuery>,
         ask_id: A,
         bid_id: B,
         match_options: Option<AdminMatchOptions>,
     ) -> MatchReport {
         let ask_id = ask_id.into();
         let bid_id = bid_id.into();
-        let binary = get_match_report(deps.as_ref(), ask_id.clone(), bid_id.clone(), match_options)
-            .expect("expected a report to be produced");
+        let binary = get_match_report(
+            deps.as_ref(),
+            mock_env(),
+            ask_id.clone(),
+            bid_id.clone(),
+            match_options,
+        )
+        .expect("expected a report to be produced");
         let report = from_binary::<MatchReport>(&binary)
             .expect("expected the binary to deserialize to a match report");
         assert_eq!(
@@ -226,18 +725,12 @@ mod tests {
         report
     }
 
-    fn assert_report_contains_error_message<S: Into<String>>(
-        report: &MatchReport,
-        expected_error_message: S,
-    ) {
-        let error_message = report
-            .error_message
-            .to_owned()
-            .expect("an error message should be included in the report");
-        assert_eq!(
-            expected_error_message.into(),
-            error_message,
-            "expected the error message in the report to be the correct text",
-        );
+    fn missing_order_failure(side: MatchSide, order_description: &str) -> MatchValidationFailure {
+        MatchValidationFailure::new(
+            MatchFailureCode::OrderMissing,
+            side,
+            "id",
+            format!("{} was missing from contract storage", order_description),
+        )
     }
 }
@@ -0,0 +1,60 @@
+use crate::storage::quote_denom_allowlist_storage::get_quote_denom_allowlist;
+use crate::types::core::error::ContractError;
+use crate::util::extensions::ResultExtensions;
+use cosmwasm_std::{to_binary, Binary, Deps};
+use provwasm_std::ProvenanceQuery;
+
+pub fn query_quote_denom_allowlist(deps: Deps<ProvenanceQuery>) -> Result<Binary, ContractError> {
+    to_binary(&get_quote_denom_allowlist(deps.storage)?)?.to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::contract::query;
+    use crate::execute::add_quote_denoms::add_quote_denoms;
+    use crate::test::cosmos_type_helpers::MockOwnedDeps;
+    use crate::test::mock_instantiate::{default_instantiate, DEFAULT_ADMIN_ADDRESS};
+    use crate::types::core::msg::QueryMsg;
+    use crate::types::core::quote_denom_allowlist::QuoteDenomAllowlist;
+    use crate::types::request::request_type::RequestType;
+    use cosmwasm_std::from_binary;
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use provwasm_mocks::mock_dependencies;
+
+    #[test]
+    fn test_query_quote_denom_allowlist() {
+        let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
+        let query_allowlist = |deps: &MockOwnedDeps| {
+            from_binary::<QuoteDenomAllowlist>(
+                &query(
+                    deps.as_ref(),
+                    mock_env(),
+                    QueryMsg::GetQuoteDenomAllowlist {},
+                )
+                .expect("the query should succeed"),
+            )
+            .expect("the allowlist should deserialize")
+        };
+        assert_eq!(
+            QuoteDenomAllowlist::default(),
+            query_allowlist(&deps),
+            "an empty allowlist should be returned when no denoms have been added",
+        );
+        add_quote_denoms(
+            deps.as_mut(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+            vec!["usd".to_string()],
+            Some(RequestType::MarkerTrade),
+        )
+        .expect("the denom should be added");
+        assert_eq!(
+            QuoteDenomAllowlist {
+                marker_trade: vec!["usd".to_string()],
+                ..QuoteDenomAllowlist::default()
+            },
+            query_allowlist(&deps),
+            "the added denom should be returned",
+        );
+    }
+}
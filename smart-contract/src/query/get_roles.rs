@@ -0,0 +1,70 @@
+use crate::storage::contract_info::get_contract_info;
+use crate::storage::contract_role_storage::get_roles_for_address;
+use crate::types::core::error::ContractError;
+use crate::util::extensions::ResultExtensions;
+use cosmwasm_std::{to_binary, Binary, Deps};
+use provwasm_std::ProvenanceQuery;
+
+pub fn query_roles(deps: Deps<ProvenanceQuery>, address: String) -> Result<Binary, ContractError> {
+    let address = deps.api.addr_validate(&address)?;
+    let contract_info = get_contract_info(deps.storage)?;
+    to_binary(&get_roles_for_address(
+        deps.storage,
+        &contract_info,
+        &address,
+    ))?
+    .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::contract::query;
+    use crate::execute::grant_role::grant_role;
+    use crate::test::mock_instantiate::{default_instantiate, DEFAULT_ADMIN_ADDRESS};
+    use crate::types::core::contract_role::ContractRole;
+    use crate::types::core::msg::QueryMsg;
+    use cosmwasm_std::from_binary;
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use provwasm_mocks::mock_dependencies;
+
+    #[test]
+    fn test_query_roles() {
+        let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
+        let roles = from_binary::<Vec<ContractRole>>(
+            &query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::GetRoles {
+                    address: DEFAULT_ADMIN_ADDRESS.to_string(),
+                },
+            )
+            .expect("the query should succeed for the admin"),
+        )
+        .expect("the roles should deserialize");
+        assert_eq!(4, roles.len(), "the admin should hold every role");
+        grant_role(
+            deps.as_mut(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+            "canceller".to_string(),
+            ContractRole::OrderCanceller,
+        )
+        .expect("the role should be granted");
+        let roles = from_binary::<Vec<ContractRole>>(
+            &query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::GetRoles {
+                    address: "canceller".to_string(),
+                },
+            )
+            .expect("the query should succeed for the canceller"),
+        )
+        .expect("the roles should deserialize");
+        assert_eq!(
+            vec![ContractRole::OrderCanceller],
+            roles,
+            "the canceller should only hold the order canceller role",
+        );
+    }
+}
@@ -1,7 +1,13 @@
 pub mod get_ask;
 pub mod get_asks_by_collateral_id;
 pub mod get_bid;
+pub mod get_bids_by_collateral_id;
 pub mod get_contract_info;
+pub mod get_match_candidates;
+pub mod get_match_history;
 pub mod get_match_report;
+pub mod get_quote_denom_allowlist;
+pub mod get_roles;
 pub mod search_asks;
 pub mod search_bids;
+pub mod search_matches;
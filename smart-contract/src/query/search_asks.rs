@@ -3,27 +3,43 @@ use crate::storage::order_search_repository::OrderSearchRepository;
 use crate::types::core::error::ContractError;
 use crate::types::request::search::Search;
 use crate::util::extensions::ResultExtensions;
-use cosmwasm_std::{to_binary, Binary, Deps};
+use crate::validation::search_validation::validate_search;
+use cosmwasm_std::{to_binary, Binary, Deps, Env};
 use provwasm_std::ProvenanceQuery;
 
-pub fn search_asks(deps: Deps<ProvenanceQuery>, search: Search) -> Result<Binary, ContractError> {
+pub fn search_asks(
+    deps: Deps<ProvenanceQuery>,
+    env: Env,
+    search: Search,
+) -> Result<Binary, ContractError> {
+    validate_search(&search)?;
     let repository = OrderSearchRepository::new(ask_orders());
-    to_binary(&repository.search(deps.storage, search))?.to_ok()
+    to_binary(&repository.search(deps.storage, search, &env.block.time)?)?.to_ok()
 }
 
 #[cfg(test)]
 mod tests {
     use crate::query::search_asks::search_asks;
-    use crate::storage::ask_order_storage::insert_ask_order;
+    use crate::storage::ask_order_storage::{
+        delete_ask_order_by_id, insert_ask_order, update_ask_order,
+    };
+    use crate::test::error_helpers::assert_validation_error_message;
     use crate::types::core::constants::{
-        DEFAULT_SEARCH_PAGE_NUMBER, DEFAULT_SEARCH_PAGE_SIZE, MAX_SEARCH_PAGE_SIZE,
+        DEFAULT_SEARCH_PAGE_NUMBER, DEFAULT_SEARCH_PAGE_SIZE, MAX_FILTER_SEARCH_SCAN,
+        MAX_SEARCH_PAGE_SIZE,
     };
+    use crate::types::core::error::ContractError;
     use crate::types::request::ask_types::ask_collateral::AskCollateral;
     use crate::types::request::ask_types::ask_order::AskOrder;
     use crate::types::request::request_descriptor::RequestDescriptor;
     use crate::types::request::request_type::RequestType;
-    use crate::types::request::search::{Search, SearchResult};
-    use cosmwasm_std::{from_binary, Addr, Deps};
+    use crate::types::request::search::{
+        OrderStatus, Search, SearchFilter, SearchResult, SearchSort,
+    };
+    use crate::types::request::share_sale_type::ShareSaleType;
+    use crate::util::constants::NHASH;
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{coin, coins, from_binary, Addr, Coin, Deps, Timestamp};
     use provwasm_mocks::mock_dependencies;
     use provwasm_std::ProvenanceQuery;
 
@@ -76,10 +92,7 @@ mod tests {
                     Some(RequestDescriptor::basic(format!("Some ask {}", index))),
                 ),
             )
-            .expect(&format!(
-                "expected ask order {} to be inserted correctly",
-                index
-            ));
+            .unwrap_or_else(|_| panic!("expected ask order {} to be inserted correctly", index));
         }
         // Search for everything with a page size of 10 and no offset key
         let first_search = Search::all(Some(10), None);
@@ -209,10 +222,7 @@ mod tests {
                     Some(RequestDescriptor::basic(format!("Some ask {}", index))),
                 ),
             )
-            .expect(&format!(
-                "expected ask order {} to be inserted correctly",
-                index
-            ));
+            .unwrap_or_else(|_| panic!("expected ask order {} to be inserted correctly", index));
         }
         let marker_page = search(
             deps.as_ref(),
@@ -264,10 +274,7 @@ mod tests {
                     Some(RequestDescriptor::basic(format!("Some ask {}", index))),
                 ),
             )
-            .expect(&format!(
-                "expected ask order {} to be inserted correctly",
-                index
-            ));
+            .unwrap_or_else(|_| panic!("expected ask order {} to be inserted correctly", index));
         }
         let coin_page = search(
             deps.as_ref(),
@@ -363,10 +370,7 @@ mod tests {
                     Some(RequestDescriptor::basic(format!("Some ask {}", index))),
                 ),
             )
-            .expect(&format!(
-                "expected ask order {} to be inserted correctly",
-                index
-            ));
+            .unwrap_or_else(|_| panic!("expected ask order {} to be inserted correctly", index));
         }
         let ask_0_page = search(deps.as_ref(), Search::id("ask_id_0", None, None));
         assert_eq!(
@@ -410,8 +414,691 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_search_hide_inactive() {
+        let mut deps = mock_dependencies(&[]);
+        let now = mock_env().block.time;
+        let descriptors = [
+            ("ask_active", RequestDescriptor::basic("no times set")),
+            (
+                "ask_effective",
+                RequestDescriptor::basic("effective").with_effective_time(now),
+            ),
+            (
+                "ask_pending",
+                RequestDescriptor::basic("pending").with_effective_time(now.plus_seconds(60)),
+            ),
+            (
+                "ask_expired",
+                RequestDescriptor::basic("expired").with_expiration_time(now),
+            ),
+        ];
+        for (id, descriptor) in descriptors {
+            insert_ask_order(
+                deps.as_mut().storage,
+                &AskOrder::new_unchecked(
+                    id,
+                    Addr::unchecked("asker"),
+                    AskCollateral::coin_trade(&[], &[]),
+                    Some(descriptor),
+                ),
+            )
+            .unwrap_or_else(|_| panic!("expected ask order {} to be inserted correctly", id));
+        }
+        let all_page = search(deps.as_ref(), Search::all(None, None));
+        assert_eq!(
+            4,
+            all_page.results.len(),
+            "all orders should be returned when inactive orders are not hidden",
+        );
+        let active_page = search(deps.as_ref(), Search::all(None, None).hide_inactive());
+        assert_eq!(
+            vec!["ask_active", "ask_effective"],
+            active_page
+                .results
+                .iter()
+                .map(|order| order.id.as_str())
+                .collect::<Vec<&str>>(),
+            "only orders that are currently active should be returned",
+        );
+        assert_eq!(
+            4,
+            search(deps.as_ref(), Search::all(Some(1), None))
+                .total_pages
+                .u128(),
+            "sanity check: every order should be counted when inactive orders are not hidden",
+        );
+        assert_eq!(
+            4,
+            search(
+                deps.as_ref(),
+                Search::owner("asker", Some(1), None).hide_inactive(),
+            )
+            .total_pages
+            .u128(),
+            "inactive orders should still be counted in the total pages",
+        );
+        let owner_page = search(
+            deps.as_ref(),
+            Search::owner("asker", None, None).hide_inactive(),
+        );
+        assert_eq!(
+            2,
+            owner_page.results.len(),
+            "inactive orders should be hidden from owner searches",
+        );
+        let id_page = search(
+            deps.as_ref(),
+            Search::id("ask_pending", None, None).hide_inactive(),
+        );
+        assert!(
+            id_page.results.is_empty(),
+            "an order that is not yet effective should be hidden from id searches",
+        );
+    }
+
+    #[test]
+    fn test_search_with_viewer_hides_private_asks() {
+        let mut deps = mock_dependencies(&[]);
+        let descriptors = [
+            ("ask_public", RequestDescriptor::basic("public")),
+            (
+                "ask_private",
+                RequestDescriptor::basic("private").with_allowed_bidders(&["buyer"]),
+            ),
+        ];
+        for (id, descriptor) in descriptors {
+            insert_ask_order(
+                deps.as_mut().storage,
+                &AskOrder::new_unchecked(
+                    id,
+                    Addr::unchecked("asker"),
+                    AskCollateral::coin_trade(&[], &[]),
+                    Some(descriptor),
+                ),
+            )
+            .unwrap_or_else(|_| panic!("expected ask order {} to be inserted correctly", id));
+        }
+        let get_ids = |search_value: Search| {
+            search(deps.as_ref(), search_value)
+                .results
+                .into_iter()
+                .map(|order| order.id)
+                .collect::<Vec<String>>()
+        };
+        assert_eq!(
+            vec!["ask_private", "ask_public"],
+            get_ids(Search::all(None, None)),
+            "all asks should be returned when no viewer is provided",
+        );
+        assert_eq!(
+            vec!["ask_public"],
+            get_ids(Search::all(None, None).with_viewer("stranger")),
+            "private asks should be hidden from viewers that are not allowed bidders",
+        );
+        assert_eq!(
+            2,
+            search(
+                deps.as_ref(),
+                Search::all(Some(1), None).with_viewer("stranger"),
+            )
+            .total_pages
+            .u128(),
+            "private asks hidden from the viewer should still be counted in the total pages",
+        );
+        assert_eq!(
+            vec!["ask_private", "ask_public"],
+            get_ids(Search::all(None, None).with_viewer("buyer")),
+            "private asks should be visible to their allowed bidders",
+        );
+        assert_eq!(
+            vec!["ask_private", "ask_public"],
+            get_ids(Search::owner("asker", None, None).with_viewer("asker")),
+            "private asks should be visible to their owner",
+        );
+        assert!(
+            get_ids(Search::id("ask_private", None, None).with_viewer("stranger")).is_empty(),
+            "private asks should be hidden from id searches by viewers that are not allowed bidders",
+        );
+    }
+
+    #[test]
+    fn test_search_by_collateral_and_quote_values() {
+        let mut deps = mock_dependencies(&[]);
+        let ask_orders = [
+            AskOrder::new_unchecked(
+                "coin_ask",
+                Addr::unchecked("asker"),
+                AskCollateral::coin_trade(&coins(100, "base"), &[coin(10, NHASH), coin(5, "usd")]),
+                None,
+            ),
+            AskOrder::new_unchecked(
+                "marker_ask",
+                Addr::unchecked("asker"),
+                AskCollateral::marker_trade(
+                    Addr::unchecked("marker"),
+                    "markerdenom",
+                    10,
+                    &coins(10, NHASH),
+                    &[],
+                ),
+                None,
+            ),
+            AskOrder::new_unchecked(
+                "scope_ask",
+                Addr::unchecked("asker"),
+                AskCollateral::scope_trade("scope1", &coins(10, "usd"))
+                    .with_scope_spec_id("scopespec"),
+                None,
+            ),
+            AskOrder::new_unchecked(
+                "bundle_ask",
+                Addr::unchecked("asker"),
+                AskCollateral::scope_bundle_trade(&["scope2", "scope3"], &coins(10, "usd")),
+                None,
+            ),
+        ];
+        for ask_order in ask_orders.iter() {
+            insert_ask_order(deps.as_mut().storage, ask_order)
+                .expect("expected the ask order to be inserted");
+        }
+        let assert_search_ids =
+            |deps: Deps<ProvenanceQuery>, search_value: Search, expected_ids: &[&str]| {
+                let page = search(deps, search_value.to_owned());
+                assert_eq!(
+                    expected_ids,
+                    page.results
+                        .iter()
+                        .map(|order| order.id.as_str())
+                        .collect::<Vec<&str>>(),
+                    "unexpected results for search {:?}",
+                    search_value,
+                );
+            };
+        assert_search_ids(
+            deps.as_ref(),
+            Search::marker_denom("markerdenom", None, None),
+            &["marker_ask"],
+        );
+        assert_search_ids(
+            deps.as_ref(),
+            Search::marker_denom("otherdenom", None, None),
+            &[],
+        );
+        assert_search_ids(
+            deps.as_ref(),
+            Search::scope_address("scope1", None, None),
+            &["scope_ask"],
+        );
+        assert_search_ids(
+            deps.as_ref(),
+            Search::scope_address("scope3", None, None),
+            &["bundle_ask"],
+        );
+        assert_search_ids(
+            deps.as_ref(),
+            Search::scope_spec_id("scopespec", None, None),
+            &["scope_ask"],
+        );
+        assert_search_ids(
+            deps.as_ref(),
+            Search::quote_denom(NHASH, None, None),
+            &["coin_ask", "marker_ask"],
+        );
+        assert_search_ids(
+            deps.as_ref(),
+            Search::quote_denom("usd", None, None),
+            &["bundle_ask", "coin_ask", "scope_ask"],
+        );
+        let page = search(deps.as_ref(), Search::quote_denom("usd", Some(2), Some(2)));
+        assert_eq!(
+            vec!["scope_ask"],
+            page.results
+                .iter()
+                .map(|order| order.id.as_str())
+                .collect::<Vec<&str>>(),
+            "paging should be applied to quote denom searches",
+        );
+        assert_eq!(
+            2,
+            page.total_pages.u128(),
+            "the correct page count should be returned"
+        );
+        delete_ask_order_by_id(deps.as_mut().storage, "bundle_ask")
+            .expect("expected the ask order to be deleted");
+        update_ask_order(
+            deps.as_mut().storage,
+            &AskOrder::new_unchecked(
+                "coin_ask",
+                Addr::unchecked("asker"),
+                AskCollateral::coin_trade(&coins(100, "base"), &coins(10, NHASH)),
+                None,
+            ),
+        )
+        .expect("expected the ask order to be updated");
+        assert_search_ids(
+            deps.as_ref(),
+            Search::scope_address("scope3", None, None),
+            &[],
+        );
+        assert_search_ids(
+            deps.as_ref(),
+            Search::quote_denom("usd", None, None),
+            &["scope_ask"],
+        );
+    }
+
+    #[test]
+    fn test_search_with_cursor() {
+        let mut deps = mock_dependencies(&[]);
+        for index in 0..5 {
+            insert_ask_order(
+                deps.as_mut().storage,
+                &AskOrder::new_unchecked(
+                    format!("ask_{}", index),
+                    Addr::unchecked(if index % 2 == 0 { "even" } else { "odd" }),
+                    AskCollateral::coin_trade(&coins(100, "base"), &coins(10, NHASH)),
+                    None,
+                ),
+            )
+            .expect("expected the ask order to be inserted");
+        }
+        let get_ids = |page: &SearchResult<AskOrder>| {
+            page.results
+                .iter()
+                .map(|order| order.id.to_owned())
+                .collect::<Vec<String>>()
+        };
+        let first_page = search(deps.as_ref(), Search::all(Some(2), None));
+        assert_eq!(
+            vec!["ask_0", "ask_1"],
+            get_ids(&first_page),
+            "the first page should contain the first two asks",
+        );
+        assert_eq!(
+            Some("ask_1".to_string()),
+            first_page.next_start_after,
+            "the cursor should be the id of the last ask on the page",
+        );
+        assert_eq!(
+            3,
+            first_page.total_pages.u128(),
+            "the total page count should be derived from the stored counts",
+        );
+        let second_page = search(
+            deps.as_ref(),
+            Search::all(Some(2), Some(10)).start_after("ask_1"),
+        );
+        assert_eq!(
+            vec!["ask_2", "ask_3"],
+            get_ids(&second_page),
+            "the cursor should be used in place of the page number",
+        );
+        let last_page = search(
+            deps.as_ref(),
+            Search::all(Some(2), None).start_after("ask_3"),
+        );
+        assert_eq!(
+            vec!["ask_4"],
+            get_ids(&last_page),
+            "the last page should contain the remaining ask",
+        );
+        assert_eq!(
+            None, last_page.next_start_after,
+            "no cursor should be returned when the page is not filled",
+        );
+        let descending_page = search(
+            deps.as_ref(),
+            Search::all(Some(2), None).descending().start_after("ask_3"),
+        );
+        assert_eq!(
+            vec!["ask_2", "ask_1"],
+            get_ids(&descending_page),
+            "a descending search should return the asks before the cursor",
+        );
+        let owner_page = search(
+            deps.as_ref(),
+            Search::owner("even", Some(2), None).start_after("ask_0"),
+        );
+        assert_eq!(
+            vec!["ask_2", "ask_4"],
+            get_ids(&owner_page),
+            "the cursor should be applied to owner searches",
+        );
+        assert_eq!(
+            2,
+            owner_page.total_pages.u128(),
+            "the total page count should be derived from the owner's ask count",
+        );
+        let quote_denom_page = search(
+            deps.as_ref(),
+            Search::quote_denom(NHASH, Some(3), None)
+                .descending()
+                .start_after("ask_4"),
+        );
+        assert_eq!(
+            vec!["ask_3", "ask_2", "ask_1"],
+            get_ids(&quote_denom_page),
+            "the cursor should be applied to quote denom searches",
+        );
+    }
+
+    #[test]
+    fn test_search_with_filter() {
+        let mut deps = mock_dependencies(&[]);
+        let mut insert_ask = |id: &str, owner: &str, collateral: AskCollateral, seconds: u64| {
+            let mut ask_order = AskOrder::new_unchecked(
+                id,
+                Addr::unchecked(owner),
+                collateral,
+                Some(
+                    RequestDescriptor::new_none().with_expiration_time(
+                        mock_env()
+                            .block
+                            .time
+                            .plus_seconds(if id == "expired" { 0 } else { 1000 }),
+                    ),
+                ),
+            );
+            ask_order.created_time = Some(Timestamp::from_seconds(seconds));
+            insert_ask_order(deps.as_mut().storage, &ask_order)
+                .expect("expected the ask order to be inserted");
+        };
+        let marker_share_sale = |quote: &[Coin]| {
+            AskCollateral::marker_share_sale(
+                Addr::unchecked("marker"),
+                "markerdenom",
+                100,
+                100,
+                quote,
+                &[],
+                ShareSaleType::MultipleTransactions,
+            )
+        };
+        insert_ask("cheap", "owner", marker_share_sale(&coins(10, NHASH)), 30);
+        insert_ask("pricey", "owner", marker_share_sale(&coins(50, NHASH)), 10);
+        insert_ask("usd", "owner", marker_share_sale(&coins(20, "usd")), 20);
+        insert_ask("expired", "owner", marker_share_sale(&coins(20, NHASH)), 40);
+        insert_ask(
+            "coin",
+            "owner",
+            AskCollateral::coin_trade(&coins(10, "base"), &coins(20, NHASH)),
+            50,
+        );
+        insert_ask(
+            "other_owner",
+            "other",
+            marker_share_sale(&coins(20, NHASH)),
+            60,
+        );
+        let search_ids = |search_value: Search| -> Vec<String> {
+            search(deps.as_ref(), search_value)
+                .results
+                .into_iter()
+                .map(|order| order.id)
+                .collect()
+        };
+        let my_open_share_sales = SearchFilter::new()
+            .with_owner("owner")
+            .with_request_type(RequestType::MarkerShareSale)
+            .with_status(OrderStatus::Active)
+            .with_quote_denom(NHASH);
+        assert_eq!(
+            vec!["cheap", "pricey"],
+            search_ids(Search::filter(my_open_share_sales.to_owned(), None, None)),
+            "only the owner's active share sales priced in nhash should be found, sorted by id",
+        );
+        assert_eq!(
+            vec!["pricey"],
+            search_ids(Search::filter(
+                my_open_share_sales
+                    .to_owned()
+                    .with_price_range(Some(20), None),
+                None,
+                None,
+            )),
+            "the price range should exclude cheaper asks",
+        );
+        assert_eq!(
+            vec!["expired"],
+            search_ids(Search::filter(
+                SearchFilter::new()
+                    .with_owner("owner")
+                    .with_status(OrderStatus::Expired),
+                None,
+                None,
+            )),
+            "the status should select expired asks",
+        );
+        assert_eq!(
+            vec!["pricey", "usd", "cheap", "expired"],
+            search_ids(Search::filter(
+                SearchFilter::new()
+                    .with_request_type(RequestType::MarkerShareSale)
+                    .with_creation_range(None, Some(Timestamp::from_seconds(40)))
+                    .sorted_by(SearchSort::CreationTime),
+                None,
+                None,
+            )),
+            "asks should be sorted by creation time within the creation range",
+        );
+        let price_filter = SearchFilter::new()
+            .with_quote_denom(NHASH)
+            .sorted_by(SearchSort::Price);
+        assert_eq!(
+            vec!["pricey", "other_owner", "expired", "coin", "cheap"],
+            search_ids(Search::filter(price_filter.to_owned(), None, None).descending()),
+            "asks should be sorted by descending price, with ties sorted by descending id",
+        );
+        let first_page = search(
+            deps.as_ref(),
+            Search::filter(price_filter.to_owned(), Some(2), None),
+        );
+        assert_eq!(
+            3,
+            first_page.total_pages.u128(),
+            "the total pages should reflect the number of matching asks",
+        );
+        assert_eq!(
+            Some("coin".to_string()),
+            first_page.next_start_after,
+            "the cursor should be the last ask on the page",
+        );
+        assert_eq!(
+            vec!["expired", "other_owner"],
+            search_ids(Search::filter(price_filter.to_owned(), Some(2), None).start_after("coin")),
+            "the cursor should resume after the last ask in the sort order",
+        );
+        assert_eq!(
+            vec!["expired", "other_owner"],
+            search_ids(Search::filter(price_filter, Some(2), Some(2))),
+            "page numbers should be applied to the sorted asks",
+        );
+    }
+
+    #[test]
+    fn test_search_with_filter_cursors_and_missing_sort_values() {
+        let mut deps = mock_dependencies(&[]);
+        for (id, created_seconds) in [
+            ("ask_1", Some(20)),
+            ("ask_2", None),
+            ("ask_3", Some(10)),
+            ("ask_4", Some(30)),
+        ] {
+            let mut ask_order = AskOrder::new_unchecked(
+                id,
+                Addr::unchecked("asker"),
+                AskCollateral::coin_trade(&coins(10, "base"), &coins(10, NHASH)),
+                None,
+            );
+            ask_order.created_time = created_seconds.map(Timestamp::from_seconds);
+            insert_ask_order(deps.as_mut().storage, &ask_order)
+                .expect("expected the ask order to be inserted");
+        }
+        let search_ids = |search_value: Search| -> Vec<String> {
+            search(deps.as_ref(), search_value)
+                .results
+                .into_iter()
+                .map(|order| order.id)
+                .collect()
+        };
+        let filter = SearchFilter::new().with_owner("asker");
+        assert_eq!(
+            vec!["ask_3", "ask_4"],
+            search_ids(Search::filter(filter.to_owned(), Some(2), None).start_after("ask_2")),
+            "the cursor should resume after the given id when sorting by id",
+        );
+        assert_eq!(
+            vec!["ask_2", "ask_1"],
+            search_ids(
+                Search::filter(filter.to_owned(), Some(2), None)
+                    .descending()
+                    .start_after("ask_3")
+            ),
+            "the cursor should resume before the given id when sorting by descending id",
+        );
+        let creation_filter = filter.sorted_by(SearchSort::CreationTime);
+        assert_eq!(
+            vec!["ask_3", "ask_1", "ask_4", "ask_2"],
+            search_ids(Search::filter(creation_filter.to_owned(), None, None)),
+            "asks without a creation time should follow the others in ascending order",
+        );
+        assert_eq!(
+            vec!["ask_4", "ask_1", "ask_3", "ask_2"],
+            search_ids(Search::filter(creation_filter.to_owned(), None, None).descending()),
+            "asks without a creation time should follow the others in descending order",
+        );
+        assert_eq!(
+            vec!["ask_2"],
+            search_ids(
+                Search::filter(creation_filter, None, None)
+                    .descending()
+                    .start_after("ask_3")
+            ),
+            "the cursor should locate the page by the sort value of the given ask",
+        );
+    }
+
+    #[test]
+    fn test_search_with_filter_scan_limit() {
+        let mut deps = mock_dependencies(&[]);
+        // Only the last ask is priced within the filter's range, so every ask before it is checked
+        for index in 0..=MAX_FILTER_SEARCH_SCAN {
+            insert_ask_order(
+                deps.as_mut().storage,
+                &AskOrder::new_unchecked(
+                    format!("ask_{:04}", index),
+                    Addr::unchecked("asker"),
+                    AskCollateral::coin_trade(
+                        &coins(10, "base"),
+                        &coins(
+                            if index == MAX_FILTER_SEARCH_SCAN {
+                                99
+                            } else {
+                                10
+                            },
+                            NHASH,
+                        ),
+                    ),
+                    None,
+                ),
+            )
+            .expect("expected the ask order to be inserted");
+        }
+        let filter = SearchFilter::new()
+            .with_owner("asker")
+            .with_quote_denom(NHASH)
+            .with_price_range(Some(50), None);
+        let first_page = search(
+            deps.as_ref(),
+            Search::filter(filter.to_owned(), Some(10), None),
+        );
+        assert!(
+            first_page.results.is_empty(),
+            "no ask within the scan limit should match the filter",
+        );
+        assert_eq!(
+            Some(format!("ask_{:04}", MAX_FILTER_SEARCH_SCAN - 1)),
+            first_page.next_start_after,
+            "the cursor should be the last ask checked before the scan limit was reached",
+        );
+        assert_eq!(
+            51,
+            first_page.total_pages.u128(),
+            "the total pages of an id sorted filter should reflect every ask in the narrowest index",
+        );
+        let second_page = search(
+            deps.as_ref(),
+            Search::filter(filter.to_owned(), Some(10), None)
+                .start_after(first_page.next_start_after.unwrap()),
+        );
+        assert_eq!(
+            vec![format!("ask_{:04}", MAX_FILTER_SEARCH_SCAN)],
+            second_page
+                .results
+                .into_iter()
+                .map(|order| order.id)
+                .collect::<Vec<String>>(),
+            "the next page should resume the scan after the cursor",
+        );
+        assert_eq!(
+            None, second_page.next_start_after,
+            "no cursor should be produced once every ask has been checked",
+        );
+        let err = search_asks(
+            deps.as_ref(),
+            mock_env(),
+            Search::filter(filter.sorted_by(SearchSort::Price), None, None),
+        )
+        .expect_err("a sorted filter should be refused when its index exceeds the scan limit");
+        match err {
+            ContractError::InvalidRequest { message } => assert_eq!(
+                format!(
+                    "filters that are not sorted by id can check at most [{}] orders, but the narrowest criterion includes [{}]",
+                    MAX_FILTER_SEARCH_SCAN,
+                    MAX_FILTER_SEARCH_SCAN + 1,
+                ),
+                message,
+                "the correct error message should be produced",
+            ),
+            e => panic!("unexpected error: {:?}", e),
+        };
+    }
+
+    #[test]
+    fn test_search_with_invalid_filter() {
+        let deps = mock_dependencies(&[]);
+        let err = search_asks(
+            deps.as_ref(),
+            mock_env(),
+            Search::filter(
+                SearchFilter::new()
+                    .with_owner("owner")
+                    .sorted_by(SearchSort::Price),
+                None,
+                None,
+            ),
+        )
+        .expect_err("an invalid filter should be rejected");
+        assert_validation_error_message(err, "filter price sort requires a quote_denom");
+        let err = search_asks(
+            deps.as_ref(),
+            mock_env(),
+            Search::filter(
+                SearchFilter::new().with_status(OrderStatus::Active),
+                None,
+                None,
+            ),
+        )
+        .expect_err("a filter without an indexed criterion should be rejected");
+        assert_validation_error_message(
+            err,
+            "filter requires an owner, request_type, or quote_denom",
+        );
+    }
+
     fn search(deps: Deps<ProvenanceQuery>, search: Search) -> SearchResult<AskOrder> {
-        let bin = search_asks(deps, search).expect("expected the result to succeed");
+        let bin = search_asks(deps, mock_env(), search).expect("expected the result to succeed");
         from_binary(&bin)
             .expect("expected binary deserialization to the appropriate type to succeed")
     }
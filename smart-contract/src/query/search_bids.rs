@@ -3,12 +3,18 @@ use crate::storage::order_search_repository::OrderSearchRepository;
 use crate::types::core::error::ContractError;
 use crate::types::request::search::Search;
 use crate::util::extensions::ResultExtensions;
-use cosmwasm_std::{to_binary, Binary, Deps};
+use crate::validation::search_validation::validate_search;
+use cosmwasm_std::{to_binary, Binary, Deps, Env};
 use provwasm_std::ProvenanceQuery;
 
-pub fn search_bids(deps: Deps<ProvenanceQuery>, search: Search) -> Result<Binary, ContractError> {
+pub fn search_bids(
+    deps: Deps<ProvenanceQuery>,
+    env: Env,
+    search: Search,
+) -> Result<Binary, ContractError> {
+    validate_search(&search)?;
     let repository = OrderSearchRepository::new(bid_orders());
-    to_binary(&repository.search(deps.storage, search))?.to_ok()
+    to_binary(&repository.search(deps.storage, search, &env.block.time)?)?.to_ok()
 }
 
 #[cfg(test)]
@@ -22,8 +28,10 @@ mod tests {
     use crate::types::request::bid_types::bid_order::BidOrder;
     use crate::types::request::request_descriptor::RequestDescriptor;
     use crate::types::request::request_type::RequestType;
-    use crate::types::request::search::{Search, SearchResult};
-    use cosmwasm_std::{from_binary, Addr, Deps};
+    use crate::types::request::search::{Search, SearchFilter, SearchResult, SearchSort};
+    use crate::util::constants::NHASH;
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{coins, from_binary, Addr, Deps};
     use provwasm_mocks::mock_dependencies;
     use provwasm_std::ProvenanceQuery;
 
@@ -75,10 +83,7 @@ mod tests {
                     Some(RequestDescriptor::basic(format!("Some bid {}", index))),
                 ),
             )
-            .expect(&format!(
-                "expected bid order {} to be inserted correctly",
-                index
-            ));
+            .unwrap_or_else(|_| panic!("expected bid order {} to be inserted correctly", index));
         }
         // Search for everything with a page size of 10 and no offset key
         let first_search = Search::all(Some(10), None);
@@ -208,10 +213,7 @@ mod tests {
                     Some(RequestDescriptor::basic(format!("Some bid {}", index))),
                 ),
             )
-            .expect(&format!(
-                "expected bid order {} to be inserted correctly",
-                index
-            ));
+            .unwrap_or_else(|_| panic!("expected bid order {} to be inserted correctly", index));
         }
         let marker_page = search(
             deps.as_ref(),
@@ -262,10 +264,7 @@ mod tests {
                     Some(RequestDescriptor::basic(format!("Some bid {}", index))),
                 ),
             )
-            .expect(&format!(
-                "expected bid order {} to be inserted correctly",
-                index
-            ));
+            .unwrap_or_else(|_| panic!("expected bid order {} to be inserted correctly", index));
         }
         let coin_page = search(
             deps.as_ref(),
@@ -360,10 +359,7 @@ mod tests {
                     Some(RequestDescriptor::basic(format!("Some bid {}", index))),
                 ),
             )
-            .expect(&format!(
-                "expected bid order {} to be inserted correctly",
-                index
-            ));
+            .unwrap_or_else(|_| panic!("expected bid order {} to be inserted correctly", index));
         }
         let bid_0_page = search(deps.as_ref(), Search::id("bid_id_0", None, None));
         assert_eq!(
@@ -407,8 +403,192 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_search_hide_inactive() {
+        let mut deps = mock_dependencies(&[]);
+        let now = mock_env().block.time;
+        let descriptors = [
+            ("bid_active", RequestDescriptor::basic("no times set")),
+            (
+                "bid_effective",
+                RequestDescriptor::basic("effective").with_effective_time(now),
+            ),
+            (
+                "bid_pending",
+                RequestDescriptor::basic("pending").with_effective_time(now.plus_seconds(60)),
+            ),
+            (
+                "bid_expired",
+                RequestDescriptor::basic("expired").with_expiration_time(now),
+            ),
+        ];
+        for (id, descriptor) in descriptors {
+            insert_bid_order(
+                deps.as_mut().storage,
+                &BidOrder::new_unchecked(
+                    id,
+                    Addr::unchecked("bidder"),
+                    BidCollateral::coin_trade(&[], &[]),
+                    Some(descriptor),
+                ),
+            )
+            .unwrap_or_else(|_| panic!("expected bid order {} to be inserted correctly", id));
+        }
+        let all_page = search(deps.as_ref(), Search::all(None, None));
+        assert_eq!(
+            4,
+            all_page.results.len(),
+            "all orders should be returned when inactive orders are not hidden",
+        );
+        let active_page = search(deps.as_ref(), Search::all(None, None).hide_inactive());
+        assert_eq!(
+            vec!["bid_active", "bid_effective"],
+            active_page
+                .results
+                .iter()
+                .map(|order| order.id.as_str())
+                .collect::<Vec<&str>>(),
+            "only orders that are currently active should be returned",
+        );
+        let owner_page = search(
+            deps.as_ref(),
+            Search::owner("bidder", None, None).hide_inactive(),
+        );
+        assert_eq!(
+            2,
+            owner_page.results.len(),
+            "inactive orders should be hidden from owner searches",
+        );
+        let id_page = search(
+            deps.as_ref(),
+            Search::id("bid_pending", None, None).hide_inactive(),
+        );
+        assert!(
+            id_page.results.is_empty(),
+            "an order that is not yet effective should be hidden from id searches",
+        );
+    }
+
+    #[test]
+    fn test_search_by_collateral_and_quote_values() {
+        let mut deps = mock_dependencies(&[]);
+        let bid_orders = [
+            BidOrder::new_unchecked(
+                "marker_bid",
+                Addr::unchecked("bidder"),
+                BidCollateral::marker_trade(
+                    Addr::unchecked("marker"),
+                    "markerdenom",
+                    &coins(10, NHASH),
+                    None,
+                ),
+                None,
+            ),
+            BidOrder::new_unchecked(
+                "scope_bid",
+                Addr::unchecked("bidder"),
+                BidCollateral::scope_trade("scope1", &coins(10, "usd")),
+                None,
+            ),
+            BidOrder::new_unchecked(
+                "scope_spec_bid",
+                Addr::unchecked("bidder"),
+                BidCollateral::scope_spec_trade("scopespec", &coins(10, "usd"), None, None),
+                None,
+            ),
+        ];
+        for bid_order in bid_orders.iter() {
+            insert_bid_order(deps.as_mut().storage, bid_order)
+                .expect("expected the bid order to be inserted");
+        }
+        let assert_search_ids =
+            |deps: Deps<ProvenanceQuery>, search_value: Search, expected_ids: &[&str]| {
+                let page = search(deps, search_value.to_owned());
+                assert_eq!(
+                    expected_ids,
+                    page.results
+                        .iter()
+                        .map(|order| order.id.as_str())
+                        .collect::<Vec<&str>>(),
+                    "unexpected results for search {:?}",
+                    search_value,
+                );
+            };
+        assert_search_ids(
+            deps.as_ref(),
+            Search::marker_denom("markerdenom", None, None),
+            &["marker_bid"],
+        );
+        assert_search_ids(
+            deps.as_ref(),
+            Search::scope_address("scope1", None, None),
+            &["scope_bid"],
+        );
+        assert_search_ids(
+            deps.as_ref(),
+            Search::scope_spec_id("scopespec", None, None),
+            &["scope_spec_bid"],
+        );
+        assert_search_ids(
+            deps.as_ref(),
+            Search::quote_denom(NHASH, None, None),
+            &["marker_bid"],
+        );
+        assert_search_ids(
+            deps.as_ref(),
+            Search::quote_denom("usd", None, None),
+            &["scope_bid", "scope_spec_bid"],
+        );
+    }
+
+    #[test]
+    fn test_search_with_filter() {
+        let mut deps = mock_dependencies(&[]);
+        for (id, owner, quote) in [
+            ("bid_1", "bidder", coins(30, NHASH)),
+            ("bid_2", "bidder", coins(10, NHASH)),
+            ("bid_3", "bidder", coins(20, "usd")),
+            ("bid_4", "other", coins(5, NHASH)),
+        ] {
+            insert_bid_order(
+                deps.as_mut().storage,
+                &BidOrder::new_unchecked(
+                    id,
+                    Addr::unchecked(owner),
+                    BidCollateral::coin_trade(&coins(100, "base"), &quote),
+                    None,
+                ),
+            )
+            .expect("expected the bid order to be inserted");
+        }
+        let page = search(
+            deps.as_ref(),
+            Search::filter(
+                SearchFilter::new()
+                    .with_owner("bidder")
+                    .with_quote_denom(NHASH)
+                    .sorted_by(SearchSort::Price),
+                None,
+                None,
+            ),
+        );
+        assert_eq!(
+            vec!["bid_2", "bid_1"],
+            page.results
+                .iter()
+                .map(|order| order.id.as_str())
+                .collect::<Vec<&str>>(),
+            "the bidder's nhash bids should be returned from the lowest price to the highest",
+        );
+        assert_eq!(
+            1,
+            page.total_pages.u128(),
+            "the total pages should reflect the matching bids",
+        );
+    }
+
     fn search(deps: Deps<ProvenanceQuery>, search: Search) -> SearchResult<BidOrder> {
-        let bin = search_bids(deps, search).expect("expected the result to succeed");
+        let bin = search_bids(deps, mock_env(), search).expect("expected the result to succeed");
         from_binary(&bin)
             .expect("expected binary deserialization to the appropriate type to succeed")
     }
@@ -0,0 +1,63 @@
+use crate::storage::match_record_storage::search_match_records;
+use crate::types::core::error::ContractError;
+use crate::types::request::search::MatchSearch;
+use crate::util::extensions::ResultExtensions;
+use cosmwasm_std::{to_binary, Binary, Deps};
+use provwasm_std::ProvenanceQuery;
+
+pub fn search_matches(
+    deps: Deps<ProvenanceQuery>,
+    search: MatchSearch,
+) -> Result<Binary, ContractError> {
+    to_binary(&search_match_records(deps.storage, &search))?.to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::contract::query;
+    use crate::storage::match_record_storage::insert_match_record;
+    use crate::test::request_helpers::mock_match_record;
+    use crate::types::core::msg::QueryMsg;
+    use crate::types::request::match_record::MatchRecord;
+    use crate::types::request::search::{MatchSearch, MatchSearchType, SearchResult};
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{from_binary, Addr};
+    use provwasm_mocks::mock_dependencies;
+
+    #[test]
+    fn test_search_matches_by_owner() {
+        let mut deps = mock_dependencies(&[]);
+        let mut record = mock_match_record();
+        insert_match_record(deps.as_mut().storage, &record)
+            .expect("the first record should be inserted");
+        record.bidder = Addr::unchecked("other_bidder");
+        insert_match_record(deps.as_mut().storage, &record)
+            .expect("the second record should be inserted");
+        let binary = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::SearchMatches {
+                search: MatchSearch::new(
+                    MatchSearchType::Owner {
+                        owner: "other_bidder".to_string(),
+                    },
+                    None,
+                    None,
+                ),
+            },
+        )
+        .expect("the query should succeed");
+        let result = from_binary::<SearchResult<MatchRecord>>(&binary)
+            .expect("the result should deserialize to a search result");
+        assert_eq!(
+            1,
+            result.results.len(),
+            "only the match including the searched owner should be returned",
+        );
+        assert_eq!(
+            "other_bidder",
+            result.results.first().unwrap().bidder.as_str(),
+            "the correct record should be returned",
+        );
+    }
+}
@@ -0,0 +1,77 @@
+use crate::types::core::error::ContractError;
+use crate::util::extensions::ResultExtensions;
+use cosmwasm_std::{Addr, Empty, Storage};
+use cw_storage_plus::Map;
+
+const NAMESPACE_ADDRESS_BLOCKLIST: &str = "address_blocklist";
+
+// Only the presence of a key matters, so no value is stored for blocked addresses
+const ADDRESS_BLOCKLIST: Map<&Addr, Empty> = Map::new(NAMESPACE_ADDRESS_BLOCKLIST);
+
+pub fn block_address(storage: &mut dyn Storage, address: &Addr) -> Result<(), ContractError> {
+    ADDRESS_BLOCKLIST
+        .save(storage, address, &Empty {})
+        .map_err(|e| ContractError::StorageError {
+            message: format!("{:?}", e),
+        })
+}
+
+pub fn unblock_address(storage: &mut dyn Storage, address: &Addr) -> Result<(), ContractError> {
+    ADDRESS_BLOCKLIST.remove(storage, address);
+    ().to_ok()
+}
+
+pub fn is_address_blocked(storage: &dyn Storage, address: &Addr) -> bool {
+    ADDRESS_BLOCKLIST.has(storage, address)
+}
+
+/// Produces an error if the address is blocked, preventing it from creating or updating orders.
+pub fn validate_address_not_blocked(
+    storage: &dyn Storage,
+    address: &Addr,
+) -> Result<(), ContractError> {
+    if is_address_blocked(storage, address) {
+        return ContractError::InvalidRequest {
+            message: format!("address [{}] is blocked", address.as_str()),
+        }
+        .to_err();
+    }
+    ().to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::storage::address_blocklist_storage::{
+        block_address, is_address_blocked, unblock_address, validate_address_not_blocked,
+    };
+    use crate::types::core::error::ContractError;
+    use cosmwasm_std::Addr;
+    use provwasm_mocks::mock_dependencies;
+
+    #[test]
+    fn test_block_and_unblock_address() {
+        let mut deps = mock_dependencies(&[]);
+        let address = Addr::unchecked("sanctioned");
+        assert!(
+            !is_address_blocked(deps.as_ref().storage, &address),
+            "addresses should not be blocked by default",
+        );
+        block_address(deps.as_mut().storage, &address).expect("the address should be blocked");
+        assert!(
+            is_address_blocked(deps.as_ref().storage, &address),
+            "the address should be blocked",
+        );
+        match validate_address_not_blocked(deps.as_ref().storage, &address)
+            .expect_err("a blocked address should fail validation")
+        {
+            ContractError::InvalidRequest { message } => assert_eq!(
+                "address [sanctioned] is blocked", message,
+                "the correct error message should be produced",
+            ),
+            e => panic!("unexpected error: {:?}", e),
+        };
+        unblock_address(deps.as_mut().storage, &address).expect("the address should be unblocked");
+        validate_address_not_blocked(deps.as_ref().storage, &address)
+            .expect("an unblocked address should pass validation");
+    }
+}
@@ -1,25 +1,55 @@
+use crate::storage::multi_value_index::MultiValueIndex;
+use crate::storage::order_count_index::{get_order_count_keys, OrderCountIndex};
 use crate::storage::order_indices::OrderIndices;
+use crate::storage::order_search_repository::take_distinct_orders;
 use crate::types::core::constants::DEFAULT_SEARCH_ORDER;
 use crate::types::core::error::ContractError;
+use crate::types::request::ask_types::ask_collateral::AskCollateral;
 use crate::types::request::ask_types::ask_order::AskOrder;
+use crate::types::request::bid_types::bid_collateral::BidCollateral;
+use crate::types::request::bid_types::bid_order::BidOrder;
 use crate::util::extensions::ResultExtensions;
-use cosmwasm_std::Storage;
-use cw_storage_plus::{Index, IndexList, IndexedMap, MultiIndex};
+use cosmwasm_std::{Storage, Timestamp};
+use cw_storage_plus::{Index, IndexList, IndexedMap, MultiIndex, PrefixBound};
 
 const NAMESPACE_ASK_PK: &str = "ask";
+const NAMESPACE_BASE_DENOM_IDX: &str = "ask__base_denom";
+const NAMESPACE_COUNT_IDX: &str = "ask__count";
 const NAMESPACE_COLLATERAL_IDX: &str = "ask__collateral_v2";
+const NAMESPACE_EXPIRATION_IDX: &str = "ask__expiration";
+const NAMESPACE_MARKER_DENOM_IDX: &str = "ask__marker_denom";
 const NAMESPACE_OWNER_IDX: &str = "ask__owner";
+const NAMESPACE_QUOTE_DENOM_IDX: &str = "ask__quote_denom";
+const NAMESPACE_SCOPE_ADDRESS_IDX: &str = "ask__scope_address";
+const NAMESPACE_SCOPE_SPEC_IDX: &str = "ask__scope_spec";
 const NAMESPACE_TYPE_IDX: &str = "ask__type";
 
 pub struct AskOrderIndices<'a> {
     pub collateral_index: MultiIndex<'a, String, AskOrder, String>,
+    pub expiration_index: MultiIndex<'a, u64, AskOrder, String>,
     pub owner_index: MultiIndex<'a, String, AskOrder, String>,
     pub type_index: MultiIndex<'a, String, AskOrder, String>,
+    pub marker_denom_index: MultiValueIndex<'a, AskOrder>,
+    pub base_denom_index: MultiValueIndex<'a, AskOrder>,
+    pub scope_address_index: MultiValueIndex<'a, AskOrder>,
+    pub scope_spec_index: MultiValueIndex<'a, AskOrder>,
+    pub quote_denom_index: MultiValueIndex<'a, AskOrder>,
+    pub count_index: OrderCountIndex<'a, AskOrder>,
 }
 impl<'a> IndexList<AskOrder> for AskOrderIndices<'a> {
     fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<AskOrder>> + '_> {
-        let v: Vec<&dyn Index<AskOrder>> =
-            vec![&self.collateral_index, &self.owner_index, &self.type_index];
+        let v: Vec<&dyn Index<AskOrder>> = vec![
+            &self.collateral_index,
+            &self.expiration_index,
+            &self.owner_index,
+            &self.type_index,
+            &self.marker_denom_index,
+            &self.base_denom_index,
+            &self.scope_address_index,
+            &self.scope_spec_index,
+            &self.quote_denom_index,
+            &self.count_index,
+        ];
         Box::new(v.into_iter())
     }
 }
@@ -31,6 +61,26 @@ impl<'a> OrderIndices<'a, AskOrder> for AskOrderIndices<'a> {
     fn type_index(&self) -> &MultiIndex<'a, String, AskOrder, String> {
         &self.type_index
     }
+
+    fn marker_denom_index(&self) -> &MultiValueIndex<'a, AskOrder> {
+        &self.marker_denom_index
+    }
+
+    fn scope_address_index(&self) -> &MultiValueIndex<'a, AskOrder> {
+        &self.scope_address_index
+    }
+
+    fn scope_spec_index(&self) -> &MultiValueIndex<'a, AskOrder> {
+        &self.scope_spec_index
+    }
+
+    fn quote_denom_index(&self) -> &MultiValueIndex<'a, AskOrder> {
+        &self.quote_denom_index
+    }
+
+    fn count_index(&self) -> &OrderCountIndex<'a, AskOrder> {
+        &self.count_index
+    }
 }
 
 pub fn ask_orders<'a>() -> IndexedMap<'a, &'a [u8], AskOrder, AskOrderIndices<'a>> {
@@ -40,6 +90,11 @@ pub fn ask_orders<'a>() -> IndexedMap<'a, &'a [u8], AskOrder, AskOrderIndices<'a
             NAMESPACE_ASK_PK,
             NAMESPACE_COLLATERAL_IDX,
         ),
+        expiration_index: MultiIndex::new(
+            |ask: &AskOrder| ask.get_expiration_index(),
+            NAMESPACE_ASK_PK,
+            NAMESPACE_EXPIRATION_IDX,
+        ),
         owner_index: MultiIndex::new(
             |ask: &AskOrder| ask.owner.clone().to_string(),
             NAMESPACE_ASK_PK,
@@ -50,6 +105,39 @@ pub fn ask_orders<'a>() -> IndexedMap<'a, &'a [u8], AskOrder, AskOrderIndices<'a
             NAMESPACE_ASK_PK,
             NAMESPACE_TYPE_IDX,
         ),
+        marker_denom_index: MultiValueIndex::new(
+            |ask: &AskOrder| ask.get_marker_denom_index(),
+            NAMESPACE_MARKER_DENOM_IDX,
+        ),
+        base_denom_index: MultiValueIndex::new(
+            |ask: &AskOrder| ask.get_base_denom_index(),
+            NAMESPACE_BASE_DENOM_IDX,
+        ),
+        scope_address_index: MultiValueIndex::new(
+            |ask: &AskOrder| ask.get_scope_address_index(),
+            NAMESPACE_SCOPE_ADDRESS_IDX,
+        ),
+        scope_spec_index: MultiValueIndex::new(
+            |ask: &AskOrder| ask.get_scope_spec_index(),
+            NAMESPACE_SCOPE_SPEC_IDX,
+        ),
+        quote_denom_index: MultiValueIndex::new(
+            |ask: &AskOrder| ask.get_quote_denom_index(),
+            NAMESPACE_QUOTE_DENOM_IDX,
+        ),
+        count_index: OrderCountIndex::new(
+            |ask: &AskOrder| {
+                get_order_count_keys(
+                    &ask.owner,
+                    ask.ask_type.get_name(),
+                    ask.get_marker_denom_index(),
+                    ask.get_scope_address_index(),
+                    ask.get_scope_spec_index(),
+                    ask.get_quote_denom_index(),
+                )
+            },
+            NAMESPACE_COUNT_IDX,
+        ),
     };
     IndexedMap::new(NAMESPACE_ASK_PK, indices)
 }
@@ -115,17 +203,113 @@ pub fn get_ask_order_by_id<S: Into<String>>(
         })
 }
 
+/// Fetches all ask orders that hold the given collateral, including any scope bundle trade that
+/// holds the collateral as one of its scopes.
 pub fn get_ask_orders_by_collateral_id<S: Into<String>>(
     storage: &dyn Storage,
     collateral_id: S,
 ) -> Vec<AskOrder> {
-    ask_orders()
+    let collateral_id = collateral_id.into();
+    let state = ask_orders();
+    let mut ask_orders_for_collateral = state
         .idx
         .collateral_index
-        .prefix(collateral_id.into())
+        .prefix(collateral_id.to_owned())
         .range(storage, None, None, DEFAULT_SEARCH_ORDER)
         .filter(|result| result.is_ok())
         .map(|result| result.unwrap().1)
+        .collect::<Vec<AskOrder>>();
+    // Scope trades are already found by the collateral index, so only bundles are added from the
+    // scope address index
+    ask_orders_for_collateral.extend(
+        state
+            .idx
+            .scope_address_index
+            .primary_keys(storage, &collateral_id, None, None, DEFAULT_SEARCH_ORDER)
+            .filter_map(|pk| state.may_load(storage, &pk).unwrap_or(None))
+            .filter(|ask_order| matches!(ask_order.collateral, AskCollateral::ScopeBundleTrade(_))),
+    );
+    ask_orders_for_collateral
+}
+
+/// Fetches up to the limit of ask orders that could be matched with the given bid, found through
+/// the indices of the bid's collateral.  Coin trades are found by the denom of the bid's first base
+/// coin, which every matching ask must include.  The second value is true when more asks remained
+/// after the limit was reached.
+pub fn get_ask_match_candidates(
+    storage: &dyn Storage,
+    bid_order: &BidOrder,
+    limit: usize,
+) -> (Vec<AskOrder>, bool) {
+    let state = ask_orders();
+    let candidates: Box<dyn Iterator<Item = AskOrder>> = match &bid_order.collateral {
+        BidCollateral::CoinTrade(collateral) => match collateral.base.first() {
+            Some(base_coin) => Box::new(
+                state
+                    .idx
+                    .base_denom_index
+                    .primary_keys(storage, &base_coin.denom, None, None, DEFAULT_SEARCH_ORDER)
+                    .filter_map(|pk| state.may_load(storage, &pk).unwrap_or(None)),
+            ),
+            None => Box::new(std::iter::empty()),
+        },
+        BidCollateral::MarkerTrade(collateral) => Box::new(
+            get_ask_orders_by_collateral_id(storage, collateral.marker_address.as_str())
+                .into_iter(),
+        ),
+        BidCollateral::MarkerShareSale(collateral) => Box::new(
+            get_ask_orders_by_collateral_id(storage, collateral.marker_address.as_str())
+                .into_iter(),
+        ),
+        BidCollateral::ScopeTrade(collateral) => Box::new(
+            get_ask_orders_by_collateral_id(storage, &collateral.scope_address).into_iter(),
+        ),
+        BidCollateral::ScopeBundleTrade(collateral) => Box::new(
+            collateral
+                .scope_addresses
+                .iter()
+                .flat_map(|scope_address| get_ask_orders_by_collateral_id(storage, scope_address)),
+        ),
+        BidCollateral::ScopeSpecTrade(collateral) => Box::new(
+            state
+                .idx
+                .scope_spec_index
+                .primary_keys(
+                    storage,
+                    &collateral.scope_spec_id,
+                    None,
+                    None,
+                    DEFAULT_SEARCH_ORDER,
+                )
+                .filter_map(|pk| state.may_load(storage, &pk).unwrap_or(None)),
+        ),
+    };
+    // A bundle ask is found once for each of its scopes that a bundle bid lists
+    take_distinct_orders(
+        candidates.filter(|ask_order| ask_order.ask_type == bid_order.bid_type),
+        limit,
+    )
+}
+
+/// Fetches up to the limit of ask orders with an expiration time at or before the given time,
+/// ordered from the earliest expiration to the latest.
+pub fn get_expired_ask_orders(
+    storage: &dyn Storage,
+    time: &Timestamp,
+    limit: usize,
+) -> Vec<AskOrder> {
+    ask_orders()
+        .idx
+        .expiration_index
+        .prefix_range_raw(
+            storage,
+            None,
+            Some(PrefixBound::inclusive(time.nanos())),
+            DEFAULT_SEARCH_ORDER,
+        )
+        .take(limit)
+        .filter(|result| result.is_ok())
+        .map(|result| result.unwrap().1)
         .collect()
 }
 
@@ -142,11 +326,29 @@ pub fn delete_ask_order_by_id<S: Into<String>>(
     ().to_ok()
 }
 
+/// Rewrites the index entries of every stored ask order.  Used during migrations to populate
+/// indices that were added after the orders were stored.
+pub fn reindex_ask_orders(storage: &mut dyn Storage) -> Result<(), ContractError> {
+    let state = ask_orders();
+    let orders = state
+        .range(storage, None, None, DEFAULT_SEARCH_ORDER)
+        .filter_map(|result| result.ok())
+        .map(|(_, order)| order)
+        .collect::<Vec<AskOrder>>();
+    // Counts are rebuilt from scratch, so no old values are removed before each order is saved
+    state.idx.count_index.clear(storage);
+    for order in orders.iter() {
+        state.replace(storage, order.id.as_bytes(), Some(order), None)?;
+    }
+    ().to_ok()
+}
+
 #[cfg(test)]
 mod tests {
     use crate::storage::ask_order_storage::{
         delete_ask_order_by_id, get_ask_order_by_id, get_ask_orders_by_collateral_id,
-        insert_ask_order, may_get_ask_order_by_id, update_ask_order,
+        insert_ask_order, may_get_ask_order_by_id, reindex_ask_orders, update_ask_order,
+        NAMESPACE_ASK_PK,
     };
     use crate::test::mock_marker::{DEFAULT_MARKER_ADDRESS, DEFAULT_MARKER_DENOM};
     use crate::types::request::ask_types::ask_collateral::AskCollateral;
@@ -154,6 +356,7 @@ mod tests {
     use crate::types::request::share_sale_type::ShareSaleType;
     use crate::util::constants::NHASH;
     use cosmwasm_std::{coins, Addr};
+    use cw_storage_plus::Map;
     use provwasm_mocks::mock_dependencies;
 
     #[test]
@@ -312,6 +515,55 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_get_ask_orders_by_collateral_id_for_scope_bundles() {
+        let mut deps = mock_dependencies(&[]);
+        let bundle_order = AskOrder::new_unchecked(
+            "bundle_ask",
+            Addr::unchecked("asker"),
+            AskCollateral::scope_bundle_trade(&["scope1", "scope2"], &coins(100, NHASH)),
+            None,
+        );
+        let scope_order = AskOrder::new_unchecked(
+            "scope_ask",
+            Addr::unchecked("asker"),
+            AskCollateral::scope_trade("scope3", &coins(100, NHASH)),
+            None,
+        );
+        // Save the orders without populating any indices to simulate orders stored before the
+        // indices existed
+        let raw_ask_orders: Map<&[u8], AskOrder> = Map::new(NAMESPACE_ASK_PK);
+        for order in [&bundle_order, &scope_order] {
+            raw_ask_orders
+                .save(deps.as_mut().storage, order.id.as_bytes(), order)
+                .expect("saving an unindexed ask order should succeed");
+        }
+        assert!(
+            get_ask_orders_by_collateral_id(deps.as_ref().storage, "scope1").is_empty(),
+            "sanity check: the bundle should not be found before the orders are reindexed",
+        );
+        reindex_ask_orders(deps.as_mut().storage).expect("reindexing ask orders should succeed");
+        for scope_address in ["scope1", "scope2"] {
+            assert_eq!(
+                vec![bundle_order.to_owned()],
+                get_ask_orders_by_collateral_id(deps.as_ref().storage, scope_address),
+                "the bundle ask should be found by its scope [{}] after reindexing",
+                scope_address,
+            );
+        }
+        assert_eq!(
+            vec![scope_order.to_owned()],
+            get_ask_orders_by_collateral_id(deps.as_ref().storage, "scope3"),
+            "the scope trade ask should be found exactly once by its scope",
+        );
+        delete_ask_order_by_id(deps.as_mut().storage, &bundle_order.id)
+            .expect("deleting the bundle ask should succeed");
+        assert!(
+            get_ask_orders_by_collateral_id(deps.as_ref().storage, "scope1").is_empty(),
+            "the bundle should no longer be found after it is deleted",
+        );
+    }
+
     #[test]
     fn test_delete_ask_order_by_id() {
         let mut deps = mock_dependencies(&[]);
@@ -1,21 +1,59 @@
+use crate::storage::multi_value_index::MultiValueIndex;
+use crate::storage::order_count_index::{
+    get_order_count_keys, OrderCountIndex, COUNT_KEY_COLLATERAL,
+};
 use crate::storage::order_indices::OrderIndices;
+use crate::storage::order_search_repository::{
+    get_page_size, take_distinct_orders, to_search_result,
+};
+use crate::types::core::constants::{DEFAULT_SEARCH_ORDER, DEFAULT_SEARCH_PAGE_NUMBER};
 use crate::types::core::error::ContractError;
+use crate::types::request::ask_types::ask_collateral::AskCollateral;
+use crate::types::request::ask_types::ask_order::AskOrder;
 use crate::types::request::bid_types::bid_order::BidOrder;
+use crate::types::request::search::SearchResult;
 use crate::util::extensions::ResultExtensions;
-use cosmwasm_std::Storage;
-use cw_storage_plus::{Index, IndexList, IndexedMap, MultiIndex};
+use cosmwasm_std::{Storage, Timestamp, Uint128};
+use cw_storage_plus::{Bound, Index, IndexList, IndexedMap, MultiIndex, PrefixBound};
 
 const NAMESPACE_BID_PK: &str = "bid";
+const NAMESPACE_BASE_DENOM_IDX: &str = "bid__base_denom";
+const NAMESPACE_COLLATERAL_IDX: &str = "bid__collateral";
+const NAMESPACE_COUNT_IDX: &str = "bid__count";
+const NAMESPACE_EXPIRATION_IDX: &str = "bid__expiration";
+const NAMESPACE_MARKER_DENOM_IDX: &str = "bid__marker_denom";
 const NAMESPACE_OWNER_IDX: &str = "bid__owner";
+const NAMESPACE_QUOTE_DENOM_IDX: &str = "bid__quote_denom";
+const NAMESPACE_SCOPE_ADDRESS_IDX: &str = "bid__scope_address";
+const NAMESPACE_SCOPE_SPEC_IDX: &str = "bid__scope_spec";
 const NAMESPACE_TYPE_IDX: &str = "bid__type";
 
 pub struct BidOrderIndices<'a> {
+    pub collateral_index: MultiValueIndex<'a, BidOrder>,
+    pub expiration_index: MultiIndex<'a, u64, BidOrder, String>,
     pub owner_index: MultiIndex<'a, String, BidOrder, String>,
     pub type_index: MultiIndex<'a, String, BidOrder, String>,
+    pub marker_denom_index: MultiValueIndex<'a, BidOrder>,
+    pub base_denom_index: MultiValueIndex<'a, BidOrder>,
+    pub scope_address_index: MultiValueIndex<'a, BidOrder>,
+    pub scope_spec_index: MultiValueIndex<'a, BidOrder>,
+    pub quote_denom_index: MultiValueIndex<'a, BidOrder>,
+    pub count_index: OrderCountIndex<'a, BidOrder>,
 }
 impl<'a> IndexList<BidOrder> for BidOrderIndices<'a> {
     fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<BidOrder>> + '_> {
-        let v: Vec<&dyn Index<BidOrder>> = vec![&self.owner_index, &self.type_index];
+        let v: Vec<&dyn Index<BidOrder>> = vec![
+            &self.collateral_index,
+            &self.expiration_index,
+            &self.owner_index,
+            &self.type_index,
+            &self.marker_denom_index,
+            &self.base_denom_index,
+            &self.scope_address_index,
+            &self.scope_spec_index,
+            &self.quote_denom_index,
+            &self.count_index,
+        ];
         Box::new(v.into_iter())
     }
 }
@@ -27,10 +65,39 @@ impl<'a> OrderIndices<'a, BidOrder> for BidOrderIndices<'a> {
     fn type_index(&self) -> &MultiIndex<'a, String, BidOrder, String> {
         &self.type_index
     }
+
+    fn marker_denom_index(&self) -> &MultiValueIndex<'a, BidOrder> {
+        &self.marker_denom_index
+    }
+
+    fn scope_address_index(&self) -> &MultiValueIndex<'a, BidOrder> {
+        &self.scope_address_index
+    }
+
+    fn scope_spec_index(&self) -> &MultiValueIndex<'a, BidOrder> {
+        &self.scope_spec_index
+    }
+
+    fn quote_denom_index(&self) -> &MultiValueIndex<'a, BidOrder> {
+        &self.quote_denom_index
+    }
+
+    fn count_index(&self) -> &OrderCountIndex<'a, BidOrder> {
+        &self.count_index
+    }
 }
 
 pub fn bid_orders<'a>() -> IndexedMap<'a, &'a [u8], BidOrder, BidOrderIndices<'a>> {
     let indices = BidOrderIndices {
+        collateral_index: MultiValueIndex::new(
+            |bid: &BidOrder| bid.get_collateral_index(),
+            NAMESPACE_COLLATERAL_IDX,
+        ),
+        expiration_index: MultiIndex::new(
+            |bid: &BidOrder| bid.get_expiration_index(),
+            NAMESPACE_BID_PK,
+            NAMESPACE_EXPIRATION_IDX,
+        ),
         owner_index: MultiIndex::new(
             |bid: &BidOrder| bid.owner.clone().to_string(),
             NAMESPACE_BID_PK,
@@ -41,6 +108,45 @@ pub fn bid_orders<'a>() -> IndexedMap<'a, &'a [u8], BidOrder, BidOrderIndices<'a
             NAMESPACE_BID_PK,
             NAMESPACE_TYPE_IDX,
         ),
+        marker_denom_index: MultiValueIndex::new(
+            |bid: &BidOrder| bid.get_marker_denom_index(),
+            NAMESPACE_MARKER_DENOM_IDX,
+        ),
+        base_denom_index: MultiValueIndex::new(
+            |bid: &BidOrder| bid.get_base_denom_index(),
+            NAMESPACE_BASE_DENOM_IDX,
+        ),
+        scope_address_index: MultiValueIndex::new(
+            |bid: &BidOrder| bid.get_scope_address_index(),
+            NAMESPACE_SCOPE_ADDRESS_IDX,
+        ),
+        scope_spec_index: MultiValueIndex::new(
+            |bid: &BidOrder| bid.get_scope_spec_index(),
+            NAMESPACE_SCOPE_SPEC_IDX,
+        ),
+        quote_denom_index: MultiValueIndex::new(
+            |bid: &BidOrder| bid.get_quote_denom_index(),
+            NAMESPACE_QUOTE_DENOM_IDX,
+        ),
+        count_index: OrderCountIndex::new(
+            |bid: &BidOrder| {
+                let mut keys = get_order_count_keys(
+                    &bid.owner,
+                    bid.bid_type.get_name(),
+                    bid.get_marker_denom_index(),
+                    bid.get_scope_address_index(),
+                    bid.get_scope_spec_index(),
+                    bid.get_quote_denom_index(),
+                );
+                keys.extend(
+                    bid.get_collateral_index()
+                        .into_iter()
+                        .map(|key| (COUNT_KEY_COLLATERAL, key)),
+                );
+                keys
+            },
+            NAMESPACE_COUNT_IDX,
+        ),
     };
     IndexedMap::new(NAMESPACE_BID_PK, indices)
 }
@@ -106,6 +212,123 @@ pub fn get_bid_order_by_id<S: Into<String>>(
         })
 }
 
+/// Fetches a page of the bid orders that target the given marker address, marker denom, or scope
+/// address, in order of their ids.  When start_after is provided, the page begins with the first
+/// bid after the bid with that id.
+pub fn get_bid_orders_by_collateral_id<S: Into<String>>(
+    storage: &dyn Storage,
+    collateral_id: S,
+    page_size: &Option<Uint128>,
+    start_after: Option<String>,
+) -> SearchResult<BidOrder> {
+    let collateral_id = collateral_id.into();
+    let page_size = get_page_size(page_size);
+    let state = bid_orders();
+    let results = state
+        .idx
+        .collateral_index
+        .primary_keys(
+            storage,
+            &collateral_id,
+            start_after.map(|id| Bound::ExclusiveRaw(id.into_bytes())),
+            None,
+            DEFAULT_SEARCH_ORDER,
+        )
+        .filter_map(|pk| state.may_load(storage, &pk).unwrap_or(None))
+        .take(page_size)
+        .collect::<Vec<BidOrder>>();
+    let next_start_after = if results.len() == page_size {
+        results.last().map(|bid_order| bid_order.id.to_owned())
+    } else {
+        None
+    };
+    let total_results = state
+        .idx
+        .count_index
+        .count(storage, COUNT_KEY_COLLATERAL, &collateral_id);
+    let mut result = to_search_result(
+        results,
+        total_results,
+        page_size,
+        DEFAULT_SEARCH_PAGE_NUMBER,
+    );
+    result.next_start_after = next_start_after;
+    result
+}
+
+/// Fetches up to the limit of bid orders that could be matched with the given ask, found through
+/// the indices of the ask's collateral.  Coin trades are found by the denom of the ask's first base
+/// coin, which every matching bid must include.  The second value is true when more bids remained
+/// after the limit was reached.
+pub fn get_bid_match_candidates(
+    storage: &dyn Storage,
+    ask_order: &AskOrder,
+    limit: usize,
+) -> (Vec<BidOrder>, bool) {
+    let state = bid_orders();
+    let load_keys = |index: &MultiValueIndex<'static, BidOrder>, key: &str| {
+        index.primary_keys(storage, key, None, None, DEFAULT_SEARCH_ORDER)
+    };
+    let primary_keys: Box<dyn Iterator<Item = Vec<u8>>> = match &ask_order.collateral {
+        AskCollateral::CoinTrade(collateral) => match collateral.base.first() {
+            Some(base_coin) => load_keys(&state.idx.base_denom_index, &base_coin.denom),
+            None => Box::new(std::iter::empty()),
+        },
+        AskCollateral::MarkerTrade(collateral) => load_keys(
+            &state.idx.collateral_index,
+            collateral.marker_address.as_str(),
+        ),
+        AskCollateral::MarkerShareSale(collateral) => load_keys(
+            &state.idx.collateral_index,
+            collateral.marker_address.as_str(),
+        ),
+        AskCollateral::ScopeTrade(collateral) => {
+            let keys = load_keys(&state.idx.collateral_index, &collateral.scope_address);
+            match &collateral.scope_spec_id {
+                Some(scope_spec_id) => {
+                    Box::new(keys.chain(load_keys(&state.idx.scope_spec_index, scope_spec_id)))
+                }
+                None => keys,
+            }
+        }
+        AskCollateral::ScopeBundleTrade(collateral) => Box::new(
+            collateral
+                .scope_addresses
+                .iter()
+                .flat_map(|scope_address| load_keys(&state.idx.collateral_index, scope_address)),
+        ),
+    };
+    // A bundle bid is found once for each of its scopes that a bundle ask holds
+    take_distinct_orders(
+        primary_keys
+            .filter_map(|pk| state.may_load(storage, &pk).unwrap_or(None))
+            .filter(|bid_order| bid_order.bid_type == ask_order.ask_type),
+        limit,
+    )
+}
+
+/// Fetches up to the limit of bid orders with an expiration time at or before the given time,
+/// ordered from the earliest expiration to the latest.
+pub fn get_expired_bid_orders(
+    storage: &dyn Storage,
+    time: &Timestamp,
+    limit: usize,
+) -> Vec<BidOrder> {
+    bid_orders()
+        .idx
+        .expiration_index
+        .prefix_range_raw(
+            storage,
+            None,
+            Some(PrefixBound::inclusive(time.nanos())),
+            DEFAULT_SEARCH_ORDER,
+        )
+        .take(limit)
+        .filter(|result| result.is_ok())
+        .map(|result| result.unwrap().1)
+        .collect()
+}
+
 pub fn delete_bid_order_by_id<S: Into<String>>(
     storage: &mut dyn Storage,
     id: S,
@@ -119,6 +342,23 @@ pub fn delete_bid_order_by_id<S: Into<String>>(
     ().to_ok()
 }
 
+/// Rewrites the index entries of every stored bid order.  Used during migrations to populate
+/// indices that were added after the orders were stored.
+pub fn reindex_bid_orders(storage: &mut dyn Storage) -> Result<(), ContractError> {
+    let state = bid_orders();
+    let orders = state
+        .range(storage, None, None, DEFAULT_SEARCH_ORDER)
+        .filter_map(|result| result.ok())
+        .map(|(_, order)| order)
+        .collect::<Vec<BidOrder>>();
+    // Counts are rebuilt from scratch, so no old values are removed before each order is saved
+    state.idx.count_index.clear(storage);
+    for order in orders.iter() {
+        state.replace(storage, order.id.as_bytes(), Some(order), None)?;
+    }
+    ().to_ok()
+}
+
 #[cfg(test)]
 mod tests {
     use crate::storage::ask_order_storage::may_get_ask_order_by_id;
@@ -3,7 +3,11 @@ use cw_storage_plus::Item;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::types::core::contract_status::ContractStatus;
 use crate::types::core::error::ContractError;
+use crate::types::request::request_descriptor::AttributeRequirement;
+use crate::types::request::request_type::RequestType;
+use crate::types::request::settlement_fees::SettlementFees;
 
 const NAMESPACE_CONTRACT_INFO_V2: &str = "contract_info_v2";
 pub const CONTRACT_TYPE: &str = env!("CARGO_CRATE_NAME");
@@ -20,6 +24,16 @@ pub struct ContractInfoV2 {
     pub contract_version: String,
     pub create_ask_nhash_fee: Uint128,
     pub create_bid_nhash_fee: Uint128,
+    pub settlement_fees: Option<SettlementFees>,
+    /// The account that receives settlement fees.  When omitted, the admin receives them.
+    pub fee_collector_address: Option<Addr>,
+    /// Restricts the operations that the contract accepts.  When omitted, the contract is active.
+    pub contract_status: Option<ContractStatus>,
+    /// The account proposed to become the new admin.  The admin is only replaced after this
+    /// account accepts the proposal.
+    pub pending_admin: Option<Addr>,
+    /// Attribute requirements that every account must meet to create asks and bids.
+    pub required_trade_attributes: Option<AttributeRequirement>,
 }
 impl ContractInfoV2 {
     pub fn new<S1: Into<String>, S2: Into<String>>(
@@ -28,6 +42,8 @@ impl ContractInfoV2 {
         contract_name: S2,
         create_ask_nhash_fee: Option<Uint128>,
         create_bid_nhash_fee: Option<Uint128>,
+        settlement_fees: Option<SettlementFees>,
+        fee_collector_address: Option<Addr>,
     ) -> Self {
         Self {
             admin,
@@ -37,8 +53,30 @@ impl ContractInfoV2 {
             contract_version: CONTRACT_VERSION.to_string(),
             create_ask_nhash_fee: create_ask_nhash_fee.unwrap_or_else(Uint128::zero),
             create_bid_nhash_fee: create_bid_nhash_fee.unwrap_or_else(Uint128::zero),
+            settlement_fees,
+            fee_collector_address,
+            contract_status: None,
+            pending_admin: None,
+            required_trade_attributes: None,
         }
     }
+
+    pub fn get_settlement_fee_bps(&self, request_type: &RequestType) -> Uint128 {
+        self.settlement_fees
+            .as_ref()
+            .map(|fees| fees.get_fee_bps(request_type))
+            .unwrap_or_else(Uint128::zero)
+    }
+
+    pub fn get_fee_collector_address(&self) -> &Addr {
+        self.fee_collector_address.as_ref().unwrap_or(&self.admin)
+    }
+
+    pub fn get_contract_status(&self) -> ContractStatus {
+        self.contract_status
+            .to_owned()
+            .unwrap_or(ContractStatus::Active)
+    }
 }
 
 pub fn set_contract_info(
@@ -89,6 +127,8 @@ mod tests {
                 DEFAULT_CONTRACT_NAME.to_string(),
                 Some(Uint128::new(100)),
                 None,
+                None,
+                None,
             ),
         );
         match result {
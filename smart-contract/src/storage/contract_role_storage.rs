@@ -0,0 +1,181 @@
+use crate::storage::contract_info::ContractInfoV2;
+use crate::types::core::contract_role::ContractRole;
+use crate::types::core::error::ContractError;
+use crate::util::extensions::ResultExtensions;
+use cosmwasm_std::{Addr, Storage};
+use cw_storage_plus::Map;
+
+const NAMESPACE_CONTRACT_ROLES: &str = "contract_roles";
+
+// The fee collector role is stored in the contract info because only one account may hold it, so
+// this map only ever contains the remaining roles
+const CONTRACT_ROLES: Map<&Addr, Vec<ContractRole>> = Map::new(NAMESPACE_CONTRACT_ROLES);
+
+/// Determines if the address holds the given role.  The contract admin always holds every role.
+pub fn address_has_role(
+    storage: &dyn Storage,
+    contract_info: &ContractInfoV2,
+    address: &Addr,
+    role: &ContractRole,
+) -> bool {
+    get_roles_for_address(storage, contract_info, address).contains(role)
+}
+
+/// Fetches every role held by the address, including those implicitly held by the contract admin.
+pub fn get_roles_for_address(
+    storage: &dyn Storage,
+    contract_info: &ContractInfoV2,
+    address: &Addr,
+) -> Vec<ContractRole> {
+    if address == &contract_info.admin {
+        return vec![
+            ContractRole::SettingsAdmin,
+            ContractRole::Matcher,
+            ContractRole::OrderCanceller,
+            ContractRole::FeeCollector,
+        ];
+    }
+    let mut roles = CONTRACT_ROLES
+        .may_load(storage, address)
+        .unwrap_or(None)
+        .unwrap_or_default();
+    if contract_info.fee_collector_address.as_ref() == Some(address) {
+        roles.push(ContractRole::FeeCollector);
+    }
+    roles
+}
+
+/// Adds a role to the address's stored roles.  The fee collector role must be set on the contract
+/// info instead.
+pub fn grant_stored_role(
+    storage: &mut dyn Storage,
+    address: &Addr,
+    role: &ContractRole,
+) -> Result<(), ContractError> {
+    let mut roles = CONTRACT_ROLES
+        .may_load(storage, address)?
+        .unwrap_or_default();
+    if !roles.contains(role) {
+        roles.push(role.to_owned());
+    }
+    CONTRACT_ROLES
+        .save(storage, address, &roles)
+        .map_err(|e| ContractError::StorageError {
+            message: format!("{:?}", e),
+        })
+}
+
+/// Removes a role from the address's stored roles, deleting the address's entry entirely when it
+/// no longer holds any roles.
+pub fn revoke_stored_role(
+    storage: &mut dyn Storage,
+    address: &Addr,
+    role: &ContractRole,
+) -> Result<(), ContractError> {
+    let roles = CONTRACT_ROLES
+        .may_load(storage, address)?
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|existing_role| existing_role != role)
+        .collect::<Vec<ContractRole>>();
+    if roles.is_empty() {
+        CONTRACT_ROLES.remove(storage, address);
+        ().to_ok()
+    } else {
+        CONTRACT_ROLES
+            .save(storage, address, &roles)
+            .map_err(|e| ContractError::StorageError {
+                message: format!("{:?}", e),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::storage::contract_info::get_contract_info;
+    use crate::storage::contract_role_storage::{
+        address_has_role, get_roles_for_address, grant_stored_role, revoke_stored_role,
+    };
+    use crate::test::mock_instantiate::{default_instantiate, DEFAULT_ADMIN_ADDRESS};
+    use crate::types::core::contract_role::ContractRole;
+    use cosmwasm_std::Addr;
+    use provwasm_mocks::mock_dependencies;
+
+    #[test]
+    fn test_admin_holds_all_roles() {
+        let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
+        let contract_info =
+            get_contract_info(deps.as_ref().storage).expect("contract info should load");
+        assert_eq!(
+            4,
+            get_roles_for_address(
+                deps.as_ref().storage,
+                &contract_info,
+                &Addr::unchecked(DEFAULT_ADMIN_ADDRESS),
+            )
+            .len(),
+            "the admin should implicitly hold every role",
+        );
+    }
+
+    #[test]
+    fn test_grant_and_revoke_stored_roles() {
+        let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
+        let address = Addr::unchecked("match_bot");
+        grant_stored_role(deps.as_mut().storage, &address, &ContractRole::Matcher)
+            .expect("the matcher role should be granted");
+        grant_stored_role(deps.as_mut().storage, &address, &ContractRole::Matcher)
+            .expect("granting a held role should succeed");
+        grant_stored_role(
+            deps.as_mut().storage,
+            &address,
+            &ContractRole::OrderCanceller,
+        )
+        .expect("the order canceller role should be granted");
+        let mut contract_info =
+            get_contract_info(deps.as_ref().storage).expect("contract info should load");
+        assert_eq!(
+            vec![ContractRole::Matcher, ContractRole::OrderCanceller],
+            get_roles_for_address(deps.as_ref().storage, &contract_info, &address),
+            "each granted role should be held once",
+        );
+        contract_info.fee_collector_address = Some(address.to_owned());
+        assert!(
+            address_has_role(
+                deps.as_ref().storage,
+                &contract_info,
+                &address,
+                &ContractRole::FeeCollector,
+            ),
+            "the fee collector role should be derived from the contract info",
+        );
+        revoke_stored_role(deps.as_mut().storage, &address, &ContractRole::Matcher)
+            .expect("the matcher role should be revoked");
+        revoke_stored_role(
+            deps.as_mut().storage,
+            &address,
+            &ContractRole::OrderCanceller,
+        )
+        .expect("the order canceller role should be revoked");
+        assert!(
+            !address_has_role(
+                deps.as_ref().storage,
+                &contract_info,
+                &address,
+                &ContractRole::Matcher,
+            ),
+            "the revoked role should no longer be held",
+        );
+        assert!(
+            !address_has_role(
+                deps.as_ref().storage,
+                &contract_info,
+                &address,
+                &ContractRole::SettingsAdmin,
+            ),
+            "roles that were never granted should not be held",
+        );
+    }
+}
@@ -0,0 +1,454 @@
+use crate::storage::order_count_index::{
+    OrderCountIndex, COUNT_KEY_ALL, COUNT_KEY_MARKER_DENOM, COUNT_KEY_OWNER,
+    COUNT_KEY_SCOPE_ADDRESS,
+};
+use crate::storage::order_search_repository::{get_page_number, get_page_size, to_search_result};
+use crate::types::core::error::ContractError;
+use crate::types::request::match_record::MatchRecord;
+use crate::types::request::search::{MatchSearch, MatchSearchType, SearchResult};
+use crate::util::extensions::ResultExtensions;
+use cosmwasm_std::{Order, Storage, Uint128};
+use cw_storage_plus::{Bound, Index, IndexList, IndexedMap, Item, MultiIndex};
+
+const NAMESPACE_MATCH_RECORD_PK: &str = "match_record";
+const NAMESPACE_MATCH_RECORD_SEQUENCE: &str = "match_record__sequence";
+const NAMESPACE_ASKER_IDX: &str = "match_record__asker";
+const NAMESPACE_BIDDER_IDX: &str = "match_record__bidder";
+const NAMESPACE_MARKER_DENOM_IDX: &str = "match_record__marker_denom";
+const NAMESPACE_SCOPE_ADDRESS_IDX: &str = "match_record__scope_address";
+const NAMESPACE_COUNT_IDX: &str = "match_record__count";
+
+// Match records are keyed by a sequential id, so iterating in descending order always produces the
+// most recent records first
+const MATCH_RECORD_SEARCH_ORDER: Order = Order::Descending;
+const MATCH_RECORD_SEQUENCE: Item<u64> = Item::new(NAMESPACE_MATCH_RECORD_SEQUENCE);
+
+pub struct MatchRecordIndices<'a> {
+    pub asker_index: MultiIndex<'a, String, MatchRecord, u64>,
+    pub bidder_index: MultiIndex<'a, String, MatchRecord, u64>,
+    pub marker_denom_index: MultiIndex<'a, String, MatchRecord, u64>,
+    pub scope_address_index: MultiIndex<'a, String, MatchRecord, u64>,
+    pub count_index: OrderCountIndex<'a, MatchRecord>,
+}
+impl<'a> IndexList<MatchRecord> for MatchRecordIndices<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<MatchRecord>> + '_> {
+        let v: Vec<&dyn Index<MatchRecord>> = vec![
+            &self.asker_index,
+            &self.bidder_index,
+            &self.marker_denom_index,
+            &self.scope_address_index,
+            &self.count_index,
+        ];
+        Box::new(v.into_iter())
+    }
+}
+
+pub fn match_records<'a>() -> IndexedMap<'a, u64, MatchRecord, MatchRecordIndices<'a>> {
+    let indices = MatchRecordIndices {
+        asker_index: MultiIndex::new(
+            |record: &MatchRecord| record.asker.to_string(),
+            NAMESPACE_MATCH_RECORD_PK,
+            NAMESPACE_ASKER_IDX,
+        ),
+        bidder_index: MultiIndex::new(
+            |record: &MatchRecord| record.bidder.to_string(),
+            NAMESPACE_MATCH_RECORD_PK,
+            NAMESPACE_BIDDER_IDX,
+        ),
+        marker_denom_index: MultiIndex::new(
+            |record: &MatchRecord| record.get_marker_denom_index(),
+            NAMESPACE_MATCH_RECORD_PK,
+            NAMESPACE_MARKER_DENOM_IDX,
+        ),
+        scope_address_index: MultiIndex::new(
+            |record: &MatchRecord| record.get_scope_address_index(),
+            NAMESPACE_MATCH_RECORD_PK,
+            NAMESPACE_SCOPE_ADDRESS_IDX,
+        ),
+        count_index: OrderCountIndex::new(get_match_record_count_keys, NAMESPACE_COUNT_IDX),
+    };
+    IndexedMap::new(NAMESPACE_MATCH_RECORD_PK, indices)
+}
+
+/// Produces a count key for each search that can find the record.  A record whose asker and
+/// bidder are the same account produces a single owner key, so it is only counted once.
+fn get_match_record_count_keys(record: &MatchRecord) -> Vec<(&'static str, String)> {
+    let mut keys = vec![
+        (COUNT_KEY_ALL, String::new()),
+        (COUNT_KEY_OWNER, record.asker.to_string()),
+        (COUNT_KEY_OWNER, record.bidder.to_string()),
+        (COUNT_KEY_MARKER_DENOM, record.get_marker_denom_index()),
+        (COUNT_KEY_SCOPE_ADDRESS, record.get_scope_address_index()),
+    ];
+    // Records that do not pertain to an index use an empty value, which is never searched for
+    keys.retain(|(kind, key)| *kind == COUNT_KEY_ALL || !key.is_empty());
+    keys
+}
+
+/// Stores a new match record, assigning it the next available sequential id.  The id value on the
+/// provided record is ignored.
+pub fn insert_match_record(
+    storage: &mut dyn Storage,
+    record: &MatchRecord,
+) -> Result<MatchRecord, ContractError> {
+    let id = MATCH_RECORD_SEQUENCE.may_load(storage)?.unwrap_or(0) + 1;
+    MATCH_RECORD_SEQUENCE.save(storage, &id)?;
+    let mut record = record.to_owned();
+    record.id = Uint128::new(id as u128);
+    match_records().save(storage, id, &record)?;
+    record.to_ok()
+}
+
+pub fn get_match_record_by_id(
+    storage: &dyn Storage,
+    id: u64,
+) -> Result<MatchRecord, ContractError> {
+    match_records()
+        .load(storage, id)
+        .map_err(|e| ContractError::StorageError {
+            message: format!("failed to find MatchRecord by id [{}]: {:?}", id, e),
+        })
+}
+
+/// Ranges over the ids of the records that match the search type from the most to least recent,
+/// beginning after the start_after id when one is provided.  Only a single page of records is
+/// loaded, and the total is read from the stored counts.
+pub fn search_match_records(
+    storage: &dyn Storage,
+    search: &MatchSearch,
+) -> SearchResult<MatchRecord> {
+    let page_size = get_page_size(&search.page_size);
+    let page_number = get_page_number(&search.page_number);
+    // A cursor replaces the page number, so no records need to be skipped
+    let skip = if search.start_after.is_some() {
+        0
+    } else {
+        page_size * (page_number - 1)
+    };
+    let records = match_records();
+    let results = get_match_record_ids(storage, &search.search_type, search.start_after)
+        .skip(skip)
+        .take(page_size)
+        .filter_map(|id| records.load(storage, id).ok())
+        .collect::<Vec<MatchRecord>>();
+    let (count_kind, count_key) = match &search.search_type {
+        MatchSearchType::All => (COUNT_KEY_ALL, ""),
+        MatchSearchType::Owner { owner } => (COUNT_KEY_OWNER, owner.as_str()),
+        MatchSearchType::MarkerDenom { marker_denom } => {
+            (COUNT_KEY_MARKER_DENOM, marker_denom.as_str())
+        }
+        MatchSearchType::ScopeAddress { scope_address } => {
+            (COUNT_KEY_SCOPE_ADDRESS, scope_address.as_str())
+        }
+    };
+    let total_results = records
+        .idx
+        .count_index
+        .count(storage, count_kind, count_key);
+    let next_start_after = if results.len() == page_size {
+        results.last().map(|record| record.id.to_string())
+    } else {
+        None
+    };
+    SearchResult {
+        next_start_after,
+        ..to_search_result(results, total_results, page_size, page_number)
+    }
+}
+
+/// Lazily produces the ids of the records that match the search type, from the most to least
+/// recent.  Only keys are read here, deferring the loading of full records until the requested
+/// page is known.
+fn get_match_record_ids<'a>(
+    storage: &'a dyn Storage,
+    search_type: &MatchSearchType,
+    start_after: Option<Uint128>,
+) -> Box<dyn Iterator<Item = u64> + 'a> {
+    let max = || start_after.map(|id| Bound::exclusive(id.u128() as u64));
+    let index_ids = |index: &MultiIndex<String, MatchRecord, u64>,
+                     value: &str|
+     -> Box<dyn Iterator<Item = u64> + 'a> {
+        // Records that do not pertain to an index are keyed by an empty string, so an empty search
+        // value should never produce results
+        if value.is_empty() {
+            return Box::new(std::iter::empty());
+        }
+        Box::new(
+            index
+                .prefix(value.to_string())
+                .keys(storage, None, max(), MATCH_RECORD_SEARCH_ORDER)
+                .filter_map(|result| result.ok()),
+        )
+    };
+    let records = match_records();
+    match search_type {
+        MatchSearchType::All => Box::new(
+            records
+                .keys(storage, None, max(), MATCH_RECORD_SEARCH_ORDER)
+                .filter_map(|result| result.ok()),
+        ),
+        MatchSearchType::Owner { owner } => {
+            // An owner may be the asker or bidder in a match, so the results of both indices are
+            // merged
+            merge_descending_ids(
+                index_ids(&records.idx.asker_index, owner),
+                index_ids(&records.idx.bidder_index, owner),
+            )
+        }
+        MatchSearchType::MarkerDenom { marker_denom } => {
+            index_ids(&records.idx.marker_denom_index, marker_denom)
+        }
+        MatchSearchType::ScopeAddress { scope_address } => {
+            index_ids(&records.idx.scope_address_index, scope_address)
+        }
+    }
+}
+
+/// Merges two descending id iterators into one, producing an id found in both only once, which
+/// occurs when an account matches with itself.
+fn merge_descending_ids<'a>(
+    first: Box<dyn Iterator<Item = u64> + 'a>,
+    second: Box<dyn Iterator<Item = u64> + 'a>,
+) -> Box<dyn Iterator<Item = u64> + 'a> {
+    let mut first = first.peekable();
+    let mut second = second.peekable();
+    Box::new(std::iter::from_fn(move || {
+        match (first.peek().copied(), second.peek().copied()) {
+            (Some(first_id), Some(second_id)) if first_id == second_id => {
+                second.next();
+                first.next()
+            }
+            (Some(first_id), Some(second_id)) if first_id < second_id => second.next(),
+            (Some(_), _) => first.next(),
+            (None, _) => second.next(),
+        }
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::storage::match_record_storage::{
+        get_match_record_by_id, insert_match_record, search_match_records,
+    };
+    use crate::test::request_helpers::mock_match_record;
+    use crate::types::request::match_record::{MatchRecord, MatchedCollateral};
+    use crate::types::request::search::{MatchSearch, MatchSearchType};
+    use cosmwasm_std::{coins, Addr, Storage, Uint128};
+    use provwasm_mocks::mock_dependencies;
+
+    #[test]
+    fn test_insert_assigns_sequential_ids() {
+        let mut deps = mock_dependencies(&[]);
+        let first = insert_match_record(deps.as_mut().storage, &mock_match_record())
+            .expect("the first record should be inserted");
+        let second = insert_match_record(deps.as_mut().storage, &mock_match_record())
+            .expect("the second record should be inserted");
+        assert_eq!(1, first.id.u128(), "the first record should receive id 1");
+        assert_eq!(2, second.id.u128(), "the second record should receive id 2");
+        assert_eq!(
+            second,
+            get_match_record_by_id(deps.as_ref().storage, 2)
+                .expect("the second record should be loadable by id"),
+            "the stored record should equal the returned record",
+        );
+        get_match_record_by_id(deps.as_ref().storage, 3)
+            .expect_err("an error should occur when loading a missing record");
+    }
+
+    #[test]
+    fn test_search_all_returns_most_recent_first() {
+        let mut deps = mock_dependencies(&[]);
+        for _ in 0..12 {
+            insert_match_record(deps.as_mut().storage, &mock_match_record())
+                .expect("the record should be inserted");
+        }
+        let first_page = search_match_records(
+            deps.as_ref().storage,
+            &MatchSearch::new(MatchSearchType::All, Some(10), None),
+        );
+        assert_eq!(
+            (3..=12).rev().collect::<Vec<u128>>(),
+            record_ids(&first_page.results),
+            "the first page should contain the ten most recent records",
+        );
+        assert_eq!(
+            2,
+            first_page.total_pages.u128(),
+            "the total pages should account for the remainder",
+        );
+        let second_page = search_match_records(
+            deps.as_ref().storage,
+            &MatchSearch::new(MatchSearchType::All, Some(10), Some(2)),
+        );
+        assert_eq!(
+            vec![2, 1],
+            record_ids(&second_page.results),
+            "the second page should contain the oldest records",
+        );
+    }
+
+    #[test]
+    fn test_search_by_owner_includes_asker_and_bidder() {
+        let mut deps = mock_dependencies(&[]);
+        insert_with_owners(deps.as_mut().storage, "alice", "bob");
+        insert_with_owners(deps.as_mut().storage, "carol", "alice");
+        insert_with_owners(deps.as_mut().storage, "alice", "alice");
+        insert_with_owners(deps.as_mut().storage, "bob", "carol");
+        let page = search_match_records(
+            deps.as_ref().storage,
+            &MatchSearch::new(
+                MatchSearchType::Owner {
+                    owner: "alice".to_string(),
+                },
+                None,
+                None,
+            ),
+        );
+        assert_eq!(
+            vec![3, 2, 1],
+            record_ids(&page.results),
+            "all matches including the owner on either side should be returned once each",
+        );
+    }
+
+    #[test]
+    fn test_search_by_owner_with_cursor() {
+        let mut deps = mock_dependencies(&[]);
+        insert_with_owners(deps.as_mut().storage, "alice", "bob");
+        insert_with_owners(deps.as_mut().storage, "carol", "alice");
+        insert_with_owners(deps.as_mut().storage, "alice", "alice");
+        insert_with_owners(deps.as_mut().storage, "bob", "carol");
+        insert_with_owners(deps.as_mut().storage, "bob", "alice");
+        let search = MatchSearch::new(
+            MatchSearchType::Owner {
+                owner: "alice".to_string(),
+            },
+            Some(2),
+            None,
+        );
+        let first_page = search_match_records(deps.as_ref().storage, &search);
+        assert_eq!(
+            vec![5, 3],
+            record_ids(&first_page.results),
+            "the first page should contain the owner's two most recent matches",
+        );
+        assert_eq!(
+            2,
+            first_page.total_pages.u128(),
+            "the total should count a match with the owner on both sides only once",
+        );
+        let second_page = search_match_records(
+            deps.as_ref().storage,
+            &search.clone().start_after(
+                first_page
+                    .next_start_after
+                    .expect("a full page should include a cursor")
+                    .parse::<u128>()
+                    .expect("the cursor should be a record id"),
+            ),
+        );
+        assert_eq!(
+            vec![2, 1],
+            record_ids(&second_page.results),
+            "the second page should continue after the cursor",
+        );
+        let last_page = search_match_records(deps.as_ref().storage, &search.start_after(1));
+        assert!(
+            last_page.results.is_empty(),
+            "no records should exist after the oldest record",
+        );
+        assert_eq!(
+            None, last_page.next_start_after,
+            "a page that is not filled should not include a cursor",
+        );
+    }
+
+    #[test]
+    fn test_search_by_marker_denom_and_scope_address() {
+        let mut deps = mock_dependencies(&[]);
+        insert_with_collateral(
+            deps.as_mut().storage,
+            MatchedCollateral::MarkerTrade {
+                marker_address: Addr::unchecked("marker"),
+                marker_denom: "markerdenom".to_string(),
+                share_count: Uint128::new(10),
+            },
+        );
+        insert_with_collateral(
+            deps.as_mut().storage,
+            MatchedCollateral::ScopeTrade {
+                scope_address: "scope".to_string(),
+            },
+        );
+        insert_with_collateral(
+            deps.as_mut().storage,
+            MatchedCollateral::MarkerShareSale {
+                marker_address: Addr::unchecked("marker"),
+                marker_denom: "markerdenom".to_string(),
+                shares_purchased: Uint128::new(5),
+            },
+        );
+        let marker_page = search_match_records(
+            deps.as_ref().storage,
+            &MatchSearch::new(
+                MatchSearchType::MarkerDenom {
+                    marker_denom: "markerdenom".to_string(),
+                },
+                None,
+                None,
+            ),
+        );
+        assert_eq!(
+            vec![3, 1],
+            record_ids(&marker_page.results),
+            "both marker matches should be found by denom",
+        );
+        let scope_page = search_match_records(
+            deps.as_ref().storage,
+            &MatchSearch::new(
+                MatchSearchType::ScopeAddress {
+                    scope_address: "scope".to_string(),
+                },
+                None,
+                None,
+            ),
+        );
+        assert_eq!(
+            vec![2],
+            record_ids(&scope_page.results),
+            "the scope match should be found by address",
+        );
+        let empty_page = search_match_records(
+            deps.as_ref().storage,
+            &MatchSearch::new(
+                MatchSearchType::ScopeAddress {
+                    scope_address: String::new(),
+                },
+                None,
+                None,
+            ),
+        );
+        assert!(
+            empty_page.results.is_empty(),
+            "an empty search value should never match records that lack the indexed value",
+        );
+    }
+
+    fn insert_with_owners(storage: &mut dyn Storage, asker: &str, bidder: &str) {
+        let mut record = mock_match_record();
+        record.asker = Addr::unchecked(asker);
+        record.bidder = Addr::unchecked(bidder);
+        insert_match_record(storage, &record).expect("the record should be inserted");
+    }
+
+    fn insert_with_collateral(storage: &mut dyn Storage, collateral: MatchedCollateral) {
+        let mut record = mock_match_record();
+        record.collateral_transferred = collateral;
+        record.quote_paid = coins(100, "quote");
+        insert_match_record(storage, &record).expect("the record should be inserted");
+    }
+
+    fn record_ids(records: &[MatchRecord]) -> Vec<u128> {
+        records.iter().map(|record| record.id.u128()).collect()
+    }
+}
@@ -1,5 +1,12 @@
+pub mod address_blocklist_storage;
 pub mod ask_order_storage;
 pub mod bid_order_storage;
 pub mod contract_info;
+pub mod contract_role_storage;
+pub mod match_record_storage;
+pub mod multi_value_index;
+pub mod order_count_index;
 pub mod order_indices;
 pub mod order_search_repository;
+pub mod quote_denom_allowlist_storage;
+pub mod scope_stake_storage;
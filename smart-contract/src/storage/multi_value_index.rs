@@ -0,0 +1,66 @@
+use cosmwasm_std::{Empty, Order, StdResult, Storage};
+use cw_storage_plus::{Bound, Index, Map};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// An index that stores any number of keys for each value, in contrast to a MultiIndex, which
+/// always stores exactly one.  This allows an order to be found by each of the scopes or quote
+/// denoms it holds, and allows orders with no relevant value to be omitted from the index.
+/// Stores (idx_namespace, index_key, pk) -> Empty.
+pub struct MultiValueIndex<'a, T> {
+    index: fn(&T) -> Vec<String>,
+    idx_map: Map<'a, (&'a str, &'a [u8]), Empty>,
+}
+impl<'a, T> MultiValueIndex<'a, T> {
+    pub fn new(idx_fn: fn(&T) -> Vec<String>, idx_namespace: &'a str) -> Self {
+        Self {
+            index: idx_fn,
+            idx_map: Map::new(idx_namespace),
+        }
+    }
+
+    /// Fetches the primary keys of the values indexed with the given key, within the bounds.
+    pub fn primary_keys<'b>(
+        &self,
+        storage: &'b dyn Storage,
+        key: &str,
+        min: Option<Bound<'a, &'a [u8]>>,
+        max: Option<Bound<'a, &'a [u8]>>,
+        order: Order,
+    ) -> Box<dyn Iterator<Item = Vec<u8>> + 'b>
+    where
+        'a: 'b,
+    {
+        Box::new(
+            self.idx_map
+                .prefix(key)
+                .keys(storage, min, max, order)
+                .filter_map(|result| result.ok()),
+        )
+    }
+
+    fn get_keys(&self, data: &T) -> Vec<String> {
+        let mut keys = (self.index)(data);
+        keys.sort();
+        keys.dedup();
+        keys
+    }
+}
+impl<'a, T> Index<T> for MultiValueIndex<'a, T>
+where
+    T: Serialize + DeserializeOwned + Clone,
+{
+    fn save(&self, store: &mut dyn Storage, pk: &[u8], data: &T) -> StdResult<()> {
+        for key in self.get_keys(data) {
+            self.idx_map.save(store, (&key, pk), &Empty {})?;
+        }
+        Ok(())
+    }
+
+    fn remove(&self, store: &mut dyn Storage, pk: &[u8], old_data: &T) -> StdResult<()> {
+        for key in self.get_keys(old_data) {
+            self.idx_map.remove(store, (&key, pk));
+        }
+        Ok(())
+    }
+}
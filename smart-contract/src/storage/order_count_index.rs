@@ -0,0 +1,216 @@
+use cosmwasm_std::{Addr, Order, StdResult, Storage};
+use cw_storage_plus::{Index, Map};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+pub const COUNT_KEY_ALL: &str = "all";
+pub const COUNT_KEY_COLLATERAL: &str = "collateral";
+pub const COUNT_KEY_OWNER: &str = "owner";
+pub const COUNT_KEY_TYPE: &str = "type";
+pub const COUNT_KEY_MARKER_DENOM: &str = "marker_denom";
+pub const COUNT_KEY_SCOPE_ADDRESS: &str = "scope_address";
+pub const COUNT_KEY_SCOPE_SPEC: &str = "scope_spec";
+pub const COUNT_KEY_QUOTE_DENOM: &str = "quote_denom";
+
+/// An index that stores no references to values, but instead keeps a running count of the values
+/// stored for each (search kind, index key) pair.  Because it is maintained by the IndexedMap
+/// along with the other indices, a search can fetch the total number of matching orders with a
+/// single read rather than ranging over all of them.
+/// Stores (idx_namespace, search kind, index key) -> count.
+pub struct OrderCountIndex<'a, T> {
+    index: fn(&T) -> Vec<(&'static str, String)>,
+    count_map: Map<'a, (&'a str, &'a str), u64>,
+}
+impl<'a, T> OrderCountIndex<'a, T> {
+    pub fn new(idx_fn: fn(&T) -> Vec<(&'static str, String)>, idx_namespace: &'a str) -> Self {
+        Self {
+            index: idx_fn,
+            count_map: Map::new(idx_namespace),
+        }
+    }
+
+    /// Fetches the number of values currently stored under the given search kind and key.
+    pub fn count(&self, storage: &dyn Storage, search_kind: &str, key: &str) -> usize {
+        self.count_map
+            .may_load(storage, (search_kind, key))
+            .unwrap_or(None)
+            .unwrap_or(0) as usize
+    }
+
+    /// Removes every stored count.  Used when re-indexing all values from scratch.
+    pub fn clear(&self, storage: &mut dyn Storage) {
+        let keys = self
+            .count_map
+            .keys(storage, None, None, Order::Ascending)
+            .filter_map(|result| result.ok())
+            .collect::<Vec<(String, String)>>();
+        for (search_kind, key) in keys.iter() {
+            self.count_map.remove(storage, (search_kind, key));
+        }
+    }
+
+    fn get_keys(&self, data: &T) -> Vec<(&'static str, String)> {
+        let mut keys = (self.index)(data);
+        keys.sort();
+        keys.dedup();
+        keys
+    }
+}
+impl<'a, T> Index<T> for OrderCountIndex<'a, T>
+where
+    T: Serialize + DeserializeOwned + Clone,
+{
+    fn save(&self, store: &mut dyn Storage, _pk: &[u8], data: &T) -> StdResult<()> {
+        for (search_kind, key) in self.get_keys(data) {
+            self.count_map
+                .update(store, (search_kind, &key), |count| -> StdResult<u64> {
+                    Ok(count.unwrap_or(0) + 1)
+                })?;
+        }
+        Ok(())
+    }
+
+    fn remove(&self, store: &mut dyn Storage, _pk: &[u8], old_data: &T) -> StdResult<()> {
+        for (search_kind, key) in self.get_keys(old_data) {
+            let count = self
+                .count_map
+                .may_load(store, (search_kind, &key))?
+                .unwrap_or(0)
+                .saturating_sub(1);
+            if count == 0 {
+                self.count_map.remove(store, (search_kind, &key));
+            } else {
+                self.count_map.save(store, (search_kind, &key), &count)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Produces the count keys for an order, one for each search that can find it.
+pub fn get_order_count_keys(
+    owner: &Addr,
+    value_type: &str,
+    marker_denoms: Vec<String>,
+    scope_addresses: Vec<String>,
+    scope_specs: Vec<String>,
+    quote_denoms: Vec<String>,
+) -> Vec<(&'static str, String)> {
+    let mut keys = vec![
+        (COUNT_KEY_ALL, String::new()),
+        (COUNT_KEY_OWNER, owner.to_string()),
+        (COUNT_KEY_TYPE, value_type.to_string()),
+    ];
+    keys.extend(
+        marker_denoms
+            .into_iter()
+            .map(|key| (COUNT_KEY_MARKER_DENOM, key)),
+    );
+    keys.extend(
+        scope_addresses
+            .into_iter()
+            .map(|key| (COUNT_KEY_SCOPE_ADDRESS, key)),
+    );
+    keys.extend(
+        scope_specs
+            .into_iter()
+            .map(|key| (COUNT_KEY_SCOPE_SPEC, key)),
+    );
+    keys.extend(
+        quote_denoms
+            .into_iter()
+            .map(|key| (COUNT_KEY_QUOTE_DENOM, key)),
+    );
+    keys
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::storage::ask_order_storage::{
+        ask_orders, delete_ask_order_by_id, insert_ask_order, update_ask_order,
+    };
+    use crate::storage::order_count_index::{
+        COUNT_KEY_ALL, COUNT_KEY_OWNER, COUNT_KEY_QUOTE_DENOM,
+    };
+    use crate::types::request::ask_types::ask_collateral::AskCollateral;
+    use crate::types::request::ask_types::ask_order::AskOrder;
+    use cosmwasm_std::{coin, coins, Addr, Storage};
+    use provwasm_mocks::mock_dependencies;
+
+    #[test]
+    fn test_counts_follow_inserts_updates_and_deletes() {
+        let mut deps = mock_dependencies(&[]);
+        let get_count = |storage: &dyn Storage, kind: &str, key: &str| {
+            ask_orders().idx.count_index.count(storage, kind, key)
+        };
+        for id in ["ask_1", "ask_2"] {
+            insert_ask_order(
+                deps.as_mut().storage,
+                &AskOrder::new_unchecked(
+                    id,
+                    Addr::unchecked(id),
+                    AskCollateral::coin_trade(&[], &[coin(10, "a"), coin(10, "b")]),
+                    None,
+                ),
+            )
+            .expect("expected the ask order to be inserted");
+        }
+        assert_eq!(
+            2,
+            get_count(deps.as_ref().storage, COUNT_KEY_ALL, ""),
+            "both asks should be counted"
+        );
+        assert_eq!(
+            1,
+            get_count(deps.as_ref().storage, COUNT_KEY_OWNER, "ask_1"),
+            "the owner should be counted"
+        );
+        assert_eq!(
+            2,
+            get_count(deps.as_ref().storage, COUNT_KEY_QUOTE_DENOM, "b"),
+            "both asks quote in b"
+        );
+        update_ask_order(
+            deps.as_mut().storage,
+            &AskOrder::new_unchecked(
+                "ask_1",
+                Addr::unchecked("ask_1"),
+                AskCollateral::coin_trade(&[], &coins(10, "a")),
+                None,
+            ),
+        )
+        .expect("expected the ask order to be updated");
+        assert_eq!(
+            2,
+            get_count(deps.as_ref().storage, COUNT_KEY_ALL, ""),
+            "an update should not change the total"
+        );
+        assert_eq!(
+            1,
+            get_count(deps.as_ref().storage, COUNT_KEY_QUOTE_DENOM, "b"),
+            "the old quote denom should be uncounted"
+        );
+        assert_eq!(
+            2,
+            get_count(deps.as_ref().storage, COUNT_KEY_QUOTE_DENOM, "a"),
+            "the kept quote denom should be unchanged"
+        );
+        delete_ask_order_by_id(deps.as_mut().storage, "ask_2")
+            .expect("expected the ask order to be deleted");
+        assert_eq!(
+            1,
+            get_count(deps.as_ref().storage, COUNT_KEY_ALL, ""),
+            "the deleted ask should be uncounted"
+        );
+        assert_eq!(
+            0,
+            get_count(deps.as_ref().storage, COUNT_KEY_QUOTE_DENOM, "b"),
+            "no asks should quote in b"
+        );
+        assert_eq!(
+            0,
+            get_count(deps.as_ref().storage, COUNT_KEY_OWNER, "ask_2"),
+            "the deleted ask's owner should have no asks"
+        );
+    }
+}
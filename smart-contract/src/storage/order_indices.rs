@@ -1,6 +1,13 @@
+use crate::storage::multi_value_index::MultiValueIndex;
+use crate::storage::order_count_index::OrderCountIndex;
 use cw_storage_plus::MultiIndex;
 
 pub trait OrderIndices<'a, T> {
     fn owner_index(&self) -> &MultiIndex<'a, String, T, String>;
     fn type_index(&self) -> &MultiIndex<'a, String, T, String>;
+    fn marker_denom_index(&self) -> &MultiValueIndex<'a, T>;
+    fn scope_address_index(&self) -> &MultiValueIndex<'a, T>;
+    fn scope_spec_index(&self) -> &MultiValueIndex<'a, T>;
+    fn quote_denom_index(&self) -> &MultiValueIndex<'a, T>;
+    fn count_index(&self) -> &OrderCountIndex<'a, T>;
 }
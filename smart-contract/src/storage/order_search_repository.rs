@@ -1,145 +1,609 @@
+use crate::storage::multi_value_index::MultiValueIndex;
+use crate::storage::order_count_index::{
+    COUNT_KEY_ALL, COUNT_KEY_MARKER_DENOM, COUNT_KEY_OWNER, COUNT_KEY_QUOTE_DENOM,
+    COUNT_KEY_SCOPE_ADDRESS, COUNT_KEY_SCOPE_SPEC, COUNT_KEY_TYPE,
+};
 use crate::storage::order_indices::OrderIndices;
 use crate::types::core::constants::{
     DEFAULT_SEARCH_ORDER, DEFAULT_SEARCH_PAGE_NUMBER, DEFAULT_SEARCH_PAGE_SIZE,
-    MAX_SEARCH_PAGE_SIZE, MIN_SEARCH_PAGE_NUMBER, MIN_SEARCH_PAGE_SIZE,
+    MAX_FILTER_SEARCH_SCAN, MAX_SEARCH_PAGE_SIZE, MIN_SEARCH_PAGE_NUMBER, MIN_SEARCH_PAGE_SIZE,
 };
-use crate::types::request::search::{Search, SearchResult, SearchType};
-use cosmwasm_std::{Storage, Uint128};
-use cw_storage_plus::{IndexList, IndexedMap, MultiIndex};
+use crate::types::core::error::ContractError;
+use crate::types::request::request_descriptor::DescribedOrder;
+use crate::types::request::search::{Search, SearchFilter, SearchResult, SearchSort, SearchType};
+use crate::util::extensions::ResultExtensions;
+use cosmwasm_std::{Addr, Order, Storage, Timestamp, Uint128};
+use cw_storage_plus::{Bound, IndexList, IndexedMap, MultiIndex, PrimaryKey};
 use serde::de::DeserializeOwned;
 use serde::Serialize;
+use std::cmp::Ordering;
+use std::collections::BTreeSet;
 
 pub struct OrderSearchRepository<'a, T, O>
 where
     T: IndexList<O> + OrderIndices<'a, O>,
-    O: Clone + Serialize + DeserializeOwned,
+    O: Clone + Serialize + DeserializeOwned + DescribedOrder,
 {
     index_map: IndexedMap<'a, &'a [u8], O, T>,
 }
 impl<'a, T, O> OrderSearchRepository<'a, T, O>
 where
     T: IndexList<O> + OrderIndices<'a, O>,
-    O: Clone + Serialize + DeserializeOwned,
+    O: Clone + Serialize + DeserializeOwned + DescribedOrder,
 {
     pub fn new(index_map: IndexedMap<'a, &'a [u8], O, T>) -> Self {
         Self { index_map }
     }
 
-    pub fn search(&self, storage: &dyn Storage, search: Search) -> SearchResult<O> {
+    pub fn search(
+        &self,
+        storage: &dyn Storage,
+        search: Search,
+        time: &Timestamp,
+    ) -> Result<SearchResult<O>, ContractError> {
         let page_size = self.get_page_size(&search);
         let page_number = self.get_page_number(&search);
-        let response = match &search.search_type {
-            SearchType::All => self.get_all_response(storage, page_size, page_number),
-            SearchType::Id { id } => self.get_id_response(storage, id),
-            SearchType::Owner { owner: id } | SearchType::ValueType { value_type: id } => self
-                .do_multi_index_search(
-                    storage,
-                    id,
-                    page_size,
-                    page_number,
-                    match &search.search_type {
-                        SearchType::Owner { .. } => self.index_map.idx.owner_index(),
-                        SearchType::ValueType { .. } => self.index_map.idx.type_index(),
-                        _ => {
-                            return SearchResult::empty();
-                        }
-                    },
-                ),
+        let hide_inactive = search.hide_inactive.unwrap_or(false);
+        let viewer = search.viewer.as_ref().map(Addr::unchecked);
+        // Orders that have not yet reached their effective time, or that have expired, are only
+        // excluded from results when the search requests it.  Private orders are likewise only
+        // excluded when a viewer is provided
+        let is_visible = |order: &O| {
+            (!hide_inactive || order.is_active(time))
+                && viewer
+                    .as_ref()
+                    .map(|viewer| order.is_visible_to(viewer))
+                    .unwrap_or(true)
+        };
+        let paging = SearchPaging::new(&search, page_size, page_number);
+        let indices = &self.index_map.idx;
+        let mut scan_cursor = None;
+        let (query_results, total_results) = match &search.search_type {
+            // Filters combine several criteria, so their results are found and counted separately
+            SearchType::Filter { filter } => {
+                let filter_results =
+                    self.do_filter_search(storage, filter, &paging, &is_visible, time)?;
+                scan_cursor = filter_results.scan_cursor;
+                (filter_results.results, filter_results.total_results)
+            }
+            SearchType::Id { id } => {
+                let query_results = self.get_id_results(storage, id, &is_visible);
+                let total_results = query_results.len();
+                (query_results, total_results)
+            }
+            SearchType::All => self.do_counted_search(storage, COUNT_KEY_ALL, "", || {
+                self.get_all_results(storage, &paging, &is_visible)
+            }),
+            SearchType::Owner { owner } => {
+                self.do_counted_search(storage, COUNT_KEY_OWNER, owner, || {
+                    self.do_multi_index_search(
+                        storage,
+                        owner,
+                        &paging,
+                        &is_visible,
+                        indices.owner_index(),
+                    )
+                })
+            }
+            SearchType::ValueType { value_type } => {
+                self.do_counted_search(storage, COUNT_KEY_TYPE, value_type, || {
+                    self.do_multi_index_search(
+                        storage,
+                        value_type,
+                        &paging,
+                        &is_visible,
+                        indices.type_index(),
+                    )
+                })
+            }
+            SearchType::MarkerDenom { marker_denom } => {
+                self.do_counted_search(storage, COUNT_KEY_MARKER_DENOM, marker_denom, || {
+                    self.do_multi_value_index_search(
+                        storage,
+                        marker_denom,
+                        &paging,
+                        &is_visible,
+                        indices.marker_denom_index(),
+                    )
+                })
+            }
+            SearchType::ScopeAddress { scope_address } => {
+                self.do_counted_search(storage, COUNT_KEY_SCOPE_ADDRESS, scope_address, || {
+                    self.do_multi_value_index_search(
+                        storage,
+                        scope_address,
+                        &paging,
+                        &is_visible,
+                        indices.scope_address_index(),
+                    )
+                })
+            }
+            SearchType::ScopeSpecId { scope_spec_id } => {
+                self.do_counted_search(storage, COUNT_KEY_SCOPE_SPEC, scope_spec_id, || {
+                    self.do_multi_value_index_search(
+                        storage,
+                        scope_spec_id,
+                        &paging,
+                        &is_visible,
+                        indices.scope_spec_index(),
+                    )
+                })
+            }
+            SearchType::QuoteDenom { quote_denom } => {
+                self.do_counted_search(storage, COUNT_KEY_QUOTE_DENOM, quote_denom, || {
+                    self.do_multi_value_index_search(
+                        storage,
+                        quote_denom,
+                        &paging,
+                        &is_visible,
+                        indices.quote_denom_index(),
+                    )
+                })
+            }
         };
-        // Multiply total pages by 100 to determine if there is some lossy remainder during division,
-        // effectively allowing a ceiling value to be established on the quotient.  This ensures that
-        // a total result count of 101 with a page size will return a page count of 11 instead of 10,
-        // etc.  This also maxes with 1 to ensure that searches that return no results still show a
-        // single page for an easier UI integration.
-        let mut total_pages = (response.total_results * 100) / page_size;
-        total_pages = (total_pages / 100 + if total_pages % 100 != 0 { 1 } else { 0 }).max(1);
-        SearchResult {
-            results: response.query_results,
-            page_number: Uint128::new(page_number as u128),
-            page_size: Uint128::new(page_size as u128),
-            total_pages: Uint128::new(total_pages as u128),
+        let mut result = paging.to_search_result(query_results, total_results, page_number);
+        // A filter that stopped scanning before filling its page continues after the last order
+        // that it checked
+        if scan_cursor.is_some() {
+            result.next_start_after = scan_cursor;
         }
+        result.to_ok()
     }
 
     fn get_page_size(&self, search: &Search) -> usize {
-        search
-            .page_size
-            .map(|u| u.u128() as usize)
-            .unwrap_or(DEFAULT_SEARCH_PAGE_SIZE)
-            // Limit page size to ensure overloads do not occur
-            .min(MAX_SEARCH_PAGE_SIZE)
-            // Never allow page sizes < 1, which would cause division panics
-            .max(MIN_SEARCH_PAGE_SIZE)
+        get_page_size(&search.page_size)
     }
 
     fn get_page_number(&self, search: &Search) -> usize {
-        search
-            .page_number
-            .map(|u| u.u128() as usize)
-            .unwrap_or(DEFAULT_SEARCH_PAGE_NUMBER)
-            // Never allow page sizes < 1, which would cause division panics
-            .max(MIN_SEARCH_PAGE_NUMBER)
+        get_page_number(&search.page_number)
     }
 
-    fn get_all_response(
+    /// Produces a page of results along with the total number of orders found by the search.
+    /// Totals are read from the counts maintained alongside the indices, so they do not require
+    /// ranging over every matching order.  The counts include orders that the visibility filters
+    /// omit from pages, because counting only the visible orders would require that range.
+    fn do_counted_search<F>(
         &self,
         storage: &dyn Storage,
-        page_size: usize,
-        page_number: usize,
-    ) -> SearchResponse<O> {
-        let query = || {
+        search_kind: &str,
+        key: &str,
+        get_results: F,
+    ) -> (Vec<O>, usize)
+    where
+        F: FnOnce() -> Vec<O>,
+    {
+        let total_results = self
+            .index_map
+            .idx
+            .count_index()
+            .count(storage, search_kind, key);
+        (get_results(), total_results)
+    }
+
+    fn get_all_results(
+        &self,
+        storage: &dyn Storage,
+        paging: &SearchPaging,
+        is_visible: &dyn Fn(&O) -> bool,
+    ) -> Vec<O> {
+        let (min, max) = paging.get_bounds();
+        paging.take_page(
             self.index_map
-                .range(storage, None, None, DEFAULT_SEARCH_ORDER)
-        };
-        SearchResponse {
-            total_results: query().count(),
-            query_results: query()
-                .skip(page_size * (page_number - 1))
-                .take(page_size)
+                .range(storage, min, max, paging.order)
                 .filter(|result| result.is_ok())
                 .map(|result| result.unwrap().1)
-                .collect(),
-        }
+                .filter(|order| is_visible(order)),
+        )
     }
 
-    fn get_id_response(&self, storage: &dyn Storage, id: &str) -> SearchResponse<O> {
-        SearchResponse {
-            total_results: 1,
-            query_results: self
-                .index_map
-                .load(storage, id.as_bytes())
-                .map(|value| vec![value])
-                .unwrap_or_default(),
-        }
+    fn get_id_results(
+        &self,
+        storage: &dyn Storage,
+        id: &str,
+        is_visible: &dyn Fn(&O) -> bool,
+    ) -> Vec<O> {
+        self.index_map
+            .load(storage, id.as_bytes())
+            .map(|value| vec![value])
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|order| is_visible(order))
+            .collect()
     }
+
     fn do_multi_index_search(
         &self,
         storage: &dyn Storage,
         id: &str,
-        page_size: usize,
-        page_number: usize,
+        paging: &SearchPaging,
+        is_visible: &dyn Fn(&O) -> bool,
         index: &MultiIndex<String, O, String>,
-    ) -> SearchResponse<O> {
-        let query = || {
+    ) -> Vec<O> {
+        let (min, max) = paging.get_bounds();
+        paging.take_page(
             index
                 .prefix(id.to_owned())
-                .range(storage, None, None, DEFAULT_SEARCH_ORDER)
-        };
-        SearchResponse {
-            total_results: query().count(),
-            query_results: query()
-                .skip(page_size * (page_number - 1))
-                .take(page_size)
+                .range(storage, min, max, paging.order)
                 .filter(|result| result.is_ok())
                 .map(|result| result.unwrap().1)
-                .collect(),
+                .filter(|order| is_visible(order)),
+        )
+    }
+
+    fn do_multi_value_index_search(
+        &self,
+        storage: &dyn Storage,
+        key: &str,
+        paging: &SearchPaging,
+        is_visible: &dyn Fn(&O) -> bool,
+        index: &MultiValueIndex<O>,
+    ) -> Vec<O> {
+        let (min, max) = paging.get_bounds();
+        paging.take_page(
+            index
+                .primary_keys(storage, key, min, max, paging.order)
+                .filter_map(|pk| self.index_map.may_load(storage, &pk).unwrap_or(None))
+                .filter(|order| is_visible(order)),
+        )
+    }
+
+    /// Produces a page of the orders that meet every criterion of a filter.  The indexed criterion
+    /// with the fewest orders finds the orders that are checked, and at most
+    /// MAX_FILTER_SEARCH_SCAN of them are checked for each page.  Id sorts follow the index, so
+    /// each page resumes after the last order checked, and the stored count of the index is the
+    /// total.  Other sorts must check every order in the index, so they are refused when the
+    /// index holds more orders than can be checked.
+    fn do_filter_search(
+        &self,
+        storage: &dyn Storage,
+        filter: &SearchFilter,
+        paging: &SearchPaging,
+        is_visible: &dyn Fn(&O) -> bool,
+        time: &Timestamp,
+    ) -> Result<FilterResults<O>, ContractError> {
+        let indices = &self.index_map.idx;
+        // The stored counts make it cheap to determine which of the indexed criteria narrows the
+        // search the most.  Validation requires at least one of them, so that a filter never
+        // checks every order in the contract
+        let ((search_kind, key), index_count) = match [
+            filter
+                .owner
+                .as_ref()
+                .map(|owner| (COUNT_KEY_OWNER, owner.to_owned())),
+            filter
+                .request_type
+                .as_ref()
+                .map(|request_type| (COUNT_KEY_TYPE, request_type.get_name().to_string())),
+            filter
+                .quote_denom
+                .as_ref()
+                .map(|quote_denom| (COUNT_KEY_QUOTE_DENOM, quote_denom.to_owned())),
+        ]
+        .into_iter()
+        .flatten()
+        .map(|(search_kind, key)| {
+            let count = indices.count_index().count(storage, search_kind, &key);
+            ((search_kind, key), count)
+        })
+        .min_by_key(|(_, count)| *count)
+        {
+            Some(narrowest_index) => narrowest_index,
+            None => return FilterResults::empty().to_ok(),
+        };
+        let is_match = |order: &O| filter.matches(order, time) && is_visible(order);
+        let sort = filter.get_sort();
+        if sort == SearchSort::Id {
+            // The index is ordered by id, so the cursor bounds the range directly and only the
+            // orders on the page are retained
+            let mut scanned_count = 0;
+            let mut last_scanned_id = None;
+            let results = paging.take_page(
+                self.range_filter_index(storage, search_kind, &key, paging)
+                    .take(MAX_FILTER_SEARCH_SCAN)
+                    .inspect(|order| {
+                        scanned_count += 1;
+                        last_scanned_id = Some(order.get_id().to_string());
+                    })
+                    .filter(|order| is_match(order)),
+            );
+            let scan_cursor =
+                if results.len() < paging.page_size && scanned_count == MAX_FILTER_SEARCH_SCAN {
+                    last_scanned_id
+                } else {
+                    None
+                };
+            return FilterResults {
+                results,
+                total_results: index_count,
+                scan_cursor,
+            }
+            .to_ok();
+        }
+        if index_count > MAX_FILTER_SEARCH_SCAN {
+            return ContractError::InvalidRequest {
+                message: format!(
+                    "filters that are not sorted by id can check at most [{}] orders, but the narrowest criterion includes [{}]",
+                    MAX_FILTER_SEARCH_SCAN, index_count,
+                ),
+            }
+            .to_err();
+        }
+        let get_sort_key = |order: &O| FilterSortKey {
+            value: match sort {
+                SearchSort::Price => filter
+                    .quote_denom
+                    .as_ref()
+                    .and_then(|quote_denom| order.get_price(quote_denom))
+                    .map(|price| price.u128()),
+                _ => order
+                    .get_created_time()
+                    .map(|created_time| created_time.nanos() as u128),
+            },
+            id: order.get_id().to_string(),
+        };
+        let mut matches = self
+            .range_filter_index(storage, search_kind, &key, &paging.unbounded())
+            .filter(|order| is_match(order))
+            .map(|order| (get_sort_key(&order), order))
+            .collect::<Vec<(FilterSortKey, O)>>();
+        matches
+            .sort_by(|(first_key, _), (second_key, _)| first_key.compare(second_key, paging.order));
+        let total_results = matches.len();
+        // Other sorts do not follow the order of the index, so the cursor order's sort key locates
+        // the page, even if the order no longer matches the filter.  If it has since been removed,
+        // the page can no longer be located
+        let cursor = match &paging.start_after {
+            Some(start_after) => match self
+                .index_map
+                .may_load(storage, start_after)
+                .unwrap_or(None)
+            {
+                Some(order) => Some(get_sort_key(&order)),
+                None => {
+                    return FilterResults {
+                        results: vec![],
+                        total_results,
+                        scan_cursor: None,
+                    }
+                    .to_ok()
+                }
+            },
+            None => None,
+        };
+        let results = paging.take_page(
+            matches
+                .into_iter()
+                .filter(|(sort_key, _)| {
+                    cursor
+                        .as_ref()
+                        .map(|cursor| sort_key.compare(cursor, paging.order) == Ordering::Greater)
+                        .unwrap_or(true)
+                })
+                .map(|(_, order)| order),
+        );
+        FilterResults {
+            results,
+            total_results,
+            scan_cursor: None,
+        }
+        .to_ok()
+    }
+
+    /// Ranges over the orders in the index chosen to find the candidates for a filter search, in
+    /// the paging's order and starting after its cursor.
+    fn range_filter_index<'s>(
+        &'s self,
+        storage: &'s dyn Storage,
+        search_kind: &str,
+        key: &str,
+        paging: &SearchPaging,
+    ) -> Box<dyn Iterator<Item = O> + 's>
+    where
+        'a: 's,
+    {
+        let indices = &self.index_map.idx;
+        let multi_index = match search_kind {
+            COUNT_KEY_OWNER => Some(indices.owner_index()),
+            COUNT_KEY_TYPE => Some(indices.type_index()),
+            _ => None,
+        };
+        match multi_index {
+            Some(index) => {
+                let (min, max) = paging.get_bounds();
+                Box::new(
+                    index
+                        .prefix(key.to_owned())
+                        .range(storage, min, max, paging.order)
+                        .filter_map(|result| result.ok())
+                        .map(|(_, order)| order),
+                )
+            }
+            None => {
+                let (min, max) = paging.get_bounds();
+                Box::new(
+                    indices
+                        .quote_denom_index()
+                        .primary_keys(storage, key, min, max, paging.order)
+                        .filter_map(move |pk| {
+                            self.index_map.may_load(storage, &pk).unwrap_or(None)
+                        }),
+                )
+            }
+        }
+    }
+}
+
+/// Locates an order within filtered results that are sorted by a value other than their ids.
+/// Orders missing the value, such as orders without a price in the filter's quote denom or orders
+/// created before creation times were recorded, always follow the others.
+struct FilterSortKey {
+    value: Option<u128>,
+    id: String,
+}
+impl FilterSortKey {
+    fn compare(&self, other: &FilterSortKey, order: Order) -> Ordering {
+        match (self.value, other.value) {
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            _ => {
+                let ordering = (self.value, &self.id).cmp(&(other.value, &other.id));
+                match order {
+                    Order::Ascending => ordering,
+                    Order::Descending => ordering.reverse(),
+                }
+            }
+        }
+    }
+}
+
+/// A page of the orders found by a filter search.
+struct FilterResults<O> {
+    results: Vec<O>,
+    total_results: usize,
+    /// The id of the last order checked when the scan limit was reached before the page was
+    /// filled, from which the next page continues.
+    scan_cursor: Option<String>,
+}
+impl<O> FilterResults<O> {
+    fn empty() -> Self {
+        Self {
+            results: vec![],
+            total_results: 0,
+            scan_cursor: None,
+        }
+    }
+}
+
+/// Determines which portion of the orders found by a search are included in its page.  Orders are
+/// always stored and indexed by their ids, so a start_after cursor bounds each range directly,
+/// whereas a page number requires skipping all orders on the preceding pages.
+struct SearchPaging {
+    start_after: Option<Vec<u8>>,
+    order: Order,
+    skip: usize,
+    page_size: usize,
+}
+impl SearchPaging {
+    fn new(search: &Search, page_size: usize, page_number: usize) -> Self {
+        let start_after = search.start_after.as_ref().map(|id| id.as_bytes().to_vec());
+        Self {
+            skip: if start_after.is_some() {
+                0
+            } else {
+                page_size * (page_number - 1)
+            },
+            start_after,
+            order: if search.descending.unwrap_or(false) {
+                Order::Descending
+            } else {
+                DEFAULT_SEARCH_ORDER
+            },
+            page_size,
+        }
+    }
+
+    /// Produces paging that includes every order found by a search, in the same order.
+    fn unbounded(&self) -> Self {
+        Self {
+            start_after: None,
+            order: self.order,
+            skip: 0,
+            page_size: usize::MAX,
+        }
+    }
+
+    /// Produces the (min, max) bounds of a range that begins after the cursor, if one exists.
+    fn get_bounds<'k, K: PrimaryKey<'k>>(&self) -> (Option<Bound<'k, K>>, Option<Bound<'k, K>>) {
+        let bound = self.start_after.to_owned().map(Bound::ExclusiveRaw);
+        match self.order {
+            Order::Ascending => (bound, None),
+            Order::Descending => (None, bound),
         }
     }
+
+    fn take_page<O>(&self, orders: impl Iterator<Item = O>) -> Vec<O> {
+        orders.skip(self.skip).take(self.page_size).collect()
+    }
+
+    /// Wraps a page of results, including a cursor to the next page when the page was filled.
+    fn to_search_result<O: DescribedOrder>(
+        &self,
+        results: Vec<O>,
+        total_results: usize,
+        page_number: usize,
+    ) -> SearchResult<O> {
+        let next_start_after = if results.len() == self.page_size {
+            results.last().map(|order| order.get_id().to_string())
+        } else {
+            None
+        };
+        let mut result = to_search_result(results, total_results, self.page_size, page_number);
+        result.next_start_after = next_start_after;
+        result
+    }
 }
 
-struct SearchResponse<T> {
-    pub total_results: usize,
-    pub query_results: Vec<T>,
+pub fn get_page_size(page_size: &Option<Uint128>) -> usize {
+    page_size
+        .map(|u| u.u128() as usize)
+        .unwrap_or(DEFAULT_SEARCH_PAGE_SIZE)
+        // Limit page size to ensure overloads do not occur, and never allow page sizes < 1,
+        // which would cause division panics
+        .clamp(MIN_SEARCH_PAGE_SIZE, MAX_SEARCH_PAGE_SIZE)
+}
+
+pub fn get_page_number(page_number: &Option<Uint128>) -> usize {
+    page_number
+        .map(|u| u.u128() as usize)
+        .unwrap_or(DEFAULT_SEARCH_PAGE_NUMBER)
+        // Never allow page sizes < 1, which would cause division panics
+        .max(MIN_SEARCH_PAGE_NUMBER)
+}
+
+/// Collects up to the limit of the given orders, skipping any order that was already collected,
+/// as an order can be found through several index keys.  The second value is true when more
+/// orders remained after the limit was reached.
+pub fn take_distinct_orders<O: DescribedOrder>(
+    orders: impl Iterator<Item = O>,
+    limit: usize,
+) -> (Vec<O>, bool) {
+    let mut collected_ids = BTreeSet::new();
+    let mut orders = orders
+        .filter(|order| collected_ids.insert(order.get_id().to_owned()))
+        .take(limit + 1)
+        .collect::<Vec<O>>();
+    let truncated = orders.len() > limit;
+    orders.truncate(limit);
+    (orders, truncated)
+}
+
+/// Wraps a page of results with the paging values used to produce it.
+pub fn to_search_result<T>(
+    results: Vec<T>,
+    total_results: usize,
+    page_size: usize,
+    page_number: usize,
+) -> SearchResult<T> {
+    // Multiply total pages by 100 to determine if there is some lossy remainder during division,
+    // effectively allowing a ceiling value to be established on the quotient.  This ensures that
+    // a total result count of 101 with a page size will return a page count of 11 instead of 10,
+    // etc.  This also maxes with 1 to ensure that searches that return no results still show a
+    // single page for an easier UI integration.
+    let mut total_pages = (total_results * 100) / page_size;
+    total_pages = (total_pages / 100
+        + if !total_pages.is_multiple_of(100) {
+            1
+        } else {
+            0
+        })
+    .max(1);
+    SearchResult {
+        results,
+        page_number: Uint128::new(page_number as u128),
+        page_size: Uint128::new(page_size as u128),
+        total_pages: Uint128::new(total_pages as u128),
+        next_start_after: None,
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,32 @@
+use crate::types::core::error::ContractError;
+use crate::types::core::quote_denom_allowlist::QuoteDenomAllowlist;
+use cosmwasm_std::Storage;
+use cw_storage_plus::Item;
+
+const NAMESPACE_QUOTE_DENOM_ALLOWLIST: &str = "quote_denom_allowlist";
+
+const QUOTE_DENOM_ALLOWLIST: Item<QuoteDenomAllowlist> = Item::new(NAMESPACE_QUOTE_DENOM_ALLOWLIST);
+
+pub fn set_quote_denom_allowlist(
+    storage: &mut dyn Storage,
+    allowlist: &QuoteDenomAllowlist,
+) -> Result<(), ContractError> {
+    QUOTE_DENOM_ALLOWLIST
+        .save(storage, allowlist)
+        .map_err(|e| ContractError::StorageError {
+            message: format!("{:?}", e),
+        })
+}
+
+/// Fetches the stored allowlist.  An empty allowlist is returned when none has been stored,
+/// allowing all quote denoms.
+pub fn get_quote_denom_allowlist(
+    storage: &dyn Storage,
+) -> Result<QuoteDenomAllowlist, ContractError> {
+    QUOTE_DENOM_ALLOWLIST
+        .may_load(storage)
+        .map(|allowlist| allowlist.unwrap_or_default())
+        .map_err(|e| ContractError::StorageError {
+            message: format!("{:?}", e),
+        })
+}
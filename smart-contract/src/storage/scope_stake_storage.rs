@@ -0,0 +1,70 @@
+use crate::types::core::error::ContractError;
+use crate::util::extensions::ResultExtensions;
+use cosmwasm_std::{Addr, Empty, Storage};
+use cw_storage_plus::Map;
+
+const NAMESPACE_SCOPE_STAKE_REGISTRATIONS: &str = "scope_stake_registrations";
+
+// Keyed by scope address and owner.  Only the presence of a key matters, so no value is stored
+const SCOPE_STAKE_REGISTRATIONS: Map<(&str, &Addr), Empty> =
+    Map::new(NAMESPACE_SCOPE_STAKE_REGISTRATIONS);
+
+pub fn register_scope_stake(
+    storage: &mut dyn Storage,
+    scope_address: &str,
+    owner: &Addr,
+) -> Result<(), ContractError> {
+    SCOPE_STAKE_REGISTRATIONS
+        .save(storage, (scope_address, owner), &Empty {})
+        .map_err(|e| ContractError::StorageError {
+            message: format!("{:?}", e),
+        })
+}
+
+pub fn is_scope_stake_registered(storage: &dyn Storage, scope_address: &str, owner: &Addr) -> bool {
+    SCOPE_STAKE_REGISTRATIONS.has(storage, (scope_address, owner))
+}
+
+pub fn remove_scope_stake_registration(
+    storage: &mut dyn Storage,
+    scope_address: &str,
+    owner: &Addr,
+) -> Result<(), ContractError> {
+    SCOPE_STAKE_REGISTRATIONS.remove(storage, (scope_address, owner));
+    ().to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::storage::scope_stake_storage::{
+        is_scope_stake_registered, register_scope_stake, remove_scope_stake_registration,
+    };
+    use cosmwasm_std::Addr;
+    use provwasm_mocks::mock_dependencies;
+
+    #[test]
+    fn test_register_and_remove_scope_stake() {
+        let mut deps = mock_dependencies(&[]);
+        let owner = Addr::unchecked("owner");
+        assert!(
+            !is_scope_stake_registered(deps.as_ref().storage, "scope", &owner),
+            "stakes should not be registered by default",
+        );
+        register_scope_stake(deps.as_mut().storage, "scope", &owner)
+            .expect("the stake should be registered");
+        assert!(
+            is_scope_stake_registered(deps.as_ref().storage, "scope", &owner),
+            "the stake should be registered",
+        );
+        assert!(
+            !is_scope_stake_registered(deps.as_ref().storage, "other_scope", &owner),
+            "registrations should not apply to other scopes",
+        );
+        remove_scope_stake_registration(deps.as_mut().storage, "scope", &owner)
+            .expect("the registration should be removed");
+        assert!(
+            !is_scope_stake_registered(deps.as_ref().storage, "scope", &owner),
+            "the stake should no longer be registered",
+        );
+    }
+}
@@ -1,4 +1,5 @@
 use crate::types::core::error::ContractError;
+use crate::types::core::quote_denom_allowlist::QuoteDenomAllowlist;
 use crate::types::request::ask_types::ask_collateral::AskCollateral;
 use crate::types::request::ask_types::ask_order::AskOrder;
 use crate::types::request::bid_types::bid_collateral::BidCollateral;
@@ -18,7 +19,7 @@ impl AskOrder {
         descriptor: Option<RequestDescriptor>,
     ) -> Result<Self, ContractError> {
         let ask_order = Self::new_unchecked(id, owner, collateral, descriptor);
-        validate_ask_order(&ask_order)?;
+        validate_ask_order(&ask_order, &QuoteDenomAllowlist::default())?;
         ask_order.to_ok()
     }
 
@@ -34,6 +35,7 @@ impl AskOrder {
             owner,
             collateral,
             descriptor,
+            created_time: None,
         }
     }
 }
@@ -46,7 +48,7 @@ impl BidOrder {
         descriptor: Option<RequestDescriptor>,
     ) -> Result<Self, ContractError> {
         let bid_order = Self::new_unchecked(id, owner, collateral, descriptor);
-        validate_bid_order(&bid_order)?;
+        validate_bid_order(&bid_order, &QuoteDenomAllowlist::default())?;
         bid_order.to_ok()
     }
 
@@ -62,6 +64,7 @@ impl BidOrder {
             owner,
             collateral,
             descriptor,
+            created_time: None,
         }
     }
 }
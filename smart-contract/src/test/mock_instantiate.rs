@@ -1,5 +1,6 @@
 use crate::instantiate::instantiate_contract::instantiate_contract;
 use crate::types::core::msg::InstantiateMsg;
+use crate::types::request::settlement_fees::SettlementFees;
 use cosmwasm_std::testing::{mock_env, mock_info};
 use cosmwasm_std::{DepsMut, Uint128};
 use provwasm_std::ProvenanceQuery;
@@ -14,6 +15,8 @@ pub struct TestInstantiate {
     pub contract_name: String,
     pub create_ask_nhash_fee: Option<Uint128>,
     pub create_bid_nhash_fee: Option<Uint128>,
+    pub settlement_fees: Option<SettlementFees>,
+    pub fee_collector_address: Option<String>,
 }
 impl Default for TestInstantiate {
     fn default() -> Self {
@@ -23,6 +26,8 @@ impl Default for TestInstantiate {
             contract_name: DEFAULT_CONTRACT_NAME.to_string(),
             create_ask_nhash_fee: None,
             create_bid_nhash_fee: None,
+            settlement_fees: None,
+            fee_collector_address: None,
         }
     }
 }
@@ -33,6 +38,8 @@ impl TestInstantiate {
             contract_name: self.contract_name,
             create_ask_nhash_fee: self.create_ask_nhash_fee,
             create_bid_nhash_fee: self.create_bid_nhash_fee,
+            settlement_fees: self.settlement_fees,
+            fee_collector_address: self.fee_collector_address,
         }
     }
 }
@@ -0,0 +1,63 @@
+use crate::test::mock_scope::MockScope;
+use cosmwasm_std::testing::{MockApi, MockQuerier, MockStorage, MOCK_CONTRACT_ADDR};
+use cosmwasm_std::{
+    from_slice, to_binary, ContractResult, OwnedDeps, Querier, QuerierResult, QueryRequest,
+    SystemResult,
+};
+use provwasm_mocks::ProvenanceMockQuerier;
+use provwasm_std::{MetadataQueryParams, ProvenanceQuery, ProvenanceQueryParams, Scope};
+use std::collections::HashMap;
+use std::marker::PhantomData;
+
+pub type MockScopesDeps = OwnedDeps<MockStorage, MockApi, MockScopesQuerier, ProvenanceQuery>;
+
+/// The provwasm mock querier can only hold a single scope at a time.  This querier wraps it and
+/// serves any number of scopes, delegating all other queries to the wrapped querier.
+pub struct MockScopesQuerier {
+    pub base: ProvenanceMockQuerier,
+    scopes: HashMap<String, Scope>,
+}
+impl MockScopesQuerier {
+    pub fn with_scope(&mut self, scope: Scope) {
+        self.scopes.insert(scope.scope_id.to_owned(), scope);
+    }
+}
+impl Querier for MockScopesQuerier {
+    fn raw_query(&self, bin_request: &[u8]) -> QuerierResult {
+        if let Ok(QueryRequest::Custom(ProvenanceQuery {
+            params: ProvenanceQueryParams::Metadata(MetadataQueryParams::GetScope { scope_id }),
+            ..
+        })) = from_slice::<QueryRequest<ProvenanceQuery>>(bin_request)
+        {
+            if let Some(scope) = self.scopes.get(&scope_id) {
+                return SystemResult::Ok(ContractResult::Ok(
+                    to_binary(scope).expect("the scope should serialize"),
+                ));
+            }
+        }
+        self.base.raw_query(bin_request)
+    }
+}
+
+/// Creates dependencies that serve a scope owned by the given address for each scope address.
+pub fn mock_dependencies_with_scopes(scope_addresses: &[&str], owner: &str) -> MockScopesDeps {
+    let mut querier = MockScopesQuerier {
+        base: ProvenanceMockQuerier::new(MockQuerier::new(&[(MOCK_CONTRACT_ADDR, &[])])),
+        scopes: HashMap::new(),
+    };
+    for scope_address in scope_addresses {
+        querier.with_scope(
+            MockScope {
+                scope_id: scope_address.to_string(),
+                ..MockScope::new_mock_scope_with_owner(owner)
+            }
+            .to_scope(),
+        );
+    }
+    OwnedDeps {
+        storage: MockStorage::default(),
+        api: MockApi::default(),
+        querier,
+        custom_query_type: PhantomData,
+    }
+}
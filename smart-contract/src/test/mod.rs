@@ -4,5 +4,6 @@ pub mod extensions;
 pub mod mock_instantiate;
 pub mod mock_marker;
 pub mod mock_scope;
+pub mod mock_scopes_querier;
 pub mod request_helpers;
 pub mod type_helpers;
@@ -2,9 +2,12 @@ use crate::types::request::ask_types::ask_collateral::AskCollateral;
 use crate::types::request::ask_types::ask_order::AskOrder;
 use crate::types::request::bid_types::bid_collateral::BidCollateral;
 use crate::types::request::bid_types::bid_order::BidOrder;
+use crate::types::request::match_record::{MatchRecord, MatchedCollateral};
 use crate::types::request::request_descriptor::RequestDescriptor;
+use crate::types::request::request_type::RequestType;
 use crate::types::request::share_sale_type::ShareSaleType;
-use cosmwasm_std::{Addr, Coin};
+use cosmwasm_std::testing::mock_env;
+use cosmwasm_std::{coins, Addr, Coin, Uint128};
 use provwasm_std::{AccessGrant, MarkerAccess};
 
 pub fn replace_ask_quote(ask_order: &mut AskOrder, quote: &[Coin]) {
@@ -17,6 +20,7 @@ pub fn replace_ask_quote(ask_order: &mut AskOrder, quote: &[Coin]) {
             collateral.quote_per_share = quote.to_vec()
         }
         AskCollateral::ScopeTrade(ref mut collateral) => collateral.quote = quote.to_vec(),
+        AskCollateral::ScopeBundleTrade(ref mut collateral) => collateral.quote = quote.to_vec(),
     };
 }
 
@@ -26,6 +30,8 @@ pub fn replace_bid_quote(bid_order: &mut BidOrder, quote: &[Coin]) {
         BidCollateral::MarkerTrade(ref mut collateral) => collateral.quote = quote.to_vec(),
         BidCollateral::MarkerShareSale(ref mut collateral) => collateral.quote = quote.to_vec(),
         BidCollateral::ScopeTrade(ref mut collateral) => collateral.quote = quote.to_vec(),
+        BidCollateral::ScopeBundleTrade(ref mut collateral) => collateral.quote = quote.to_vec(),
+        BidCollateral::ScopeSpecTrade(ref mut collateral) => collateral.quote = quote.to_vec(),
     };
 }
 
@@ -131,3 +137,24 @@ pub fn mock_bid_marker_share_sale<S1: Into<String>, S2: Into<String>>(
 pub fn mock_bid_scope_trade<S: Into<String>>(scope_address: S, quote: &[Coin]) -> BidCollateral {
     BidCollateral::scope_trade(scope_address, quote)
 }
+
+pub fn mock_match_record() -> MatchRecord {
+    let env = mock_env();
+    MatchRecord {
+        id: Uint128::zero(),
+        ask_id: "ask_id".to_string(),
+        bid_id: "bid_id".to_string(),
+        asker: Addr::unchecked("asker"),
+        bidder: Addr::unchecked("bidder"),
+        request_type: RequestType::CoinTrade,
+        quote_paid: coins(100, "quote"),
+        settlement_fee: vec![],
+        collateral_transferred: MatchedCollateral::CoinTrade {
+            base: coins(100, "base"),
+        },
+        admin_match_options: None,
+        executed_by: Addr::unchecked("asker"),
+        block_height: env.block.height,
+        block_time: env.block.time,
+    }
+}
@@ -6,3 +6,11 @@ pub const MIN_SEARCH_PAGE_SIZE: usize = 1;
 pub const DEFAULT_SEARCH_PAGE_NUMBER: usize = 1;
 pub const MIN_SEARCH_PAGE_NUMBER: usize = 1;
 pub const DEFAULT_SEARCH_ORDER: Order = Order::Ascending;
+pub const DEFAULT_EXPIRE_ORDERS_LIMIT: usize = 10;
+pub const MAX_EXPIRE_ORDERS_LIMIT: usize = 25;
+pub const MAX_EXECUTE_MATCHES_BATCH_SIZE: usize = 50;
+pub const MAX_FILTER_SEARCH_SCAN: usize = 500;
+pub const MAX_MATCH_CANDIDATE_LOADS: usize = 250;
+pub const MAX_MATCH_CANDIDATE_EVALUATIONS: usize = 50;
+pub const BASIS_POINTS_PER_WHOLE: u128 = 10_000;
+pub const MAX_SETTLEMENT_FEE_BPS: u128 = 10_000;
@@ -0,0 +1,35 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+const SETTINGS_ADMIN_NAME: &str = "settings_admin";
+const MATCHER_NAME: &str = "matcher";
+const ORDER_CANCELLER_NAME: &str = "order_canceller";
+const FEE_COLLECTOR_NAME: &str = "fee_collector";
+
+/// Permissions that can be granted to accounts other than the contract admin.  The contract admin
+/// implicitly holds every role.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ContractRole {
+    /// Allows contract settings to be updated and roles to be granted and revoked.  Only the
+    /// contract admin may change the admin address.
+    SettingsAdmin,
+    /// Allows any ask and bid to be matched, including with admin match options.
+    Matcher,
+    /// Allows any ask or bid to be cancelled, and expired orders to be swept.
+    OrderCanceller,
+    /// Receives all fees charged by the contract.  Only a single account may hold this role at a
+    /// time, and granting it to a new account revokes it from the previous holder.
+    FeeCollector,
+}
+impl ContractRole {
+    pub fn get_name(&self) -> String {
+        match self {
+            ContractRole::SettingsAdmin => SETTINGS_ADMIN_NAME,
+            ContractRole::Matcher => MATCHER_NAME,
+            ContractRole::OrderCanceller => ORDER_CANCELLER_NAME,
+            ContractRole::FeeCollector => FEE_COLLECTOR_NAME,
+        }
+        .to_string()
+    }
+}
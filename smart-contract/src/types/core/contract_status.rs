@@ -0,0 +1,47 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+const ACTIVE_NAME: &str = "active";
+const CREATION_HALTED_NAME: &str = "creation_halted";
+const MATCHING_HALTED_NAME: &str = "matching_halted";
+const FULLY_PAUSED_NAME: &str = "fully_paused";
+
+/// Controls which operations the contract currently accepts.  Cancellations are allowed in every
+/// status to ensure that asker and bidder collateral can always be reclaimed.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ContractStatus {
+    /// All operations are allowed.
+    Active,
+    /// Asks and bids cannot be created or updated, but existing orders can still be matched.
+    CreationHalted,
+    /// Asks and bids cannot be matched, but orders can still be created and updated.
+    MatchingHalted,
+    /// Asks and bids cannot be created, updated, or matched.
+    FullyPaused,
+}
+impl ContractStatus {
+    pub fn get_name(&self) -> String {
+        match self {
+            ContractStatus::Active => ACTIVE_NAME,
+            ContractStatus::CreationHalted => CREATION_HALTED_NAME,
+            ContractStatus::MatchingHalted => MATCHING_HALTED_NAME,
+            ContractStatus::FullyPaused => FULLY_PAUSED_NAME,
+        }
+        .to_string()
+    }
+
+    pub fn allows_order_creation(&self) -> bool {
+        matches!(
+            self,
+            ContractStatus::Active | ContractStatus::MatchingHalted
+        )
+    }
+
+    pub fn allows_matching(&self) -> bool {
+        matches!(
+            self,
+            ContractStatus::Active | ContractStatus::CreationHalted
+        )
+    }
+}
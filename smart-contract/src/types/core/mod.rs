@@ -1,3 +1,6 @@
 pub mod constants;
+pub mod contract_role;
+pub mod contract_status;
 pub mod error;
 pub mod msg;
+pub mod quote_denom_allowlist;
@@ -1,9 +1,14 @@
+use crate::types::core::contract_role::ContractRole;
 use crate::types::request::admin_match_options::AdminMatchOptions;
 use crate::types::request::ask_types::ask::Ask;
 use crate::types::request::bid_types::bid::Bid;
+use crate::types::request::match_batch::{BatchMatchMode, MatchPair};
+use crate::types::request::match_candidates::MatchCandidateOptions;
 use crate::types::request::request_descriptor::RequestDescriptor;
-use crate::types::request::search::Search;
+use crate::types::request::request_type::RequestType;
+use crate::types::request::search::{MatchSearch, Search};
 use crate::types::request::settings_update::SettingsUpdate;
+use crate::types::request::settlement_fees::SettlementFees;
 use cosmwasm_std::Uint128;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -14,6 +19,8 @@ pub struct InstantiateMsg {
     pub contract_name: String,
     pub create_ask_nhash_fee: Option<Uint128>,
     pub create_bid_nhash_fee: Option<Uint128>,
+    pub settlement_fees: Option<SettlementFees>,
+    pub fee_collector_address: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -46,9 +53,46 @@ pub enum ExecuteMsg {
         bid_id: String,
         admin_match_options: Option<AdminMatchOptions>,
     },
+    ExecuteMatches {
+        matches: Vec<MatchPair>,
+        mode: Option<BatchMatchMode>,
+    },
     UpdateSettings {
         update: SettingsUpdate,
     },
+    ExpireOrders {
+        limit: Option<Uint128>,
+    },
+    GrantRole {
+        address: String,
+        role: ContractRole,
+    },
+    RevokeRole {
+        address: String,
+        role: ContractRole,
+    },
+    ProposeAdmin {
+        address: String,
+    },
+    AcceptAdmin {},
+    CancelAdminProposal {},
+    AddQuoteDenoms {
+        denoms: Vec<String>,
+        request_type: Option<RequestType>,
+    },
+    RemoveQuoteDenoms {
+        denoms: Vec<String>,
+        request_type: Option<RequestType>,
+    },
+    BlockAddresses {
+        addresses: Vec<String>,
+    },
+    UnblockAddresses {
+        addresses: Vec<String>,
+    },
+    RegisterScopeStake {
+        scope_address: String,
+    },
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
@@ -63,11 +107,25 @@ pub enum QueryMsg {
     GetBid {
         id: String,
     },
+    /// Fetches a page of the bids that target the given marker address, marker denom, or scope
+    /// address.  Pass the next_start_after value of a result as start_after to fetch the next page.
+    GetBidsByCollateralId {
+        collateral_id: String,
+        page_size: Option<Uint128>,
+        start_after: Option<String>,
+    },
     GetMatchReport {
         ask_id: String,
         bid_id: String,
         admin_match_options: Option<AdminMatchOptions>,
     },
+    /// Finds the orders that can currently be matched with the given ask or bid, ranked by price,
+    /// each with a full match report.  Exactly one of ask_id or bid_id must be provided.
+    GetMatchCandidates {
+        ask_id: Option<String>,
+        bid_id: Option<String>,
+        options: Option<MatchCandidateOptions>,
+    },
     GetContractInfo {},
     SearchAsks {
         search: Search,
@@ -75,6 +133,17 @@ pub enum QueryMsg {
     SearchBids {
         search: Search,
     },
+    GetMatchHistory {
+        page_size: Option<Uint128>,
+        page_number: Option<Uint128>,
+    },
+    SearchMatches {
+        search: MatchSearch,
+    },
+    GetRoles {
+        address: String,
+    },
+    GetQuoteDenomAllowlist {},
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
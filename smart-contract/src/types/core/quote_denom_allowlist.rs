@@ -0,0 +1,109 @@
+use crate::types::request::request_type::RequestType;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+const ALL_REQUEST_TYPES_NAME: &str = "all request types";
+
+/// The denoms that may be used as quotes in asks and bids.  Denoms in `all_request_types` are
+/// allowed for every request type, and the remaining lists add denoms for a single request type.
+/// A request type is unrestricted when neither `all_request_types` nor its own list has any
+/// denoms, so denoms that would leave a restricted type without any allowed denoms cannot be
+/// removed.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct QuoteDenomAllowlist {
+    pub all_request_types: Vec<String>,
+    pub coin_trade: Vec<String>,
+    pub marker_trade: Vec<String>,
+    pub marker_share_sale: Vec<String>,
+    pub scope_trade: Vec<String>,
+    #[serde(default)]
+    pub scope_bundle_trade: Vec<String>,
+}
+impl QuoteDenomAllowlist {
+    /// Fetches the denoms added for the request type, or the denoms added for all request types
+    /// when no request type is provided.
+    pub fn get_denoms(&self, request_type: &Option<RequestType>) -> &Vec<String> {
+        match request_type {
+            None => &self.all_request_types,
+            Some(RequestType::CoinTrade) => &self.coin_trade,
+            Some(RequestType::MarkerTrade) => &self.marker_trade,
+            Some(RequestType::MarkerShareSale) => &self.marker_share_sale,
+            Some(RequestType::ScopeTrade) => &self.scope_trade,
+            Some(RequestType::ScopeBundleTrade) => &self.scope_bundle_trade,
+        }
+    }
+
+    pub fn get_denoms_mut(&mut self, request_type: &Option<RequestType>) -> &mut Vec<String> {
+        match request_type {
+            None => &mut self.all_request_types,
+            Some(RequestType::CoinTrade) => &mut self.coin_trade,
+            Some(RequestType::MarkerTrade) => &mut self.marker_trade,
+            Some(RequestType::MarkerShareSale) => &mut self.marker_share_sale,
+            Some(RequestType::ScopeTrade) => &mut self.scope_trade,
+            Some(RequestType::ScopeBundleTrade) => &mut self.scope_bundle_trade,
+        }
+    }
+
+    /// A request type is restricted when any denom is allowed for it or for all request types.
+    pub fn is_restricted(&self, request_type: &RequestType) -> bool {
+        !self.all_request_types.is_empty()
+            || !self.get_denoms(&Some(request_type.to_owned())).is_empty()
+    }
+
+    pub fn is_denom_allowed(&self, request_type: &RequestType, denom: &str) -> bool {
+        if !self.is_restricted(request_type) {
+            return true;
+        }
+        self.all_request_types.iter().any(|d| d == denom)
+            || self
+                .get_denoms(&Some(request_type.to_owned()))
+                .iter()
+                .any(|d| d == denom)
+    }
+}
+
+pub fn get_allowlist_target_name(request_type: &Option<RequestType>) -> String {
+    request_type
+        .as_ref()
+        .map(|t| t.get_name())
+        .unwrap_or(ALL_REQUEST_TYPES_NAME)
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::core::quote_denom_allowlist::QuoteDenomAllowlist;
+    use crate::types::request::request_type::RequestType;
+
+    #[test]
+    fn test_is_denom_allowed() {
+        let mut allowlist = QuoteDenomAllowlist::default();
+        assert!(
+            allowlist.is_denom_allowed(&RequestType::CoinTrade, "spamcoin"),
+            "any denom should be allowed when no allowlist is configured",
+        );
+        allowlist.coin_trade.push("usd".to_string());
+        assert!(
+            allowlist.is_denom_allowed(&RequestType::CoinTrade, "usd"),
+            "a denom in the request type's list should be allowed",
+        );
+        assert!(
+            !allowlist.is_denom_allowed(&RequestType::CoinTrade, "spamcoin"),
+            "a denom missing from the request type's list should not be allowed",
+        );
+        assert!(
+            allowlist.is_denom_allowed(&RequestType::ScopeTrade, "spamcoin"),
+            "request types without their own list should remain unrestricted",
+        );
+        allowlist.all_request_types.push("nhash".to_string());
+        assert!(
+            allowlist.is_denom_allowed(&RequestType::CoinTrade, "nhash"),
+            "a denom allowed for all request types should be allowed for a restricted type",
+        );
+        assert!(
+            !allowlist.is_denom_allowed(&RequestType::ScopeTrade, "usd"),
+            "request types should become restricted once denoms are allowed for all types",
+        );
+    }
+}
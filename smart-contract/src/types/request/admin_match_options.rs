@@ -28,6 +28,11 @@ pub enum AdminMatchOptions {
         /// Defaults to false if not specified.
         accept_mismatched_bids: Option<bool>,
     },
+    ScopeBundleTrade {
+        /// Allows trades with an ask quote that does not match the bid quote to still be executed.
+        /// Defaults to false if not specified.
+        accept_mismatched_bids: Option<bool>,
+    },
 }
 #[cfg(test)]
 impl AdminMatchOptions {
@@ -78,6 +83,12 @@ impl AdminMatchOptions {
             accept_mismatched_bids: Some(accept_mismatched_bids),
         }
     }
+
+    pub fn scope_bundle_trade_options(accept_mismatched_bids: bool) -> Self {
+        Self::ScopeBundleTrade {
+            accept_mismatched_bids: Some(accept_mismatched_bids),
+        }
+    }
 }
 
 /// Determines the source of the quote to be sent to the asker after a match executes.  By standard,
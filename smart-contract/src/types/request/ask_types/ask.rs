@@ -1,3 +1,4 @@
+use crate::types::request::scope_transfer_policy::ScopeTransferPolicy;
 use crate::types::request::share_sale_type::ShareSaleType;
 use cosmwasm_std::{Coin, Uint128};
 use schemars::JsonSchema;
@@ -10,10 +11,15 @@ pub enum Ask {
     MarkerTrade(MarkerTradeAsk),
     MarkerShareSale(MarkerShareSaleAsk),
     ScopeTrade(ScopeTradeAsk),
+    ScopeBundleTrade(ScopeBundleTradeAsk),
 }
 impl Ask {
     pub fn new_coin_trade<S: Into<String>>(id: S, quote: &[Coin]) -> Self {
-        Self::CoinTrade(CoinTradeAsk::new(id, quote))
+        Self::CoinTrade(CoinTradeAsk::new(id, quote, None))
+    }
+
+    pub fn new_partial_fill_coin_trade<S: Into<String>>(id: S, quote: &[Coin]) -> Self {
+        Self::CoinTrade(CoinTradeAsk::new(id, quote, Some(true)))
     }
 
     pub fn new_marker_trade<S1: Into<String>, S2: Into<String>>(
@@ -45,7 +51,55 @@ impl Ask {
         scope_address: S2,
         quote: &[Coin],
     ) -> Self {
-        Self::ScopeTrade(ScopeTradeAsk::new(id, scope_address, quote))
+        Self::ScopeTrade(ScopeTradeAsk::new(id, scope_address, quote, None, None))
+    }
+
+    pub fn new_co_owned_scope_trade<
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<String> + Clone,
+    >(
+        id: S1,
+        scope_address: S2,
+        quote: &[Coin],
+        sold_owner_addresses: &[S3],
+    ) -> Self {
+        Self::ScopeTrade(ScopeTradeAsk::new(
+            id,
+            scope_address,
+            quote,
+            None,
+            Some(
+                sold_owner_addresses
+                    .iter()
+                    .cloned()
+                    .map(|s| s.into())
+                    .collect(),
+            ),
+        ))
+    }
+
+    pub fn new_scope_trade_with_transfer_policy<S1: Into<String>, S2: Into<String>>(
+        id: S1,
+        scope_address: S2,
+        quote: &[Coin],
+        transfer_policy: ScopeTransferPolicy,
+    ) -> Self {
+        Self::ScopeTrade(ScopeTradeAsk::new(
+            id,
+            scope_address,
+            quote,
+            Some(transfer_policy),
+            None,
+        ))
+    }
+
+    pub fn new_scope_bundle_trade<S1: Into<String>, S2: Into<String> + Clone>(
+        id: S1,
+        scope_addresses: &[S2],
+        quote: &[Coin],
+    ) -> Self {
+        Self::ScopeBundleTrade(ScopeBundleTradeAsk::new(id, scope_addresses, quote))
     }
 
     pub fn get_id(&self) -> &str {
@@ -54,6 +108,7 @@ impl Ask {
             Self::MarkerTrade(trade) => &trade.id,
             Self::MarkerShareSale(sale) => &sale.id,
             Self::ScopeTrade(trade) => &trade.id,
+            Self::ScopeBundleTrade(trade) => &trade.id,
         }
     }
 
@@ -66,13 +121,19 @@ impl Ask {
 #[serde(rename_all = "snake_case")]
 pub struct CoinTradeAsk {
     pub id: String,
+    /// The quote requested for the entirety of the base funds sent with the ask.
     pub quote: Vec<Coin>,
+    /// When true, bids may purchase a portion of the base, paying a quote proportional to the
+    /// ratio of the full quote to the full base.  The base must be a single coin.  Defaults to
+    /// false if not specified.
+    pub allow_partial_fills: Option<bool>,
 }
 impl CoinTradeAsk {
-    pub fn new<S: Into<String>>(id: S, quote: &[Coin]) -> Self {
+    pub fn new<S: Into<String>>(id: S, quote: &[Coin], allow_partial_fills: Option<bool>) -> Self {
         Self {
             id: id.into(),
             quote: quote.to_owned(),
+            allow_partial_fills,
         }
     }
 }
@@ -131,17 +192,54 @@ pub struct ScopeTradeAsk {
     pub id: String,
     pub scope_address: String,
     pub quote: Vec<Coin>,
+    /// Controls how the scope's parties, value owner and data access are modified when it is
+    /// transferred to the bidder.  The default behavior is used if not specified.
+    pub transfer_policy: Option<ScopeTransferPolicy>,
+    /// The addresses of the scope's owners whose stakes are sold by this ask.  When provided, the
+    /// scope may have multiple owners.  Each of these owners must have been replaced on the scope
+    /// by a single Owner party for the contract before the ask is created.  On a match, the
+    /// contract's party is replaced by the bidder, and on a cancellation, it is replaced by these
+    /// addresses.  All other owners are left unchanged.
+    pub sold_owner_addresses: Option<Vec<String>>,
 }
 impl ScopeTradeAsk {
     pub fn new<S1: Into<String>, S2: Into<String>>(
         id: S1,
         scope_address: S2,
         quote: &[Coin],
+        transfer_policy: Option<ScopeTransferPolicy>,
+        sold_owner_addresses: Option<Vec<String>>,
     ) -> Self {
         Self {
             id: id.into(),
             scope_address: scope_address.into(),
             quote: quote.to_owned(),
+            transfer_policy,
+            sold_owner_addresses,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ScopeBundleTradeAsk {
+    pub id: String,
+    /// Every scope in the bundle.  All scopes are transferred together to the bidder on a match,
+    /// and all are returned to the asker on a cancellation.
+    pub scope_addresses: Vec<String>,
+    /// The quote requested for the entire bundle.
+    pub quote: Vec<Coin>,
+}
+impl ScopeBundleTradeAsk {
+    pub fn new<S1: Into<String>, S2: Into<String> + Clone>(
+        id: S1,
+        scope_addresses: &[S2],
+        quote: &[Coin],
+    ) -> Self {
+        Self {
+            id: id.into(),
+            scope_addresses: scope_addresses.iter().cloned().map(|s| s.into()).collect(),
+            quote: quote.to_owned(),
         }
     }
 }
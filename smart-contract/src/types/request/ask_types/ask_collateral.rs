@@ -1,7 +1,8 @@
 use crate::types::core::error::ContractError;
+use crate::types::request::scope_transfer_policy::ScopeTransferPolicy;
 use crate::types::request::share_sale_type::ShareSaleType;
 use crate::util::extensions::ResultExtensions;
-use cosmwasm_std::{Addr, Coin, Uint128};
+use cosmwasm_std::{coin, Addr, Coin, Uint128};
 use provwasm_std::AccessGrant;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -13,10 +14,19 @@ pub enum AskCollateral {
     MarkerTrade(MarkerTradeAskCollateral),
     MarkerShareSale(MarkerShareSaleAskCollateral),
     ScopeTrade(ScopeTradeAskCollateral),
+    ScopeBundleTrade(ScopeBundleTradeAskCollateral),
 }
 impl AskCollateral {
     pub fn coin_trade(base: &[Coin], quote: &[Coin]) -> Self {
-        Self::CoinTrade(CoinTradeAskCollateral::new(base, quote))
+        Self::CoinTrade(CoinTradeAskCollateral::new(base, quote, None))
+    }
+
+    pub fn partial_fill_coin_trade(base: &[Coin], quote: &[Coin], remaining_base: u128) -> Self {
+        Self::CoinTrade(CoinTradeAskCollateral::new(
+            base,
+            quote,
+            Some(Uint128::new(remaining_base)),
+        ))
     }
 
     pub fn marker_trade<S: Into<String>>(
@@ -56,7 +66,54 @@ impl AskCollateral {
     }
 
     pub fn scope_trade<S: Into<String>>(scope_address: S, quote: &[Coin]) -> Self {
-        Self::ScopeTrade(ScopeTradeAskCollateral::new(scope_address, quote))
+        Self::ScopeTrade(ScopeTradeAskCollateral::new(
+            scope_address,
+            quote,
+            None,
+            None,
+        ))
+    }
+
+    pub fn scope_trade_with_transfer_policy<S: Into<String>>(
+        scope_address: S,
+        quote: &[Coin],
+        transfer_policy: Option<ScopeTransferPolicy>,
+    ) -> Self {
+        Self::ScopeTrade(ScopeTradeAskCollateral::new(
+            scope_address,
+            quote,
+            transfer_policy,
+            None,
+        ))
+    }
+
+    pub fn co_owned_scope_trade<S: Into<String>>(
+        scope_address: S,
+        quote: &[Coin],
+        transfer_policy: Option<ScopeTransferPolicy>,
+        sold_owner_addresses: &[Addr],
+    ) -> Self {
+        Self::ScopeTrade(ScopeTradeAskCollateral::new(
+            scope_address,
+            quote,
+            transfer_policy,
+            Some(sold_owner_addresses.to_vec()),
+        ))
+    }
+
+    pub fn scope_bundle_trade<S: Into<String> + Clone>(
+        scope_addresses: &[S],
+        quote: &[Coin],
+    ) -> Self {
+        Self::ScopeBundleTrade(ScopeBundleTradeAskCollateral::new(scope_addresses, quote))
+    }
+
+    /// Records the scope specification of a scope trade's scope.  Has no effect on other types.
+    pub fn with_scope_spec_id<S: Into<String>>(mut self, scope_spec_id: S) -> Self {
+        if let Self::ScopeTrade(ref mut collateral) = self {
+            collateral.scope_spec_id = Some(scope_spec_id.into());
+        }
+        self
     }
 
     pub fn get_coin_trade(&self) -> Result<&CoinTradeAskCollateral, ContractError> {
@@ -98,6 +155,27 @@ impl AskCollateral {
             .to_err(),
         }
     }
+
+    pub fn get_scope_bundle_trade(&self) -> Result<&ScopeBundleTradeAskCollateral, ContractError> {
+        match self {
+            AskCollateral::ScopeBundleTrade(collateral) => collateral.to_ok(),
+            _ => ContractError::InvalidType {
+                explanation: "expected scope bundle trade ask collateral".to_string(),
+            }
+            .to_err(),
+        }
+    }
+
+    /// Fetches the quote requested by the asker.  Marker asks request a quote per share.
+    pub fn get_quote(&self) -> Vec<Coin> {
+        match self {
+            AskCollateral::CoinTrade(c) => c.quote.to_owned(),
+            AskCollateral::MarkerTrade(c) => c.quote_per_share.to_owned(),
+            AskCollateral::MarkerShareSale(c) => c.quote_per_share.to_owned(),
+            AskCollateral::ScopeTrade(c) => c.quote.to_owned(),
+            AskCollateral::ScopeBundleTrade(c) => c.quote.to_owned(),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -105,14 +183,54 @@ impl AskCollateral {
 pub struct CoinTradeAskCollateral {
     pub base: Vec<Coin>,
     pub quote: Vec<Coin>,
+    /// Only populated for asks that allow partial fills.  Partial fill asks always have a single
+    /// base coin, and this value indicates how much of that coin is still held by the contract.
+    /// The base and quote retain their original values, defining the price ratio for each fill.
+    pub remaining_base: Option<Uint128>,
 }
 impl CoinTradeAskCollateral {
-    fn new(base: &[Coin], quote: &[Coin]) -> Self {
+    fn new(base: &[Coin], quote: &[Coin], remaining_base: Option<Uint128>) -> Self {
         Self {
             base: base.to_owned(),
             quote: quote.to_owned(),
+            remaining_base,
         }
     }
+
+    pub fn allows_partial_fills(&self) -> bool {
+        self.remaining_base.is_some()
+    }
+
+    /// Produces the base funds currently held by the contract for this ask.
+    pub fn get_remaining_base(&self) -> Vec<Coin> {
+        match (self.remaining_base, self.base.first()) {
+            (Some(remaining_base), Some(base_coin)) => {
+                vec![coin(remaining_base.u128(), &base_coin.denom)]
+            }
+            _ => self.base.to_owned(),
+        }
+    }
+
+    /// Calculates the quote owed for the given amount of the base coin, based on the ratio of the
+    /// total base to the total quote.  Returns None if any coin in the quote cannot be evenly
+    /// divided for the amount.
+    pub fn get_quote_for_base_amount(&self, base_amount: u128) -> Option<Vec<Coin>> {
+        let total_base = self.base.first()?.amount.u128();
+        if total_base == 0 {
+            return None;
+        }
+        self.quote
+            .iter()
+            .map(|quote_coin| {
+                let numerator = quote_coin.amount.u128().checked_mul(base_amount)?;
+                if numerator % total_base == 0 {
+                    Some(coin(numerator / total_base, &quote_coin.denom))
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -180,12 +298,44 @@ impl MarkerShareSaleAskCollateral {
 pub struct ScopeTradeAskCollateral {
     pub scope_address: String,
     pub quote: Vec<Coin>,
+    pub transfer_policy: Option<ScopeTransferPolicy>,
+    /// Only populated for co-owned scopes.  The owners whose stakes the contract holds in escrow.
+    pub sold_owner_addresses: Option<Vec<Addr>>,
+    /// The specification of the scope, recorded when the ask is created.
+    pub scope_spec_id: Option<String>,
 }
 impl ScopeTradeAskCollateral {
-    fn new<S: Into<String>>(scope_address: S, quote: &[Coin]) -> Self {
+    fn new<S: Into<String>>(
+        scope_address: S,
+        quote: &[Coin],
+        transfer_policy: Option<ScopeTransferPolicy>,
+        sold_owner_addresses: Option<Vec<Addr>>,
+    ) -> Self {
         Self {
             scope_address: scope_address.into(),
             quote: quote.to_owned(),
+            transfer_policy,
+            sold_owner_addresses,
+            scope_spec_id: None,
+        }
+    }
+
+    pub fn is_co_owned(&self) -> bool {
+        self.sold_owner_addresses.is_some()
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ScopeBundleTradeAskCollateral {
+    pub scope_addresses: Vec<String>,
+    pub quote: Vec<Coin>,
+}
+impl ScopeBundleTradeAskCollateral {
+    fn new<S: Into<String> + Clone>(scope_addresses: &[S], quote: &[Coin]) -> Self {
+        Self {
+            scope_addresses: scope_addresses.iter().cloned().map(|s| s.into()).collect(),
+            quote: quote.to_owned(),
         }
     }
 }
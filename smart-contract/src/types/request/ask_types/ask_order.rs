@@ -1,7 +1,7 @@
 use crate::types::request::ask_types::ask_collateral::AskCollateral;
-use crate::types::request::request_descriptor::RequestDescriptor;
+use crate::types::request::request_descriptor::{DescribedOrder, RequestDescriptor};
 use crate::types::request::request_type::RequestType;
-use cosmwasm_std::Addr;
+use cosmwasm_std::{Addr, Coin, Timestamp};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -13,6 +13,9 @@ pub struct AskOrder {
     pub owner: Addr,
     pub collateral: AskCollateral,
     pub descriptor: Option<RequestDescriptor>,
+    /// The block time at which the order was created.  Updates retain the original value.  Not
+    /// populated for orders created by contract versions that did not record it.
+    pub created_time: Option<Timestamp>,
 }
 impl AskOrder {
     pub fn get_collateral_index(&self) -> String {
@@ -25,6 +28,89 @@ impl AskOrder {
             AskCollateral::MarkerShareSale(collateral) => collateral.marker_address.to_string(),
             // Scope trades include a scope address - only one ask per scope should be created at a time
             AskCollateral::ScopeTrade(collateral) => collateral.scope_address.to_owned(),
+            // Scope bundle trades hold many scopes, so the ask id is used as a duplicate index.
+            // Each bundled scope is found through the scope address index instead.
+            AskCollateral::ScopeBundleTrade(_) => self.id.clone(),
         }
     }
+
+    pub fn get_marker_denom_index(&self) -> Vec<String> {
+        match &self.collateral {
+            AskCollateral::MarkerTrade(collateral) => vec![collateral.marker_denom.to_owned()],
+            AskCollateral::MarkerShareSale(collateral) => vec![collateral.marker_denom.to_owned()],
+            _ => vec![],
+        }
+    }
+
+    pub fn get_base_denom_index(&self) -> Vec<String> {
+        match &self.collateral {
+            AskCollateral::CoinTrade(collateral) => collateral
+                .base
+                .iter()
+                .map(|coin| coin.denom.to_owned())
+                .collect(),
+            _ => vec![],
+        }
+    }
+
+    pub fn get_scope_address_index(&self) -> Vec<String> {
+        match &self.collateral {
+            AskCollateral::ScopeTrade(collateral) => vec![collateral.scope_address.to_owned()],
+            AskCollateral::ScopeBundleTrade(collateral) => collateral.scope_addresses.to_owned(),
+            _ => vec![],
+        }
+    }
+
+    pub fn get_scope_spec_index(&self) -> Vec<String> {
+        match &self.collateral {
+            // Scope trades created before scope specifications were recorded are not indexed
+            AskCollateral::ScopeTrade(collateral) => {
+                collateral.scope_spec_id.iter().cloned().collect()
+            }
+            _ => vec![],
+        }
+    }
+
+    pub fn get_quote_denom_index(&self) -> Vec<String> {
+        self.collateral
+            .get_quote()
+            .into_iter()
+            .map(|coin| coin.denom)
+            .collect()
+    }
+
+    pub fn get_expiration_index(&self) -> u64 {
+        // Asks without an expiration time are keyed to the maximum possible value, ensuring that
+        // they are never included in a range search for expired orders
+        self.descriptor
+            .as_ref()
+            .and_then(|d| d.expiration_time)
+            .map(|time| time.nanos())
+            .unwrap_or(u64::MAX)
+    }
+}
+impl DescribedOrder for AskOrder {
+    fn get_id(&self) -> &str {
+        &self.id
+    }
+
+    fn get_descriptor(&self) -> &Option<RequestDescriptor> {
+        &self.descriptor
+    }
+
+    fn get_owner(&self) -> &Addr {
+        &self.owner
+    }
+
+    fn get_request_type(&self) -> &RequestType {
+        &self.ask_type
+    }
+
+    fn get_quote(&self) -> Vec<Coin> {
+        self.collateral.get_quote()
+    }
+
+    fn get_created_time(&self) -> Option<Timestamp> {
+        self.created_time
+    }
 }
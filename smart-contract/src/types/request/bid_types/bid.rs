@@ -1,3 +1,4 @@
+use crate::types::request::request_descriptor::AttributeRequirement;
 use cosmwasm_std::{Coin, Uint128};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -9,6 +10,8 @@ pub enum Bid {
     MarkerTrade(MarkerTradeBid),
     MarkerShareSale(MarkerShareSaleBid),
     ScopeTrade(ScopeTradeBid),
+    ScopeBundleTrade(ScopeBundleTradeBid),
+    ScopeSpecTrade(ScopeSpecTradeBid),
 }
 impl Bid {
     pub fn new_coin_trade<S: Into<String>>(id: S, base: &[Coin]) -> Self {
@@ -39,12 +42,35 @@ impl Bid {
         Self::ScopeTrade(ScopeTradeBid::new(id, scope_address))
     }
 
+    pub fn new_scope_bundle_trade<S1: Into<String>, S2: Into<String> + Clone>(
+        id: S1,
+        scope_addresses: &[S2],
+    ) -> Self {
+        Self::ScopeBundleTrade(ScopeBundleTradeBid::new(id, scope_addresses))
+    }
+
+    pub fn new_scope_spec_trade<S1: Into<String>, S2: Into<String>>(
+        id: S1,
+        scope_spec_id: S2,
+        scope_attribute_requirement: Option<AttributeRequirement>,
+        require_value_ownership: Option<bool>,
+    ) -> Self {
+        Self::ScopeSpecTrade(ScopeSpecTradeBid::new(
+            id,
+            scope_spec_id,
+            scope_attribute_requirement,
+            require_value_ownership,
+        ))
+    }
+
     pub fn get_id(&self) -> &str {
         match self {
             Self::CoinTrade(trade) => &trade.id,
             Self::MarkerTrade(trade) => &trade.id,
             Self::MarkerShareSale(sale) => &sale.id,
             Self::ScopeTrade(trade) => &trade.id,
+            Self::ScopeBundleTrade(trade) => &trade.id,
+            Self::ScopeSpecTrade(trade) => &trade.id,
         }
     }
 
@@ -124,3 +150,49 @@ impl ScopeTradeBid {
         }
     }
 }
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ScopeBundleTradeBid {
+    pub id: String,
+    /// Every scope in the bundle being bid on.  Must contain the same scopes as the ask's bundle,
+    /// in any order.
+    pub scope_addresses: Vec<String>,
+}
+impl ScopeBundleTradeBid {
+    pub fn new<S1: Into<String>, S2: Into<String> + Clone>(id: S1, scope_addresses: &[S2]) -> Self {
+        Self {
+            id: id.into(),
+            scope_addresses: scope_addresses.iter().cloned().map(|s| s.into()).collect(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ScopeSpecTradeBid {
+    pub id: String,
+    /// The scope specification that an ask's scope must use to be matched with this bid.  Any
+    /// scope trade ask with a scope of this specification is eligible.
+    pub scope_spec_id: String,
+    /// Attributes that the ask's scope address must have to be matched with this bid.
+    pub scope_attribute_requirement: Option<AttributeRequirement>,
+    /// When true, the ask's scope must have its value ownership held by the contract, ensuring
+    /// that the bidder receives value ownership of the scope.  Defaults to false if not specified.
+    pub require_value_ownership: Option<bool>,
+}
+impl ScopeSpecTradeBid {
+    pub fn new<S1: Into<String>, S2: Into<String>>(
+        id: S1,
+        scope_spec_id: S2,
+        scope_attribute_requirement: Option<AttributeRequirement>,
+        require_value_ownership: Option<bool>,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            scope_spec_id: scope_spec_id.into(),
+            scope_attribute_requirement,
+            require_value_ownership,
+        }
+    }
+}
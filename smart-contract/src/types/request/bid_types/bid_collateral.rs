@@ -1,4 +1,5 @@
 use crate::types::core::error::ContractError;
+use crate::types::request::request_descriptor::AttributeRequirement;
 use crate::util::coin_utilities::divide_coins_by_amount;
 use crate::util::extensions::ResultExtensions;
 use cosmwasm_std::{Addr, Coin, Uint128};
@@ -12,6 +13,8 @@ pub enum BidCollateral {
     MarkerTrade(MarkerTradeBidCollateral),
     MarkerShareSale(MarkerShareSaleBidCollateral),
     ScopeTrade(ScopeTradeBidCollateral),
+    ScopeBundleTrade(ScopeBundleTradeBidCollateral),
+    ScopeSpecTrade(ScopeSpecTradeBidCollateral),
 }
 impl BidCollateral {
     pub fn coin_trade(base: &[Coin], quote: &[Coin]) -> Self {
@@ -50,6 +53,27 @@ impl BidCollateral {
         Self::ScopeTrade(ScopeTradeBidCollateral::new(scope_address, quote))
     }
 
+    pub fn scope_bundle_trade<S: Into<String> + Clone>(
+        scope_addresses: &[S],
+        quote: &[Coin],
+    ) -> Self {
+        Self::ScopeBundleTrade(ScopeBundleTradeBidCollateral::new(scope_addresses, quote))
+    }
+
+    pub fn scope_spec_trade<S: Into<String>>(
+        scope_spec_id: S,
+        quote: &[Coin],
+        scope_attribute_requirement: Option<AttributeRequirement>,
+        require_value_ownership: Option<bool>,
+    ) -> Self {
+        Self::ScopeSpecTrade(ScopeSpecTradeBidCollateral::new(
+            scope_spec_id,
+            quote,
+            scope_attribute_requirement,
+            require_value_ownership,
+        ))
+    }
+
     pub fn get_coin_trade(&self) -> Result<&CoinTradeBidCollateral, ContractError> {
         match self {
             Self::CoinTrade(collateral) => collateral.to_ok(),
@@ -90,12 +114,34 @@ impl BidCollateral {
         }
     }
 
+    pub fn get_scope_bundle_trade(&self) -> Result<&ScopeBundleTradeBidCollateral, ContractError> {
+        match self {
+            Self::ScopeBundleTrade(collateral) => collateral.to_ok(),
+            _ => ContractError::InvalidType {
+                explanation: "expected scope bundle trade bid collateral".to_string(),
+            }
+            .to_err(),
+        }
+    }
+
+    pub fn get_scope_spec_trade(&self) -> Result<&ScopeSpecTradeBidCollateral, ContractError> {
+        match self {
+            Self::ScopeSpecTrade(collateral) => collateral.to_ok(),
+            _ => ContractError::InvalidType {
+                explanation: "expected scope spec trade bid collateral".to_string(),
+            }
+            .to_err(),
+        }
+    }
+
     pub fn get_quote(&self) -> Vec<Coin> {
         match self {
             BidCollateral::CoinTrade(c) => c.quote.to_owned(),
             BidCollateral::MarkerTrade(c) => c.quote.to_owned(),
             BidCollateral::MarkerShareSale(c) => c.quote.to_owned(),
             BidCollateral::ScopeTrade(c) => c.quote.to_owned(),
+            BidCollateral::ScopeBundleTrade(c) => c.quote.to_owned(),
+            BidCollateral::ScopeSpecTrade(c) => c.quote.to_owned(),
         }
     }
 }
@@ -182,6 +228,51 @@ impl ScopeTradeBidCollateral {
     }
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ScopeBundleTradeBidCollateral {
+    pub scope_addresses: Vec<String>,
+    pub quote: Vec<Coin>,
+}
+impl ScopeBundleTradeBidCollateral {
+    pub fn new<S: Into<String> + Clone>(scope_addresses: &[S], quote: &[Coin]) -> Self {
+        Self {
+            scope_addresses: scope_addresses.iter().cloned().map(|s| s.into()).collect(),
+            quote: quote.to_owned(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ScopeSpecTradeBidCollateral {
+    pub scope_spec_id: String,
+    /// The maximum quote that the bidder will pay for a scope.  Only the ask's quote is paid
+    /// when it is lower, and the remainder is refunded to the bidder.
+    pub quote: Vec<Coin>,
+    pub scope_attribute_requirement: Option<AttributeRequirement>,
+    pub require_value_ownership: Option<bool>,
+}
+impl ScopeSpecTradeBidCollateral {
+    pub fn new<S: Into<String>>(
+        scope_spec_id: S,
+        quote: &[Coin],
+        scope_attribute_requirement: Option<AttributeRequirement>,
+        require_value_ownership: Option<bool>,
+    ) -> Self {
+        Self {
+            scope_spec_id: scope_spec_id.into(),
+            quote: quote.to_owned(),
+            scope_attribute_requirement,
+            require_value_ownership,
+        }
+    }
+
+    pub fn requires_value_ownership(&self) -> bool {
+        self.require_value_ownership.unwrap_or(false)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::test::mock_marker::{DEFAULT_MARKER_ADDRESS, DEFAULT_MARKER_DENOM};
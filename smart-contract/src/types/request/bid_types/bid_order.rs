@@ -1,7 +1,7 @@
 use crate::types::request::bid_types::bid_collateral::BidCollateral;
-use crate::types::request::request_descriptor::RequestDescriptor;
+use crate::types::request::request_descriptor::{DescribedOrder, RequestDescriptor};
 use crate::types::request::request_type::RequestType;
-use cosmwasm_std::Addr;
+use cosmwasm_std::{Addr, Coin, Timestamp};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -13,4 +13,104 @@ pub struct BidOrder {
     pub owner: Addr,
     pub collateral: BidCollateral,
     pub descriptor: Option<RequestDescriptor>,
+    /// The block time at which the order was created.  Updates retain the original value.  Not
+    /// populated for orders created by contract versions that did not record it.
+    pub created_time: Option<Timestamp>,
+}
+impl BidOrder {
+    /// Produces the addresses and denoms of the markers and scopes that the bid targets.  Marker
+    /// bids can be found by either their marker address or their marker denom.
+    pub fn get_collateral_index(&self) -> Vec<String> {
+        match &self.collateral {
+            BidCollateral::MarkerTrade(collateral) => vec![
+                collateral.marker_address.to_string(),
+                collateral.marker_denom.to_owned(),
+            ],
+            BidCollateral::MarkerShareSale(collateral) => vec![
+                collateral.marker_address.to_string(),
+                collateral.marker_denom.to_owned(),
+            ],
+            BidCollateral::ScopeTrade(_) | BidCollateral::ScopeBundleTrade(_) => {
+                self.get_scope_address_index()
+            }
+            _ => vec![],
+        }
+    }
+
+    pub fn get_marker_denom_index(&self) -> Vec<String> {
+        match &self.collateral {
+            BidCollateral::MarkerTrade(collateral) => vec![collateral.marker_denom.to_owned()],
+            BidCollateral::MarkerShareSale(collateral) => vec![collateral.marker_denom.to_owned()],
+            _ => vec![],
+        }
+    }
+
+    pub fn get_base_denom_index(&self) -> Vec<String> {
+        match &self.collateral {
+            BidCollateral::CoinTrade(collateral) => collateral
+                .base
+                .iter()
+                .map(|coin| coin.denom.to_owned())
+                .collect(),
+            _ => vec![],
+        }
+    }
+
+    pub fn get_scope_address_index(&self) -> Vec<String> {
+        match &self.collateral {
+            BidCollateral::ScopeTrade(collateral) => vec![collateral.scope_address.to_owned()],
+            BidCollateral::ScopeBundleTrade(collateral) => collateral.scope_addresses.to_owned(),
+            _ => vec![],
+        }
+    }
+
+    pub fn get_scope_spec_index(&self) -> Vec<String> {
+        match &self.collateral {
+            BidCollateral::ScopeSpecTrade(collateral) => vec![collateral.scope_spec_id.to_owned()],
+            _ => vec![],
+        }
+    }
+
+    pub fn get_quote_denom_index(&self) -> Vec<String> {
+        self.collateral
+            .get_quote()
+            .into_iter()
+            .map(|coin| coin.denom)
+            .collect()
+    }
+
+    pub fn get_expiration_index(&self) -> u64 {
+        // Bids without an expiration time are keyed to the maximum possible value, ensuring that
+        // they are never included in a range search for expired orders
+        self.descriptor
+            .as_ref()
+            .and_then(|d| d.expiration_time)
+            .map(|time| time.nanos())
+            .unwrap_or(u64::MAX)
+    }
+}
+impl DescribedOrder for BidOrder {
+    fn get_id(&self) -> &str {
+        &self.id
+    }
+
+    fn get_descriptor(&self) -> &Option<RequestDescriptor> {
+        &self.descriptor
+    }
+
+    fn get_owner(&self) -> &Addr {
+        &self.owner
+    }
+
+    fn get_request_type(&self) -> &RequestType {
+        &self.bid_type
+    }
+
+    fn get_quote(&self) -> Vec<Coin> {
+        self.collateral.get_quote()
+    }
+
+    fn get_created_time(&self) -> Option<Timestamp> {
+        self.created_time
+    }
 }
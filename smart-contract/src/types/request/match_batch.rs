@@ -0,0 +1,63 @@
+use crate::types::request::admin_match_options::AdminMatchOptions;
+use cosmwasm_std::{Coin, Uint128};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// A single ask and bid combination to be executed as part of a batch of matches.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct MatchPair {
+    pub ask_id: String,
+    pub bid_id: String,
+    pub admin_match_options: Option<AdminMatchOptions>,
+}
+impl MatchPair {
+    pub fn new<A: Into<String>, B: Into<String>>(
+        ask_id: A,
+        bid_id: B,
+        admin_match_options: Option<AdminMatchOptions>,
+    ) -> Self {
+        Self {
+            ask_id: ask_id.into(),
+            bid_id: bid_id.into(),
+            admin_match_options,
+        }
+    }
+}
+
+/// Dictates how a batch of matches handles a failure in any of its pairs.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BatchMatchMode {
+    /// Any failing pair causes the entire batch to be rejected, and no matches are executed.
+    AllOrNothing,
+    /// Failing pairs are skipped and reported in the results, and all other pairs are executed.
+    /// Errors raised after a pair's orders have been modified still reject the entire batch.
+    BestEffort,
+}
+
+/// The outcome of a single pair in a batch of matches.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct MatchPairResult {
+    pub ask_id: String,
+    pub bid_id: String,
+    pub success: bool,
+    pub ask_deleted: bool,
+    pub bid_deleted: bool,
+    pub collateral_released: bool,
+    /// The id of the stored match record.  Only populated when the match succeeds.
+    pub match_record_id: Option<Uint128>,
+    /// The portion of the quote sent to the fee collector.  Empty when the match fails.
+    pub settlement_fee: Vec<Coin>,
+    /// The reason that the match was not executed.  Only populated when the match fails.
+    pub error_message: Option<String>,
+}
+
+/// The data emitted by the contract after a batch of matches is executed, containing one result
+/// for each requested pair in the order that they were provided.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ExecuteMatchesResponse {
+    pub results: Vec<MatchPairResult>,
+}
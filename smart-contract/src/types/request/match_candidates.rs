@@ -0,0 +1,70 @@
+use crate::types::request::admin_match_options::AdminMatchOptions;
+use crate::types::request::match_report::MatchReport;
+use cosmwasm_std::{Coin, Uint128};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Alters how the candidates for a match with an order are found and ranked.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct MatchCandidateOptions {
+    /// The admin match options with which each candidate is validated, allowing candidates that
+    /// could only be matched by the admin to be found.
+    pub admin_match_options: Option<AdminMatchOptions>,
+    /// The denom by which candidates are ranked.  Defaults to the first denom of the order's quote.
+    pub quote_denom: Option<String>,
+    /// The maximum number of candidates to return.
+    pub limit: Option<Uint128>,
+}
+impl MatchCandidateOptions {
+    pub fn new() -> Self {
+        Self {
+            admin_match_options: None,
+            quote_denom: None,
+            limit: None,
+        }
+    }
+
+    pub fn with_admin_match_options(mut self, admin_match_options: AdminMatchOptions) -> Self {
+        self.admin_match_options = Some(admin_match_options);
+        self
+    }
+
+    pub fn with_quote_denom<S: Into<String>>(mut self, quote_denom: S) -> Self {
+        self.quote_denom = Some(quote_denom.into());
+        self
+    }
+
+    pub fn with_limit(mut self, limit: u128) -> Self {
+        self.limit = Some(Uint128::new(limit));
+        self
+    }
+}
+impl Default for MatchCandidateOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// An order on the other side of the book that can currently be matched with the requested order.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct MatchCandidate {
+    /// The id of the candidate AskOrder or BidOrder.
+    pub order_id: String,
+    /// The quote that the bidder would pay if the match were executed, by which the candidate is
+    /// ranked.
+    pub quote_paid: Vec<Coin>,
+    pub report: MatchReport,
+}
+
+/// The candidates found for a match with an order, from the best ranked to the worst.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct MatchCandidatesResponse {
+    pub candidates: Vec<MatchCandidate>,
+    /// True when the contract stopped looking before every order on the other side of the book
+    /// was checked and fewer candidates than the limit were found, so matchable orders may have
+    /// been left out.
+    pub truncated: bool,
+}
@@ -0,0 +1,76 @@
+use crate::types::request::admin_match_options::AdminMatchOptions;
+use crate::types::request::request_type::RequestType;
+use cosmwasm_std::{Addr, Coin, Timestamp, Uint128};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// A permanent record of a successful match execution, stored by the contract after the ask and
+/// bid orders involved have been settled.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct MatchRecord {
+    /// A sequential identifier assigned by the contract when the record is stored.
+    pub id: Uint128,
+    pub ask_id: String,
+    pub bid_id: String,
+    pub asker: Addr,
+    pub bidder: Addr,
+    pub request_type: RequestType,
+    /// The funds taken from the bidder's quote to pay for the goods, including any settlement fee.
+    pub quote_paid: Vec<Coin>,
+    /// The portion of the quote paid that was sent to the fee collector instead of the asker.
+    pub settlement_fee: Vec<Coin>,
+    /// The goods that were transferred to the bidder.
+    pub collateral_transferred: MatchedCollateral,
+    /// The admin match options provided during execution, if any.  When this value is populated,
+    /// the match may have been executed with overrides to the default matching rules.
+    pub admin_match_options: Option<AdminMatchOptions>,
+    pub executed_by: Addr,
+    pub block_height: u64,
+    pub block_time: Timestamp,
+}
+impl MatchRecord {
+    /// Marker trades and marker share sales are indexed by their marker's denom.  All other types
+    /// use an empty string, which is never a valid search value.
+    pub fn get_marker_denom_index(&self) -> String {
+        match &self.collateral_transferred {
+            MatchedCollateral::MarkerTrade { marker_denom, .. }
+            | MatchedCollateral::MarkerShareSale { marker_denom, .. } => marker_denom.to_owned(),
+            _ => String::new(),
+        }
+    }
+
+    /// Scope trades are indexed by their scope's bech32 address.  All other types, including scope
+    /// bundle trades, use an empty string, which is never a valid search value.
+    pub fn get_scope_address_index(&self) -> String {
+        match &self.collateral_transferred {
+            MatchedCollateral::ScopeTrade { scope_address } => scope_address.to_owned(),
+            _ => String::new(),
+        }
+    }
+}
+
+/// Describes the goods that were moved from the asker to the bidder during a match.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum MatchedCollateral {
+    CoinTrade {
+        base: Vec<Coin>,
+    },
+    MarkerTrade {
+        marker_address: Addr,
+        marker_denom: String,
+        share_count: Uint128,
+    },
+    MarkerShareSale {
+        marker_address: Addr,
+        marker_denom: String,
+        shares_purchased: Uint128,
+    },
+    ScopeTrade {
+        scope_address: String,
+    },
+    ScopeBundleTrade {
+        scope_addresses: Vec<String>,
+    },
+}
@@ -1,7 +1,9 @@
+use crate::types::request::match_record::MatchedCollateral;
+use cosmwasm_std::{Coin, Uint128};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub struct MatchReport {
     pub ask_id: String,
@@ -9,5 +11,129 @@ pub struct MatchReport {
     pub ask_exists: bool,
     pub bid_exists: bool,
     pub match_possible: bool,
-    pub error_message: Option<String>,
+    /// Every reason that the match cannot be executed.  Empty when the match is possible.
+    pub validation_failures: Vec<MatchValidationFailure>,
+    /// The settlement fee rate charged for the ask's request type, in basis points.
+    pub settlement_fee_bps: Uint128,
+    /// The portion of the quote that would be sent to the fee collector if the match is executed.
+    pub settlement_fee: Vec<Coin>,
+    /// The outcome of executing the match.  Only populated when the match is possible.
+    pub settlement_preview: Option<SettlementPreview>,
+}
+
+/// A single reason that an ask and bid cannot be matched.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct MatchValidationFailure {
+    /// A stable identifier for the type of failure, intended for programmatic use.
+    pub code: MatchFailureCode,
+    /// The order that caused the failure.
+    pub side: MatchSide,
+    /// The path of the order field that caused the failure, ex: collateral.quote.
+    pub field: String,
+    /// A human-readable description of the failure.
+    pub message: String,
+}
+impl MatchValidationFailure {
+    pub fn new<F: Into<String>, M: Into<String>>(
+        code: MatchFailureCode,
+        side: MatchSide,
+        field: F,
+        message: M,
+    ) -> Self {
+        Self {
+            code,
+            side,
+            field: field.into(),
+            message: message.into(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum MatchFailureCode {
+    /// The order does not exist in contract storage.
+    OrderMissing,
+    /// The ask and bid are of different request types.
+    RequestTypeMismatch,
+    /// The ask and bid hold collateral of incompatible types.
+    CollateralTypeMismatch,
+    /// The admin match options provided are not of the variant for the trade type.
+    InvalidAdminMatchOptions,
+    /// The order's effective time has not yet been reached.
+    NotYetEffective,
+    /// The order's expiration time has passed.
+    Expired,
+    /// The order's owner is on the contract's address blocklist.
+    OwnerBlocked,
+    /// The bidder is not on the ask's list of allowed bidders.
+    BidderNotAllowed,
+    /// An account or scope does not meet the attribute requirements of the other order.
+    AttributeRequirementNotMet,
+    /// The bid base is not the base held by the ask.
+    BaseMismatch,
+    /// The bid base is more than the base remaining in a partial fill ask.
+    BaseExceedsRemaining,
+    /// The bid quote is not the quote requested by the ask.
+    QuoteMismatch,
+    /// The bid quote is less than the quote requested by the ask.
+    QuoteTooLow,
+    /// The ask quote cannot be evenly divided for the portion of the base requested by the bid.
+    QuoteNotDivisible,
+    /// The orders refer to markers with different denoms.
+    MarkerDenomMismatch,
+    /// The orders refer to markers with different addresses.
+    MarkerAddressMismatch,
+    /// The marker could not be found.
+    MarkerNotFound,
+    /// The marker does not hold a single coin of its own denom.
+    InvalidMarkerHoldings,
+    /// The marker's share count changed after the ask was created.
+    MarkerShareCountChanged,
+    /// The marker holds fewer shares than remain in the sale.
+    MarkerSharesUnavailable,
+    /// The bid requests fewer shares than the ask requires to be purchased.
+    ShareCountTooLow,
+    /// The orders refer to different scopes.
+    ScopeAddressMismatch,
+    /// The scope could not be found.
+    ScopeNotFound,
+    /// The ask's scope does not use the scope specification requested by the bid.
+    ScopeSpecMismatch,
+    /// The ask's scope does not have its value ownership held by the contract.
+    ScopeValueOwnershipNotHeld,
+    /// The orders passed validation, but the outcome of settling their match could not be
+    /// calculated.
+    SettlementFailed,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum MatchSide {
+    Ask,
+    Bid,
+    /// The failure is caused by a disagreement between the orders rather than either one alone.
+    Both,
+}
+
+/// Describes the funds and goods that would be moved, and the orders that would be removed, if a
+/// match were executed.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct SettlementPreview {
+    /// The funds taken from the bidder's quote to pay for the goods, including any settlement fee.
+    pub quote_paid: Vec<Coin>,
+    /// The portion of the quote paid that would be sent to the asker.
+    pub asker_proceeds: Vec<Coin>,
+    /// The goods that would be transferred to the bidder.
+    pub collateral_transferred: MatchedCollateral,
+    /// The unspent funds from the bid's quote that would be returned to the bidder.
+    pub bidder_refund: Vec<Coin>,
+    /// The marker shares that would be withdrawn from the marker into the bidder's account.
+    pub shares_withdrawn: Uint128,
+    pub ask_deleted: bool,
+    pub bid_deleted: bool,
+    /// Whether the contract would relinquish control of the ask's collateral.
+    pub collateral_released: bool,
 }
@@ -1,9 +1,14 @@
 pub mod admin_match_options;
 pub mod ask_types;
 pub mod bid_types;
+pub mod match_batch;
+pub mod match_candidates;
+pub mod match_record;
 pub mod match_report;
 pub mod request_descriptor;
 pub mod request_type;
+pub mod scope_transfer_policy;
 pub mod search;
 pub mod settings_update;
+pub mod settlement_fees;
 pub mod share_sale_type;
@@ -1,4 +1,6 @@
-use cosmwasm_std::Timestamp;
+use crate::types::request::request_type::RequestType;
+use cosmwasm_std::{Addr, Coin, Timestamp, Uint128};
+use provwasm_std::{Attribute, AttributeValueType};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -8,6 +10,20 @@ pub struct RequestDescriptor {
     pub description: Option<String>,
     pub effective_time: Option<Timestamp>,
     pub attribute_requirement: Option<AttributeRequirement>,
+    /// The time at which the ask or bid order will no longer be eligible for a match.  Expired
+    /// orders can be removed from the contract, and their collateral returned to their owners, by
+    /// the admin via the expire_orders execution route.
+    pub expiration_time: Option<Timestamp>,
+    /// The addresses of the only bidders that may be matched with an ask order, allowing an ask to
+    /// be used as escrow for a deal negotiated with specific buyers.  Only valid on ask orders.
+    pub allowed_bidders: Option<Vec<String>>,
+    /// A boolean expression over the counterparty's account attributes that must evaluate to true
+    /// for a match to be made.  Checked in addition to the attribute_requirement, if any.
+    pub attribute_expression: Option<AttributeExpression>,
+    /// The id of the counterparty order that this order intends to fill: an AskOrder for bids and a
+    /// BidOrder for asks.  When provided, the creator's attributes are checked against the target
+    /// order's requirements at creation time, rather than only when a match is attempted.
+    pub target_order_id: Option<String>,
 }
 impl RequestDescriptor {
     pub fn new_none() -> Self {
@@ -15,6 +31,10 @@ impl RequestDescriptor {
             description: None,
             effective_time: None,
             attribute_requirement: None,
+            expiration_time: None,
+            allowed_bidders: None,
+            attribute_expression: None,
+            target_order_id: None,
         }
     }
 
@@ -33,8 +53,122 @@ impl RequestDescriptor {
             description: Some(description.into()),
             effective_time: Some(Timestamp::default()),
             attribute_requirement: Some(attribute_requirement),
+            expiration_time: None,
+            allowed_bidders: None,
+            attribute_expression: None,
+            target_order_id: None,
         }
     }
+
+    pub fn with_expiration_time(mut self, expiration_time: Timestamp) -> Self {
+        self.expiration_time = Some(expiration_time);
+        self
+    }
+
+    pub fn with_effective_time(mut self, effective_time: Timestamp) -> Self {
+        self.effective_time = Some(effective_time);
+        self
+    }
+
+    pub fn with_allowed_bidders<S: Into<String> + Clone>(mut self, allowed_bidders: &[S]) -> Self {
+        self.allowed_bidders = Some(allowed_bidders.iter().cloned().map(|s| s.into()).collect());
+        self
+    }
+
+    pub fn with_attribute_expression(mut self, attribute_expression: AttributeExpression) -> Self {
+        self.attribute_expression = Some(attribute_expression);
+        self
+    }
+
+    pub fn with_target_order_id<S: Into<String>>(mut self, target_order_id: S) -> Self {
+        self.target_order_id = Some(target_order_id.into());
+        self
+    }
+
+    /// Determines if the descriptor defines any checks against the counterparty's attributes.
+    pub fn has_attribute_checks(&self) -> bool {
+        self.attribute_requirement
+            .as_ref()
+            .map(|requirement| requirement.has_checks())
+            .unwrap_or(false)
+            || self.attribute_expression.is_some()
+    }
+
+    /// Determines if the bidder may be matched with the order.  All bidders are allowed when the
+    /// descriptor does not specify any allowed bidders.
+    pub fn allows_bidder(&self, bidder: &Addr) -> bool {
+        self.allowed_bidders
+            .as_ref()
+            .map(|allowed| allowed.iter().any(|address| address == bidder.as_str()))
+            .unwrap_or(true)
+    }
+
+    /// Determines if the descriptor specifies an expiration time that is at or before the given time.
+    pub fn is_expired_at(&self, time: &Timestamp) -> bool {
+        self.expiration_time
+            .map(|expiration_time| expiration_time <= *time)
+            .unwrap_or(false)
+    }
+
+    /// Determines if the descriptor's effective time, if any, has been reached at the given time.
+    pub fn is_effective_at(&self, time: &Timestamp) -> bool {
+        self.effective_time
+            .map(|effective_time| effective_time <= *time)
+            .unwrap_or(true)
+    }
+}
+
+/// Exposes the request descriptor attached to an ask or bid order, allowing the time-based rules
+/// that it defines to be checked against either order type.
+pub trait DescribedOrder {
+    fn get_id(&self) -> &str;
+
+    fn get_descriptor(&self) -> &Option<RequestDescriptor>;
+
+    fn get_owner(&self) -> &Addr;
+
+    fn get_request_type(&self) -> &RequestType;
+
+    /// The quote of the order.  Marker asks quote a price per share.
+    fn get_quote(&self) -> Vec<Coin>;
+
+    fn get_created_time(&self) -> Option<Timestamp>;
+
+    /// The amount of the given denom included in the order's quote, if any.
+    fn get_price(&self, quote_denom: &str) -> Option<Uint128> {
+        self.get_quote()
+            .into_iter()
+            .find(|coin| coin.denom == quote_denom)
+            .map(|coin| coin.amount)
+    }
+
+    /// Private orders are only visible to their owner and their allowed bidders.
+    fn is_visible_to(&self, viewer: &Addr) -> bool {
+        self.get_owner() == viewer
+            || self
+                .get_descriptor()
+                .as_ref()
+                .map(|d| d.allows_bidder(viewer))
+                .unwrap_or(true)
+    }
+
+    /// An order is expired once its expiration time, if specified, has been reached.
+    fn is_expired(&self, time: &Timestamp) -> bool {
+        self.get_descriptor()
+            .as_ref()
+            .map(|d| d.is_expired_at(time))
+            .unwrap_or(false)
+    }
+
+    /// An order is active once its effective time, if specified, has been reached, up until it
+    /// expires.
+    fn is_active(&self, time: &Timestamp) -> bool {
+        self.get_descriptor()
+            .as_ref()
+            .map(|d| d.is_effective_at(time))
+            .unwrap_or(true)
+            && !self.is_expired(time)
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
@@ -42,38 +176,73 @@ impl RequestDescriptor {
 pub struct AttributeRequirement {
     pub attributes: Vec<String>,
     pub requirement_type: AttributeRequirementType,
+    /// Conditions on the values of the account's attributes, all of which must be met in addition
+    /// to the requirement_type check over the attribute names.
+    pub value_requirements: Option<Vec<AttributeValueRequirement>>,
 }
 impl AttributeRequirement {
-    pub fn all<S: Into<String>>(attributes: &[S]) -> Self
-    where
-        S: Clone,
-    {
+    pub fn all<S: Into<String> + Clone>(attributes: &[S]) -> Self {
         Self::new(attributes, AttributeRequirementType::All)
     }
 
-    pub fn any<S: Into<String>>(attributes: &[S]) -> Self
-    where
-        S: Clone,
-    {
+    pub fn any<S: Into<String> + Clone>(attributes: &[S]) -> Self {
         Self::new(attributes, AttributeRequirementType::Any)
     }
 
-    pub fn none<S: Into<String>>(attributes: &[S]) -> Self
-    where
-        S: Clone,
-    {
+    pub fn none<S: Into<String> + Clone>(attributes: &[S]) -> Self {
         Self::new(attributes, AttributeRequirementType::None)
     }
 
-    fn new<S: Into<String>>(attributes: &[S], requirement_type: AttributeRequirementType) -> Self
-    where
-        S: Clone,
-    {
+    fn new<S: Into<String> + Clone>(
+        attributes: &[S],
+        requirement_type: AttributeRequirementType,
+    ) -> Self {
         Self {
             attributes: attributes.iter().cloned().map(|s| s.into()).collect(),
             requirement_type,
+            value_requirements: None,
         }
     }
+
+    pub fn with_value_requirements(
+        mut self,
+        value_requirements: &[AttributeValueRequirement],
+    ) -> Self {
+        self.value_requirements = Some(value_requirements.to_vec());
+        self
+    }
+
+    /// Determines if the requirement defines anything to check.  Requirements with no attribute
+    /// names and no value requirements are satisfied by every account.
+    pub fn has_checks(&self) -> bool {
+        !self.attributes.is_empty() || !self.get_value_requirements().is_empty()
+    }
+
+    pub fn get_value_requirements(&self) -> &[AttributeValueRequirement] {
+        self.value_requirements.as_deref().unwrap_or(&[])
+    }
+
+    /// Renders the attribute names and value requirements in a readable form.
+    pub fn get_description(&self) -> String {
+        let mut descriptions = vec![];
+        if !self.attributes.is_empty() {
+            descriptions.push(format!(
+                "{} of {:?}",
+                match self.requirement_type {
+                    AttributeRequirementType::All => "all",
+                    AttributeRequirementType::Any => "any",
+                    AttributeRequirementType::None => "none",
+                },
+                self.attributes,
+            ));
+        }
+        descriptions.extend(
+            self.get_value_requirements()
+                .iter()
+                .map(|requirement| requirement.get_description()),
+        );
+        descriptions.join("; ")
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
@@ -88,3 +257,374 @@ pub enum AttributeRequirementType {
     // account
     None,
 }
+
+/// A condition on the value of a single named attribute.  The condition is met when the account
+/// holds at least one attribute with the given name whose value satisfies it.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct AttributeValueRequirement {
+    pub attribute: String,
+    pub condition: AttributeValueCondition,
+}
+impl AttributeValueRequirement {
+    pub fn new<S: Into<String>>(attribute: S, condition: AttributeValueCondition) -> Self {
+        Self {
+            attribute: attribute.into(),
+            condition,
+        }
+    }
+
+    pub fn is_met_by(&self, attributes: &[Attribute]) -> bool {
+        attributes
+            .iter()
+            .filter(|attribute| attribute.name == self.attribute)
+            .any(|attribute| self.condition.is_met_by(attribute))
+    }
+
+    pub fn get_description(&self) -> String {
+        format!("[{}] {}", self.attribute, self.condition.get_description())
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AttributeValueCondition {
+    // Requires a string attribute with exactly the given value
+    Equals { value: String },
+    // Requires a string attribute with any of the given values
+    OneOf { values: Vec<String> },
+    // The following require an int attribute compared numerically against the given value
+    GreaterThan { value: i64 },
+    GreaterThanOrEqual { value: i64 },
+    LessThan { value: i64 },
+    LessThanOrEqual { value: i64 },
+}
+impl AttributeValueCondition {
+    pub fn equals<S: Into<String>>(value: S) -> Self {
+        Self::Equals {
+            value: value.into(),
+        }
+    }
+
+    pub fn one_of<S: Into<String> + Clone>(values: &[S]) -> Self {
+        Self::OneOf {
+            values: values.iter().cloned().map(|s| s.into()).collect(),
+        }
+    }
+
+    pub fn is_met_by(&self, attribute: &Attribute) -> bool {
+        match self {
+            Self::Equals { value } => get_string_value(attribute)
+                .map(|attribute_value| &attribute_value == value)
+                .unwrap_or(false),
+            Self::OneOf { values } => get_string_value(attribute)
+                .map(|attribute_value| values.contains(&attribute_value))
+                .unwrap_or(false),
+            Self::GreaterThan { value } => get_int_value(attribute)
+                .map(|attribute_value| attribute_value > i128::from(*value))
+                .unwrap_or(false),
+            Self::GreaterThanOrEqual { value } => get_int_value(attribute)
+                .map(|attribute_value| attribute_value >= i128::from(*value))
+                .unwrap_or(false),
+            Self::LessThan { value } => get_int_value(attribute)
+                .map(|attribute_value| attribute_value < i128::from(*value))
+                .unwrap_or(false),
+            Self::LessThanOrEqual { value } => get_int_value(attribute)
+                .map(|attribute_value| attribute_value <= i128::from(*value))
+                .unwrap_or(false),
+        }
+    }
+
+    pub fn get_description(&self) -> String {
+        match self {
+            Self::Equals { value } => format!("equal to {:?}", value),
+            Self::OneOf { values } => format!("one of {:?}", values),
+            Self::GreaterThan { value } => format!("greater than {}", value),
+            Self::GreaterThanOrEqual { value } => format!("greater than or equal to {}", value),
+            Self::LessThan { value } => format!("less than {}", value),
+            Self::LessThanOrEqual { value } => format!("less than or equal to {}", value),
+        }
+    }
+}
+
+/// A composable boolean expression over an account's attributes, allowing requirements such as
+/// "(accredited AND us_resident) OR institutional" or "NOT sanctioned".
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AttributeExpression {
+    // Requires that every nested expression is true
+    And {
+        expressions: Vec<AttributeExpression>,
+    },
+    // Requires that at least one nested expression is true
+    Or {
+        expressions: Vec<AttributeExpression>,
+    },
+    // Requires that the nested expression is false
+    Not {
+        expression: Box<AttributeExpression>,
+    },
+    // Requires that the account holds an attribute with the given name
+    HasAttribute {
+        attribute: String,
+    },
+    // Requires that the account holds an attribute with the given name and a matching value
+    AttributeValue {
+        attribute: String,
+        condition: AttributeValueCondition,
+    },
+}
+impl AttributeExpression {
+    pub fn and(expressions: &[AttributeExpression]) -> Self {
+        Self::And {
+            expressions: expressions.to_vec(),
+        }
+    }
+
+    pub fn or(expressions: &[AttributeExpression]) -> Self {
+        Self::Or {
+            expressions: expressions.to_vec(),
+        }
+    }
+
+    pub fn negate(expression: AttributeExpression) -> Self {
+        Self::Not {
+            expression: Box::new(expression),
+        }
+    }
+
+    pub fn has_attribute<S: Into<String>>(attribute: S) -> Self {
+        Self::HasAttribute {
+            attribute: attribute.into(),
+        }
+    }
+
+    pub fn attribute_value<S: Into<String>>(
+        attribute: S,
+        condition: AttributeValueCondition,
+    ) -> Self {
+        Self::AttributeValue {
+            attribute: attribute.into(),
+            condition,
+        }
+    }
+
+    pub fn evaluate(&self, attributes: &[Attribute]) -> bool {
+        match self {
+            Self::And { expressions } => expressions.iter().all(|e| e.evaluate(attributes)),
+            Self::Or { expressions } => expressions.iter().any(|e| e.evaluate(attributes)),
+            Self::Not { expression } => !expression.evaluate(attributes),
+            Self::HasAttribute { attribute } => attributes.iter().any(|a| &a.name == attribute),
+            Self::AttributeValue {
+                attribute,
+                condition,
+            } => attributes
+                .iter()
+                .filter(|a| &a.name == attribute)
+                .any(|a| condition.is_met_by(a)),
+        }
+    }
+
+    /// Renders the expression in a readable form, parenthesizing nested compound expressions.
+    pub fn get_description(&self) -> String {
+        match self {
+            Self::And { expressions } => Self::describe_compound(expressions, " AND "),
+            Self::Or { expressions } => Self::describe_compound(expressions, " OR "),
+            Self::Not { expression } => format!("NOT {}", expression.get_nested_description()),
+            Self::HasAttribute { attribute } => format!("[{}]", attribute),
+            Self::AttributeValue {
+                attribute,
+                condition,
+            } => format!("[{}] {}", attribute, condition.get_description()),
+        }
+    }
+
+    fn get_nested_description(&self) -> String {
+        match self {
+            Self::And { .. } | Self::Or { .. } | Self::AttributeValue { .. } => {
+                format!("({})", self.get_description())
+            }
+            _ => self.get_description(),
+        }
+    }
+
+    fn describe_compound(expressions: &[AttributeExpression], separator: &str) -> String {
+        expressions
+            .iter()
+            .map(|e| e.get_nested_description())
+            .collect::<Vec<String>>()
+            .join(separator)
+    }
+}
+
+fn get_string_value(attribute: &Attribute) -> Option<String> {
+    if attribute.value_type != AttributeValueType::String {
+        return None;
+    }
+    String::from_utf8(attribute.value.to_vec()).ok()
+}
+
+// Int attributes store their values as decimal strings.  Parsing into an i128 allows the full range
+// of both signed and unsigned 64 bit values to be compared.
+fn get_int_value(attribute: &Attribute) -> Option<i128> {
+    if attribute.value_type != AttributeValueType::Int {
+        return None;
+    }
+    String::from_utf8(attribute.value.to_vec())
+        .ok()
+        .and_then(|value| value.trim().parse::<i128>().ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::request::request_descriptor::{
+        AttributeExpression, AttributeValueCondition, AttributeValueRequirement,
+    };
+    use cosmwasm_std::Binary;
+    use provwasm_std::{Attribute, AttributeValueType};
+
+    fn attribute(name: &str, value: &str, value_type: AttributeValueType) -> Attribute {
+        Attribute {
+            name: name.to_string(),
+            value: Binary::from(value.as_bytes()),
+            value_type,
+        }
+    }
+
+    #[test]
+    fn test_string_value_conditions() {
+        let attributes = vec![attribute(
+            "jurisdiction.pb",
+            "US",
+            AttributeValueType::String,
+        )];
+        assert!(
+            AttributeValueRequirement::new(
+                "jurisdiction.pb",
+                AttributeValueCondition::equals("US")
+            )
+            .is_met_by(&attributes),
+            "an equal string value should meet the requirement",
+        );
+        assert!(
+            !AttributeValueRequirement::new(
+                "jurisdiction.pb",
+                AttributeValueCondition::equals("CA")
+            )
+            .is_met_by(&attributes),
+            "a different string value should not meet the requirement",
+        );
+        assert!(
+            AttributeValueRequirement::new(
+                "jurisdiction.pb",
+                AttributeValueCondition::one_of(&["US", "CA"])
+            )
+            .is_met_by(&attributes),
+            "a value in the list should meet the requirement",
+        );
+        assert!(
+            !AttributeValueRequirement::new(
+                "other.pb",
+                AttributeValueCondition::one_of(&["US", "CA"])
+            )
+            .is_met_by(&attributes),
+            "a missing attribute should not meet the requirement",
+        );
+        assert!(
+            !AttributeValueCondition::equals("1").is_met_by(&attribute(
+                "level.pb",
+                "1",
+                AttributeValueType::Int
+            )),
+            "string conditions should not match non-string attributes",
+        );
+    }
+
+    #[test]
+    fn test_numeric_value_conditions() {
+        let level = attribute("kyc_level.pb", "3", AttributeValueType::Int);
+        assert!(AttributeValueCondition::GreaterThan { value: 2 }.is_met_by(&level));
+        assert!(!AttributeValueCondition::GreaterThan { value: 3 }.is_met_by(&level));
+        assert!(AttributeValueCondition::GreaterThanOrEqual { value: 3 }.is_met_by(&level));
+        assert!(AttributeValueCondition::LessThan { value: 4 }.is_met_by(&level));
+        assert!(!AttributeValueCondition::LessThan { value: 3 }.is_met_by(&level));
+        assert!(AttributeValueCondition::LessThanOrEqual { value: 3 }.is_met_by(&level));
+        assert!(
+            AttributeValueCondition::GreaterThan { value: i64::MAX }.is_met_by(&attribute(
+                "big.pb",
+                &u64::MAX.to_string(),
+                AttributeValueType::Int,
+            )),
+            "unsigned values above the signed range should still be compared",
+        );
+        assert!(
+            !AttributeValueCondition::GreaterThan { value: 0 }.is_met_by(&attribute(
+                "kyc_level.pb",
+                "3",
+                AttributeValueType::String,
+            )),
+            "numeric conditions should not match non-int attributes",
+        );
+        assert!(
+            !AttributeValueCondition::GreaterThan { value: 0 }.is_met_by(&attribute(
+                "kyc_level.pb",
+                "three",
+                AttributeValueType::Int,
+            )),
+            "unparseable int values should not meet numeric conditions",
+        );
+    }
+
+    #[test]
+    fn test_attribute_expression_evaluation() {
+        // (accredited AND us_resident) OR institutional
+        let expression = AttributeExpression::or(&[
+            AttributeExpression::and(&[
+                AttributeExpression::has_attribute("accredited.pb"),
+                AttributeExpression::has_attribute("us_resident.pb"),
+            ]),
+            AttributeExpression::has_attribute("institutional.pb"),
+        ]);
+        assert_eq!(
+            "([accredited.pb] AND [us_resident.pb]) OR [institutional.pb]",
+            expression.get_description(),
+            "the expression should be described readably",
+        );
+        assert!(expression.evaluate(&[
+            attribute("accredited.pb", "true", AttributeValueType::String),
+            attribute("us_resident.pb", "true", AttributeValueType::String),
+        ]));
+        assert!(expression.evaluate(&[attribute(
+            "institutional.pb",
+            "true",
+            AttributeValueType::String
+        )]));
+        assert!(!expression.evaluate(&[attribute(
+            "accredited.pb",
+            "true",
+            AttributeValueType::String
+        )]));
+        let not_sanctioned =
+            AttributeExpression::negate(AttributeExpression::has_attribute("sanctioned.pb"));
+        assert_eq!("NOT [sanctioned.pb]", not_sanctioned.get_description());
+        assert!(not_sanctioned.evaluate(&[]));
+        assert!(!not_sanctioned.evaluate(&[attribute(
+            "sanctioned.pb",
+            "true",
+            AttributeValueType::String
+        )]));
+        let jurisdiction = AttributeExpression::negate(AttributeExpression::attribute_value(
+            "jurisdiction.pb",
+            AttributeValueCondition::one_of(&["KP"]),
+        ));
+        assert_eq!(
+            "NOT ([jurisdiction.pb] one of [\"KP\"])",
+            jurisdiction.get_description(),
+        );
+        assert!(jurisdiction.evaluate(&[attribute(
+            "jurisdiction.pb",
+            "US",
+            AttributeValueType::String
+        )]));
+    }
+}
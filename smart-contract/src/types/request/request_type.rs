@@ -8,12 +8,14 @@ const REQUEST_TYPE_COIN_TRADE: &str = "coin_trade";
 const REQUEST_TYPE_MARKER_TRADE: &str = "marker_trade";
 const REQUEST_TYPE_MARKER_SHARE_SALE: &str = "marker_share_sale";
 const REQUEST_TYPE_SCOPE_TRADE: &str = "scope_trade";
+const REQUEST_TYPE_SCOPE_BUNDLE_TRADE: &str = "scope_bundle_trade";
 
-static REQUEST_TYPES: [RequestType; 4] = [
+static REQUEST_TYPES: [RequestType; 5] = [
     RequestType::CoinTrade,
     RequestType::MarkerTrade,
     RequestType::MarkerShareSale,
     RequestType::ScopeTrade,
+    RequestType::ScopeBundleTrade,
 ];
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
@@ -23,6 +25,7 @@ pub enum RequestType {
     MarkerTrade,
     MarkerShareSale,
     ScopeTrade,
+    ScopeBundleTrade,
 }
 impl RequestType {
     pub fn from_ask_collateral(collateral: &AskCollateral) -> Self {
@@ -31,6 +34,7 @@ impl RequestType {
             AskCollateral::MarkerTrade(_) => Self::MarkerTrade,
             AskCollateral::MarkerShareSale(_) => Self::MarkerShareSale,
             AskCollateral::ScopeTrade(_) => Self::ScopeTrade,
+            AskCollateral::ScopeBundleTrade(_) => Self::ScopeBundleTrade,
         }
     }
 
@@ -39,7 +43,9 @@ impl RequestType {
             BidCollateral::CoinTrade(_) => Self::CoinTrade,
             BidCollateral::MarkerTrade(_) => Self::MarkerTrade,
             BidCollateral::MarkerShareSale(_) => Self::MarkerShareSale,
-            BidCollateral::ScopeTrade(_) => Self::ScopeTrade,
+            // Scope spec trade bids are generic scope trade bids, and are matched with scope trade asks
+            BidCollateral::ScopeTrade(_) | BidCollateral::ScopeSpecTrade(_) => Self::ScopeTrade,
+            BidCollateral::ScopeBundleTrade(_) => Self::ScopeBundleTrade,
         }
     }
 
@@ -49,6 +55,7 @@ impl RequestType {
             Self::MarkerTrade => REQUEST_TYPE_MARKER_TRADE,
             Self::MarkerShareSale => REQUEST_TYPE_MARKER_SHARE_SALE,
             Self::ScopeTrade => REQUEST_TYPE_SCOPE_TRADE,
+            Self::ScopeBundleTrade => REQUEST_TYPE_SCOPE_BUNDLE_TRADE,
         }
     }
 
@@ -0,0 +1,70 @@
+use provwasm_std::PartyType;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Defines how a scope is modified when it is transferred to a bidder after a scope trade match.
+/// When no policy is specified, the bidder replaces all parties with the Owner role, becomes the
+/// scope's value owner, and the scope's data access list is left unchanged.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ScopeTransferPolicy {
+    /// Determines what happens to the scope's data_access addresses.  Defaults to keeping them.
+    pub data_access: Option<DataAccessPolicy>,
+    /// Party roles that the bidder is granted in addition to their Owner or value owner status.
+    /// Ex: [servicer] to designate the bidder as the servicer of the scope.
+    pub additional_bidder_roles: Option<Vec<PartyType>>,
+    /// When provided, only value ownership is transferred to the bidder, and this address becomes
+    /// the scope's sole party with the Owner role.  Used when an originator or servicer must
+    /// remain the scope's owner after a sale.
+    pub retained_owner_address: Option<String>,
+}
+impl ScopeTransferPolicy {
+    pub fn new() -> Self {
+        Self {
+            data_access: None,
+            additional_bidder_roles: None,
+            retained_owner_address: None,
+        }
+    }
+
+    pub fn with_data_access(mut self, data_access: DataAccessPolicy) -> Self {
+        self.data_access = Some(data_access);
+        self
+    }
+
+    pub fn with_additional_bidder_roles(mut self, roles: &[PartyType]) -> Self {
+        self.additional_bidder_roles = Some(roles.to_vec());
+        self
+    }
+
+    pub fn with_retained_owner_address<S: Into<String>>(mut self, address: S) -> Self {
+        self.retained_owner_address = Some(address.into());
+        self
+    }
+
+    pub fn get_data_access(&self) -> DataAccessPolicy {
+        self.data_access
+            .to_owned()
+            .unwrap_or(DataAccessPolicy::Keep)
+    }
+
+    pub fn get_additional_bidder_roles(&self) -> Vec<PartyType> {
+        self.additional_bidder_roles.to_owned().unwrap_or_default()
+    }
+}
+impl Default for ScopeTransferPolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DataAccessPolicy {
+    /// Leaves the scope's data_access addresses unchanged.
+    Keep,
+    /// Removes all data_access addresses from the scope.
+    Clear,
+    /// Leaves the existing data_access addresses in place and adds the bidder to them.
+    AddBidder,
+}
@@ -1,4 +1,6 @@
-use cosmwasm_std::Uint128;
+use crate::types::request::request_descriptor::DescribedOrder;
+use crate::types::request::request_type::RequestType;
+use cosmwasm_std::{Timestamp, Uint128};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -8,6 +10,18 @@ pub struct Search {
     pub search_type: SearchType,
     pub page_size: Option<Uint128>,
     pub page_number: Option<Uint128>,
+    /// When true, orders that have not yet reached their effective time, or that have expired,
+    /// are omitted from the results.
+    pub hide_inactive: Option<bool>,
+    /// When provided, private asks are omitted from the results unless the viewer is their owner
+    /// or one of their allowed bidders.
+    pub viewer: Option<String>,
+    /// When provided, results begin with the first order after the order with this id, and the
+    /// page_number is ignored.  Pass the next_start_after value of the previous result to fetch
+    /// the next page at a constant cost, regardless of how deep into the results it is.
+    pub start_after: Option<String>,
+    /// When true, orders are returned in descending order of their ids.
+    pub descending: Option<bool>,
 }
 impl Search {
     pub fn all(page_size: Option<u128>, page_number: Option<u128>) -> Self {
@@ -15,6 +29,10 @@ impl Search {
             search_type: SearchType::All,
             page_size: page_size.map(Uint128::new),
             page_number: page_number.map(Uint128::new),
+            hide_inactive: None,
+            viewer: None,
+            start_after: None,
+            descending: None,
         }
     }
 
@@ -29,6 +47,10 @@ impl Search {
             },
             page_size: page_size.map(Uint128::new),
             page_number: page_number.map(Uint128::new),
+            hide_inactive: None,
+            viewer: None,
+            start_after: None,
+            descending: None,
         }
     }
 
@@ -37,6 +59,10 @@ impl Search {
             search_type: SearchType::Id { id: id.into() },
             page_size: page_size.map(Uint128::new),
             page_number: page_number.map(Uint128::new),
+            hide_inactive: None,
+            viewer: None,
+            start_after: None,
+            descending: None,
         }
     }
 
@@ -51,8 +77,108 @@ impl Search {
             },
             page_size: page_size.map(Uint128::new),
             page_number: page_number.map(Uint128::new),
+            hide_inactive: None,
+            viewer: None,
+            start_after: None,
+            descending: None,
         }
     }
+
+    pub fn marker_denom<S: Into<String>>(
+        marker_denom: S,
+        page_size: Option<u128>,
+        page_number: Option<u128>,
+    ) -> Self {
+        Self::new(
+            SearchType::MarkerDenom {
+                marker_denom: marker_denom.into(),
+            },
+            page_size,
+            page_number,
+        )
+    }
+
+    pub fn scope_address<S: Into<String>>(
+        scope_address: S,
+        page_size: Option<u128>,
+        page_number: Option<u128>,
+    ) -> Self {
+        Self::new(
+            SearchType::ScopeAddress {
+                scope_address: scope_address.into(),
+            },
+            page_size,
+            page_number,
+        )
+    }
+
+    pub fn scope_spec_id<S: Into<String>>(
+        scope_spec_id: S,
+        page_size: Option<u128>,
+        page_number: Option<u128>,
+    ) -> Self {
+        Self::new(
+            SearchType::ScopeSpecId {
+                scope_spec_id: scope_spec_id.into(),
+            },
+            page_size,
+            page_number,
+        )
+    }
+
+    pub fn quote_denom<S: Into<String>>(
+        quote_denom: S,
+        page_size: Option<u128>,
+        page_number: Option<u128>,
+    ) -> Self {
+        Self::new(
+            SearchType::QuoteDenom {
+                quote_denom: quote_denom.into(),
+            },
+            page_size,
+            page_number,
+        )
+    }
+
+    pub fn filter(
+        filter: SearchFilter,
+        page_size: Option<u128>,
+        page_number: Option<u128>,
+    ) -> Self {
+        Self::new(SearchType::Filter { filter }, page_size, page_number)
+    }
+
+    fn new(search_type: SearchType, page_size: Option<u128>, page_number: Option<u128>) -> Self {
+        Self {
+            search_type,
+            page_size: page_size.map(Uint128::new),
+            page_number: page_number.map(Uint128::new),
+            hide_inactive: None,
+            viewer: None,
+            start_after: None,
+            descending: None,
+        }
+    }
+
+    pub fn hide_inactive(mut self) -> Self {
+        self.hide_inactive = Some(true);
+        self
+    }
+
+    pub fn with_viewer<S: Into<String>>(mut self, viewer: S) -> Self {
+        self.viewer = Some(viewer.into());
+        self
+    }
+
+    pub fn start_after<S: Into<String>>(mut self, start_after: S) -> Self {
+        self.start_after = Some(start_after.into());
+        self
+    }
+
+    pub fn descending(mut self) -> Self {
+        self.descending = Some(true);
+        self
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
@@ -61,7 +187,12 @@ pub struct SearchResult<T> {
     pub results: Vec<T>,
     pub page_number: Uint128,
     pub page_size: Uint128,
+    /// Derived from the stored order counts, so orders omitted by hide_inactive and viewer are
+    /// included.
     pub total_pages: Uint128,
+    /// The id of the last result when the page was filled, to be used as the start_after value
+    /// of the next search.  Omitted when the page was not filled, as no further results exist.
+    pub next_start_after: Option<String>,
 }
 impl<T> SearchResult<T> {
     pub fn empty() -> Self {
@@ -70,6 +201,7 @@ impl<T> SearchResult<T> {
             page_number: Uint128::zero(),
             page_size: Uint128::zero(),
             total_pages: Uint128::zero(),
+            next_start_after: None,
         }
     }
 }
@@ -78,7 +210,253 @@ impl<T> SearchResult<T> {
 #[serde(rename_all = "snake_case")]
 pub enum SearchType {
     All,
-    ValueType { value_type: String },
-    Id { id: String },
-    Owner { owner: String },
+    ValueType {
+        value_type: String,
+    },
+    Id {
+        id: String,
+    },
+    Owner {
+        owner: String,
+    },
+    /// Finds marker trades and marker share sales for the given marker denom.
+    MarkerDenom {
+        marker_denom: String,
+    },
+    /// Finds scope trades and scope bundle trades that include the given scope address.
+    ScopeAddress {
+        scope_address: String,
+    },
+    /// Finds scope trade asks whose scope uses the given scope specification, and scope spec
+    /// trade bids that target it.
+    ScopeSpecId {
+        scope_spec_id: String,
+    },
+    /// Finds orders that include the given denom in their quote.
+    QuoteDenom {
+        quote_denom: String,
+    },
+    /// Finds orders that meet every criterion of the filter.
+    Filter {
+        filter: SearchFilter,
+    },
+}
+
+/// Combines several search criteria, all of which an order must meet to be included in the
+/// results.  The owner, request type, and quote denom criteria are served by the contract's
+/// indices, so at least one of them is required, and the one with the fewest orders is used to
+/// find the orders checked against the remaining criteria.  At most 500 orders are checked for
+/// each page.  When sorted by id, a page that reaches this limit ends early with a cursor to
+/// continue from, and the total_pages counts every order in the narrowest index.  Other sorts
+/// check the whole index, so they are refused when it holds more than 500 orders, and their
+/// total_pages reflects the exact number of matching orders.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct SearchFilter {
+    pub owner: Option<String>,
+    pub request_type: Option<RequestType>,
+    pub status: Option<OrderStatus>,
+    pub quote_denom: Option<String>,
+    /// The minimum amount of the quote_denom in the order's quote, inclusive.  Requires a
+    /// quote_denom.  Marker asks quote a price per share.
+    pub min_price: Option<Uint128>,
+    /// The maximum amount of the quote_denom in the order's quote, inclusive.  Requires a
+    /// quote_denom.
+    pub max_price: Option<Uint128>,
+    /// Includes orders created at or after this time.  Orders created by contract versions that
+    /// did not record creation times are never included when a creation time range is provided.
+    pub created_after: Option<Timestamp>,
+    /// Includes orders created at or before this time.
+    pub created_before: Option<Timestamp>,
+    /// Determines the order of the results.  Defaults to sorting by order id.
+    pub sort: Option<SearchSort>,
+}
+impl SearchFilter {
+    pub fn new() -> Self {
+        Self {
+            owner: None,
+            request_type: None,
+            status: None,
+            quote_denom: None,
+            min_price: None,
+            max_price: None,
+            created_after: None,
+            created_before: None,
+            sort: None,
+        }
+    }
+
+    pub fn with_owner<S: Into<String>>(mut self, owner: S) -> Self {
+        self.owner = Some(owner.into());
+        self
+    }
+
+    pub fn with_request_type(mut self, request_type: RequestType) -> Self {
+        self.request_type = Some(request_type);
+        self
+    }
+
+    pub fn with_status(mut self, status: OrderStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    pub fn with_quote_denom<S: Into<String>>(mut self, quote_denom: S) -> Self {
+        self.quote_denom = Some(quote_denom.into());
+        self
+    }
+
+    pub fn with_price_range(mut self, min_price: Option<u128>, max_price: Option<u128>) -> Self {
+        self.min_price = min_price.map(Uint128::new);
+        self.max_price = max_price.map(Uint128::new);
+        self
+    }
+
+    pub fn with_creation_range(
+        mut self,
+        created_after: Option<Timestamp>,
+        created_before: Option<Timestamp>,
+    ) -> Self {
+        self.created_after = created_after;
+        self.created_before = created_before;
+        self
+    }
+
+    pub fn sorted_by(mut self, sort: SearchSort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    pub fn get_sort(&self) -> SearchSort {
+        self.sort.to_owned().unwrap_or(SearchSort::Id)
+    }
+
+    /// Determines if the order meets all of the filter's criteria at the given time.
+    pub fn matches<O: DescribedOrder>(&self, order: &O, time: &Timestamp) -> bool {
+        let price = self
+            .quote_denom
+            .as_ref()
+            .map(|quote_denom| order.get_price(quote_denom));
+        let created_time = order.get_created_time();
+        self.owner
+            .as_ref()
+            .map(|owner| order.get_owner().as_str() == owner)
+            .unwrap_or(true)
+            && self
+                .request_type
+                .as_ref()
+                .map(|request_type| order.get_request_type() == request_type)
+                .unwrap_or(true)
+            && self
+                .status
+                .as_ref()
+                .map(|status| status == &OrderStatus::of(order, time))
+                .unwrap_or(true)
+            // When a quote denom is provided, orders must include it in their quote
+            && price.map(|price| price.is_some()).unwrap_or(true)
+            && self
+                .min_price
+                .map(|min_price| price.flatten().map(|p| p >= min_price).unwrap_or(false))
+                .unwrap_or(true)
+            && self
+                .max_price
+                .map(|max_price| price.flatten().map(|p| p <= max_price).unwrap_or(false))
+                .unwrap_or(true)
+            && self
+                .created_after
+                .map(|after| created_time.map(|t| t >= after).unwrap_or(false))
+                .unwrap_or(true)
+            && self
+                .created_before
+                .map(|before| created_time.map(|t| t <= before).unwrap_or(false))
+                .unwrap_or(true)
+    }
+}
+impl Default for SearchFilter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum OrderStatus {
+    /// The order has not yet reached its effective time.
+    Pending,
+    /// The order is effective and has not expired, so it is eligible for a match.
+    Active,
+    /// The order has reached its expiration time.
+    Expired,
+}
+impl OrderStatus {
+    pub fn of<O: DescribedOrder>(order: &O, time: &Timestamp) -> Self {
+        if order.is_expired(time) {
+            Self::Expired
+        } else if order.is_active(time) {
+            Self::Active
+        } else {
+            Self::Pending
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchSort {
+    Id,
+    /// Sorts orders from the earliest created to the latest.  Orders created by contract versions
+    /// that did not record creation times are sorted first.
+    CreationTime,
+    /// Sorts orders by the amount of the filter's quote_denom in their quotes, from the lowest to
+    /// the highest.  Requires a quote_denom.
+    Price,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct MatchSearch {
+    pub search_type: MatchSearchType,
+    pub page_size: Option<Uint128>,
+    pub page_number: Option<Uint128>,
+    /// When provided, results begin with the first record older than the record with this id, and
+    /// the page_number is ignored.  Pass the next_start_after value of the previous result to fetch
+    /// the next page at a constant cost, regardless of how deep into the results it is.
+    pub start_after: Option<Uint128>,
+}
+impl MatchSearch {
+    pub fn new(
+        search_type: MatchSearchType,
+        page_size: Option<u128>,
+        page_number: Option<u128>,
+    ) -> Self {
+        Self {
+            search_type,
+            page_size: page_size.map(Uint128::new),
+            page_number: page_number.map(Uint128::new),
+            start_after: None,
+        }
+    }
+
+    pub fn start_after(mut self, start_after: u128) -> Self {
+        self.start_after = Some(Uint128::new(start_after));
+        self
+    }
+}
+
+/// Match records are always returned from the most recent to the oldest, regardless of the type
+/// of search performed.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum MatchSearchType {
+    All,
+    /// Finds all matches in which the given address was either the asker or the bidder.
+    Owner {
+        owner: String,
+    },
+    MarkerDenom {
+        marker_denom: String,
+    },
+    ScopeAddress {
+        scope_address: String,
+    },
 }
@@ -1,3 +1,6 @@
+use crate::types::core::contract_status::ContractStatus;
+use crate::types::request::request_descriptor::AttributeRequirement;
+use crate::types::request::settlement_fees::SettlementFees;
 use cosmwasm_std::Uint128;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -5,7 +8,13 @@ use serde::{Deserialize, Serialize};
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub struct SettingsUpdate {
-    pub new_admin_address: Option<String>,
     pub new_create_ask_nhash_fee: Option<Uint128>,
     pub new_create_bid_nhash_fee: Option<Uint128>,
+    /// Replaces all settlement fee rates.  Rates omitted from the new value charge no fee.
+    pub new_settlement_fees: Option<SettlementFees>,
+    pub new_fee_collector_address: Option<String>,
+    pub new_contract_status: Option<ContractStatus>,
+    /// Replaces the attribute requirements that accounts must meet to create asks and bids.  A
+    /// requirement with no attributes or value requirements removes the restriction.
+    pub new_required_trade_attributes: Option<AttributeRequirement>,
 }
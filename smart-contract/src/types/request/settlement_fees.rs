@@ -0,0 +1,40 @@
+use crate::types::request::request_type::RequestType;
+use cosmwasm_std::Uint128;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Fees charged against the quote paid to the asker when a match is executed, expressed in basis
+/// points (1/100th of a percent).  Each request type has its own rate, and an omitted rate charges
+/// no fee for that type.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct SettlementFees {
+    pub coin_trade_bps: Option<Uint128>,
+    pub marker_trade_bps: Option<Uint128>,
+    pub marker_share_sale_bps: Option<Uint128>,
+    pub scope_trade_bps: Option<Uint128>,
+    pub scope_bundle_trade_bps: Option<Uint128>,
+}
+impl SettlementFees {
+    pub fn get_fee_bps(&self, request_type: &RequestType) -> Uint128 {
+        match request_type {
+            RequestType::CoinTrade => self.coin_trade_bps,
+            RequestType::MarkerTrade => self.marker_trade_bps,
+            RequestType::MarkerShareSale => self.marker_share_sale_bps,
+            RequestType::ScopeTrade => self.scope_trade_bps,
+            RequestType::ScopeBundleTrade => self.scope_bundle_trade_bps,
+        }
+        .unwrap_or_else(Uint128::zero)
+    }
+
+    /// Pairs each configured rate with a display name, allowing validation to address each value.
+    pub fn get_named_fee_bps(&self) -> Vec<(&str, Option<Uint128>)> {
+        vec![
+            ("coin_trade_bps", self.coin_trade_bps),
+            ("marker_trade_bps", self.marker_trade_bps),
+            ("marker_share_sale_bps", self.marker_share_sale_bps),
+            ("scope_trade_bps", self.scope_trade_bps),
+            ("scope_bundle_trade_bps", self.scope_bundle_trade_bps),
+        ]
+    }
+}
@@ -0,0 +1,111 @@
+use crate::storage::ask_order_storage::{delete_ask_order_by_id, get_ask_orders_by_collateral_id};
+use crate::storage::bid_order_storage::delete_bid_order_by_id;
+use crate::types::core::error::ContractError;
+use crate::types::request::ask_types::ask_collateral::AskCollateral;
+use crate::types::request::ask_types::ask_order::AskOrder;
+use crate::types::request::bid_types::bid_order::BidOrder;
+use crate::util::extensions::ResultExtensions;
+use crate::util::provenance_utilities::{
+    release_marker_from_contract, replace_scope_owner, return_scope_stake,
+};
+use cosmwasm_std::{BankMsg, CosmosMsg, DepsMut, Env, Storage};
+use provwasm_std::{write_scope, ProvenanceMsg, ProvenanceQuerier, ProvenanceQuery};
+
+/// The result of removing an ask order from the contract.
+pub struct AskCancellationResult {
+    /// All messages required to return the ask's collateral to its owner.
+    pub messages: Vec<CosmosMsg<ProvenanceMsg>>,
+    /// Whether or not the collateral was returned.  Marker share sales retain their marker until
+    /// the final ask for that marker is removed.
+    pub collateral_released: bool,
+}
+
+/// Deletes an ask order from storage and produces the messages required to return its collateral
+/// to the owner.  No authorization checks are made; the caller is responsible for verifying that
+/// the ask order can be removed.
+pub fn cancel_ask_order(
+    deps: &mut DepsMut<ProvenanceQuery>,
+    env: &Env,
+    ask_order: &AskOrder,
+) -> Result<AskCancellationResult, ContractError> {
+    let mut messages: Vec<CosmosMsg<ProvenanceMsg>> = vec![];
+    let collateral_released = match &ask_order.collateral {
+        AskCollateral::CoinTrade(collateral) => {
+            messages.push(CosmosMsg::Bank(BankMsg::Send {
+                to_address: ask_order.owner.to_string(),
+                amount: collateral.get_remaining_base(),
+            }));
+            true
+        }
+        AskCollateral::MarkerTrade(collateral) => {
+            messages.append(&mut release_marker_from_contract(
+                &collateral.marker_denom,
+                &env.contract.address,
+                &collateral.removed_permissions,
+            )?);
+            true
+        }
+        AskCollateral::MarkerShareSale(collateral) => {
+            // Only release the marker if this is the final remaining ask for the given marker.
+            // Multiple marker share sales can be created for a single marker while it is held by
+            // the contract, so this check ensures that the marker is only relinquished when the
+            // final sale is cancelled.
+            if get_ask_orders_by_collateral_id(deps.storage, collateral.marker_address.as_str())
+                .len()
+                <= 1
+            {
+                messages.append(&mut release_marker_from_contract(
+                    &collateral.marker_denom,
+                    &env.contract.address,
+                    &collateral.removed_permissions,
+                )?);
+                true
+            } else {
+                false
+            }
+        }
+        AskCollateral::ScopeTrade(collateral) => {
+            let mut scope =
+                ProvenanceQuerier::new(&deps.querier).get_scope(&collateral.scope_address)?;
+            scope = match &collateral.sold_owner_addresses {
+                Some(sold_owner_addresses) => {
+                    return_scope_stake(scope, &env.contract.address, sold_owner_addresses)
+                }
+                None => replace_scope_owner(scope, ask_order.owner.to_owned()),
+            };
+            messages.push(write_scope(scope, vec![env.contract.address.to_owned()])?);
+            true
+        }
+        AskCollateral::ScopeBundleTrade(collateral) => {
+            for scope_address in collateral.scope_addresses.iter() {
+                let scope = ProvenanceQuerier::new(&deps.querier).get_scope(scope_address)?;
+                messages.push(write_scope(
+                    replace_scope_owner(scope, ask_order.owner.to_owned()),
+                    vec![env.contract.address.to_owned()],
+                )?);
+            }
+            true
+        }
+    };
+    delete_ask_order_by_id(deps.storage, &ask_order.id)?;
+    AskCancellationResult {
+        messages,
+        collateral_released,
+    }
+    .to_ok()
+}
+
+/// Deletes a bid order from storage and produces the message required to return its quote funds
+/// to the owner.  No authorization checks are made; the caller is responsible for verifying that
+/// the bid order can be removed.
+pub fn cancel_bid_order(
+    storage: &mut dyn Storage,
+    bid_order: &BidOrder,
+) -> Result<CosmosMsg<ProvenanceMsg>, ContractError> {
+    delete_bid_order_by_id(storage, &bid_order.id)?;
+    CosmosMsg::Bank(BankMsg::Send {
+        to_address: bid_order.owner.to_string(),
+        amount: bid_order.collateral.get_quote(),
+    })
+    .to_ok()
+}
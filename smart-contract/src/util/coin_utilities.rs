@@ -1,8 +1,10 @@
 use crate::types::core::error::ContractError;
+use crate::types::request::admin_match_options::OverrideQuoteSource;
+use crate::types::request::ask_types::ask_collateral::MarkerShareSaleAskCollateral;
 use crate::types::request::bid_types::bid_collateral::MarkerShareSaleBidCollateral;
 use crate::util::extensions::ResultExtensions;
 use crate::util::provenance_utilities::format_coin_display;
-use cosmwasm_std::{coin, Coin};
+use cosmwasm_std::{coin, Coin, Uint128};
 use std::cmp::Ordering;
 
 pub fn coin_sort(first: &Coin, second: &Coin) -> Ordering {
@@ -77,6 +79,38 @@ pub fn subtract_coins<S: Into<String>>(
         .to_ok()
 }
 
+pub struct MSSPurchaseCalc {
+    pub shares_purchased: u128,
+    pub bid_overage_shares: u128,
+    pub quote_paid: Vec<Coin>,
+}
+
+/// Determines how many shares a marker share sale bid purchases from an ask, and the quote that
+/// is paid to the asker for them.  Bids requesting more shares than remain in the sale only
+/// purchase the remaining shares, leaving the difference as overage.
+pub fn calculate_marker_share_sale_purchase(
+    ask_collateral: &MarkerShareSaleAskCollateral,
+    bid_collateral: &MarkerShareSaleBidCollateral,
+    override_quote_source: &Option<OverrideQuoteSource>,
+) -> MSSPurchaseCalc {
+    let bid_overage_shares = bid_collateral
+        .share_count
+        .checked_sub(ask_collateral.remaining_shares_in_sale)
+        .unwrap_or(Uint128::zero())
+        .u128();
+    let shares_purchased = bid_collateral.share_count.u128() - bid_overage_shares;
+    let quote_paid = if let Some(OverrideQuoteSource::Bid) = override_quote_source {
+        multiply_coins_by_amount(&bid_collateral.get_quote_per_share(), shares_purchased)
+    } else {
+        multiply_coins_by_amount(&ask_collateral.quote_per_share, shares_purchased)
+    };
+    MSSPurchaseCalc {
+        shares_purchased,
+        bid_overage_shares,
+        quote_paid,
+    }
+}
+
 pub struct MSSBidTotalsCalc {
     pub expected_remaining_bidder_coin: Vec<Coin>,
     pub actual_remaining_bidder_coin: Vec<Coin>,
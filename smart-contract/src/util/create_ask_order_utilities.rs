@@ -1,20 +1,31 @@
+use crate::storage::address_blocklist_storage::validate_address_not_blocked;
 use crate::storage::ask_order_storage::get_ask_orders_by_collateral_id;
+use crate::storage::contract_info::get_contract_info;
+use crate::storage::quote_denom_allowlist_storage::get_quote_denom_allowlist;
+use crate::storage::scope_stake_storage::is_scope_stake_registered;
 use crate::types::core::error::ContractError;
 use crate::types::request::ask_types::ask::{
-    Ask, CoinTradeAsk, MarkerShareSaleAsk, MarkerTradeAsk, ScopeTradeAsk,
+    Ask, CoinTradeAsk, MarkerShareSaleAsk, MarkerTradeAsk, ScopeBundleTradeAsk, ScopeTradeAsk,
 };
 use crate::types::request::ask_types::ask_collateral::AskCollateral;
 use crate::types::request::ask_types::ask_order::AskOrder;
 use crate::types::request::request_descriptor::RequestDescriptor;
 use crate::types::request::request_type::RequestType;
 use crate::util::extensions::ResultExtensions;
-use crate::util::provenance_utilities::{check_scope_owners, get_single_marker_coin_holding};
+use crate::util::provenance_utilities::{
+    check_scope_owners, check_scope_stake_escrowed, get_single_marker_coin_holding,
+};
 use crate::util::request_fee::generate_request_fee_msg;
 use crate::validation::ask_order_validation::validate_ask_order;
+use crate::validation::contract_status_validation::validate_order_creation_allowed;
+use crate::validation::counterparty_attribute_validation::{
+    validate_ask_target, validate_required_trade_attributes,
+};
 use crate::validation::marker_exchange_validation::{
     validate_marker_for_ask, ShareSaleValidationDetail,
 };
-use cosmwasm_std::{Addr, BankMsg, CosmosMsg, DepsMut, Env, MessageInfo};
+use crate::validation::request_descriptor_validation::validate_request_descriptor_times;
+use cosmwasm_std::{Addr, BankMsg, CosmosMsg, DepsMut, Env, MessageInfo, StdResult};
 use provwasm_std::{
     revoke_marker_access, AccessGrant, Marker, MarkerAccess, ProvenanceMsg, ProvenanceQuerier,
     ProvenanceQuery,
@@ -39,6 +50,10 @@ pub fn create_ask_order(
     descriptor: Option<RequestDescriptor>,
     creation_type: AskCreationType,
 ) -> Result<AskOrderCreationResponse, ContractError> {
+    let contract_info = get_contract_info(deps.storage)?;
+    validate_order_creation_allowed(&contract_info)?;
+    validate_address_not_blocked(deps.storage, &info.sender)?;
+    validate_required_trade_attributes(&deps.as_ref(), &contract_info, &info.sender, "asker")?;
     let ask_fee_msg = match &creation_type {
         AskCreationType::New => generate_request_fee_msg(
             "ask creation",
@@ -49,6 +64,11 @@ pub fn create_ask_order(
         // Updates do not charge creation fees
         AskCreationType::Update { .. } => None,
     };
+    let created_time = match &creation_type {
+        AskCreationType::New => Some(env.block.time),
+        // Updates retain the time at which the ask was originally created
+        AskCreationType::Update { existing_ask_order } => existing_ask_order.created_time,
+    };
     let AskCreationData {
         collateral,
         messages,
@@ -67,6 +87,13 @@ pub fn create_ask_order(
         Ask::ScopeTrade(scope_trade) => {
             create_scope_trade_ask_collateral(creation_type, deps, info, env, scope_trade)
         }
+        Ask::ScopeBundleTrade(scope_bundle_trade) => create_scope_bundle_trade_ask_collateral(
+            creation_type,
+            deps,
+            info,
+            env,
+            scope_bundle_trade,
+        ),
     }?;
     let ask_order = AskOrder {
         id: ask.get_id().to_string(),
@@ -74,8 +101,26 @@ pub fn create_ask_order(
         owner: info.sender.clone(),
         collateral,
         descriptor,
+        created_time,
     };
-    validate_ask_order(&ask_order)?;
+    validate_ask_order(&ask_order, &get_quote_denom_allowlist(deps.storage)?)?;
+    // Ensure that allowed bidders are real addresses, because a malformed address could never be
+    // matched with the ask
+    if let Some(allowed_bidders) = ask_order
+        .descriptor
+        .as_ref()
+        .and_then(|d| d.allowed_bidders.as_ref())
+    {
+        for bidder in allowed_bidders {
+            deps.api.addr_validate(bidder)?;
+        }
+    }
+    validate_request_descriptor_times(
+        format!("AskOrder [{}]", &ask_order.id),
+        &ask_order.descriptor,
+        env,
+    )?;
+    validate_ask_target(&deps.as_ref(), &ask_order)?;
     AskOrderCreationResponse {
         ask_order,
         messages,
@@ -128,13 +173,24 @@ fn create_coin_trade_ask_collateral(
                 amount: existing_ask_order
                     .collateral
                     .get_coin_trade()?
-                    .base
-                    .to_owned(),
+                    .get_remaining_base(),
             })]
         }
     };
     AskCreationData {
-        collateral: AskCollateral::coin_trade(&info.funds, &coin_trade.quote),
+        collateral: if coin_trade.allow_partial_fills.unwrap_or(false) {
+            AskCollateral::partial_fill_coin_trade(
+                &info.funds,
+                &coin_trade.quote,
+                // Validation ensures that partial fill asks only include a single base coin
+                info.funds
+                    .first()
+                    .map(|base_coin| base_coin.amount.u128())
+                    .unwrap_or_default(),
+            )
+        } else {
+            AskCollateral::coin_trade(&info.funds, &coin_trade.quote)
+        },
         messages,
     }
     .to_ok()
@@ -398,11 +454,54 @@ fn create_scope_trade_ask_collateral(
         }
         .to_err();
     }
-    check_scope_owners(
-        &ProvenanceQuerier::new(&deps.querier).get_scope(&scope_trade.scope_address)?,
-        Some(&env.contract.address),
-        Some(&env.contract.address),
-    )?;
+    let scope = ProvenanceQuerier::new(&deps.querier).get_scope(&scope_trade.scope_address)?;
+    let sold_owner_addresses = match &scope_trade.sold_owner_addresses {
+        // Co-owned scopes only require that the sold stakes are held by the contract
+        Some(addresses) => {
+            let sold_owner_addresses = addresses
+                .iter()
+                .map(|address| deps.api.addr_validate(address))
+                .collect::<StdResult<Vec<Addr>>>()?;
+            check_scope_stake_escrowed(&scope, &env.contract.address, &sold_owner_addresses)?;
+            // Each sold owner must have registered its stake while it was still an owner of the
+            // scope, so only the owners whose stakes were actually escrowed can be listed as sold.
+            // Registrations are consumed when the ask is created, so updates skip this check
+            if matches!(creation_type, AskCreationType::New) {
+                if let Some(unregistered_owner) = sold_owner_addresses.iter().find(|owner| {
+                    !is_scope_stake_registered(deps.storage, &scope_trade.scope_address, owner)
+                }) {
+                    return ContractError::InvalidScopeOwner {
+                        scope_address: scope_trade.scope_address.to_owned(),
+                        explanation: format!(
+                            "sold owner [{}] did not register its stake before it was escrowed",
+                            unregistered_owner.as_str(),
+                        ),
+                    }
+                    .to_err();
+                }
+            }
+            // Only a sold owner may list the escrowed stakes and collect the quote paid for them
+            if !sold_owner_addresses.contains(&info.sender) {
+                return ContractError::Unauthorized.to_err();
+            }
+            Some(sold_owner_addresses)
+        }
+        None => {
+            check_scope_owners(
+                &scope,
+                Some(&env.contract.address),
+                Some(&env.contract.address),
+            )?;
+            None
+        }
+    };
+    if let Some(retained_owner_address) = scope_trade
+        .transfer_policy
+        .as_ref()
+        .and_then(|policy| policy.retained_owner_address.as_ref())
+    {
+        deps.api.addr_validate(retained_owner_address)?;
+    }
     match creation_type {
         AskCreationType::New => {
             if !get_ask_orders_by_collateral_id(deps.storage, &scope_trade.scope_address).is_empty()
@@ -433,15 +532,116 @@ fn create_scope_trade_ask_collateral(
                     )
                 }.to_err();
             }
+            if existing_collateral.sold_owner_addresses != sold_owner_addresses {
+                return ContractError::InvalidUpdate {
+                    explanation: format!(
+                        "scope trade with id [{}] cannot change its sold owner addresses with an update",
+                        existing_ask_order.id,
+                    ),
+                }
+                .to_err();
+            }
         }
     }
     AskCreationData {
-        collateral: AskCollateral::scope_trade(&scope_trade.scope_address, &scope_trade.quote),
+        collateral: match &sold_owner_addresses {
+            Some(addresses) => AskCollateral::co_owned_scope_trade(
+                &scope_trade.scope_address,
+                &scope_trade.quote,
+                scope_trade.transfer_policy.to_owned(),
+                addresses,
+            ),
+            None => AskCollateral::scope_trade_with_transfer_policy(
+                &scope_trade.scope_address,
+                &scope_trade.quote,
+                scope_trade.transfer_policy.to_owned(),
+            ),
+        }
+        // Record the scope's specification to allow asks to be found by it
+        .with_scope_spec_id(scope.specification_id),
         messages: vec![],
     }
     .to_ok()
 }
 
+fn create_scope_bundle_trade_ask_collateral(
+    creation_type: AskCreationType,
+    deps: &DepsMut<ProvenanceQuery>,
+    info: &MessageInfo,
+    env: &Env,
+    scope_bundle_trade: &ScopeBundleTradeAsk,
+) -> Result<AskCreationData, ContractError> {
+    if !info.funds.is_empty() {
+        return ContractError::InvalidFundsProvided {
+            message: "scope bundle trade ask requests should not include funds".to_string(),
+        }
+        .to_err();
+    }
+    let existing_ask_id = match &creation_type {
+        AskCreationType::New => None,
+        AskCreationType::Update { existing_ask_order } => {
+            check_ask_type(
+                &existing_ask_order.id,
+                &existing_ask_order.ask_type,
+                &RequestType::ScopeBundleTrade,
+            )?;
+            let existing_collateral = existing_ask_order.collateral.get_scope_bundle_trade()?;
+            if !contains_same_scopes(
+                &existing_collateral.scope_addresses,
+                &scope_bundle_trade.scope_addresses,
+            ) {
+                return ContractError::InvalidUpdate {
+                    explanation: format!(
+                        "scope bundle trade with id [{}] cannot change scope addresses with an update. current addresses [{}], proposed new addresses [{}]",
+                        existing_ask_order.id,
+                        existing_collateral.scope_addresses.join(", "),
+                        scope_bundle_trade.scope_addresses.join(", "),
+                    ),
+                }
+                .to_err();
+            }
+            Some(existing_ask_order.id.to_owned())
+        }
+    };
+    // Every scope in the bundle must be escrowed by the contract and held by no other ask
+    for scope_address in scope_bundle_trade.scope_addresses.iter() {
+        check_scope_owners(
+            &ProvenanceQuerier::new(&deps.querier).get_scope(scope_address)?,
+            Some(&env.contract.address),
+            Some(&env.contract.address),
+        )?;
+        if get_ask_orders_by_collateral_id(deps.storage, scope_address)
+            .iter()
+            .any(|ask_order| Some(&ask_order.id) != existing_ask_id.as_ref())
+        {
+            return ContractError::InvalidRequest {
+                message: format!(
+                    "only one scope trade can exist at a time for scope [{}]",
+                    scope_address,
+                ),
+            }
+            .to_err();
+        }
+    }
+    AskCreationData {
+        collateral: AskCollateral::scope_bundle_trade(
+            &scope_bundle_trade.scope_addresses,
+            &scope_bundle_trade.quote,
+        ),
+        messages: vec![],
+    }
+    .to_ok()
+}
+
+/// Determines if both lists contain the same scope addresses, regardless of order.
+fn contains_same_scopes(first: &[String], second: &[String]) -> bool {
+    let mut first = first.to_vec();
+    let mut second = second.to_vec();
+    first.sort();
+    second.sort();
+    first == second
+}
+
 fn get_marker_permission_revoke_messages(
     marker: &Marker,
     contract_address: &Addr,
@@ -1,6 +1,10 @@
+use crate::storage::address_blocklist_storage::validate_address_not_blocked;
+use crate::storage::contract_info::get_contract_info;
+use crate::storage::quote_denom_allowlist_storage::get_quote_denom_allowlist;
 use crate::types::core::error::ContractError;
 use crate::types::request::bid_types::bid::{
-    Bid, CoinTradeBid, MarkerShareSaleBid, MarkerTradeBid, ScopeTradeBid,
+    Bid, CoinTradeBid, MarkerShareSaleBid, MarkerTradeBid, ScopeBundleTradeBid, ScopeSpecTradeBid,
+    ScopeTradeBid,
 };
 use crate::types::request::bid_types::bid_collateral::BidCollateral;
 use crate::types::request::bid_types::bid_order::BidOrder;
@@ -10,6 +14,11 @@ use crate::util::extensions::ResultExtensions;
 use crate::util::provenance_utilities::get_single_marker_coin_holding;
 use crate::util::request_fee::generate_request_fee_msg;
 use crate::validation::bid_order_validation::validate_bid_order;
+use crate::validation::contract_status_validation::validate_order_creation_allowed;
+use crate::validation::counterparty_attribute_validation::{
+    validate_bid_target, validate_required_trade_attributes,
+};
+use crate::validation::request_descriptor_validation::validate_request_descriptor_times;
 use cosmwasm_std::{CosmosMsg, DepsMut, Env, MessageInfo};
 use provwasm_std::{ProvenanceMsg, ProvenanceQuerier, ProvenanceQuery};
 
@@ -31,6 +40,10 @@ pub fn create_bid_order(
     descriptor: Option<RequestDescriptor>,
     creation_type: BidCreationType,
 ) -> Result<BidOrderCreationResponse, ContractError> {
+    let contract_info = get_contract_info(deps.storage)?;
+    validate_order_creation_allowed(&contract_info)?;
+    validate_address_not_blocked(deps.storage, &info.sender)?;
+    validate_required_trade_attributes(&deps.as_ref(), &contract_info, &info.sender, "bidder")?;
     let bid_fee_msg = match &creation_type {
         BidCreationType::New => generate_request_fee_msg(
             "bid creation",
@@ -48,6 +61,12 @@ pub fn create_bid_order(
             create_marker_share_sale_collateral(deps, info, marker_share_sale)
         }
         Bid::ScopeTrade(scope_trade) => create_scope_trade_collateral(info, scope_trade),
+        Bid::ScopeBundleTrade(scope_bundle_trade) => {
+            create_scope_bundle_trade_collateral(info, scope_bundle_trade)
+        }
+        Bid::ScopeSpecTrade(scope_spec_trade) => {
+            create_scope_spec_trade_collateral(info, scope_spec_trade)
+        }
     }?;
     let bid_order = BidOrder {
         id: bid.get_id().to_string(),
@@ -55,8 +74,15 @@ pub fn create_bid_order(
         owner: info.sender.clone(),
         collateral,
         descriptor,
+        created_time: Some(env.block.time),
     };
-    validate_bid_order(&bid_order)?;
+    validate_bid_order(&bid_order, &get_quote_denom_allowlist(deps.storage)?)?;
+    validate_request_descriptor_times(
+        format!("BidOrder [{}]", &bid_order.id),
+        &bid_order.descriptor,
+        env,
+    )?;
+    validate_bid_target(&deps.as_ref(), &bid_order)?;
     BidOrderCreationResponse {
         bid_order,
         bid_fee_msg,
@@ -203,3 +229,61 @@ fn create_scope_trade_collateral(
     }
     BidCollateral::scope_trade(&scope_trade.scope_address, &info.funds).to_ok()
 }
+
+fn create_scope_bundle_trade_collateral(
+    info: &MessageInfo,
+    scope_bundle_trade: &ScopeBundleTradeBid,
+) -> Result<BidCollateral, ContractError> {
+    if scope_bundle_trade.id.is_empty() {
+        return ContractError::MissingField {
+            field: "id".to_string(),
+        }
+        .to_err();
+    }
+    if scope_bundle_trade.scope_addresses.is_empty() {
+        return ContractError::MissingField {
+            field: "scope_addresses".to_string(),
+        }
+        .to_err();
+    }
+    if info.funds.is_empty() {
+        return ContractError::InvalidFundsProvided {
+            message: "funds must be provided during a scope bundle trade bid to establish a quote"
+                .to_string(),
+        }
+        .to_err();
+    }
+    BidCollateral::scope_bundle_trade(&scope_bundle_trade.scope_addresses, &info.funds).to_ok()
+}
+
+fn create_scope_spec_trade_collateral(
+    info: &MessageInfo,
+    scope_spec_trade: &ScopeSpecTradeBid,
+) -> Result<BidCollateral, ContractError> {
+    if scope_spec_trade.id.is_empty() {
+        return ContractError::MissingField {
+            field: "id".to_string(),
+        }
+        .to_err();
+    }
+    if scope_spec_trade.scope_spec_id.is_empty() {
+        return ContractError::MissingField {
+            field: "scope_spec_id".to_string(),
+        }
+        .to_err();
+    }
+    if info.funds.is_empty() {
+        return ContractError::InvalidFundsProvided {
+            message: "funds must be provided during a scope spec trade bid to establish a quote"
+                .to_string(),
+        }
+        .to_err();
+    }
+    BidCollateral::scope_spec_trade(
+        &scope_spec_trade.scope_spec_id,
+        &info.funds,
+        scope_spec_trade.scope_attribute_requirement.to_owned(),
+        scope_spec_trade.require_value_ownership,
+    )
+    .to_ok()
+}
@@ -0,0 +1,73 @@
+use crate::storage::contract_info::get_contract_info;
+use crate::types::core::error::ContractError;
+use crate::types::request::admin_match_options::AdminMatchOptions;
+use crate::types::request::ask_types::ask_order::AskOrder;
+use crate::types::request::bid_types::bid_order::BidOrder;
+use crate::types::request::match_report::{
+    MatchFailureCode, MatchReport, MatchSide, MatchValidationFailure, SettlementPreview,
+};
+use crate::util::extensions::ResultExtensions;
+use crate::util::settlement_fee_utilities::{
+    calculate_settlement_fee, get_expected_quote_paid, get_quote_payment,
+};
+use crate::util::settlement_utilities::plan_settlement;
+use crate::validation::execute_match_validation::get_match_validation_failures;
+use cosmwasm_std::{Deps, Env};
+use provwasm_std::ProvenanceQuery;
+
+/// Determines whether or not the existing ask and bid can be matched, and the settlement fee that
+/// would be charged if they were.  When the match is possible, the report also previews the
+/// settlement that executing it would produce, calculated by the same plan that match execution
+/// follows.  A settlement that cannot be calculated is reported as a validation failure.
+pub fn create_match_report(
+    deps: &Deps<ProvenanceQuery>,
+    env: &Env,
+    ask_order: &AskOrder,
+    bid_order: &BidOrder,
+    admin_match_options: &Option<AdminMatchOptions>,
+) -> Result<MatchReport, ContractError> {
+    let mut validation_failures =
+        get_match_validation_failures(deps, env, ask_order, bid_order, admin_match_options);
+    let contract_info = get_contract_info(deps.storage)?;
+    let settlement_fee_bps = contract_info.get_settlement_fee_bps(&ask_order.ask_type);
+    let quote_paid = get_expected_quote_paid(ask_order, bid_order, admin_match_options);
+    let settlement_fee = calculate_settlement_fee(&quote_paid, settlement_fee_bps);
+    let settlement_preview = if validation_failures.is_empty() {
+        match plan_settlement(deps, env, ask_order, bid_order, admin_match_options) {
+            Ok(plan) => Some(SettlementPreview {
+                asker_proceeds: get_quote_payment(&contract_info, ask_order, &plan.quote_paid)
+                    .asker_proceeds,
+                ask_deleted: plan.is_ask_deleted(),
+                bid_deleted: plan.is_bid_deleted(),
+                quote_paid: plan.quote_paid,
+                collateral_transferred: plan.collateral_transferred,
+                bidder_refund: plan.bidder_refund,
+                shares_withdrawn: plan.shares_withdrawn,
+                collateral_released: plan.collateral_released,
+            }),
+            Err(e) => {
+                validation_failures.push(MatchValidationFailure::new(
+                    MatchFailureCode::SettlementFailed,
+                    MatchSide::Both,
+                    "settlement",
+                    format!("Settlement could not be calculated: {}", e),
+                ));
+                None
+            }
+        }
+    } else {
+        None
+    };
+    MatchReport {
+        ask_id: ask_order.id.to_owned(),
+        bid_id: bid_order.id.to_owned(),
+        ask_exists: true,
+        bid_exists: true,
+        match_possible: validation_failures.is_empty(),
+        validation_failures,
+        settlement_fee_bps,
+        settlement_fee,
+        settlement_preview,
+    }
+    .to_ok()
+}
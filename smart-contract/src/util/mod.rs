@@ -1,7 +1,11 @@
+pub mod cancel_order_utilities;
 pub mod coin_utilities;
 pub mod constants;
 pub mod create_ask_order_utilities;
 pub mod create_bid_order_utilities;
 pub mod extensions;
+pub mod match_report_utilities;
 pub mod provenance_utilities;
 pub mod request_fee;
+pub mod settlement_fee_utilities;
+pub mod settlement_utilities;
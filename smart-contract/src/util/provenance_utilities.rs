@@ -1,4 +1,5 @@
 use crate::types::core::error::ContractError;
+use crate::types::request::scope_transfer_policy::{DataAccessPolicy, ScopeTransferPolicy};
 use crate::util::extensions::ResultExtensions;
 use cosmwasm_std::{coin, Addr, Coin, CosmosMsg};
 use provwasm_std::{
@@ -35,8 +36,8 @@ pub fn get_single_marker_coin_holding(marker: &Marker) -> Result<Coin, ContractE
     let marker_denom_holdings = marker
         .coins
         .iter()
-        .cloned()
         .filter(|coin| coin.denom == marker.denom)
+        .cloned()
         .collect::<Vec<Coin>>();
     if marker_denom_holdings.len() != 1 {
         return ContractError::InvalidMarker {
@@ -158,6 +159,162 @@ pub fn replace_scope_owner(mut scope: Scope, new_owner: Addr) -> Scope {
     scope
 }
 
+/// Transfers the scope to the bidder of a scope trade, applying the asker's transfer policy.  If no
+/// policy is provided, this is equivalent to replace_scope_owner.
+pub fn transfer_scope_with_policy(
+    scope: Scope,
+    bidder: Addr,
+    transfer_policy: Option<&ScopeTransferPolicy>,
+) -> Scope {
+    let policy = transfer_policy.cloned().unwrap_or_default();
+    let scope = match &policy.retained_owner_address {
+        // Value-only transfers leave the retained address as the sole Owner party
+        Some(retained_owner) => {
+            let mut scope = replace_scope_owner(scope, Addr::unchecked(retained_owner));
+            scope.value_owner_address = bidder.to_owned();
+            scope
+        }
+        None => replace_scope_owner(scope, bidder.to_owned()),
+    };
+    apply_bidder_transfer_policy(scope, bidder, &policy)
+}
+
+/// Verifies that a co-owned scope has had the sold owners' stakes escrowed to the contract.  The
+/// escrow address must be listed as an owner, and none of the sold owners may still be listed as
+/// owners.  Other owners are not checked.
+pub fn check_scope_stake_escrowed(
+    scope: &Scope,
+    escrow_address: &Addr,
+    sold_owner_addresses: &[Addr],
+) -> Result<(), ContractError> {
+    let owners = scope
+        .owners
+        .iter()
+        .filter(|owner| owner.role == PartyType::Owner)
+        .collect::<Vec<&Party>>();
+    if !owners.iter().any(|owner| &owner.address == escrow_address) {
+        return ContractError::InvalidScopeOwner {
+            scope_address: scope.scope_id.to_owned(),
+            explanation: format!(
+                "the scope should include [{}] as an owner, but found: [{}]",
+                escrow_address.as_str(),
+                owners
+                    .iter()
+                    .map(|owner| owner.address.as_str())
+                    .collect::<Vec<&str>>()
+                    .join(", "),
+            ),
+        }
+        .to_err();
+    }
+    if let Some(sold_owner) = sold_owner_addresses
+        .iter()
+        .find(|sold_owner| owners.iter().any(|owner| &&owner.address == sold_owner))
+    {
+        return ContractError::InvalidScopeOwner {
+            scope_address: scope.scope_id.to_owned(),
+            explanation: format!(
+                "the stake of owner [{}] should be held by [{}], but the owner is still listed on the scope",
+                sold_owner.as_str(),
+                escrow_address.as_str(),
+            ),
+        }
+        .to_err();
+    }
+    ().to_ok()
+}
+
+/// Replaces a single Owner party on the scope with the given owners, leaving all other parties
+/// unchanged.
+pub fn replace_scope_owner_party(
+    mut scope: Scope,
+    previous_owner: &Addr,
+    new_owners: &[Addr],
+) -> Scope {
+    scope
+        .owners
+        .retain(|owner| !(owner.role == PartyType::Owner && &owner.address == previous_owner));
+    for new_owner in new_owners {
+        if !scope
+            .owners
+            .iter()
+            .any(|owner| owner.role == PartyType::Owner && &owner.address == new_owner)
+        {
+            scope.owners.push(Party {
+                address: new_owner.to_owned(),
+                role: PartyType::Owner,
+            });
+        }
+    }
+    // Value ownership only moves if it was escrowed alongside the stake
+    if &scope.value_owner_address == previous_owner {
+        if let Some(new_value_owner) = new_owners.first() {
+            scope.value_owner_address = new_value_owner.to_owned();
+        }
+    }
+    scope
+}
+
+/// Transfers the escrowed stake in a co-owned scope to the bidder of a scope trade, applying the
+/// asker's transfer policy.  Owners that did not sell their stake are left unchanged.
+pub fn transfer_scope_stake_with_policy(
+    scope: Scope,
+    escrow_address: &Addr,
+    bidder: Addr,
+    transfer_policy: Option<&ScopeTransferPolicy>,
+) -> Scope {
+    let policy = transfer_policy.cloned().unwrap_or_default();
+    let scope = replace_scope_owner_party(scope, escrow_address, &[bidder.to_owned()]);
+    apply_bidder_transfer_policy(scope, bidder, &policy)
+}
+
+/// Returns the escrowed stake in a co-owned scope to the owners that sold it.  If the escrow
+/// address held value ownership, it is returned to the first sold owner, regardless of which of
+/// the sold owners created the ask.
+pub fn return_scope_stake(
+    scope: Scope,
+    escrow_address: &Addr,
+    sold_owner_addresses: &[Addr],
+) -> Scope {
+    let holds_value_ownership = &scope.value_owner_address == escrow_address;
+    let mut scope = replace_scope_owner_party(scope, escrow_address, sold_owner_addresses);
+    if holds_value_ownership {
+        if let Some(first_sold_owner) = sold_owner_addresses.first() {
+            scope.value_owner_address = first_sold_owner.to_owned();
+        }
+    }
+    scope
+}
+
+fn apply_bidder_transfer_policy(
+    mut scope: Scope,
+    bidder: Addr,
+    policy: &ScopeTransferPolicy,
+) -> Scope {
+    for role in policy.get_additional_bidder_roles() {
+        if !scope
+            .owners
+            .iter()
+            .any(|party| party.address == bidder && party.role == role)
+        {
+            scope.owners.push(Party {
+                address: bidder.to_owned(),
+                role,
+            });
+        }
+    }
+    match policy.get_data_access() {
+        DataAccessPolicy::Keep => {}
+        DataAccessPolicy::Clear => scope.data_access.clear(),
+        DataAccessPolicy::AddBidder => {
+            if !scope.data_access.contains(&bidder) {
+                scope.data_access.push(bidder);
+            }
+        }
+    }
+    scope
+}
+
 pub fn get_custom_fee_amount_display(
     msg: &CosmosMsg<ProvenanceMsg>,
 ) -> Result<String, ContractError> {
@@ -609,6 +766,226 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_check_scope_stake_escrowed() {
+        let scope = MockScope {
+            owners: vec![
+                Party {
+                    address: Addr::unchecked(MOCK_CONTRACT_ADDR),
+                    role: PartyType::Owner,
+                },
+                Party {
+                    address: Addr::unchecked("co_owner"),
+                    role: PartyType::Owner,
+                },
+            ],
+            ..MockScope::default()
+        }
+        .to_scope();
+        let contract = Addr::unchecked(MOCK_CONTRACT_ADDR);
+        check_scope_stake_escrowed(&scope, &contract, &[Addr::unchecked("seller")])
+            .expect("a scope with the contract as one of multiple owners should pass the check");
+        match check_scope_stake_escrowed(&scope, &contract, &[Addr::unchecked("co_owner")])
+            .expect_err("an error should occur when a sold owner is still listed on the scope")
+        {
+            ContractError::InvalidScopeOwner { explanation, .. } => {
+                assert_eq!(
+                    format!(
+                        "the stake of owner [co_owner] should be held by [{}], but the owner is still listed on the scope",
+                        MOCK_CONTRACT_ADDR,
+                    ),
+                    explanation,
+                    "unexpected error explanation",
+                );
+            }
+            e => panic!("unexpected error type encountered: {:?}", e),
+        }
+        match check_scope_stake_escrowed(
+            &scope,
+            &Addr::unchecked("other"),
+            &[Addr::unchecked("seller")],
+        )
+        .expect_err("an error should occur when the escrow address is not an owner")
+        {
+            ContractError::InvalidScopeOwner { explanation, .. } => {
+                assert_eq!(
+                    format!(
+                        "the scope should include [other] as an owner, but found: [{}, co_owner]",
+                        MOCK_CONTRACT_ADDR,
+                    ),
+                    explanation,
+                    "unexpected error explanation",
+                );
+            }
+            e => panic!("unexpected error type encountered: {:?}", e),
+        }
+    }
+
+    #[test]
+    fn test_transfer_and_return_scope_stake() {
+        let get_scope = || {
+            MockScope {
+                owners: vec![
+                    Party {
+                        address: Addr::unchecked(MOCK_CONTRACT_ADDR),
+                        role: PartyType::Owner,
+                    },
+                    Party {
+                        address: Addr::unchecked("co_owner"),
+                        role: PartyType::Owner,
+                    },
+                ],
+                value_owner_address: Addr::unchecked("co_owner"),
+                ..MockScope::default()
+            }
+            .to_scope()
+        };
+        let contract = Addr::unchecked(MOCK_CONTRACT_ADDR);
+        let scope = transfer_scope_stake_with_policy(
+            get_scope(),
+            &contract,
+            Addr::unchecked("bidder"),
+            Some(&ScopeTransferPolicy::new().with_additional_bidder_roles(&[PartyType::Servicer])),
+        );
+        assert_eq!(
+            vec![
+                Party {
+                    address: Addr::unchecked("co_owner"),
+                    role: PartyType::Owner,
+                },
+                Party {
+                    address: Addr::unchecked("bidder"),
+                    role: PartyType::Owner,
+                },
+                Party {
+                    address: Addr::unchecked("bidder"),
+                    role: PartyType::Servicer,
+                },
+            ],
+            scope.owners,
+            "only the contract's stake should be transferred to the bidder",
+        );
+        assert_eq!(
+            "co_owner",
+            scope.value_owner_address.as_str(),
+            "value ownership should not move when the contract does not hold it",
+        );
+        let scope = return_scope_stake(
+            get_scope(),
+            &contract,
+            &[Addr::unchecked("seller1"), Addr::unchecked("seller2")],
+        );
+        assert_eq!(
+            vec!["co_owner", "seller1", "seller2"],
+            scope
+                .owners
+                .iter()
+                .map(|owner| owner.address.as_str())
+                .collect::<Vec<&str>>(),
+            "the contract's stake should be returned to each seller",
+        );
+        assert_eq!(
+            "co_owner",
+            scope.value_owner_address.as_str(),
+            "value ownership should not move when the contract does not hold it",
+        );
+    }
+
+    #[test]
+    fn test_transfer_scope_with_policy() {
+        let get_scope = || {
+            MockScope {
+                owners: vec![
+                    Party {
+                        address: Addr::unchecked(MOCK_CONTRACT_ADDR),
+                        role: PartyType::Owner,
+                    },
+                    Party {
+                        address: Addr::unchecked("servicer"),
+                        role: PartyType::Servicer,
+                    },
+                ],
+                data_access: vec![Addr::unchecked("auditor")],
+                value_owner_address: Addr::unchecked(MOCK_CONTRACT_ADDR),
+                ..MockScope::default()
+            }
+            .to_scope()
+        };
+        let bidder = Addr::unchecked("bidder");
+        assert_eq!(
+            replace_scope_owner(get_scope(), bidder.to_owned()),
+            transfer_scope_with_policy(get_scope(), bidder.to_owned(), None),
+            "omitting a transfer policy should produce the same result as replacing the scope owner",
+        );
+        let scope = transfer_scope_with_policy(
+            get_scope(),
+            bidder.to_owned(),
+            Some(
+                &ScopeTransferPolicy::new()
+                    .with_data_access(DataAccessPolicy::Clear)
+                    .with_additional_bidder_roles(&[PartyType::Investor]),
+            ),
+        );
+        assert!(
+            scope.data_access.is_empty(),
+            "the clear policy should remove all data access addresses",
+        );
+        assert_eq!(
+            vec![
+                Party {
+                    address: Addr::unchecked("servicer"),
+                    role: PartyType::Servicer,
+                },
+                Party {
+                    address: bidder.to_owned(),
+                    role: PartyType::Owner,
+                },
+                Party {
+                    address: bidder.to_owned(),
+                    role: PartyType::Investor,
+                },
+            ],
+            scope.owners,
+            "the bidder should become the owner and be added with the additional role",
+        );
+        assert_eq!(
+            bidder, scope.value_owner_address,
+            "the bidder should become the value owner",
+        );
+        let scope = transfer_scope_with_policy(
+            get_scope(),
+            bidder.to_owned(),
+            Some(
+                &ScopeTransferPolicy::new()
+                    .with_data_access(DataAccessPolicy::AddBidder)
+                    .with_retained_owner_address("originator"),
+            ),
+        );
+        assert_eq!(
+            vec![Addr::unchecked("auditor"), bidder.to_owned()],
+            scope.data_access,
+            "the bidder should be appended to the existing data access addresses",
+        );
+        assert_eq!(
+            vec![
+                Party {
+                    address: Addr::unchecked("servicer"),
+                    role: PartyType::Servicer,
+                },
+                Party {
+                    address: Addr::unchecked("originator"),
+                    role: PartyType::Owner,
+                },
+            ],
+            scope.owners,
+            "the retained owner should become the sole owner and the bidder should not be added as a party",
+        );
+        assert_eq!(
+            bidder, scope.value_owner_address,
+            "the bidder should receive value ownership when the owner is retained",
+        );
+    }
+
     #[test]
     fn test_get_custom_fee_amount_display() {
         let invalid_msg: CosmosMsg<ProvenanceMsg> = CosmosMsg::Bank(BankMsg::Send {
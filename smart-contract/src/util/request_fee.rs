@@ -28,9 +28,9 @@ pub fn generate_request_fee_msg<S: Into<String>, F: Fn(&ContractInfoV2) -> u128>
             // the contract sends the fee, but it is required for the contract to sign and dispatch
             // the message that will charge the request sender the fee
             contract_addr,
-            // Always send fees charged to the admin address.  This ensures that the admin is
-            // always funded in order to make future requests
-            Some(contract_info.admin),
+            // Creation fees are collected by the same account as settlement fees, which is the
+            // admin when no fee collector has been set
+            Some(contract_info.get_fee_collector_address().to_owned()),
         )?)
     } else {
         None
@@ -114,7 +114,43 @@ mod tests {
                 assert_eq!(
                     DEFAULT_ADMIN_ADDRESS,
                     recipient.expect("a recipient should be set").as_str(),
-                    "the recipient should be the admin",
+                    "the recipient should be the admin when no fee collector is set",
+                );
+            }
+            other => panic!("unexpected response option: {:?}", other),
+        };
+    }
+
+    #[test]
+    fn test_generate_request_fee_sends_fee_to_fee_collector() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(
+            deps.as_mut(),
+            TestInstantiate {
+                create_ask_nhash_fee: Some(Uint128::new(100)),
+                fee_collector_address: Some("fee_collector".to_string()),
+                ..TestInstantiate::default()
+            },
+        );
+        let fee_msg_option = generate_request_fee_msg(
+            "some fee",
+            &deps.as_ref(),
+            Addr::unchecked(MOCK_CONTRACT_ADDR),
+            |c| c.create_ask_nhash_fee.u128(),
+        )
+        .expect("proper fee specification should result in a fee msg");
+        match fee_msg_option {
+            Some(CosmosMsg::Custom(ProvenanceMsg {
+                params:
+                    ProvenanceMsgParams::MsgFees(MsgFeesMsgParams::AssessCustomFee {
+                        recipient, ..
+                    }),
+                ..
+            })) => {
+                assert_eq!(
+                    "fee_collector",
+                    recipient.expect("a recipient should be set").as_str(),
+                    "the recipient should be the fee collector",
                 );
             }
             other => panic!("unexpected response option: {:?}", other),
@@ -0,0 +1,253 @@
+use crate::storage::contract_info::ContractInfoV2;
+use crate::types::core::constants::BASIS_POINTS_PER_WHOLE;
+use crate::types::request::admin_match_options::AdminMatchOptions;
+use crate::types::request::ask_types::ask_collateral::AskCollateral;
+use crate::types::request::ask_types::ask_order::AskOrder;
+use crate::types::request::bid_types::bid_collateral::BidCollateral;
+use crate::types::request::bid_types::bid_order::BidOrder;
+use crate::util::coin_utilities::calculate_marker_share_sale_purchase;
+use cosmwasm_std::{coin, Addr, BankMsg, Coin, CosmosMsg, Uint128};
+use provwasm_std::ProvenanceMsg;
+
+pub struct QuotePayment {
+    pub messages: Vec<CosmosMsg<ProvenanceMsg>>,
+    pub asker_proceeds: Vec<Coin>,
+    pub settlement_fee: Vec<Coin>,
+}
+
+/// Calculates the portion of each quote coin owed as a settlement fee.  Fractional amounts are
+/// rounded down in favor of the asker, and coins that produce no fee are omitted.
+pub fn calculate_settlement_fee(quote: &[Coin], fee_bps: Uint128) -> Vec<Coin> {
+    quote
+        .iter()
+        .map(|quote_coin| {
+            coin(
+                quote_coin
+                    .amount
+                    .multiply_ratio(fee_bps, BASIS_POINTS_PER_WHOLE)
+                    .u128(),
+                &quote_coin.denom,
+            )
+        })
+        .filter(|fee_coin| !fee_coin.amount.is_zero())
+        .collect()
+}
+
+/// Splits the quote paid during a match between the asker and the contract's fee collector,
+/// producing the bank messages required to distribute it.
+pub fn get_quote_payment(
+    contract_info: &ContractInfoV2,
+    ask_order: &AskOrder,
+    quote_paid: &[Coin],
+) -> QuotePayment {
+    let settlement_fee = calculate_settlement_fee(
+        quote_paid,
+        contract_info.get_settlement_fee_bps(&ask_order.ask_type),
+    );
+    let asker_proceeds = quote_paid
+        .iter()
+        .map(|quote_coin| {
+            let fee_amount = settlement_fee
+                .iter()
+                .find(|fee_coin| fee_coin.denom == quote_coin.denom)
+                .map(|fee_coin| fee_coin.amount)
+                .unwrap_or_else(Uint128::zero);
+            coin((quote_coin.amount - fee_amount).u128(), &quote_coin.denom)
+        })
+        .filter(|proceeds_coin| !proceeds_coin.amount.is_zero())
+        .collect::<Vec<Coin>>();
+    let mut messages = vec![];
+    // Bank sends with no coin are rejected, so each message is only included when funds are owed
+    if !asker_proceeds.is_empty() {
+        messages.push(bank_send(&ask_order.owner, &asker_proceeds));
+    }
+    if !settlement_fee.is_empty() {
+        messages.push(bank_send(
+            contract_info.get_fee_collector_address(),
+            &settlement_fee,
+        ));
+    }
+    QuotePayment {
+        messages,
+        asker_proceeds,
+        settlement_fee,
+    }
+}
+
+/// Determines the quote that would be paid to the asker if the ask and bid were matched.  An
+/// empty vector is returned when the orders are not of the same type.
+pub fn get_expected_quote_paid(
+    ask_order: &AskOrder,
+    bid_order: &BidOrder,
+    admin_match_options: &Option<AdminMatchOptions>,
+) -> Vec<Coin> {
+    match (&ask_order.collateral, &bid_order.collateral) {
+        (
+            AskCollateral::MarkerShareSale(ask_collateral),
+            BidCollateral::MarkerShareSale(bid_collateral),
+        ) => {
+            calculate_marker_share_sale_purchase(
+                ask_collateral,
+                bid_collateral,
+                &match admin_match_options {
+                    Some(AdminMatchOptions::MarkerShareSale {
+                        override_quote_source,
+                    }) => override_quote_source.to_owned(),
+                    _ => None,
+                },
+            )
+            .quote_paid
+        }
+        (AskCollateral::CoinTrade(_), BidCollateral::CoinTrade(_))
+        | (AskCollateral::MarkerTrade(_), BidCollateral::MarkerTrade(_))
+        | (AskCollateral::ScopeTrade(_), BidCollateral::ScopeTrade(_))
+        | (AskCollateral::ScopeBundleTrade(_), BidCollateral::ScopeBundleTrade(_)) => {
+            bid_order.collateral.get_quote()
+        }
+        // Scope spec bids pay the ask's quote, unless the admin accepts the bid's quote instead
+        (AskCollateral::ScopeTrade(ask_collateral), BidCollateral::ScopeSpecTrade(_)) => {
+            match admin_match_options {
+                Some(AdminMatchOptions::ScopeTrade {
+                    accept_mismatched_bids: Some(true),
+                }) => bid_order.collateral.get_quote(),
+                _ => ask_collateral.quote.to_owned(),
+            }
+        }
+        _ => vec![],
+    }
+}
+
+fn bank_send(recipient: &Addr, amount: &[Coin]) -> CosmosMsg<ProvenanceMsg> {
+    CosmosMsg::Bank(BankMsg::Send {
+        to_address: recipient.to_string(),
+        amount: amount.to_vec(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::storage::contract_info::ContractInfoV2;
+    use crate::test::mock_instantiate::DEFAULT_ADMIN_ADDRESS;
+    use crate::test::request_helpers::mock_ask_order;
+    use crate::types::request::ask_types::ask_collateral::AskCollateral;
+    use crate::types::request::settlement_fees::SettlementFees;
+    use crate::util::settlement_fee_utilities::{calculate_settlement_fee, get_quote_payment};
+    use cosmwasm_std::{coin, coins, Addr, BankMsg, Coin, CosmosMsg, Uint128};
+    use provwasm_std::ProvenanceMsg;
+
+    #[test]
+    fn test_calculate_settlement_fee() {
+        assert!(
+            calculate_settlement_fee(&coins(1000, "quote"), Uint128::zero()).is_empty(),
+            "a zero fee rate should produce no fee",
+        );
+        assert_eq!(
+            vec![coin(25, "quote"), coin(2, "other")],
+            calculate_settlement_fee(&[coin(1000, "quote"), coin(99, "other")], Uint128::new(250),),
+            "each quote coin should be charged the fee rate, rounding down",
+        );
+        assert!(
+            calculate_settlement_fee(&coins(39, "quote"), Uint128::new(250)).is_empty(),
+            "fees that round down to zero should be omitted",
+        );
+        assert_eq!(
+            coins(1000, "quote"),
+            calculate_settlement_fee(&coins(1000, "quote"), Uint128::new(10_000)),
+            "the maximum fee rate should charge the entire quote",
+        );
+    }
+
+    #[test]
+    fn test_get_quote_payment_without_fees() {
+        let payment = get_quote_payment(
+            &mock_contract_info(None, None),
+            &mock_ask_order(AskCollateral::coin_trade(&[], &[])),
+            &coins(1000, "quote"),
+        );
+        assert!(
+            payment.settlement_fee.is_empty(),
+            "no fee should be charged"
+        );
+        assert_eq!(
+            vec![bank_send("asker", &coins(1000, "quote"))],
+            payment.messages,
+            "the entire quote should be sent to the asker",
+        );
+    }
+
+    #[test]
+    fn test_get_quote_payment_with_fees() {
+        let fees = SettlementFees {
+            coin_trade_bps: Some(Uint128::new(100)),
+            ..SettlementFees::default()
+        };
+        let payment = get_quote_payment(
+            &mock_contract_info(Some(fees.to_owned()), None),
+            &mock_ask_order(AskCollateral::coin_trade(&[], &[])),
+            &coins(1000, "quote"),
+        );
+        assert_eq!(
+            coins(990, "quote"),
+            payment.asker_proceeds,
+            "the asker should receive the quote minus the fee",
+        );
+        assert_eq!(
+            vec![
+                bank_send("asker", &coins(990, "quote")),
+                bank_send(DEFAULT_ADMIN_ADDRESS, &coins(10, "quote")),
+            ],
+            payment.messages,
+            "the fee should be sent to the admin when no fee collector is set",
+        );
+        let payment = get_quote_payment(
+            &mock_contract_info(Some(fees), Some("collector")),
+            &mock_ask_order(AskCollateral::coin_trade(&[], &[])),
+            &coins(1000, "quote"),
+        );
+        assert_eq!(
+            vec![
+                bank_send("asker", &coins(990, "quote")),
+                bank_send("collector", &coins(10, "quote")),
+            ],
+            payment.messages,
+            "the fee should be sent to the fee collector when one is set",
+        );
+        let payment = get_quote_payment(
+            &mock_contract_info(
+                Some(SettlementFees {
+                    marker_trade_bps: Some(Uint128::new(100)),
+                    ..SettlementFees::default()
+                }),
+                None,
+            ),
+            &mock_ask_order(AskCollateral::coin_trade(&[], &[])),
+            &coins(1000, "quote"),
+        );
+        assert!(
+            payment.settlement_fee.is_empty(),
+            "fees for other request types should not be charged",
+        );
+    }
+
+    fn mock_contract_info(
+        settlement_fees: Option<SettlementFees>,
+        fee_collector_address: Option<&str>,
+    ) -> ContractInfoV2 {
+        ContractInfoV2::new(
+            Addr::unchecked(DEFAULT_ADMIN_ADDRESS),
+            "bind_name",
+            "contract_name",
+            None,
+            None,
+            settlement_fees,
+            fee_collector_address.map(Addr::unchecked),
+        )
+    }
+
+    fn bank_send(recipient: &str, amount: &[Coin]) -> CosmosMsg<ProvenanceMsg> {
+        CosmosMsg::Bank(BankMsg::Send {
+            to_address: recipient.to_string(),
+            amount: amount.to_vec(),
+        })
+    }
+}
@@ -0,0 +1,430 @@
+use crate::storage::ask_order_storage::get_ask_orders_by_collateral_id;
+use crate::types::core::error::ContractError;
+use crate::types::request::admin_match_options::{AdminMatchOptions, OverrideQuoteSource};
+use crate::types::request::ask_types::ask_collateral::{
+    AskCollateral, CoinTradeAskCollateral, MarkerShareSaleAskCollateral, MarkerTradeAskCollateral,
+    ScopeBundleTradeAskCollateral, ScopeTradeAskCollateral,
+};
+use crate::types::request::ask_types::ask_order::AskOrder;
+use crate::types::request::bid_types::bid_collateral::{
+    BidCollateral, CoinTradeBidCollateral, MarkerShareSaleBidCollateral, MarkerTradeBidCollateral,
+    ScopeBundleTradeBidCollateral,
+};
+use crate::types::request::bid_types::bid_order::BidOrder;
+use crate::types::request::match_record::MatchedCollateral;
+use crate::types::request::share_sale_type::ShareSaleType;
+use crate::util::coin_utilities::{
+    calculate_marker_share_sale_bid_totals, calculate_marker_share_sale_purchase, subtract_coins,
+    MSSBidTotalsCalc, MSSPurchaseCalc,
+};
+use crate::util::extensions::ResultExtensions;
+use crate::util::provenance_utilities::{
+    release_marker_from_contract, replace_scope_owner, transfer_scope_stake_with_policy,
+    transfer_scope_with_policy,
+};
+use crate::util::settlement_fee_utilities::get_expected_quote_paid;
+use cosmwasm_std::{BankMsg, Coin, CosmosMsg, Deps, Env, Uint128};
+use provwasm_std::{
+    withdraw_coins, write_scope, ProvenanceMsg, ProvenanceQuerier, ProvenanceQuery,
+};
+
+/// Determines what becomes of an order when its match is settled.
+pub enum OrderUpdate<T> {
+    /// The order is removed because its trade has concluded.
+    Delete,
+    /// The order is replaced with an updated version that remains open.
+    Replace(T),
+}
+
+/// Describes every change that settling a match will make, calculated without modifying any state.
+pub struct SettlementPlan {
+    pub messages: Vec<CosmosMsg<ProvenanceMsg>>,
+    pub ask_update: OrderUpdate<AskOrder>,
+    pub bid_update: OrderUpdate<BidOrder>,
+    pub collateral_released: bool,
+    pub quote_paid: Vec<Coin>,
+    pub collateral_transferred: MatchedCollateral,
+    /// The unspent funds from the bid's quote that are returned to the bidder.
+    pub bidder_refund: Vec<Coin>,
+    /// The marker shares withdrawn from the marker into the bidder's account.
+    pub shares_withdrawn: Uint128,
+}
+impl SettlementPlan {
+    pub fn is_ask_deleted(&self) -> bool {
+        matches!(self.ask_update, OrderUpdate::Delete)
+    }
+
+    pub fn is_bid_deleted(&self) -> bool {
+        matches!(self.bid_update, OrderUpdate::Delete)
+    }
+}
+
+/// Calculates the settlement of a match between an ask and bid that have already passed
+/// validation.
+pub fn plan_settlement(
+    deps: &Deps<ProvenanceQuery>,
+    env: &Env,
+    ask_order: &AskOrder,
+    bid_order: &BidOrder,
+    admin_match_options: &Option<AdminMatchOptions>,
+) -> Result<SettlementPlan, ContractError> {
+    match &ask_order.collateral {
+        AskCollateral::CoinTrade(collateral) => plan_coin_trade(
+            ask_order,
+            bid_order,
+            collateral,
+            bid_order.collateral.get_coin_trade()?,
+        ),
+        AskCollateral::MarkerTrade(collateral) => plan_marker_trade(
+            env,
+            ask_order,
+            bid_order,
+            collateral,
+            bid_order.collateral.get_marker_trade()?,
+        ),
+        AskCollateral::MarkerShareSale(collateral) => plan_marker_share_sale(
+            deps,
+            env,
+            ask_order,
+            bid_order,
+            collateral,
+            bid_order.collateral.get_marker_share_sale()?,
+            admin_match_options.to_owned().and_then(|opt| match opt {
+                AdminMatchOptions::MarkerShareSale {
+                    override_quote_source,
+                } => override_quote_source,
+                _ => None,
+            }),
+        ),
+        AskCollateral::ScopeTrade(collateral) => plan_scope_trade(
+            deps,
+            env,
+            bid_order,
+            collateral,
+            // Scope spec bids may offer more than the ask's quote, so the amount paid is resolved
+            // the same way as it is for match reports
+            get_expected_quote_paid(ask_order, bid_order, admin_match_options),
+        ),
+        AskCollateral::ScopeBundleTrade(collateral) => plan_scope_bundle_trade(
+            deps,
+            env,
+            bid_order,
+            collateral,
+            bid_order.collateral.get_scope_bundle_trade()?,
+        ),
+    }
+}
+
+fn plan_coin_trade(
+    ask_order: &AskOrder,
+    bid_order: &BidOrder,
+    ask_collateral: &CoinTradeAskCollateral,
+    bid_collateral: &CoinTradeBidCollateral,
+) -> Result<SettlementPlan, ContractError> {
+    // Partial fill asks only send the portion of the base requested by the bidder.  Validation has
+    // already ensured that the bid base is a single coin that does not exceed the remaining base.
+    let base_transferred = if ask_collateral.allows_partial_fills() {
+        bid_collateral.base.to_owned()
+    } else {
+        ask_collateral.base.to_owned()
+    };
+    let remaining_base_after_match = ask_collateral.remaining_base.map(|remaining_base| {
+        remaining_base.u128()
+            - base_transferred
+                .first()
+                .map(|base_coin| base_coin.amount.u128())
+                .unwrap_or_default()
+    });
+    let ask_update = match remaining_base_after_match {
+        // Replace the ask order in storage with an updated remaining base value, leaving it open
+        // for additional bids
+        Some(remaining_base) if remaining_base > 0 => {
+            let mut ask_order = ask_order.to_owned();
+            let mut ask_collateral = ask_collateral.to_owned();
+            ask_collateral.remaining_base = Some(Uint128::new(remaining_base));
+            ask_order.collateral = AskCollateral::CoinTrade(ask_collateral);
+            OrderUpdate::Replace(ask_order)
+        }
+        // Remove the ask - its base has been entirely sold
+        _ => OrderUpdate::Delete,
+    };
+    // When the ask remains open, the contract continues to hold its remaining base
+    let collateral_released = matches!(ask_update, OrderUpdate::Delete);
+    SettlementPlan {
+        messages: vec![CosmosMsg::Bank(BankMsg::Send {
+            to_address: bid_order.owner.to_string(),
+            amount: base_transferred.to_owned(),
+        })],
+        ask_update,
+        // Remove the bid - this transaction has concluded for the bidder
+        bid_update: OrderUpdate::Delete,
+        collateral_released,
+        quote_paid: bid_collateral.quote.to_owned(),
+        collateral_transferred: MatchedCollateral::CoinTrade {
+            base: base_transferred,
+        },
+        bidder_refund: vec![],
+        shares_withdrawn: Uint128::zero(),
+    }
+    .to_ok()
+}
+
+fn plan_marker_trade(
+    env: &Env,
+    ask_order: &AskOrder,
+    bid_order: &BidOrder,
+    ask_collateral: &MarkerTradeAskCollateral,
+    bid_collateral: &MarkerTradeBidCollateral,
+) -> Result<SettlementPlan, ContractError> {
+    let mut messages = vec![];
+    let mut shares_withdrawn = Uint128::zero();
+    // Only transfer marker shares to the bidder if the bidder explicitly requested it with a Some(true)
+    // value for their withdraw_shares_after_match param during BidOrder creation
+    if bid_collateral.withdraw_shares_after_match.unwrap_or(false) {
+        messages.push(withdraw_coins(
+            &ask_collateral.marker_denom,
+            // Withdraw all remaining shares in the marker to the bidder's account upon marker
+            // trade completion.  This will cause them to immediately show up in the bidder's wallet.
+            ask_collateral.share_count.u128(),
+            &ask_collateral.marker_denom,
+            bid_order.owner.to_owned(),
+        )?);
+        shares_withdrawn = ask_collateral.share_count;
+    }
+    if let Some(asker_permissions) = ask_collateral
+        .removed_permissions
+        .iter()
+        .find(|perm| perm.address == ask_order.owner)
+    {
+        // Now that the match has been made, grant all permissions on the marker to the bidder that
+        // the asker once had.  The validation code has already ensured that the asker was an admin
+        // of the marker, so the bidder at very least has the permission on the marker to grant
+        // themselves any remaining permissions they desire.
+        let mut bidder_permissions = asker_permissions.to_owned();
+        bidder_permissions.address = bid_order.owner.to_owned();
+        messages.append(&mut release_marker_from_contract(
+            &ask_collateral.marker_denom,
+            &env.contract.address,
+            &[bidder_permissions],
+        )?);
+    } else {
+        return ContractError::ValidationError {
+            messages: vec![
+                "failed to find access permissions in the revoked permissions for the asker"
+                    .to_string(),
+            ],
+        }
+        .to_err();
+    }
+    SettlementPlan {
+        messages,
+        // Remove ask and bid - this transaction has concluded
+        ask_update: OrderUpdate::Delete,
+        bid_update: OrderUpdate::Delete,
+        collateral_released: true,
+        quote_paid: bid_collateral.quote.to_owned(),
+        collateral_transferred: MatchedCollateral::MarkerTrade {
+            marker_address: ask_collateral.marker_address.to_owned(),
+            marker_denom: ask_collateral.marker_denom.to_owned(),
+            share_count: ask_collateral.share_count,
+        },
+        bidder_refund: vec![],
+        shares_withdrawn,
+    }
+    .to_ok()
+}
+
+fn plan_marker_share_sale(
+    deps: &Deps<ProvenanceQuery>,
+    env: &Env,
+    ask_order: &AskOrder,
+    bid_order: &BidOrder,
+    ask_collateral: &MarkerShareSaleAskCollateral,
+    bid_collateral: &MarkerShareSaleBidCollateral,
+    override_quote_source: Option<OverrideQuoteSource>,
+) -> Result<SettlementPlan, ContractError> {
+    let MSSPurchaseCalc {
+        shares_purchased,
+        bid_overage_shares,
+        quote_paid,
+    } = calculate_marker_share_sale_purchase(
+        ask_collateral,
+        bid_collateral,
+        &override_quote_source,
+    );
+    // Bidder gets their X marker coins withdrawn to them from the contract-controlled marker
+    let mut messages = vec![withdraw_coins(
+        &ask_collateral.marker_denom,
+        shares_purchased,
+        &ask_collateral.marker_denom,
+        bid_order.owner.to_owned(),
+    )?];
+    let ask_update = match ask_collateral.sale_type {
+        // Single transaction sales should always terminate immediately after the sale completes
+        ShareSaleType::SingleTransaction => OrderUpdate::Delete,
+        ShareSaleType::MultipleTransactions => {
+            // Validation will prevent this value from ever becoming less than zero from the sale,
+            // so this is a safe operation
+            let shares_remaining_after_sale =
+                ask_collateral.remaining_shares_in_sale.u128() - shares_purchased;
+            // If all listed shares are now sold, terminate the sale
+            if shares_remaining_after_sale == 0 {
+                OrderUpdate::Delete
+            } else {
+                let mut ask_order = ask_order.to_owned();
+                let mut ask_collateral = ask_collateral.to_owned();
+                ask_collateral.remaining_shares_in_sale = Uint128::new(shares_remaining_after_sale);
+                ask_order.collateral = AskCollateral::MarkerShareSale(ask_collateral);
+                // Replace the ask order in storage with an updated remaining_shares value
+                OrderUpdate::Replace(ask_order)
+            }
+        }
+    };
+    // Only release the marker if this is the final remaining ask for the given marker.  Multiple
+    // marker share sales can be created for a single marker while it is held by the contract, so
+    // this check ensures that the marker is only relinquished when the final sale is completed.
+    let collateral_released = matches!(ask_update, OrderUpdate::Delete)
+        && get_ask_orders_by_collateral_id(deps.storage, ask_collateral.marker_address.as_str())
+            .len()
+            <= 1;
+    if collateral_released {
+        // Marker gets released to the asker.  The sale is effectively over.
+        messages.append(&mut release_marker_from_contract(
+            &ask_collateral.marker_denom,
+            &env.contract.address,
+            &ask_collateral.removed_permissions,
+        )?);
+    }
+    let MSSBidTotalsCalc {
+        expected_remaining_bidder_coin,
+        bidder_refund,
+        ..
+    } = calculate_marker_share_sale_bid_totals(bid_collateral, &quote_paid, bid_overage_shares)?;
+    // Subtract coins will not add coin values of zero into the vector.  This ensures that a vector
+    // that would be the result of perfect subtraction and cause zeroed out values will be empty,
+    // allowing this check to ensure a refund message is only populated when actual coin needs to
+    // be sent
+    if !bidder_refund.is_empty() {
+        messages.push(CosmosMsg::Bank(BankMsg::Send {
+            to_address: bid_order.owner.to_string(),
+            amount: bidder_refund.to_owned(),
+        }));
+    }
+    // If a bid overage occurred, then the bid should remain open with a reduced number of shares
+    // and a reset quote.
+    let bid_update = if bid_overage_shares > 0 {
+        let mut updated_bidder_collateral = bid_collateral.to_owned();
+        updated_bidder_collateral.share_count = Uint128::new(bid_overage_shares);
+        updated_bidder_collateral.quote = expected_remaining_bidder_coin;
+        let mut updated_bid_order = bid_order.to_owned();
+        updated_bid_order.collateral = BidCollateral::MarkerShareSale(updated_bidder_collateral);
+        OrderUpdate::Replace(updated_bid_order)
+    } else {
+        // If no bid overage occurred, then all shares were purchased at the expected amount and the
+        // bid should be closed.
+        OrderUpdate::Delete
+    };
+    SettlementPlan {
+        messages,
+        ask_update,
+        bid_update,
+        collateral_released,
+        quote_paid,
+        collateral_transferred: MatchedCollateral::MarkerShareSale {
+            marker_address: ask_collateral.marker_address.to_owned(),
+            marker_denom: ask_collateral.marker_denom.to_owned(),
+            shares_purchased: Uint128::new(shares_purchased),
+        },
+        bidder_refund,
+        shares_withdrawn: Uint128::new(shares_purchased),
+    }
+    .to_ok()
+}
+
+fn plan_scope_trade(
+    deps: &Deps<ProvenanceQuery>,
+    env: &Env,
+    bid_order: &BidOrder,
+    ask_collateral: &ScopeTradeAskCollateral,
+    quote_paid: Vec<Coin>,
+) -> Result<SettlementPlan, ContractError> {
+    let scope = ProvenanceQuerier::new(&deps.querier).get_scope(&ask_collateral.scope_address)?;
+    // Bidder gets the scope transferred to them
+    let mut messages = vec![write_scope(
+        if ask_collateral.is_co_owned() {
+            transfer_scope_stake_with_policy(
+                scope,
+                &env.contract.address,
+                bid_order.owner.to_owned(),
+                ask_collateral.transfer_policy.as_ref(),
+            )
+        } else {
+            transfer_scope_with_policy(
+                scope,
+                bid_order.owner.to_owned(),
+                ask_collateral.transfer_policy.as_ref(),
+            )
+        },
+        vec![env.contract.address.to_owned()],
+    )?];
+    // Generic scope spec bids can offer more than the quote that is paid.  The bid is always
+    // deleted, so any unspent funds are returned to the bidder
+    let bidder_refund = subtract_coins(
+        "scope trade bidder refund",
+        &bid_order.collateral.get_quote(),
+        &quote_paid,
+    )?;
+    if !bidder_refund.is_empty() {
+        messages.push(CosmosMsg::Bank(BankMsg::Send {
+            to_address: bid_order.owner.to_string(),
+            amount: bidder_refund.to_owned(),
+        }));
+    }
+    SettlementPlan {
+        messages,
+        // Remove the ask and bid orders now that the trade has been finalized
+        ask_update: OrderUpdate::Delete,
+        bid_update: OrderUpdate::Delete,
+        collateral_released: true,
+        quote_paid,
+        collateral_transferred: MatchedCollateral::ScopeTrade {
+            scope_address: ask_collateral.scope_address.to_owned(),
+        },
+        bidder_refund,
+        shares_withdrawn: Uint128::zero(),
+    }
+    .to_ok()
+}
+
+fn plan_scope_bundle_trade(
+    deps: &Deps<ProvenanceQuery>,
+    env: &Env,
+    bid_order: &BidOrder,
+    ask_collateral: &ScopeBundleTradeAskCollateral,
+    bid_collateral: &ScopeBundleTradeBidCollateral,
+) -> Result<SettlementPlan, ContractError> {
+    // Bidder gets every scope in the bundle transferred to them.  All writes are dispatched in the
+    // same response, so the transfer either completes for every scope or fails entirely
+    let mut messages = vec![];
+    for scope_address in ask_collateral.scope_addresses.iter() {
+        let scope = ProvenanceQuerier::new(&deps.querier).get_scope(scope_address)?;
+        messages.push(write_scope(
+            replace_scope_owner(scope, bid_order.owner.to_owned()),
+            vec![env.contract.address.to_owned()],
+        )?);
+    }
+    SettlementPlan {
+        messages,
+        // Remove the ask and bid orders now that the trade has been finalized
+        ask_update: OrderUpdate::Delete,
+        bid_update: OrderUpdate::Delete,
+        collateral_released: true,
+        quote_paid: bid_collateral.quote.to_owned(),
+        collateral_transferred: MatchedCollateral::ScopeBundleTrade {
+            scope_addresses: ask_collateral.scope_addresses.to_owned(),
+        },
+        bidder_refund: vec![],
+        shares_withdrawn: Uint128::zero(),
+    }
+    .to_ok()
+}
@@ -1,11 +1,20 @@
 use crate::types::core::error::ContractError;
+use crate::types::core::quote_denom_allowlist::QuoteDenomAllowlist;
 use crate::types::request::ask_types::ask_collateral::AskCollateral;
 use crate::types::request::ask_types::ask_order::AskOrder;
 use crate::types::request::request_type::RequestType;
+use crate::validation::attribute_requirement_validation::{
+    validate_attribute_expression, validate_attribute_requirement,
+};
+use crate::validation::quote_denom_validation::validate_quote_denoms;
 use crate::validation::validation_handler::ValidationHandler;
 use cosmwasm_std::Coin;
+use provwasm_std::PartyType;
 
-pub fn validate_ask_order(ask_order: &AskOrder) -> Result<(), ContractError> {
+pub fn validate_ask_order(
+    ask_order: &AskOrder,
+    quote_denom_allowlist: &QuoteDenomAllowlist,
+) -> Result<(), ContractError> {
     let handler = ValidationHandler::new();
     if ask_order.id.is_empty() {
         handler.push("id for AskOrder must not be empty");
@@ -18,9 +27,35 @@ pub fn validate_ask_order(ask_order: &AskOrder) -> Result<(), ContractError> {
         .as_ref()
         .and_then(|d| d.attribute_requirement.as_ref())
     {
-        if attribute_requirement.attributes.is_empty() {
+        handler.append(&validate_attribute_requirement(
+            format!("AskOrder [{}]", ask_order.id),
+            attribute_requirement,
+        ));
+    }
+    if let Some(attribute_expression) = ask_order
+        .descriptor
+        .as_ref()
+        .and_then(|d| d.attribute_expression.as_ref())
+    {
+        handler.append(&validate_attribute_expression(
+            format!("AskOrder [{}]", ask_order.id),
+            attribute_expression,
+        ));
+    }
+    if let Some(allowed_bidders) = ask_order
+        .descriptor
+        .as_ref()
+        .and_then(|d| d.allowed_bidders.as_ref())
+    {
+        if allowed_bidders.is_empty() {
             handler.push(format!(
-                "AskOrder [{}] specified RequiredAttributes, but the value included no attributes to check",
+                "AskOrder [{}] specified allowed_bidders, but the value included no addresses",
+                ask_order.id,
+            ));
+        }
+        if allowed_bidders.iter().any(|bidder| bidder.is_empty()) {
+            handler.push(format!(
+                "AskOrder [{}] specified allowed_bidders with an empty address",
                 ask_order.id,
             ));
         }
@@ -58,6 +93,14 @@ pub fn validate_ask_order(ask_order: &AskOrder) -> Result<(), ContractError> {
                 ))
             }
         }
+        RequestType::ScopeBundleTrade => {
+            if !matches!(ask_order.collateral, AskCollateral::ScopeBundleTrade(_)) {
+                handler.push(format!(
+                    "ask type [{}] for AskOrder [{}] is invalid. type requires collateral type of AskCollateral::ScopeBundleTrade",
+                    ask_order.ask_type.get_name(), ask_order.id,
+                ))
+            }
+        }
     };
     let validate_coin = |coin: &Coin, coin_type: &str| {
         let mut messages: Vec<String> = vec![];
@@ -106,6 +149,23 @@ pub fn validate_ask_order(ask_order: &AskOrder) -> Result<(), ContractError> {
                     .flat_map(|coin| validate_coin(coin, "AskCollateral Quote Coin"))
                     .collect::<Vec<String>>(),
             );
+            if let Some(remaining_base) = collateral.remaining_base {
+                if collateral.base.len() != 1 {
+                    handler.push(format!(
+                        "{} must include exactly one base coin to allow partial fills, but found [{}]",
+                        prefix,
+                        collateral.base.len(),
+                    ));
+                } else if remaining_base.is_zero()
+                    || remaining_base > collateral.base.first().unwrap().amount
+                {
+                    handler.push(format!(
+                        "{} must have a remaining base greater than zero and no more than the total base, but found [{}]",
+                        prefix,
+                        remaining_base,
+                    ));
+                }
+            }
         }
         AskCollateral::MarkerTrade(collateral) => {
             let prefix = format!("AskOrder [{}] of type marker trade", ask_order.id);
@@ -193,6 +253,101 @@ pub fn validate_ask_order(ask_order: &AskOrder) -> Result<(), ContractError> {
             if collateral.scope_address.is_empty() {
                 handler.push(format!("{} must have a valid scope address", prefix));
             }
+            if let Some(policy) = &collateral.transfer_policy {
+                if policy
+                    .retained_owner_address
+                    .as_ref()
+                    .map(|address| address.is_empty())
+                    .unwrap_or(false)
+                {
+                    handler.push(format!(
+                        "{} must have a valid retained owner address in its transfer policy",
+                        prefix
+                    ));
+                }
+                let additional_roles = policy.get_additional_bidder_roles();
+                if additional_roles.contains(&PartyType::Owner) {
+                    handler.push(format!(
+                        "{} must not include the owner role in its transfer policy's additional bidder roles",
+                        prefix
+                    ));
+                }
+                if additional_roles
+                    .iter()
+                    .enumerate()
+                    .any(|(index, role)| additional_roles[..index].contains(role))
+                {
+                    handler.push(format!(
+                        "{} must not include duplicate additional bidder roles in its transfer policy",
+                        prefix
+                    ));
+                }
+                if collateral.is_co_owned() && policy.retained_owner_address.is_some() {
+                    handler.push(format!(
+                        "{} must not include a retained owner address in its transfer policy when selling a co-owned scope",
+                        prefix
+                    ));
+                }
+            }
+            if let Some(sold_owner_addresses) = &collateral.sold_owner_addresses {
+                if sold_owner_addresses.is_empty() {
+                    handler.push(format!(
+                        "{} must include at least one sold owner address",
+                        prefix
+                    ));
+                }
+                if sold_owner_addresses
+                    .iter()
+                    .any(|address| address.as_str().is_empty())
+                {
+                    handler.push(format!(
+                        "{} must have only valid sold owner addresses",
+                        prefix
+                    ));
+                }
+                if sold_owner_addresses
+                    .iter()
+                    .enumerate()
+                    .any(|(index, address)| sold_owner_addresses[..index].contains(address))
+                {
+                    handler.push(format!(
+                        "{} must not include duplicate sold owner addresses",
+                        prefix
+                    ));
+                }
+            }
+            if collateral.quote.is_empty() {
+                handler.push(format!("{} must have a valid quote specified", prefix));
+            }
+            handler.append(
+                &collateral
+                    .quote
+                    .iter()
+                    .flat_map(|coin| validate_coin(coin, "AskCollateral Quote"))
+                    .collect::<Vec<String>>(),
+            );
+        }
+        AskCollateral::ScopeBundleTrade(collateral) => {
+            let prefix = format!("AskOrder [{}] of type scope bundle trade", ask_order.id);
+            if collateral.scope_addresses.is_empty() {
+                handler.push(format!("{} must have at least one scope address", prefix));
+            }
+            if collateral
+                .scope_addresses
+                .iter()
+                .any(|scope_address| scope_address.is_empty())
+            {
+                handler.push(format!("{} must have only valid scope addresses", prefix));
+            }
+            let mut unique_scope_addresses = collateral.scope_addresses.to_owned();
+            unique_scope_addresses.sort();
+            unique_scope_addresses.dedup();
+            if unique_scope_addresses.len() != collateral.scope_addresses.len() {
+                handler.push(format!(
+                    "{} must not include duplicate scope addresses",
+                    prefix
+                ));
+            }
             if collateral.quote.is_empty() {
                 handler.push(format!("{} must have a valid quote specified", prefix));
             }
@@ -205,6 +360,12 @@ pub fn validate_ask_order(ask_order: &AskOrder) -> Result<(), ContractError> {
             );
         }
     }
+    handler.append(&validate_quote_denoms(
+        format!("AskOrder [{}]", ask_order.id),
+        &ask_order.ask_type,
+        &ask_order.collateral.get_quote(),
+        quote_denom_allowlist,
+    ));
     handler.handle()
 }
 
@@ -215,15 +376,17 @@ mod tests {
         mock_ask_order_with_descriptor, mock_ask_scope_trade,
     };
     use crate::types::core::error::ContractError;
+    use crate::types::core::quote_denom_allowlist::QuoteDenomAllowlist;
     use crate::types::request::ask_types::ask_collateral::AskCollateral;
     use crate::types::request::ask_types::ask_order::AskOrder;
     use crate::types::request::request_descriptor::{AttributeRequirement, RequestDescriptor};
     use crate::types::request::request_type::RequestType;
+    use crate::types::request::scope_transfer_policy::ScopeTransferPolicy;
     use crate::types::request::share_sale_type::ShareSaleType;
     use crate::util::constants::NHASH;
     use crate::validation::ask_order_validation::validate_ask_order;
-    use cosmwasm_std::{coins, Addr};
-    use provwasm_std::AccessGrant;
+    use cosmwasm_std::{coin, coins, Addr};
+    use provwasm_std::{AccessGrant, PartyType};
 
     #[test]
     fn test_missing_id() {
@@ -276,6 +439,7 @@ mod tests {
             owner: Addr::unchecked("addr"),
             collateral: AskCollateral::scope_trade("scope", &[]),
             descriptor: None,
+            created_time: None,
         };
         assert_validation_failure(
             "ask order provided coin_trade request type but wrong collateral type",
@@ -349,6 +513,41 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_coin_trade_partial_fill_invalid_remaining_base() {
+        let mut base = coins(100, NHASH);
+        base.push(coin(100, "othercoin"));
+        assert_validation_failure(
+            "partial fill ask order includes multiple base coins",
+            &mock_ask_order(AskCollateral::partial_fill_coin_trade(
+                &base,
+                &coins(100, "quote"),
+                100,
+            )),
+            coin_trade_error(
+                "must include exactly one base coin to allow partial fills, but found [2]",
+            ),
+        );
+        assert_validation_failure(
+            "partial fill ask order has no remaining base",
+            &mock_ask_order(AskCollateral::partial_fill_coin_trade(
+                &coins(100, NHASH),
+                &coins(100, "quote"),
+                0,
+            )),
+            coin_trade_error("must have a remaining base greater than zero and no more than the total base, but found [0]"),
+        );
+        assert_validation_failure(
+            "partial fill ask order has more remaining base than total base",
+            &mock_ask_order(AskCollateral::partial_fill_coin_trade(
+                &coins(100, NHASH),
+                &coins(100, "quote"),
+                101,
+            )),
+            coin_trade_error("must have a remaining base greater than zero and no more than the total base, but found [101]"),
+        );
+    }
+
     #[test]
     fn test_marker_trade_empty_marker_address() {
         assert_validation_failure(
@@ -569,6 +768,86 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_scope_trade_invalid_transfer_policy() {
+        let get_ask_order = |policy: ScopeTransferPolicy| {
+            let mut ask_order = mock_ask_order(mock_ask_scope_trade("scope", &coins(100, NHASH)));
+            ask_order.collateral = AskCollateral::scope_trade_with_transfer_policy(
+                "scope",
+                &coins(100, NHASH),
+                Some(policy),
+            );
+            ask_order
+        };
+        assert_validation_failure(
+            "ask order includes an empty retained owner address",
+            &get_ask_order(ScopeTransferPolicy::new().with_retained_owner_address("")),
+            scope_trade_error("must have a valid retained owner address in its transfer policy"),
+        );
+        assert_validation_failure(
+            "ask order includes the owner role in its additional bidder roles",
+            &get_ask_order(
+                ScopeTransferPolicy::new().with_additional_bidder_roles(&[PartyType::Owner]),
+            ),
+            scope_trade_error(
+                "must not include the owner role in its transfer policy's additional bidder roles",
+            ),
+        );
+        assert_validation_failure(
+            "ask order includes duplicate additional bidder roles",
+            &get_ask_order(ScopeTransferPolicy::new().with_additional_bidder_roles(&[
+                PartyType::Servicer,
+                PartyType::Investor,
+                PartyType::Servicer,
+            ])),
+            scope_trade_error(
+                "must not include duplicate additional bidder roles in its transfer policy",
+            ),
+        );
+    }
+
+    #[test]
+    fn test_scope_trade_invalid_sold_owner_addresses() {
+        let get_ask_order = |policy: Option<ScopeTransferPolicy>, sold_owners: &[&str]| {
+            let mut ask_order = mock_ask_order(mock_ask_scope_trade("scope", &coins(100, NHASH)));
+            ask_order.collateral = AskCollateral::co_owned_scope_trade(
+                "scope",
+                &coins(100, NHASH),
+                policy,
+                &sold_owners
+                    .iter()
+                    .map(|address| Addr::unchecked(*address))
+                    .collect::<Vec<Addr>>(),
+            );
+            ask_order
+        };
+        assert_validation_failure(
+            "ask order includes no sold owner addresses",
+            &get_ask_order(None, &[]),
+            scope_trade_error("must include at least one sold owner address"),
+        );
+        assert_validation_failure(
+            "ask order includes an empty sold owner address",
+            &get_ask_order(None, &["owner", ""]),
+            scope_trade_error("must have only valid sold owner addresses"),
+        );
+        assert_validation_failure(
+            "ask order includes duplicate sold owner addresses",
+            &get_ask_order(None, &["owner", "other", "owner"]),
+            scope_trade_error("must not include duplicate sold owner addresses"),
+        );
+        assert_validation_failure(
+            "ask order includes a retained owner address for a co-owned scope",
+            &get_ask_order(
+                Some(ScopeTransferPolicy::new().with_retained_owner_address("servicer")),
+                &["owner"],
+            ),
+            scope_trade_error(
+                "must not include a retained owner address in its transfer policy when selling a co-owned scope",
+            ),
+        );
+    }
+
     #[test]
     fn test_scope_trade_empty_quote() {
         assert_validation_failure(
@@ -592,6 +871,52 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_empty_allowed_bidders() {
+        assert_validation_failure(
+            "ask order includes no allowed bidders",
+            &mock_ask_order_with_descriptor(
+                AskCollateral::coin_trade(&coins(100, "base"), &coins(100, NHASH)),
+                RequestDescriptor::basic("private").with_allowed_bidders::<String>(&[]),
+            ),
+            "AskOrder [ask_id] specified allowed_bidders, but the value included no addresses",
+        );
+        assert_validation_failure(
+            "ask order includes an empty allowed bidder",
+            &mock_ask_order_with_descriptor(
+                AskCollateral::coin_trade(&coins(100, "base"), &coins(100, NHASH)),
+                RequestDescriptor::basic("private").with_allowed_bidders(&[""]),
+            ),
+            "AskOrder [ask_id] specified allowed_bidders with an empty address",
+        );
+    }
+
+    #[test]
+    fn test_quote_denom_not_in_allowlist() {
+        let allowlist = QuoteDenomAllowlist {
+            scope_trade: vec![NHASH.to_string()],
+            ..QuoteDenomAllowlist::default()
+        };
+        validate_ask_order(
+            &mock_ask_order(mock_ask_scope_trade("scope", &coins(100, NHASH))),
+            &allowlist,
+        )
+        .expect("an ask with an allowed quote denom should pass validation");
+        match validate_ask_order(
+            &mock_ask_order(mock_ask_scope_trade("scope", &coins(100, "spamcoin"))),
+            &allowlist,
+        )
+        .expect_err("an ask with a quote denom missing from the allowlist should fail validation")
+        {
+            ContractError::ValidationError { messages } => assert_eq!(
+                vec!["AskOrder [ask_id] quote denom [spamcoin] is not allowed for request type [scope_trade]".to_string()],
+                messages,
+                "the correct validation message should be produced",
+            ),
+            e => panic!("unexpected error: {:?}", e),
+        };
+    }
+
     fn collateral_type_error<S1: Into<String>, S2: Into<String>>(
         collateral_type: S1,
         suffix: S2,
@@ -642,7 +967,7 @@ mod tests {
     ) {
         let test_name = test_name.into();
         let message = expected_error_message.into();
-        let messages = match validate_ask_order(&ask_order) {
+        let messages = match validate_ask_order(ask_order, &QuoteDenomAllowlist::default()) {
             Err(e) => match e {
                 ContractError::ValidationError { messages } => messages,
                 e => panic!(
@@ -0,0 +1,190 @@
+use crate::types::request::request_descriptor::{
+    AttributeExpression, AttributeRequirement, AttributeValueCondition,
+};
+
+/// Produces a message for each malformed portion of an order's attribute requirement.
+pub fn validate_attribute_requirement<S: Into<String>>(
+    order_description: S,
+    attribute_requirement: &AttributeRequirement,
+) -> Vec<String> {
+    let order_description = order_description.into();
+    let mut messages = vec![];
+    if !attribute_requirement.has_checks() {
+        messages.push(format!(
+            "{} specified RequiredAttributes, but the value included no attributes to check",
+            order_description,
+        ));
+    }
+    if attribute_requirement
+        .value_requirements
+        .as_ref()
+        .filter(|requirements| requirements.is_empty())
+        .is_some()
+    {
+        messages.push(format!(
+            "{} specified value_requirements, but the value included no requirements",
+            order_description,
+        ));
+    }
+    for value_requirement in attribute_requirement.get_value_requirements() {
+        if value_requirement.attribute.is_empty() {
+            messages.push(format!(
+                "{} specified a value requirement with an empty attribute name",
+                order_description,
+            ));
+        }
+        if let AttributeValueCondition::OneOf { values } = &value_requirement.condition {
+            if values.is_empty() {
+                messages.push(format!(
+                    "{} specified a one_of value requirement for attribute [{}] with no values",
+                    order_description, value_requirement.attribute,
+                ));
+            }
+        }
+    }
+    messages
+}
+
+/// Produces a message for each malformed node of an order's attribute expression.
+pub fn validate_attribute_expression<S: Into<String>>(
+    order_description: S,
+    attribute_expression: &AttributeExpression,
+) -> Vec<String> {
+    let order_description = order_description.into();
+    let mut messages = vec![];
+    collect_attribute_expression_messages(&order_description, attribute_expression, &mut messages);
+    messages
+}
+
+fn collect_attribute_expression_messages(
+    order_description: &str,
+    attribute_expression: &AttributeExpression,
+    messages: &mut Vec<String>,
+) {
+    match attribute_expression {
+        AttributeExpression::And { expressions } | AttributeExpression::Or { expressions } => {
+            if expressions.is_empty() {
+                messages.push(format!(
+                    "{} specified an attribute expression with an empty and/or clause",
+                    order_description,
+                ));
+            }
+            for expression in expressions {
+                collect_attribute_expression_messages(order_description, expression, messages);
+            }
+        }
+        AttributeExpression::Not { expression } => {
+            collect_attribute_expression_messages(order_description, expression, messages)
+        }
+        AttributeExpression::HasAttribute { attribute } => {
+            if attribute.is_empty() {
+                messages.push(format!(
+                    "{} specified an attribute expression with an empty attribute name",
+                    order_description,
+                ));
+            }
+        }
+        AttributeExpression::AttributeValue {
+            attribute,
+            condition,
+        } => {
+            if attribute.is_empty() {
+                messages.push(format!(
+                    "{} specified an attribute expression with an empty attribute name",
+                    order_description,
+                ));
+            }
+            if let AttributeValueCondition::OneOf { values } = condition {
+                if values.is_empty() {
+                    messages.push(format!(
+                        "{} specified a one_of value requirement for attribute [{}] with no values",
+                        order_description, attribute,
+                    ));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::request::request_descriptor::{
+        AttributeExpression, AttributeRequirement, AttributeValueCondition,
+        AttributeValueRequirement,
+    };
+    use crate::validation::attribute_requirement_validation::{
+        validate_attribute_expression, validate_attribute_requirement,
+    };
+
+    #[test]
+    fn test_validate_attribute_requirement() {
+        assert!(
+            validate_attribute_requirement(
+                "AskOrder [ask_id]",
+                &AttributeRequirement::all::<String>(&[]).with_value_requirements(&[
+                    AttributeValueRequirement::new(
+                        "jurisdiction.pb",
+                        AttributeValueCondition::one_of(&["US", "CA"]),
+                    )
+                ]),
+            )
+            .is_empty(),
+            "value requirements alone should be a valid attribute requirement",
+        );
+        assert_eq!(
+            vec![
+                "AskOrder [ask_id] specified RequiredAttributes, but the value included no attributes to check".to_string(),
+                "AskOrder [ask_id] specified value_requirements, but the value included no requirements".to_string(),
+            ],
+            validate_attribute_requirement(
+                "AskOrder [ask_id]",
+                &AttributeRequirement::all::<String>(&[]).with_value_requirements(&[]),
+            ),
+            "an empty requirement should produce messages",
+        );
+        assert_eq!(
+            vec![
+                "BidOrder [bid_id] specified a value requirement with an empty attribute name".to_string(),
+                "BidOrder [bid_id] specified a one_of value requirement for attribute [] with no values".to_string(),
+            ],
+            validate_attribute_requirement(
+                "BidOrder [bid_id]",
+                &AttributeRequirement::any(&["a.pb"]).with_value_requirements(&[
+                    AttributeValueRequirement::new("", AttributeValueCondition::one_of::<String>(&[]))
+                ]),
+            ),
+            "malformed value requirements should produce messages",
+        );
+    }
+
+    #[test]
+    fn test_validate_attribute_expression() {
+        assert!(
+            validate_attribute_expression(
+                "AskOrder [ask_id]",
+                &AttributeExpression::negate(AttributeExpression::has_attribute("sanctioned.pb")),
+            )
+            .is_empty(),
+            "a well-formed expression should produce no messages",
+        );
+        assert_eq!(
+            vec![
+                "AskOrder [ask_id] specified an attribute expression with an empty and/or clause".to_string(),
+                "AskOrder [ask_id] specified an attribute expression with an empty attribute name".to_string(),
+                "AskOrder [ask_id] specified a one_of value requirement for attribute [jurisdiction.pb] with no values".to_string(),
+            ],
+            validate_attribute_expression(
+                "AskOrder [ask_id]",
+                &AttributeExpression::or(&[
+                    AttributeExpression::and(&[]),
+                    AttributeExpression::negate(AttributeExpression::has_attribute("")),
+                    AttributeExpression::attribute_value(
+                        "jurisdiction.pb",
+                        AttributeValueCondition::one_of::<String>(&[]),
+                    ),
+                ]),
+            ),
+            "each malformed node should produce a message",
+        );
+    }
+}
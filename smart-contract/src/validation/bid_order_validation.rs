@@ -1,13 +1,21 @@
 use crate::types::core::error::ContractError;
+use crate::types::core::quote_denom_allowlist::QuoteDenomAllowlist;
 use crate::types::request::bid_types::bid_collateral::BidCollateral;
 use crate::types::request::bid_types::bid_order::BidOrder;
 use crate::types::request::request_type::RequestType;
 use crate::util::coin_utilities::multiply_coins_by_amount;
 use crate::util::provenance_utilities::format_coin_display;
+use crate::validation::attribute_requirement_validation::{
+    validate_attribute_expression, validate_attribute_requirement,
+};
+use crate::validation::quote_denom_validation::validate_quote_denoms;
 use crate::validation::validation_handler::ValidationHandler;
 use cosmwasm_std::Coin;
 
-pub fn validate_bid_order(bid_order: &BidOrder) -> Result<(), ContractError> {
+pub fn validate_bid_order(
+    bid_order: &BidOrder,
+    quote_denom_allowlist: &QuoteDenomAllowlist,
+) -> Result<(), ContractError> {
     let handler = ValidationHandler::new();
     if bid_order.id.is_empty() {
         handler.push("id for BidOrder must not be empty");
@@ -20,12 +28,31 @@ pub fn validate_bid_order(bid_order: &BidOrder) -> Result<(), ContractError> {
         .as_ref()
         .and_then(|d| d.attribute_requirement.as_ref())
     {
-        if attribute_requirement.attributes.is_empty() {
-            handler.push(format!(
-                "BidOrder [{}] specified RequiredAttributes, but the value included no attributes to check",
-                bid_order.id,
-            ));
-        }
+        handler.append(&validate_attribute_requirement(
+            format!("BidOrder [{}]", bid_order.id),
+            attribute_requirement,
+        ));
+    }
+    if let Some(attribute_expression) = bid_order
+        .descriptor
+        .as_ref()
+        .and_then(|d| d.attribute_expression.as_ref())
+    {
+        handler.append(&validate_attribute_expression(
+            format!("BidOrder [{}]", bid_order.id),
+            attribute_expression,
+        ));
+    }
+    if bid_order
+        .descriptor
+        .as_ref()
+        .and_then(|d| d.allowed_bidders.as_ref())
+        .is_some()
+    {
+        handler.push(format!(
+            "BidOrder [{}] specified allowed_bidders, which may only be used on an AskOrder",
+            bid_order.id,
+        ));
     }
     match bid_order.bid_type {
         RequestType::CoinTrade => {
@@ -53,9 +80,20 @@ pub fn validate_bid_order(bid_order: &BidOrder) -> Result<(), ContractError> {
             }
         }
         RequestType::ScopeTrade => {
-            if !matches!(bid_order.collateral, BidCollateral::ScopeTrade(_)) {
+            if !matches!(
+                bid_order.collateral,
+                BidCollateral::ScopeTrade(_) | BidCollateral::ScopeSpecTrade(_)
+            ) {
+                handler.push(format!(
+                    "bid type [{}] for BidOrder [{}] is invalid. type requires collateral type of BidCollateral::ScopeTrade or BidCollateral::ScopeSpecTrade",
+                    bid_order.bid_type.get_name(), bid_order.id,
+                ))
+            }
+        }
+        RequestType::ScopeBundleTrade => {
+            if !matches!(bid_order.collateral, BidCollateral::ScopeBundleTrade(_)) {
                 handler.push(format!(
-                    "bid type [{}] for BidOrder [{}] is invalid. type requires collateral type of BidCollateral::ScopeTrade",
+                    "bid type [{}] for BidOrder [{}] is invalid. type requires collateral type of BidCollateral::ScopeBundleTrade",
                     bid_order.bid_type.get_name(), bid_order.id,
                 ))
             }
@@ -184,7 +222,73 @@ pub fn validate_bid_order(bid_order: &BidOrder) -> Result<(), ContractError> {
                     .collect::<Vec<String>>(),
             );
         }
+        BidCollateral::ScopeBundleTrade(collateral) => {
+            let prefix = format!("BidOrder [{}] of type scope bundle trade", bid_order.id);
+            if collateral.scope_addresses.is_empty() {
+                handler.push(format!(
+                    "{} must include at least one scope address",
+                    prefix
+                ));
+            }
+            if collateral
+                .scope_addresses
+                .iter()
+                .any(|scope_address| scope_address.is_empty())
+            {
+                handler.push(format!(
+                    "{} must include only valid scope addresses",
+                    prefix
+                ));
+            }
+            let mut unique_scope_addresses = collateral.scope_addresses.to_owned();
+            unique_scope_addresses.sort();
+            unique_scope_addresses.dedup();
+            if unique_scope_addresses.len() != collateral.scope_addresses.len() {
+                handler.push(format!(
+                    "{} must not include duplicate scope addresses",
+                    prefix
+                ));
+            }
+            if collateral.quote.is_empty() {
+                handler.push(format!("{} must include at least one quote coin", prefix));
+            }
+            handler.append(
+                &collateral
+                    .quote
+                    .iter()
+                    .flat_map(|coin| validate_coin(coin, "BidCollateral Quote Coin"))
+                    .collect::<Vec<String>>(),
+            );
+        }
+        BidCollateral::ScopeSpecTrade(collateral) => {
+            let prefix = format!("BidOrder [{}] of type scope spec trade", bid_order.id);
+            if collateral.scope_spec_id.is_empty() {
+                handler.push(format!("{} must include a valid scope spec id", prefix));
+            }
+            if let Some(attribute_requirement) = &collateral.scope_attribute_requirement {
+                handler.append(&validate_attribute_requirement(
+                    format!("{} scope attribute requirement", prefix),
+                    attribute_requirement,
+                ));
+            }
+            if collateral.quote.is_empty() {
+                handler.push(format!("{} must include at least one quote coin", prefix));
+            }
+            handler.append(
+                &collateral
+                    .quote
+                    .iter()
+                    .flat_map(|coin| validate_coin(coin, "BidCollateral Quote Coin"))
+                    .collect::<Vec<String>>(),
+            );
+        }
     }
+    handler.append(&validate_quote_denoms(
+        format!("BidOrder [{}]", bid_order.id),
+        &bid_order.bid_type,
+        &bid_order.collateral.get_quote(),
+        quote_denom_allowlist,
+    ));
     handler.handle()
 }
 
@@ -195,6 +299,7 @@ mod tests {
         mock_bid_with_descriptor,
     };
     use crate::types::core::error::ContractError;
+    use crate::types::core::quote_denom_allowlist::QuoteDenomAllowlist;
     use crate::types::request::bid_types::bid_collateral::BidCollateral;
     use crate::types::request::bid_types::bid_order::BidOrder;
     use crate::types::request::request_descriptor::{AttributeRequirement, RequestDescriptor};
@@ -254,6 +359,7 @@ mod tests {
             owner: Addr::unchecked("bidder"),
             collateral: BidCollateral::scope_trade("scope", &[]),
             descriptor: None,
+            created_time: None,
         };
         assert_validation_failure(
             "bid order provided coin_trade request type but wrong collateral type",
@@ -277,7 +383,7 @@ mod tests {
         assert_validation_failure(
             "bid order provided scope_trade request type but wrong collateral type",
             &bid_order,
-            "bid type [scope_trade] for BidOrder [bid_id] is invalid. type requires collateral type of BidCollateral::ScopeTrade",
+            "bid type [scope_trade] for BidOrder [bid_id] is invalid. type requires collateral type of BidCollateral::ScopeTrade or BidCollateral::ScopeSpecTrade",
         );
     }
 
@@ -485,6 +591,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_scope_spec_trade_invalid_collateral() {
+        assert_validation_failure(
+            "bid order does not include a valid scope spec id",
+            &mock_bid_order(BidCollateral::scope_spec_trade(
+                "",
+                &coins(100, NHASH),
+                None,
+                None,
+            )),
+            collateral_type_error("scope spec trade", "must include a valid scope spec id"),
+        );
+        assert_validation_failure(
+            "bid order does not include quote funds",
+            &mock_bid_order(BidCollateral::scope_spec_trade(
+                "scope-spec",
+                &[],
+                None,
+                None,
+            )),
+            collateral_type_error("scope spec trade", "must include at least one quote coin"),
+        );
+        assert_validation_failure(
+            "bid order includes an empty scope attribute requirement",
+            &mock_bid_order(BidCollateral::scope_spec_trade(
+                "scope-spec",
+                &coins(100, NHASH),
+                Some(AttributeRequirement::all::<String>(&[])),
+                None,
+            )),
+            "BidOrder [bid_id] of type scope spec trade scope attribute requirement specified RequiredAttributes, but the value included no attributes to check",
+        );
+    }
+
     #[test]
     fn test_scope_trade_quote_funds_include_invalid_coins() {
         assert_validation_failure(
@@ -499,6 +639,44 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_allowed_bidders_on_bid() {
+        assert_validation_failure(
+            "bid order includes allowed bidders",
+            &mock_bid_with_descriptor(
+                BidCollateral::coin_trade(&coins(100, "base"), &coins(100, NHASH)),
+                RequestDescriptor::basic("private").with_allowed_bidders(&["bidder"]),
+            ),
+            "BidOrder [bid_id] specified allowed_bidders, which may only be used on an AskOrder",
+        );
+    }
+
+    #[test]
+    fn test_quote_denom_not_in_allowlist() {
+        let allowlist = QuoteDenomAllowlist {
+            all_request_types: vec![NHASH.to_string()],
+            ..QuoteDenomAllowlist::default()
+        };
+        validate_bid_order(
+            &mock_bid_order(mock_bid_scope_trade("scope", &coins(100, NHASH))),
+            &allowlist,
+        )
+        .expect("a bid with an allowed quote denom should pass validation");
+        match validate_bid_order(
+            &mock_bid_order(mock_bid_scope_trade("scope", &coins(100, "spamcoin"))),
+            &allowlist,
+        )
+        .expect_err("a bid with a quote denom missing from the allowlist should fail validation")
+        {
+            ContractError::ValidationError { messages } => assert_eq!(
+                vec!["BidOrder [bid_id] quote denom [spamcoin] is not allowed for request type [scope_trade]".to_string()],
+                messages,
+                "the correct validation message should be produced",
+            ),
+            e => panic!("unexpected error: {:?}", e),
+        };
+    }
+
     fn collateral_type_error<S1: Into<String>, S2: Into<String>>(
         collateral_type: S1,
         suffix: S2,
@@ -549,7 +727,7 @@ mod tests {
     ) {
         let test_name = test_name.into();
         let message = expected_error_message.into();
-        let messages = match validate_bid_order(&bid_order) {
+        let messages = match validate_bid_order(bid_order, &QuoteDenomAllowlist::default()) {
             Err(e) => match e {
                 ContractError::ValidationError { messages } => messages,
                 e => panic!(
@@ -0,0 +1,91 @@
+use crate::storage::contract_info::ContractInfoV2;
+use crate::types::core::error::ContractError;
+use crate::util::extensions::ResultExtensions;
+
+pub fn validate_order_creation_allowed(
+    contract_info: &ContractInfoV2,
+) -> Result<(), ContractError> {
+    let status = contract_info.get_contract_status();
+    if !status.allows_order_creation() {
+        return ContractError::InvalidRequest {
+            message: format!(
+                "asks and bids cannot be created or updated while the contract status is [{}]",
+                status.get_name(),
+            ),
+        }
+        .to_err();
+    }
+    ().to_ok()
+}
+
+pub fn validate_matching_allowed(contract_info: &ContractInfoV2) -> Result<(), ContractError> {
+    let status = contract_info.get_contract_status();
+    if !status.allows_matching() {
+        return ContractError::InvalidRequest {
+            message: format!(
+                "asks and bids cannot be matched while the contract status is [{}]",
+                status.get_name(),
+            ),
+        }
+        .to_err();
+    }
+    ().to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::storage::contract_info::ContractInfoV2;
+    use crate::types::core::contract_status::ContractStatus;
+    use crate::types::core::error::ContractError;
+    use crate::validation::contract_status_validation::{
+        validate_matching_allowed, validate_order_creation_allowed,
+    };
+    use cosmwasm_std::Addr;
+
+    #[test]
+    fn test_validate_contract_status() {
+        let mut contract_info = ContractInfoV2::new(
+            Addr::unchecked("admin"),
+            "bind_name",
+            "contract_name",
+            None,
+            None,
+            None,
+            None,
+        );
+        validate_order_creation_allowed(&contract_info)
+            .expect("creation should be allowed when no status has been set");
+        validate_matching_allowed(&contract_info)
+            .expect("matching should be allowed when no status has been set");
+        for (status, creation_allowed, matching_allowed) in [
+            (ContractStatus::Active, true, true),
+            (ContractStatus::CreationHalted, false, true),
+            (ContractStatus::MatchingHalted, true, false),
+            (ContractStatus::FullyPaused, false, false),
+        ] {
+            contract_info.contract_status = Some(status.to_owned());
+            assert_eq!(
+                creation_allowed,
+                validate_order_creation_allowed(&contract_info).is_ok(),
+                "unexpected creation result for status [{}]",
+                status.get_name(),
+            );
+            assert_eq!(
+                matching_allowed,
+                validate_matching_allowed(&contract_info).is_ok(),
+                "unexpected matching result for status [{}]",
+                status.get_name(),
+            );
+        }
+        match validate_order_creation_allowed(&contract_info)
+            .expect_err("creation should be rejected when the contract is fully paused")
+        {
+            ContractError::InvalidRequest { message } => assert_eq!(
+                "asks and bids cannot be created or updated while the contract status is [fully_paused]",
+                message,
+                "the correct error message should be produced",
+            ),
+            e => panic!("unexpected error: {:?}", e),
+        };
+    }
+}
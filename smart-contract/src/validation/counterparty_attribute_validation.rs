@@ -0,0 +1,275 @@
+use crate::storage::ask_order_storage::may_get_ask_order_by_id;
+use crate::storage::bid_order_storage::may_get_bid_order_by_id;
+use crate::storage::contract_info::ContractInfoV2;
+use crate::types::core::error::ContractError;
+use crate::types::request::ask_types::ask_order::AskOrder;
+use crate::types::request::bid_types::bid_order::BidOrder;
+use crate::types::request::request_descriptor::RequestDescriptor;
+use crate::util::extensions::ResultExtensions;
+use crate::validation::execute_match_validation::{
+    get_attribute_checks_error, get_required_attributes_error,
+};
+use cosmwasm_std::{Addr, Deps};
+use provwasm_std::ProvenanceQuery;
+
+/// Verifies that the creator of an ask or bid holds the attributes that the contract requires of
+/// all traders.
+pub fn validate_required_trade_attributes<S: Into<String>>(
+    deps: &Deps<ProvenanceQuery>,
+    contract_info: &ContractInfoV2,
+    creator: &Addr,
+    checked_account_type: S,
+) -> Result<(), ContractError> {
+    if let Some(message) = get_attribute_checks_error(
+        deps,
+        contract_info.required_trade_attributes.as_ref(),
+        None,
+        creator,
+        checked_account_type,
+    ) {
+        return ContractError::InvalidRequest {
+            message: format!("required trade attributes are not met: {}", message),
+        }
+        .to_err();
+    }
+    ().to_ok()
+}
+
+/// Verifies that the owner of a bid that names a target AskOrder could be matched with it, based
+/// on the ask's allowed bidders and attribute requirements.
+pub fn validate_bid_target(
+    deps: &Deps<ProvenanceQuery>,
+    bid_order: &BidOrder,
+) -> Result<(), ContractError> {
+    if let Some(target_order_id) = get_target_order_id(&bid_order.descriptor) {
+        let identifiers = format!(
+            "BidOrder [{}] target AskOrder [{}]",
+            bid_order.id, target_order_id
+        );
+        let ask_order = match may_get_ask_order_by_id(deps.storage, target_order_id) {
+            Some(ask_order) => ask_order,
+            None => {
+                return ContractError::InvalidRequest {
+                    message: format!("{} does not exist", identifiers),
+                }
+                .to_err()
+            }
+        };
+        if let Some(descriptor) = &ask_order.descriptor {
+            if !descriptor.allows_bidder(&bid_order.owner) {
+                return ContractError::InvalidRequest {
+                    message: format!(
+                        "{} does not allow bidder [{}]",
+                        identifiers,
+                        bid_order.owner.as_str(),
+                    ),
+                }
+                .to_err();
+            }
+        }
+        if let Some(message) =
+            get_required_attributes_error(deps, &ask_order.descriptor, &bid_order.owner, "bidder")
+        {
+            return ContractError::InvalidRequest {
+                message: format!("{} cannot be filled: {}", identifiers, message),
+            }
+            .to_err();
+        }
+    }
+    ().to_ok()
+}
+
+/// Verifies that the owner of an ask that names a target BidOrder meets the bid's attribute
+/// requirements.
+pub fn validate_ask_target(
+    deps: &Deps<ProvenanceQuery>,
+    ask_order: &AskOrder,
+) -> Result<(), ContractError> {
+    if let Some(target_order_id) = get_target_order_id(&ask_order.descriptor) {
+        let identifiers = format!(
+            "AskOrder [{}] target BidOrder [{}]",
+            ask_order.id, target_order_id
+        );
+        let bid_order = match may_get_bid_order_by_id(deps.storage, target_order_id) {
+            Some(bid_order) => bid_order,
+            None => {
+                return ContractError::InvalidRequest {
+                    message: format!("{} does not exist", identifiers),
+                }
+                .to_err()
+            }
+        };
+        if let Some(message) =
+            get_required_attributes_error(deps, &bid_order.descriptor, &ask_order.owner, "asker")
+        {
+            return ContractError::InvalidRequest {
+                message: format!("{} cannot be filled: {}", identifiers, message),
+            }
+            .to_err();
+        }
+    }
+    ().to_ok()
+}
+
+fn get_target_order_id(descriptor: &Option<RequestDescriptor>) -> Option<&String> {
+    descriptor.as_ref().and_then(|d| d.target_order_id.as_ref())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::storage::ask_order_storage::insert_ask_order;
+    use crate::storage::bid_order_storage::insert_bid_order;
+    use crate::storage::contract_info::get_contract_info;
+    use crate::test::mock_instantiate::default_instantiate;
+    use crate::test::request_helpers::{
+        mock_ask_order, mock_ask_order_with_descriptor, mock_bid_order, mock_bid_with_descriptor,
+    };
+    use crate::types::core::error::ContractError;
+    use crate::types::request::ask_types::ask_collateral::AskCollateral;
+    use crate::types::request::bid_types::bid_collateral::BidCollateral;
+    use crate::types::request::request_descriptor::{AttributeRequirement, RequestDescriptor};
+    use crate::validation::counterparty_attribute_validation::{
+        validate_ask_target, validate_bid_target, validate_required_trade_attributes,
+    };
+    use cosmwasm_std::{coins, Addr};
+    use provwasm_mocks::mock_dependencies;
+
+    #[test]
+    fn test_validate_required_trade_attributes() {
+        let mut deps = mock_dependencies(&[]);
+        default_instantiate(deps.as_mut());
+        let mut contract_info =
+            get_contract_info(deps.as_ref().storage).expect("contract info should be available");
+        let creator = Addr::unchecked("bidder");
+        validate_required_trade_attributes(&deps.as_ref(), &contract_info, &creator, "bidder")
+            .expect("no requirements should allow all creators");
+        contract_info.required_trade_attributes = Some(AttributeRequirement::all(&["kyc.pb"]));
+        assert_invalid_request(
+            validate_required_trade_attributes(&deps.as_ref(), &contract_info, &creator, "bidder")
+                .expect_err("a creator missing the attributes should be rejected"),
+            "required trade attributes are not met: the [bidder account] is required to have all of the following attributes: [\"kyc.pb\"]",
+        );
+        deps.querier
+            .with_attributes("bidder", &[("kyc.pb", "true", "string")]);
+        validate_required_trade_attributes(&deps.as_ref(), &contract_info, &creator, "bidder")
+            .expect("a creator with the attributes should be accepted");
+    }
+
+    #[test]
+    fn test_validate_bid_target() {
+        let mut deps = mock_dependencies(&[]);
+        let bid_order = mock_bid_with_descriptor(
+            BidCollateral::coin_trade(&coins(100, "base"), &coins(100, "quote")),
+            RequestDescriptor::new_none().with_target_order_id("ask_id"),
+        );
+        assert_invalid_request(
+            validate_bid_target(&deps.as_ref(), &bid_order)
+                .expect_err("a missing target should be rejected"),
+            "BidOrder [bid_id] target AskOrder [ask_id] does not exist",
+        );
+        insert_ask_order(
+            deps.as_mut().storage,
+            &mock_ask_order_with_descriptor(
+                AskCollateral::coin_trade(&coins(100, "base"), &coins(100, "quote")),
+                RequestDescriptor::new_populated_attributes(
+                    "description",
+                    AttributeRequirement::all(&["accredited.pb"]),
+                ),
+            ),
+        )
+        .expect("the ask should be inserted");
+        assert_invalid_request(
+            validate_bid_target(&deps.as_ref(), &bid_order)
+                .expect_err("a bidder missing the ask's attributes should be rejected"),
+            "BidOrder [bid_id] target AskOrder [ask_id] cannot be filled: the [bidder account] is required to have all of the following attributes: [\"accredited.pb\"]",
+        );
+        deps.querier
+            .with_attributes("bidder", &[("accredited.pb", "true", "string")]);
+        validate_bid_target(&deps.as_ref(), &bid_order)
+            .expect("a bidder holding the ask's attributes should be accepted");
+        validate_bid_target(
+            &deps.as_ref(),
+            &mock_bid_order(BidCollateral::coin_trade(
+                &coins(100, "base"),
+                &coins(100, "quote"),
+            )),
+        )
+        .expect("a bid without a target should always be accepted");
+    }
+
+    #[test]
+    fn test_validate_bid_target_private_ask() {
+        let mut deps = mock_dependencies(&[]);
+        insert_ask_order(
+            deps.as_mut().storage,
+            &mock_ask_order_with_descriptor(
+                AskCollateral::coin_trade(&coins(100, "base"), &coins(100, "quote")),
+                RequestDescriptor::new_none().with_allowed_bidders(&["other_bidder"]),
+            ),
+        )
+        .expect("the ask should be inserted");
+        assert_invalid_request(
+            validate_bid_target(
+                &deps.as_ref(),
+                &mock_bid_with_descriptor(
+                    BidCollateral::coin_trade(&coins(100, "base"), &coins(100, "quote")),
+                    RequestDescriptor::new_none().with_target_order_id("ask_id"),
+                ),
+            )
+            .expect_err("a bidder not allowed by a private ask should be rejected"),
+            "BidOrder [bid_id] target AskOrder [ask_id] does not allow bidder [bidder]",
+        );
+    }
+
+    #[test]
+    fn test_validate_ask_target() {
+        let mut deps = mock_dependencies(&[]);
+        let ask_order = mock_ask_order_with_descriptor(
+            AskCollateral::coin_trade(&coins(100, "base"), &coins(100, "quote")),
+            RequestDescriptor::new_none().with_target_order_id("bid_id"),
+        );
+        assert_invalid_request(
+            validate_ask_target(&deps.as_ref(), &ask_order)
+                .expect_err("a missing target should be rejected"),
+            "AskOrder [ask_id] target BidOrder [bid_id] does not exist",
+        );
+        insert_bid_order(
+            deps.as_mut().storage,
+            &mock_bid_with_descriptor(
+                BidCollateral::coin_trade(&coins(100, "base"), &coins(100, "quote")),
+                RequestDescriptor::new_populated_attributes(
+                    "description",
+                    AttributeRequirement::none(&["sanctioned.pb"]),
+                ),
+            ),
+        )
+        .expect("the bid should be inserted");
+        validate_ask_target(&deps.as_ref(), &ask_order)
+            .expect("an asker meeting the bid's requirements should be accepted");
+        deps.querier
+            .with_attributes("asker", &[("sanctioned.pb", "true", "string")]);
+        assert_invalid_request(
+            validate_ask_target(&deps.as_ref(), &ask_order)
+                .expect_err("an asker failing the bid's requirements should be rejected"),
+            "AskOrder [ask_id] target BidOrder [bid_id] cannot be filled: the [asker account] is required to not have any of the following attributes: [\"sanctioned.pb\"]",
+        );
+        validate_ask_target(
+            &deps.as_ref(),
+            &mock_ask_order(AskCollateral::coin_trade(
+                &coins(100, "base"),
+                &coins(100, "quote"),
+            )),
+        )
+        .expect("an ask without a target should always be accepted");
+    }
+
+    fn assert_invalid_request(error: ContractError, expected_message: &str) {
+        match error {
+            ContractError::InvalidRequest { message } => assert_eq!(
+                expected_message, message,
+                "the correct error message should be produced",
+            ),
+            e => panic!("unexpected error: {:?}", e),
+        }
+    }
+}
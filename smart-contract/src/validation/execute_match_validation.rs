@@ -1,203 +1,515 @@
+use crate::storage::address_blocklist_storage::is_address_blocked;
 use crate::types::core::error::ContractError;
 use crate::types::request::admin_match_options::{AdminMatchOptions, OverrideQuoteSource};
 use crate::types::request::ask_types::ask_collateral::{
     AskCollateral, CoinTradeAskCollateral, MarkerShareSaleAskCollateral, MarkerTradeAskCollateral,
-    ScopeTradeAskCollateral,
+    ScopeBundleTradeAskCollateral, ScopeTradeAskCollateral,
 };
 use crate::types::request::ask_types::ask_order::AskOrder;
 use crate::types::request::bid_types::bid_collateral::{
     BidCollateral, CoinTradeBidCollateral, MarkerShareSaleBidCollateral, MarkerTradeBidCollateral,
-    ScopeTradeBidCollateral,
+    ScopeBundleTradeBidCollateral, ScopeSpecTradeBidCollateral, ScopeTradeBidCollateral,
 };
 use crate::types::request::bid_types::bid_order::BidOrder;
-use crate::types::request::request_descriptor::{AttributeRequirementType, RequestDescriptor};
+use crate::types::request::match_report::{MatchFailureCode, MatchSide, MatchValidationFailure};
+use crate::types::request::request_descriptor::{
+    AttributeExpression, AttributeRequirement, AttributeRequirementType, DescribedOrder,
+    RequestDescriptor,
+};
 use crate::types::request::share_sale_type::ShareSaleType;
-use crate::util::coin_utilities::{coin_sort, multiply_coins_by_amount};
+use crate::util::coin_utilities::{coin_sort, multiply_coins_by_amount, subtract_coins};
 use crate::util::provenance_utilities::{format_coin_display, get_single_marker_coin_holding};
 use crate::validation::validation_handler::ValidationHandler;
-use cosmwasm_std::{Addr, Deps};
+use cosmwasm_std::{Addr, Coin, Deps, Env, Timestamp};
 use provwasm_std::{ProvenanceQuerier, ProvenanceQuery};
-use take_if::TakeIf;
 
 pub fn validate_match(
     deps: &Deps<ProvenanceQuery>,
+    env: &Env,
     ask: &AskOrder,
     bid: &BidOrder,
     admin_match_options: &Option<AdminMatchOptions>,
 ) -> Result<(), ContractError> {
     let handler = ValidationHandler::new();
-    let identifiers = format!(
-        "Match Validation for AskOrder [{}] and BidOrder [{}]:",
-        &ask.id, &bid.id
-    );
+    for (identifiers, failure) in
+        collect_match_failures(deps, env, ask, bid, admin_match_options).failures
+    {
+        if identifiers.is_empty() {
+            handler.push(failure.message);
+        } else {
+            handler.push(format!("{} {}", identifiers, failure.message));
+        }
+    }
+    handler.handle()
+}
+
+/// Produces every reason that the ask and bid cannot be matched.  The result is empty when the
+/// match is possible.
+pub fn get_match_validation_failures(
+    deps: &Deps<ProvenanceQuery>,
+    env: &Env,
+    ask: &AskOrder,
+    bid: &BidOrder,
+    admin_match_options: &Option<AdminMatchOptions>,
+) -> Vec<MatchValidationFailure> {
+    collect_match_failures(deps, env, ask, bid, admin_match_options)
+        .failures
+        .into_iter()
+        .map(|(_, failure)| failure)
+        .collect()
+}
+
+/// Pairs each match validation failure with the identifiers that prefix its message in the errors
+/// produced by validate_match.
+struct MatchFailureCollector {
+    failures: Vec<(String, MatchValidationFailure)>,
+}
+impl MatchFailureCollector {
+    fn new() -> Self {
+        Self { failures: vec![] }
+    }
+
+    fn push(&mut self, identifiers: &str, failure: MatchValidationFailure) {
+        self.failures.push((identifiers.to_string(), failure));
+    }
+
+    fn append(&mut self, identifiers: &str, failures: Vec<MatchValidationFailure>) {
+        for failure in failures {
+            self.push(identifiers, failure);
+        }
+    }
+}
+
+fn get_identifiers(trade_name: Option<&str>, ask: &AskOrder, bid: &BidOrder) -> String {
+    format!(
+        "{}Match Validation for AskOrder [{}] and BidOrder [{}]:",
+        trade_name
+            .map(|name| format!("{} ", name))
+            .unwrap_or_default(),
+        &ask.id,
+        &bid.id,
+    )
+}
+
+fn collect_match_failures(
+    deps: &Deps<ProvenanceQuery>,
+    env: &Env,
+    ask: &AskOrder,
+    bid: &BidOrder,
+    admin_match_options: &Option<AdminMatchOptions>,
+) -> MatchFailureCollector {
+    let mut collector = MatchFailureCollector::new();
+    let identifiers = get_identifiers(None, ask, bid);
     if ask.ask_type != bid.bid_type {
-        handler.push(format!(
-            "{} Ask type [{}] does not match bid type [{}]",
+        collector.push(
             &identifiers,
-            &ask.ask_type.get_name(),
-            &bid.bid_type.get_name(),
-        ));
+            MatchValidationFailure::new(
+                MatchFailureCode::RequestTypeMismatch,
+                MatchSide::Both,
+                "request_type",
+                format!(
+                    "Ask type [{}] does not match bid type [{}]",
+                    &ask.ask_type.get_name(),
+                    &bid.bid_type.get_name(),
+                ),
+            ),
+        );
+    }
+    // Orders cannot be matched before their effective time, allowing listings to be scheduled
+    if let Some(effective_time) = get_pending_effective_time(&ask.descriptor, env) {
+        collector.push(
+            &identifiers,
+            MatchValidationFailure::new(
+                MatchFailureCode::NotYetEffective,
+                MatchSide::Ask,
+                "descriptor.effective_time",
+                format!("AskOrder is not effective until [{}]", effective_time),
+            ),
+        );
+    }
+    if let Some(effective_time) = get_pending_effective_time(&bid.descriptor, env) {
+        collector.push(
+            &identifiers,
+            MatchValidationFailure::new(
+                MatchFailureCode::NotYetEffective,
+                MatchSide::Bid,
+                "descriptor.effective_time",
+                format!("BidOrder is not effective until [{}]", effective_time),
+            ),
+        );
+    }
+    // Expired orders are never eligible for a match, even if they have yet to be swept from storage
+    if ask.is_expired(&env.block.time) {
+        collector.push(
+            &identifiers,
+            MatchValidationFailure::new(
+                MatchFailureCode::Expired,
+                MatchSide::Ask,
+                "descriptor.expiration_time",
+                format!(
+                    "AskOrder expired at [{}]",
+                    ask.descriptor
+                        .as_ref()
+                        .and_then(|d| d.expiration_time)
+                        .unwrap_or_default(),
+                ),
+            ),
+        );
+    }
+    if bid.is_expired(&env.block.time) {
+        collector.push(
+            &identifiers,
+            MatchValidationFailure::new(
+                MatchFailureCode::Expired,
+                MatchSide::Bid,
+                "descriptor.expiration_time",
+                format!(
+                    "BidOrder expired at [{}]",
+                    bid.descriptor
+                        .as_ref()
+                        .and_then(|d| d.expiration_time)
+                        .unwrap_or_default(),
+                ),
+            ),
+        );
+    }
+    // Blocked addresses may not take part in a match or receive any funds from one
+    if is_address_blocked(deps.storage, &ask.owner) {
+        collector.push(
+            &identifiers,
+            MatchValidationFailure::new(
+                MatchFailureCode::OwnerBlocked,
+                MatchSide::Ask,
+                "owner",
+                format!("AskOrder owner [{}] is blocked", ask.owner.as_str()),
+            ),
+        );
+    }
+    if is_address_blocked(deps.storage, &bid.owner) {
+        collector.push(
+            &identifiers,
+            MatchValidationFailure::new(
+                MatchFailureCode::OwnerBlocked,
+                MatchSide::Bid,
+                "owner",
+                format!("BidOrder owner [{}] is blocked", bid.owner.as_str()),
+            ),
+        );
+    }
+    // Private asks may only be matched with the bidders that the asker has allowed
+    if !ask
+        .descriptor
+        .as_ref()
+        .map(|d| d.allows_bidder(&bid.owner))
+        .unwrap_or(true)
+    {
+        collector.push(
+            &identifiers,
+            MatchValidationFailure::new(
+                MatchFailureCode::BidderNotAllowed,
+                MatchSide::Bid,
+                "owner",
+                format!(
+                    "BidOrder owner [{}] is not an allowed bidder for the AskOrder",
+                    bid.owner.as_str(),
+                ),
+            ),
+        );
     }
     // Verify that the asker has appropriate attributes based on the request descriptor of the bid
     if let Some(validation_err) =
         get_required_attributes_error(deps, &bid.descriptor, &ask.owner, "asker")
     {
-        handler.push(validation_err);
+        collector.push(
+            "",
+            MatchValidationFailure::new(
+                MatchFailureCode::AttributeRequirementNotMet,
+                MatchSide::Ask,
+                "owner",
+                validation_err,
+            ),
+        );
     }
 
     // Verify that the bidder has appropriate attributes based on the request descriptor of the ask
     if let Some(validation_err) =
         get_required_attributes_error(deps, &ask.descriptor, &bid.owner, "bidder")
     {
-        handler.push(validation_err);
+        collector.push(
+            "",
+            MatchValidationFailure::new(
+                MatchFailureCode::AttributeRequirementNotMet,
+                MatchSide::Bid,
+                "owner",
+                validation_err,
+            ),
+        );
     }
 
+    // Reject match requests that send the wrong type of match options to notify the caller that
+    // they are attempting an action that is unrelated to the selected match
     match &ask.collateral {
         AskCollateral::CoinTrade(ask_collat) => match &bid.collateral {
             BidCollateral::CoinTrade(bid_collat) => {
-                // Reject match requests that send the wrong type of match options to notify the
-                // caller that they are attempting an action that is unrelated to the selected match
-                if admin_match_options.is_some() && !matches!(admin_match_options, Some(AdminMatchOptions::CoinTrade {.. })) {
-                    handler.push(format!(
-                        "{} Admin match options were provided, but were not of the coin_trade variant",
-                        identifiers,
-                    ));
+                if admin_match_options.is_some()
+                    && !matches!(
+                        admin_match_options,
+                        Some(AdminMatchOptions::CoinTrade { .. })
+                    )
+                {
+                    collector.push(&identifiers, get_invalid_options_failure("coin_trade"));
                 }
-                handler.append(
-                    &get_coin_trade_collateral_validation(
-                        ask,
-                        bid,
+                collector.append(
+                    &get_identifiers(Some("COIN TRADE"), ask, bid),
+                    get_coin_trade_collateral_validation(
                         ask_collat,
                         bid_collat,
-                        if let Some(
-                            AdminMatchOptions::CoinTrade {
-                                accept_mismatched_bids: Some(mismatched_bids)
-                            }
-                        ) = admin_match_options {
+                        if let Some(AdminMatchOptions::CoinTrade {
+                            accept_mismatched_bids: Some(mismatched_bids),
+                        }) = admin_match_options
+                        {
                             *mismatched_bids
                         } else {
                             false
-                        }
+                        },
                     ),
                 );
-            },
-            _ => handler.push(format!(
-                "{} Ask collateral was of type coin trade, which did not match bid collateral",
-                identifiers
-            )),
+            }
+            _ => collector.push(
+                &identifiers,
+                get_collateral_mismatch_failure("Ask collateral was of type coin trade"),
+            ),
         },
         AskCollateral::MarkerTrade(ask_collat) => match &bid.collateral {
             BidCollateral::MarkerTrade(bid_collat) => {
-                // Reject match requests that send the wrong type of match options to notify the
-                // caller that they are attempting an action that is unrelated to the selected match
-                if admin_match_options.is_some() && !matches!(admin_match_options, Some(AdminMatchOptions::MarkerTrade {.. })) {
-                    handler.push(format!(
-                        "{} Admin match options were provided, but were not of the marker_trade variant",
-                        identifiers,
-                    ));
+                if admin_match_options.is_some()
+                    && !matches!(
+                        admin_match_options,
+                        Some(AdminMatchOptions::MarkerTrade { .. })
+                    )
+                {
+                    collector.push(&identifiers, get_invalid_options_failure("marker_trade"));
                 }
-                handler.append(
-                    &get_marker_trade_collateral_validation(
+                collector.append(
+                    &get_identifiers(Some("MARKER TRADE"), ask, bid),
+                    get_marker_trade_collateral_validation(
                         deps,
-                        ask,
-                        bid,
                         ask_collat,
                         bid_collat,
-                        if let Some(
-                            AdminMatchOptions::MarkerTrade {
-                                accept_mismatched_bids: Some(mismatched_bids)
-                            }
-                        ) = admin_match_options {
+                        if let Some(AdminMatchOptions::MarkerTrade {
+                            accept_mismatched_bids: Some(mismatched_bids),
+                        }) = admin_match_options
+                        {
                             *mismatched_bids
                         } else {
                             false
-                        }
+                        },
                     ),
                 );
-            },
-            _ => handler.push(format!(
-                "{} Ask collateral was of type marker trade, which did not match bid collateral",
-                identifiers
-            )),
+            }
+            _ => collector.push(
+                &identifiers,
+                get_collateral_mismatch_failure("Ask collateral was of type marker trade"),
+            ),
         },
         AskCollateral::MarkerShareSale(ask_collat) => match &bid.collateral {
             BidCollateral::MarkerShareSale(bid_collat) => {
-                // Reject match requests that send the wrong type of match options to notify the
-                // caller that they are attempting an action that is unrelated to the selected match
-                if admin_match_options.is_some() && !matches!(admin_match_options, Some(AdminMatchOptions::MarkerShareSale { .. })) {
-                    handler.push(format!(
-                        "{} Admin match options were provided, but were not of the marker_share_sale variant",
-                        identifiers,
-                    ));
+                if admin_match_options.is_some()
+                    && !matches!(
+                        admin_match_options,
+                        Some(AdminMatchOptions::MarkerShareSale { .. })
+                    )
+                {
+                    collector.push(
+                        &identifiers,
+                        get_invalid_options_failure("marker_share_sale"),
+                    );
                 }
-                handler.append(
-                    &get_marker_share_sale_collateral_validation(
+                collector.append(
+                    &get_identifiers(Some("MARKER SHARE SALE"), ask, bid),
+                    get_marker_share_sale_collateral_validation(
                         deps,
-                        ask,
-                        bid,
                         ask_collat,
                         bid_collat,
-                        if let Some(
-                            AdminMatchOptions::MarkerShareSale { override_quote_source }
-                        ) = admin_match_options {
+                        if let Some(AdminMatchOptions::MarkerShareSale {
+                            override_quote_source,
+                        }) = admin_match_options
+                        {
                             override_quote_source
                         } else {
                             &None
-                        }
+                        },
                     ),
                 );
-            },
-            _ => handler.push(format!(
-                "{} Ask Collateral was of type marker share sale, which did not match bid collateral",
-                identifiers,
-            )),
+            }
+            _ => collector.push(
+                &identifiers,
+                get_collateral_mismatch_failure("Ask Collateral was of type marker share sale"),
+            ),
         },
         AskCollateral::ScopeTrade(ask_collat) => match &bid.collateral {
             BidCollateral::ScopeTrade(bid_collat) => {
-                // Reject match requests that send the wrong type of match options to notify the
-                // caller that they are attempting an action that is unrelated to the selected match
-                if admin_match_options.is_some() && !matches!(admin_match_options, Some(AdminMatchOptions::ScopeTrade {.. })) {
-                    handler.push(format!(
-                        "{} Admin match options were provided, but were not of the scope_trade variant",
-                        identifiers,
-                    ));
+                if admin_match_options.is_some()
+                    && !matches!(
+                        admin_match_options,
+                        Some(AdminMatchOptions::ScopeTrade { .. })
+                    )
+                {
+                    collector.push(&identifiers, get_invalid_options_failure("scope_trade"));
                 }
-                handler.append(
-                    &get_scope_trade_collateral_validation(
-                        ask,
-                        bid,
+                collector.append(
+                    &get_identifiers(Some("SCOPE TRADE"), ask, bid),
+                    get_scope_trade_collateral_validation(
                         ask_collat,
                         bid_collat,
-                        if let Some(
-                            AdminMatchOptions::ScopeTrade {
-                                accept_mismatched_bids: Some(mismatched_bids)
-                            }) = admin_match_options {
-                                *mismatched_bids
+                        if let Some(AdminMatchOptions::ScopeTrade {
+                            accept_mismatched_bids: Some(mismatched_bids),
+                        }) = admin_match_options
+                        {
+                            *mismatched_bids
                         } else {
                             false
-                        }
+                        },
                     ),
                 );
-            },
-            _ => handler.push(format!(
-                "{} Ask Collateral was of type scope trade, which did not match bid collateral",
-                identifiers,
-            )),
-        }
+            }
+            BidCollateral::ScopeSpecTrade(bid_collat) => {
+                if admin_match_options.is_some()
+                    && !matches!(
+                        admin_match_options,
+                        Some(AdminMatchOptions::ScopeTrade { .. })
+                    )
+                {
+                    collector.push(&identifiers, get_invalid_options_failure("scope_trade"));
+                }
+                collector.append(
+                    &get_identifiers(Some("SCOPE SPEC TRADE"), ask, bid),
+                    get_scope_spec_trade_collateral_validation(
+                        deps,
+                        env,
+                        ask_collat,
+                        bid_collat,
+                        if let Some(AdminMatchOptions::ScopeTrade {
+                            accept_mismatched_bids: Some(mismatched_bids),
+                        }) = admin_match_options
+                        {
+                            *mismatched_bids
+                        } else {
+                            false
+                        },
+                    ),
+                );
+            }
+            _ => collector.push(
+                &identifiers,
+                get_collateral_mismatch_failure("Ask Collateral was of type scope trade"),
+            ),
+        },
+        AskCollateral::ScopeBundleTrade(ask_collat) => match &bid.collateral {
+            BidCollateral::ScopeBundleTrade(bid_collat) => {
+                if admin_match_options.is_some()
+                    && !matches!(
+                        admin_match_options,
+                        Some(AdminMatchOptions::ScopeBundleTrade { .. })
+                    )
+                {
+                    collector.push(
+                        &identifiers,
+                        get_invalid_options_failure("scope_bundle_trade"),
+                    );
+                }
+                collector.append(
+                    &get_identifiers(Some("SCOPE BUNDLE TRADE"), ask, bid),
+                    get_scope_bundle_trade_collateral_validation(
+                        ask_collat,
+                        bid_collat,
+                        if let Some(AdminMatchOptions::ScopeBundleTrade {
+                            accept_mismatched_bids: Some(mismatched_bids),
+                        }) = admin_match_options
+                        {
+                            *mismatched_bids
+                        } else {
+                            false
+                        },
+                    ),
+                );
+            }
+            _ => collector.push(
+                &identifiers,
+                get_collateral_mismatch_failure("Ask Collateral was of type scope bundle trade"),
+            ),
+        },
     };
-    handler.handle()
+    collector
 }
 
-fn get_required_attributes_error<S: Into<String>>(
+fn get_invalid_options_failure(variant_name: &str) -> MatchValidationFailure {
+    MatchValidationFailure::new(
+        MatchFailureCode::InvalidAdminMatchOptions,
+        MatchSide::Both,
+        "admin_match_options",
+        format!(
+            "Admin match options were provided, but were not of the {} variant",
+            variant_name,
+        ),
+    )
+}
+
+fn get_collateral_mismatch_failure(ask_collateral_description: &str) -> MatchValidationFailure {
+    MatchValidationFailure::new(
+        MatchFailureCode::CollateralTypeMismatch,
+        MatchSide::Both,
+        "collateral",
+        format!(
+            "{}, which did not match bid collateral",
+            ask_collateral_description,
+        ),
+    )
+}
+
+fn get_pending_effective_time(
+    descriptor: &Option<RequestDescriptor>,
+    env: &Env,
+) -> Option<Timestamp> {
+    descriptor
+        .as_ref()
+        .filter(|d| !d.is_effective_at(&env.block.time))
+        .and_then(|d| d.effective_time)
+}
+
+/// Checks the target account's attributes against the attribute requirement and expression of
+/// the descriptor, producing a message describing every unmet requirement.
+pub fn get_required_attributes_error<S: Into<String>>(
     deps: &Deps<ProvenanceQuery>,
     descriptor: &Option<RequestDescriptor>,
     target_address: &Addr,
     checked_account_type: S,
 ) -> Option<String> {
-    if let Some(attribute_requirement) = descriptor
-        .clone()
-        .and_then(|d| d.attribute_requirement)
-        .take_if(|ar| !ar.attributes.is_empty())
+    descriptor
+        .as_ref()
+        .filter(|d| d.has_attribute_checks())
+        .and_then(|d| {
+            get_attribute_checks_error(
+                deps,
+                d.attribute_requirement.as_ref(),
+                d.attribute_expression.as_ref(),
+                target_address,
+                checked_account_type,
+            )
+        })
+}
+
+pub fn get_attribute_checks_error<S: Into<String>>(
+    deps: &Deps<ProvenanceQuery>,
+    attribute_requirement: Option<&AttributeRequirement>,
+    attribute_expression: Option<&AttributeExpression>,
+    target_address: &Addr,
+    checked_account_type: S,
+) -> Option<String> {
+    if attribute_requirement
+        .map(|requirement| requirement.has_checks())
+        .unwrap_or(false)
+        || attribute_expression.is_some()
     {
         let checked_account_type = checked_account_type.into();
         let attribute_response = ProvenanceQuerier::new(&deps.querier)
@@ -205,53 +517,84 @@ fn get_required_attributes_error<S: Into<String>>(
         if let Ok(attributes) = attribute_response {
             let account_attribute_names = attributes
                 .attributes
-                .into_iter()
-                .map(|a| a.name)
+                .iter()
+                .map(|a| a.name.to_owned())
                 .collect::<Vec<String>>();
-            let (requirements_met, error_msg) = match attribute_requirement.requirement_type {
-                AttributeRequirementType::All => {
-                    (
-                        attribute_requirement
-                            .attributes
-                            .iter()
-                            .all(|attribute_name| account_attribute_names.contains(attribute_name)),
-                        format!(
-                            "the [{} account] is required to have all of the following attributes: {:?}",
-                            checked_account_type,
-                            &attribute_requirement.attributes
-                        ),
-                    )
+            let mut error_messages: Vec<String> = vec![];
+            let attribute_requirement = attribute_requirement
+                .cloned()
+                .unwrap_or_else(|| AttributeRequirement::all::<String>(&[]));
+            if !attribute_requirement.attributes.is_empty() {
+                let (requirements_met, error_msg) = match attribute_requirement.requirement_type {
+                    AttributeRequirementType::All => {
+                        (
+                            attribute_requirement
+                                .attributes
+                                .iter()
+                                .all(|attribute_name| account_attribute_names.contains(attribute_name)),
+                            format!(
+                                "the [{} account] is required to have all of the following attributes: {:?}",
+                                checked_account_type,
+                                &attribute_requirement.attributes
+                            ),
+                        )
+                    }
+                    AttributeRequirementType::Any => {
+                        (
+                            attribute_requirement.attributes
+                                .iter()
+                                .any(|attribute_name| account_attribute_names.contains(attribute_name)),
+                            format!(
+                                "the [{} account] did not have any of the following attributes: {:?}",
+                                checked_account_type,
+                                &attribute_requirement.attributes,
+                            ),
+                        )
+                    },
+                    AttributeRequirementType::None => {
+                        (
+                            // Negate the .any() to convert it into a .none(), which sadly does not exist
+                            !attribute_requirement.attributes
+                                .iter()
+                                .any(|attribute_name| account_attribute_names.contains(attribute_name)),
+                            format!(
+                                "the [{} account] is required to not have any of the following attributes: {:?}",
+                                checked_account_type,
+                                &attribute_requirement.attributes
+                            ),
+                        )
+                    }
+                };
+                if !requirements_met {
+                    error_messages.push(error_msg);
                 }
-                AttributeRequirementType::Any => {
-                    (
-                        attribute_requirement.attributes
-                            .iter()
-                            .any(|attribute_name| account_attribute_names.contains(attribute_name)),
-                        format!(
-                            "the [{} account] did not have any of the following attributes: {:?}",
-                            checked_account_type,
-                            &attribute_requirement.attributes,
-                        ),
-                    )
-                },
-                AttributeRequirementType::None => {
-                    (
-                        // Negate the .any() to convert it into a .none(), which sadly does not exist
-                        !attribute_requirement.attributes
-                            .iter()
-                            .any(|attribute_name| account_attribute_names.contains(attribute_name)),
-                        format!(
-                            "the [{} account] is required to not have any of the following attributes: {:?}",
-                            checked_account_type,
-                            &attribute_requirement.attributes
-                        ),
-                    )
+            }
+            let unmet_value_requirements = attribute_requirement
+                .get_value_requirements()
+                .iter()
+                .filter(|requirement| !requirement.is_met_by(&attributes.attributes))
+                .map(|requirement| requirement.get_description())
+                .collect::<Vec<String>>();
+            if !unmet_value_requirements.is_empty() {
+                error_messages.push(format!(
+                    "the [{} account] is required to have attributes with the following values: {}",
+                    checked_account_type,
+                    unmet_value_requirements.join(", "),
+                ));
+            }
+            if let Some(attribute_expression) = attribute_expression {
+                if !attribute_expression.evaluate(&attributes.attributes) {
+                    error_messages.push(format!(
+                        "the [{} account] did not satisfy the attribute expression: {}",
+                        checked_account_type,
+                        attribute_expression.get_description(),
+                    ));
                 }
-            };
-            return if requirements_met {
+            }
+            return if error_messages.is_empty() {
                 None
             } else {
-                Some(error_msg)
+                Some(error_messages.join("; "))
             };
         }
         return Some(format!(
@@ -264,77 +607,122 @@ fn get_required_attributes_error<S: Into<String>>(
 }
 
 fn get_coin_trade_collateral_validation(
-    ask: &AskOrder,
-    bid: &BidOrder,
     ask_collateral: &CoinTradeAskCollateral,
     bid_collateral: &CoinTradeBidCollateral,
     accept_mismatched_bids: bool,
-) -> Vec<String> {
-    let mut validation_messages: Vec<String> = vec![];
-    let identifiers = format!(
-        "COIN TRADE Match Validation for AskOrder [{}] and BidOrder [{}]:",
-        &ask.id, &bid.id
-    );
+) -> Vec<MatchValidationFailure> {
+    let mut failures: Vec<MatchValidationFailure> = vec![];
+    // Partial fill asks allow bids to purchase any portion of the remaining base at a quote that
+    // follows the ratio of the ask's full quote to its full base
+    if let Some(remaining_base) = ask_collateral.remaining_base {
+        let remaining_base_display = format_coin_display(&ask_collateral.get_remaining_base());
+        match bid_collateral.base.as_slice() {
+            [bid_base_coin]
+                if Some(&bid_base_coin.denom)
+                    == ask_collateral.base.first().map(|base_coin| &base_coin.denom) =>
+            {
+                if bid_base_coin.amount > remaining_base {
+                    failures.push(MatchValidationFailure::new(
+                        MatchFailureCode::BaseExceedsRemaining,
+                        MatchSide::Bid,
+                        "collateral.base",
+                        format!(
+                            "Bid base [{}] exceeds the remaining ask base [{}]",
+                            format_coin_display(&bid_collateral.base),
+                            remaining_base_display,
+                        ),
+                    ));
+                }
+                if !accept_mismatched_bids {
+                    match ask_collateral.get_quote_for_base_amount(bid_base_coin.amount.u128()) {
+                        Some(mut expected_quote) => {
+                            let mut bid_quote = bid_collateral.quote.to_owned();
+                            expected_quote.sort_by(coin_sort);
+                            bid_quote.sort_by(coin_sort);
+                            if expected_quote != bid_quote {
+                                failures.push(MatchValidationFailure::new(
+                                    MatchFailureCode::QuoteMismatch,
+                                    MatchSide::Both,
+                                    "collateral.quote",
+                                    format!(
+                                        "Ask quote [{}] for bid base [{}] does not match bid quote [{}]",
+                                        format_coin_display(&expected_quote),
+                                        format_coin_display(&bid_collateral.base),
+                                        format_coin_display(&bid_quote),
+                                    ),
+                                ));
+                            }
+                        }
+                        None => failures.push(MatchValidationFailure::new(
+                            MatchFailureCode::QuoteNotDivisible,
+                            MatchSide::Both,
+                            "collateral.quote",
+                            format!(
+                                "Ask quote [{}] cannot be evenly divided for bid base [{}]",
+                                format_coin_display(&ask_collateral.quote),
+                                format_coin_display(&bid_collateral.base),
+                            ),
+                        )),
+                    }
+                }
+            }
+            _ => failures.push(MatchValidationFailure::new(
+                MatchFailureCode::BaseMismatch,
+                MatchSide::Bid,
+                "collateral.base",
+                format!(
+                    "Bid base [{}] must be a single coin of the same denom as the remaining ask base [{}]",
+                    format_coin_display(&bid_collateral.base),
+                    remaining_base_display,
+                ),
+            )),
+        }
+        return failures;
+    }
     let mut ask_base = ask_collateral.base.to_owned();
     let mut bid_base = bid_collateral.base.to_owned();
     ask_base.sort_by(coin_sort);
     bid_base.sort_by(coin_sort);
     if ask_base != bid_base {
-        validation_messages.push(format!(
-            "{} Ask base [{}] does not match bid base [{}]",
-            &identifiers,
-            format_coin_display(&ask_base),
-            format_coin_display(&bid_base)
+        failures.push(MatchValidationFailure::new(
+            MatchFailureCode::BaseMismatch,
+            MatchSide::Both,
+            "collateral.base",
+            format!(
+                "Ask base [{}] does not match bid base [{}]",
+                format_coin_display(&ask_base),
+                format_coin_display(&bid_base)
+            ),
         ));
     }
     if !accept_mismatched_bids {
-        let mut ask_quote = ask_collateral.quote.to_owned();
-        let mut bid_quote = bid_collateral.quote.to_owned();
-        ask_quote.sort_by(coin_sort);
-        bid_quote.sort_by(coin_sort);
-        if ask_quote != bid_quote {
-            validation_messages.push(format!(
-                "{} Ask quote [{}] does not match bid quote [{}]",
-                &identifiers,
-                format_coin_display(&ask_quote),
-                format_coin_display(&bid_quote),
-            ));
+        if let Some(failure) = get_quote_mismatch_failure(
+            &ask_collateral.quote,
+            &bid_collateral.quote,
+            "does not match",
+        ) {
+            failures.push(failure);
         }
     }
-    validation_messages
+    failures
 }
 
 fn get_marker_trade_collateral_validation(
     deps: &Deps<ProvenanceQuery>,
-    ask: &AskOrder,
-    bid: &BidOrder,
     ask_collateral: &MarkerTradeAskCollateral,
     bid_collateral: &MarkerTradeBidCollateral,
     accept_mismatched_bids: bool,
-) -> Vec<String> {
-    let mut validation_messages: Vec<String> = vec![];
-    let identifiers = format!(
-        "MARKER TRADE Match Validation for AskOrder [{}] and BidOrder [{}]:",
-        &ask.id, &bid.id
+) -> Vec<MatchValidationFailure> {
+    let mut failures = get_marker_mismatch_failures(
+        &ask_collateral.marker_denom,
+        &ask_collateral.marker_address,
+        &bid_collateral.marker_denom,
+        &bid_collateral.marker_address,
     );
-    if ask_collateral.marker_denom != bid_collateral.marker_denom {
-        validation_messages.push(format!(
-            "{} Ask marker denom [{}] does not match bid marker denom [{}]",
-            &identifiers, &ask_collateral.marker_denom, &bid_collateral.marker_denom
-        ));
-    }
-    if ask_collateral.marker_address.as_str() != bid_collateral.marker_address.as_str() {
-        validation_messages.push(format!(
-            "{} Ask marker address [{}] does not match bid marker address [{}]",
-            &identifiers,
-            &ask_collateral.marker_address.as_str(),
-            &bid_collateral.marker_address.as_str()
-        ));
-    }
     // If a denom or address mismatch exists between the ask and bid, no other sane checks can be
     // made because each refers to a different marker
-    if !validation_messages.is_empty() {
-        return validation_messages;
+    if !failures.is_empty() {
+        return failures;
     }
     let marker = match ProvenanceQuerier::new(&deps.querier)
         .get_marker_by_denom(&ask_collateral.marker_denom)
@@ -343,92 +731,72 @@ fn get_marker_trade_collateral_validation(
         // Exit early if the marker does not appear to be available in the Provenance Blockchain
         // system.  No marker means the remaining checks are meaningless.
         Err(_) => {
-            validation_messages.push(format!(
-                "{} Failed to find marker for denom [{}]",
-                &identifiers, &ask_collateral.marker_denom
-            ));
-            return validation_messages;
+            failures.push(get_marker_not_found_failure(&ask_collateral.marker_denom));
+            return failures;
         }
     };
     let marker_share_count = if let Ok(marker_coin) = get_single_marker_coin_holding(&marker) {
         if marker_coin.amount.u128() != ask_collateral.share_count.u128() {
-            validation_messages.push(
+            failures.push(MatchValidationFailure::new(
+                MatchFailureCode::MarkerShareCountChanged,
+                MatchSide::Ask,
+                "collateral.share_count",
                 format!(
-                    "{} Marker share count was [{}] but the original value when added to the contract was [{}]",
-                    &identifiers,
+                    "Marker share count was [{}] but the original value when added to the contract was [{}]",
                     marker_coin.amount.u128(),
                     ask_collateral.share_count.u128(),
-                )
-            );
+                ),
+            ));
         }
         marker_coin.amount.u128()
     } else {
-        validation_messages.push(format!(
-            "{} Marker had invalid coin holdings for match: [{}]. Expected a single instance of coin [{}]",
-            &identifiers,
-            format_coin_display(&marker.coins),
+        failures.push(get_invalid_marker_holdings_failure(
+            &marker.coins,
             &ask_collateral.marker_denom,
         ));
-        return validation_messages;
+        return failures;
     };
     if !accept_mismatched_bids {
-        let mut ask_quote =
-            multiply_coins_by_amount(&ask_collateral.quote_per_share, marker_share_count);
-        let mut bid_quote = bid_collateral.quote.to_owned();
-        ask_quote.sort_by(coin_sort);
-        bid_quote.sort_by(coin_sort);
-        if ask_quote != bid_quote {
-            validation_messages.push(format!(
-                "{} Ask quote [{}] did not match bid quote [{}]",
-                &identifiers,
-                format_coin_display(&ask_quote),
-                format_coin_display(&bid_quote),
-            ));
+        if let Some(failure) = get_quote_mismatch_failure(
+            &multiply_coins_by_amount(&ask_collateral.quote_per_share, marker_share_count),
+            &bid_collateral.quote,
+            "did not match",
+        ) {
+            failures.push(failure);
         }
     }
-    validation_messages
+    failures
 }
 
 fn get_marker_share_sale_collateral_validation(
     deps: &Deps<ProvenanceQuery>,
-    ask: &AskOrder,
-    bid: &BidOrder,
     ask_collateral: &MarkerShareSaleAskCollateral,
     bid_collateral: &MarkerShareSaleBidCollateral,
     override_quote_source: &Option<OverrideQuoteSource>,
-) -> Vec<String> {
-    let mut validation_messages: Vec<String> = vec![];
-    let identifiers = format!(
-        "MARKER SHARE SALE Match Validation for AskOrder [{}] and BidOrder [{}]:",
-        &ask.id, &bid.id,
+) -> Vec<MatchValidationFailure> {
+    let mut failures = get_marker_mismatch_failures(
+        &ask_collateral.marker_denom,
+        &ask_collateral.marker_address,
+        &bid_collateral.marker_denom,
+        &bid_collateral.marker_address,
     );
-    if ask_collateral.marker_denom != bid_collateral.marker_denom {
-        validation_messages.push(format!(
-            "{} Ask marker denom [{}] does not match bid marker denom [{}]",
-            &identifiers, &ask_collateral.marker_denom, &bid_collateral.marker_denom,
-        ));
-    }
-    if ask_collateral.marker_address.as_str() != bid_collateral.marker_address.as_str() {
-        validation_messages.push(format!(
-            "{} Ask marker address [{}] does not match bid marker address [{}]",
-            &identifiers,
-            &ask_collateral.marker_address.as_str(),
-            &bid_collateral.marker_address.as_str()
-        ));
-    }
     // If a denom or address mismatch exists between the ask and bid, no other sane checks can be
     // made because each refers to a different marker
-    if !validation_messages.is_empty() {
-        return validation_messages;
+    if !failures.is_empty() {
+        return failures;
     }
     match ask_collateral.sale_type {
         ShareSaleType::SingleTransaction => {
             if bid_collateral.share_count.u128() < ask_collateral.total_shares_in_sale.u128() {
-                validation_messages.push(format!(
-                    "{} Ask requested that [{}] shares be purchased, but bid wanted too few [{}]",
-                    &identifiers,
-                    ask_collateral.total_shares_in_sale.u128(),
-                    bid_collateral.share_count.u128(),
+                failures.push(MatchValidationFailure::new(
+                    MatchFailureCode::ShareCountTooLow,
+                    MatchSide::Bid,
+                    "collateral.share_count",
+                    format!(
+                        "Ask requested that [{}] shares be purchased, but bid wanted too few [{}]",
+                        ask_collateral.total_shares_in_sale.u128(),
+                        bid_collateral.share_count.u128(),
+                    ),
                 ));
             }
         }
@@ -441,30 +809,29 @@ fn get_marker_share_sale_collateral_validation(
         // Exit early if the marker does not appear to be available in the Provenance Blockchain
         // system.  No marker means the remaining checks are meaningless.
         Err(_) => {
-            validation_messages.push(format!(
-                "{} Failed to find marker for denom [{}]",
-                &identifiers, &ask_collateral.marker_denom
-            ));
-            return validation_messages;
+            failures.push(get_marker_not_found_failure(&ask_collateral.marker_denom));
+            return failures;
         }
     };
     if let Ok(marker_coin) = get_single_marker_coin_holding(&marker) {
         if marker_coin.amount.u128() < ask_collateral.remaining_shares_in_sale.u128() {
-            validation_messages.push(format!(
-                "{} Marker is not synced with the contract! Marker had [{}] shares remaining, which is less than the listed available share count of [{}]",
-                &identifiers,
-                marker_coin.amount.u128(),
-                ask_collateral.remaining_shares_in_sale.u128(),
+            failures.push(MatchValidationFailure::new(
+                MatchFailureCode::MarkerSharesUnavailable,
+                MatchSide::Ask,
+                "collateral.remaining_shares_in_sale",
+                format!(
+                    "Marker is not synced with the contract! Marker had [{}] shares remaining, which is less than the listed available share count of [{}]",
+                    marker_coin.amount.u128(),
+                    ask_collateral.remaining_shares_in_sale.u128(),
+                ),
             ));
         }
     } else {
-        validation_messages.push(format!(
-            "{} Marker had invalid coin holdings for match: [{}]. Expected a single instance of coin [{}]",
-            &identifiers,
-            format_coin_display(&marker.coins),
+        failures.push(get_invalid_marker_holdings_failure(
+            &marker.coins,
             &ask_collateral.marker_denom,
         ));
-        return validation_messages;
+        return failures;
     }
     let mut ask_quote_per_share = ask_collateral.quote_per_share.to_owned();
     ask_quote_per_share.sort_by(coin_sort);
@@ -476,11 +843,15 @@ fn get_marker_share_sale_collateral_validation(
             // both contain the same coin types.  Having a bad alignment on quote coin types is prone
             // to produce odd values after match execution
             if ask_quote_per_share.len() != bid_quote_per_share.len() {
-                validation_messages.push(format!(
-                    "{} Ask quote per share [{}] had a different amount of specified coin types than bid quote per share [{}]",
-                    &identifiers,
-                    format_coin_display(&ask_quote_per_share),
-                    format_coin_display(&bid_quote_per_share),
+                failures.push(MatchValidationFailure::new(
+                    MatchFailureCode::QuoteMismatch,
+                    MatchSide::Both,
+                    "collateral.quote_per_share",
+                    format!(
+                        "Ask quote per share [{}] had a different amount of specified coin types than bid quote per share [{}]",
+                        format_coin_display(&ask_quote_per_share),
+                        format_coin_display(&bid_quote_per_share),
+                    ),
                 ));
             } else {
                 // Verify that each ask coin's denom matches a bid coin denom.  This, in tandem with
@@ -495,24 +866,32 @@ fn get_marker_share_sale_collateral_validation(
                         // This ensures that the funds stored for the bid will never be exceeded when
                         // executing the match.
                         if bid_coin.amount.u128() < ask_coin.amount.u128() {
-                            validation_messages.push(format!(
-                                "{} Ask quote per share [{}] required at least [{}{}] but bid quote per share [{}] only specified [{}{}]",
-                                &identifiers,
-                                format_coin_display(&ask_quote_per_share),
-                                ask_coin.amount.u128(),
-                                &ask_coin.denom,
-                                format_coin_display(&bid_quote_per_share),
-                                bid_coin.amount.u128(),
-                                &bid_coin.denom,
+                            failures.push(MatchValidationFailure::new(
+                                MatchFailureCode::QuoteTooLow,
+                                MatchSide::Bid,
+                                "collateral.quote_per_share",
+                                format!(
+                                    "Ask quote per share [{}] required at least [{}{}] but bid quote per share [{}] only specified [{}{}]",
+                                    format_coin_display(&ask_quote_per_share),
+                                    ask_coin.amount.u128(),
+                                    &ask_coin.denom,
+                                    format_coin_display(&bid_quote_per_share),
+                                    bid_coin.amount.u128(),
+                                    &bid_coin.denom,
+                                ),
                             ));
                         }
                     } else {
-                        validation_messages.push(format!(
-                            "{} Ask quote per share [{}] contained coin denom [{}] but bid quote per share [{}] did not",
-                            &identifiers,
-                            format_coin_display(&ask_quote_per_share),
-                            ask_coin.denom,
-                            format_coin_display(&bid_quote_per_share),
+                        failures.push(MatchValidationFailure::new(
+                            MatchFailureCode::QuoteMismatch,
+                            MatchSide::Both,
+                            "collateral.quote_per_share",
+                            format!(
+                                "Ask quote per share [{}] contained coin denom [{}] but bid quote per share [{}] did not",
+                                format_coin_display(&ask_quote_per_share),
+                                ask_coin.denom,
+                                format_coin_display(&bid_quote_per_share),
+                            ),
                         ));
                         continue;
                     }
@@ -521,57 +900,263 @@ fn get_marker_share_sale_collateral_validation(
         }
         None => {
             if ask_quote_per_share != bid_quote_per_share {
-                validation_messages.push(format!(
-                    "{} Ask quote per share [{}] did not equal bid quote per share [{}]",
-                    &identifiers,
-                    format_coin_display(&ask_collateral.quote_per_share),
-                    format_coin_display(&bid_collateral.get_quote_per_share()),
+                failures.push(MatchValidationFailure::new(
+                    MatchFailureCode::QuoteMismatch,
+                    MatchSide::Both,
+                    "collateral.quote_per_share",
+                    format!(
+                        "Ask quote per share [{}] did not equal bid quote per share [{}]",
+                        format_coin_display(&ask_collateral.quote_per_share),
+                        format_coin_display(&bid_collateral.get_quote_per_share()),
+                    ),
                 ));
             }
         }
     }
-    validation_messages
+    failures
 }
 
 fn get_scope_trade_collateral_validation(
-    ask: &AskOrder,
-    bid: &BidOrder,
     ask_collateral: &ScopeTradeAskCollateral,
     bid_collateral: &ScopeTradeBidCollateral,
     accept_mismatched_bids: bool,
-) -> Vec<String> {
-    let mut validation_messages: Vec<String> = vec![];
-    let identifiers = format!(
-        "SCOPE TRADE Match Validation for AskOrder [{}] and BidOrder [{}]:",
-        &ask.id, &bid.id,
-    );
+) -> Vec<MatchValidationFailure> {
+    let mut failures: Vec<MatchValidationFailure> = vec![];
     if ask_collateral.scope_address != bid_collateral.scope_address {
-        validation_messages.push(format!(
-            "{} Ask scope address [{}] does not match bid scope address [{}]",
-            &identifiers, &ask_collateral.scope_address, &bid_collateral.scope_address,
+        failures.push(MatchValidationFailure::new(
+            MatchFailureCode::ScopeAddressMismatch,
+            MatchSide::Both,
+            "collateral.scope_address",
+            format!(
+                "Ask scope address [{}] does not match bid scope address [{}]",
+                &ask_collateral.scope_address, &bid_collateral.scope_address,
+            ),
         ));
     }
     if !accept_mismatched_bids {
-        let mut ask_quote = ask_collateral.quote.to_owned();
-        let mut bid_quote = bid_collateral.quote.to_owned();
-        ask_quote.sort_by(coin_sort);
-        bid_quote.sort_by(coin_sort);
-        if ask_quote != bid_quote {
-            validation_messages.push(format!(
-                "{} Ask quote [{}] does not match bid quote [{}]",
-                &identifiers,
-                format_coin_display(&ask_quote),
-                format_coin_display(&bid_quote),
-            ));
+        if let Some(failure) = get_quote_mismatch_failure(
+            &ask_collateral.quote,
+            &bid_collateral.quote,
+            "does not match",
+        ) {
+            failures.push(failure);
+        }
+    }
+    failures
+}
+
+/// Validates a generic scope spec bid against a scope trade ask.  The ask's scope is fetched from the
+/// Provenance metadata module to verify that it uses the bid's scope specification.
+fn get_scope_spec_trade_collateral_validation(
+    deps: &Deps<ProvenanceQuery>,
+    env: &Env,
+    ask_collateral: &ScopeTradeAskCollateral,
+    bid_collateral: &ScopeSpecTradeBidCollateral,
+    accept_mismatched_bids: bool,
+) -> Vec<MatchValidationFailure> {
+    let mut failures: Vec<MatchValidationFailure> = vec![];
+    match ProvenanceQuerier::new(&deps.querier).get_scope(&ask_collateral.scope_address) {
+        Ok(scope) => {
+            if scope.specification_id != bid_collateral.scope_spec_id {
+                failures.push(MatchValidationFailure::new(
+                    MatchFailureCode::ScopeSpecMismatch,
+                    MatchSide::Both,
+                    "collateral.scope_spec_id",
+                    format!(
+                        "Ask scope [{}] has scope spec id [{}], which does not match bid scope spec id [{}]",
+                        &ask_collateral.scope_address,
+                        &scope.specification_id,
+                        &bid_collateral.scope_spec_id,
+                    ),
+                ));
+            }
+            if bid_collateral.requires_value_ownership()
+                && scope.value_owner_address != env.contract.address
+            {
+                failures.push(MatchValidationFailure::new(
+                    MatchFailureCode::ScopeValueOwnershipNotHeld,
+                    MatchSide::Ask,
+                    "collateral.scope_address",
+                    format!(
+                        "Ask scope [{}] does not have its value ownership held by the contract",
+                        &ask_collateral.scope_address,
+                    ),
+                ));
+            }
         }
+        Err(e) => failures.push(MatchValidationFailure::new(
+            MatchFailureCode::ScopeNotFound,
+            MatchSide::Ask,
+            "collateral.scope_address",
+            format!(
+                "Ask scope [{}] could not be found: {:?}",
+                &ask_collateral.scope_address, e,
+            ),
+        )),
+    }
+    if let Some(message) = get_attribute_checks_error(
+        deps,
+        bid_collateral.scope_attribute_requirement.as_ref(),
+        None,
+        &Addr::unchecked(&ask_collateral.scope_address),
+        "scope",
+    ) {
+        failures.push(MatchValidationFailure::new(
+            MatchFailureCode::AttributeRequirementNotMet,
+            MatchSide::Ask,
+            "collateral.scope_address",
+            message,
+        ));
+    }
+    // Scope spec bids specify the maximum quote they will pay, so any ask quote covered by the bid
+    // quote is acceptable
+    if !accept_mismatched_bids
+        && subtract_coins(
+            "scope spec trade quote",
+            &bid_collateral.quote,
+            &ask_collateral.quote,
+        )
+        .is_err()
+    {
+        failures.push(MatchValidationFailure::new(
+            MatchFailureCode::QuoteTooLow,
+            MatchSide::Bid,
+            "collateral.quote",
+            format!(
+                "Ask quote [{}] exceeds bid quote [{}]",
+                format_coin_display(&ask_collateral.quote),
+                format_coin_display(&bid_collateral.quote),
+            ),
+        ));
     }
-    validation_messages
+    failures
+}
+
+fn get_scope_bundle_trade_collateral_validation(
+    ask_collateral: &ScopeBundleTradeAskCollateral,
+    bid_collateral: &ScopeBundleTradeBidCollateral,
+    accept_mismatched_bids: bool,
+) -> Vec<MatchValidationFailure> {
+    let mut failures: Vec<MatchValidationFailure> = vec![];
+    // The bid must target exactly the scopes held in the bundle, but may list them in any order
+    let mut ask_scopes = ask_collateral.scope_addresses.to_owned();
+    let mut bid_scopes = bid_collateral.scope_addresses.to_owned();
+    ask_scopes.sort();
+    bid_scopes.sort();
+    if ask_scopes != bid_scopes {
+        failures.push(MatchValidationFailure::new(
+            MatchFailureCode::ScopeAddressMismatch,
+            MatchSide::Both,
+            "collateral.scope_addresses",
+            format!(
+                "Ask scope addresses [{}] do not match bid scope addresses [{}]",
+                ask_scopes.join(", "),
+                bid_scopes.join(", "),
+            ),
+        ));
+    }
+    if !accept_mismatched_bids {
+        if let Some(failure) = get_quote_mismatch_failure(
+            &ask_collateral.quote,
+            &bid_collateral.quote,
+            "does not match",
+        ) {
+            failures.push(failure);
+        }
+    }
+    failures
+}
+
+/// Compares the quotes without regard to coin order, producing a failure when they differ.
+fn get_quote_mismatch_failure(
+    ask_quote: &[Coin],
+    bid_quote: &[Coin],
+    mismatch_description: &str,
+) -> Option<MatchValidationFailure> {
+    let mut ask_quote = ask_quote.to_vec();
+    let mut bid_quote = bid_quote.to_vec();
+    ask_quote.sort_by(coin_sort);
+    bid_quote.sort_by(coin_sort);
+    if ask_quote == bid_quote {
+        return None;
+    }
+    MatchValidationFailure::new(
+        MatchFailureCode::QuoteMismatch,
+        MatchSide::Both,
+        "collateral.quote",
+        format!(
+            "Ask quote [{}] {} bid quote [{}]",
+            format_coin_display(&ask_quote),
+            mismatch_description,
+            format_coin_display(&bid_quote),
+        ),
+    )
+    .into()
+}
+
+fn get_marker_mismatch_failures(
+    ask_marker_denom: &str,
+    ask_marker_address: &Addr,
+    bid_marker_denom: &str,
+    bid_marker_address: &Addr,
+) -> Vec<MatchValidationFailure> {
+    let mut failures: Vec<MatchValidationFailure> = vec![];
+    if ask_marker_denom != bid_marker_denom {
+        failures.push(MatchValidationFailure::new(
+            MatchFailureCode::MarkerDenomMismatch,
+            MatchSide::Both,
+            "collateral.marker_denom",
+            format!(
+                "Ask marker denom [{}] does not match bid marker denom [{}]",
+                ask_marker_denom, bid_marker_denom,
+            ),
+        ));
+    }
+    if ask_marker_address.as_str() != bid_marker_address.as_str() {
+        failures.push(MatchValidationFailure::new(
+            MatchFailureCode::MarkerAddressMismatch,
+            MatchSide::Both,
+            "collateral.marker_address",
+            format!(
+                "Ask marker address [{}] does not match bid marker address [{}]",
+                ask_marker_address.as_str(),
+                bid_marker_address.as_str(),
+            ),
+        ));
+    }
+    failures
+}
+
+fn get_marker_not_found_failure(marker_denom: &str) -> MatchValidationFailure {
+    MatchValidationFailure::new(
+        MatchFailureCode::MarkerNotFound,
+        MatchSide::Ask,
+        "collateral.marker_denom",
+        format!("Failed to find marker for denom [{}]", marker_denom),
+    )
+}
+
+fn get_invalid_marker_holdings_failure(
+    marker_coins: &[Coin],
+    marker_denom: &str,
+) -> MatchValidationFailure {
+    MatchValidationFailure::new(
+        MatchFailureCode::InvalidMarkerHoldings,
+        MatchSide::Ask,
+        "collateral.marker_denom",
+        format!(
+            "Marker had invalid coin holdings for match: [{}]. Expected a single instance of coin [{}]",
+            format_coin_display(marker_coins),
+            marker_denom,
+        ),
+    )
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::test::error_helpers::assert_validation_error_message;
     use crate::test::mock_marker::{MockMarker, DEFAULT_MARKER_ADDRESS, DEFAULT_MARKER_DENOM};
-    use crate::test::mock_scope::DEFAULT_SCOPE_ADDR;
+    use crate::test::mock_scope::{MockScope, DEFAULT_SCOPE_ADDR, DEFAULT_SCOPE_SPEC_ID};
     use crate::test::request_helpers::{
         mock_ask_marker_share_sale, mock_ask_marker_trade, mock_ask_order,
         mock_ask_order_with_descriptor, mock_ask_scope_trade, mock_bid_marker_share_sale,
@@ -579,21 +1164,29 @@ mod tests {
         replace_ask_quote, replace_bid_quote,
     };
     use crate::types::core::error::ContractError;
+    use crate::types::core::quote_denom_allowlist::QuoteDenomAllowlist;
     use crate::types::request::admin_match_options::{AdminMatchOptions, OverrideQuoteSource};
     use crate::types::request::ask_types::ask_collateral::AskCollateral;
     use crate::types::request::ask_types::ask_order::AskOrder;
     use crate::types::request::bid_types::bid_collateral::BidCollateral;
     use crate::types::request::bid_types::bid_order::BidOrder;
-    use crate::types::request::request_descriptor::{AttributeRequirement, RequestDescriptor};
+    use crate::types::request::match_report::{
+        MatchFailureCode, MatchSide, MatchValidationFailure,
+    };
+    use crate::types::request::request_descriptor::{
+        AttributeExpression, AttributeRequirement, AttributeValueCondition,
+        AttributeValueRequirement, RequestDescriptor,
+    };
     use crate::types::request::request_type::RequestType;
     use crate::types::request::share_sale_type::ShareSaleType;
     use crate::util::constants::NHASH;
     use crate::validation::ask_order_validation::validate_ask_order;
     use crate::validation::bid_order_validation::validate_bid_order;
     use crate::validation::execute_match_validation::{
-        get_required_attributes_error, validate_match,
+        get_match_validation_failures, get_required_attributes_error, validate_match,
     };
-    use cosmwasm_std::{coin, coins, Addr, Deps};
+    use cosmwasm_std::testing::{mock_env, MOCK_CONTRACT_ADDR};
+    use cosmwasm_std::{coin, coins, Addr, Coin, Deps};
     use provwasm_mocks::mock_dependencies;
     use provwasm_std::{AccessGrant, MarkerAccess, ProvenanceQuery};
 
@@ -625,19 +1218,21 @@ mod tests {
         .expect("expected validation to pass for the new bid order");
         deps.querier
             .with_attributes("bidder", &[("attribute.pb", "value", "string")]);
-        validate_match(&deps.as_ref(), &ask_order, &bid_order, &None)
+        validate_match(&deps.as_ref(), &mock_env(), &ask_order, &bid_order, &None)
             .expect("expected validation to pass for a simple coin to coin trade");
         ask_order.collateral = AskCollateral::coin_trade(
             &[coin(10, "a"), coin(20, "b"), coin(30, "c")],
             &[coin(50, "d"), coin(60, "e"), coin(70, "f")],
         );
-        validate_ask_order(&ask_order).expect("expected modified ask order to remain valid");
+        validate_ask_order(&ask_order, &QuoteDenomAllowlist::default())
+            .expect("expected modified ask order to remain valid");
         bid_order.collateral = BidCollateral::coin_trade(
             &[coin(30, "c"), coin(10, "a"), coin(20, "b")],
             &[coin(50, "d"), coin(70, "f"), coin(60, "e")],
         );
-        validate_bid_order(&bid_order).expect("expected modified bid order to remain valid");
-        validate_match(&deps.as_ref(), &ask_order, &bid_order, &None)
+        validate_bid_order(&bid_order, &QuoteDenomAllowlist::default())
+            .expect("expected modified bid order to remain valid");
+        validate_match(&deps.as_ref(), &mock_env(), &ask_order, &bid_order, &None)
             .expect("expected validation to pass for a complex coin trade with mismatched orders");
     }
 
@@ -687,7 +1282,7 @@ mod tests {
             )),
         )
         .expect("expected the bid order to be valid");
-        validate_match(&deps.as_ref(), &ask_order, &bid_order, &None)
+        validate_match(&deps.as_ref(), &mock_env(), &ask_order, &bid_order, &None)
             .expect("expected validation to pass for a single coin quote");
         replace_ask_quote(
             &mut ask_order,
@@ -697,7 +1292,7 @@ mod tests {
                 coin(13, "worstthing"),
             ],
         );
-        validate_ask_order(&ask_order)
+        validate_ask_order(&ask_order, &QuoteDenomAllowlist::default())
             .expect("expected the ask order to remain valid after changes");
         replace_bid_quote(
             &mut bid_order,
@@ -707,9 +1302,9 @@ mod tests {
                 coin(130, "worstthing"),
             ],
         );
-        validate_bid_order(&bid_order)
+        validate_bid_order(&bid_order, &QuoteDenomAllowlist::default())
             .expect("expected the bid order to remain valid after changes");
-        validate_match(&deps.as_ref(), &ask_order, &bid_order, &None)
+        validate_match(&deps.as_ref(), &mock_env(), &ask_order, &bid_order, &None)
             .expect("expected the validation to pass for a multi-coin quote");
     }
 
@@ -766,15 +1361,15 @@ mod tests {
                 ("required2.pb", "value2", "string"),
             ],
         );
-        validate_match(&deps.as_ref(), &ask_order, &bid_order, &None)
+        validate_match(&deps.as_ref(), &mock_env(), &ask_order, &bid_order, &None)
             .expect("expected match validation to pass with correct parameters");
         replace_ask_quote(&mut ask_order, &[coin(100, NHASH), coin(250, "yolocoin")]);
-        validate_ask_order(&ask_order)
+        validate_ask_order(&ask_order, &QuoteDenomAllowlist::default())
             .expect("expected ask order to pass validation with a multi coin quote per share");
         replace_bid_quote(&mut bid_order, &[coin(500, NHASH), coin(1250, "yolocoin")]);
-        validate_bid_order(&bid_order)
+        validate_bid_order(&bid_order, &QuoteDenomAllowlist::default())
             .expect("expected bid order to pass validation with multi coin quote");
-        validate_match(&deps.as_ref(), &ask_order, &bid_order, &None).expect(
+        validate_match(&deps.as_ref(), &mock_env(), &ask_order, &bid_order, &None).expect(
             "expected match validation to pass when ask and bid order used a multi-coin quote",
         );
     }
@@ -827,19 +1422,146 @@ mod tests {
             )),
         )
         .expect("expected bid order to pass validation");
-        validate_match(&deps.as_ref(), &ask_order, &bid_order, &None)
+        validate_match(&deps.as_ref(), &mock_env(), &ask_order, &bid_order, &None)
             .expect("expected match validation to pass with correct parameters");
         replace_ask_quote(&mut ask_order, &[coin(100, NHASH), coin(250, "yolocoin")]);
-        validate_ask_order(&ask_order)
+        validate_ask_order(&ask_order, &QuoteDenomAllowlist::default())
             .expect("expected ask order to pass validation with a multi coin quote per share");
         replace_bid_quote(&mut bid_order, &[coin(500, NHASH), coin(1250, "yolocoin")]);
-        validate_bid_order(&bid_order)
+        validate_bid_order(&bid_order, &QuoteDenomAllowlist::default())
             .expect("expected bid order to pass validation with multi coin quote");
-        validate_match(&deps.as_ref(), &ask_order, &bid_order, &None).expect(
+        validate_match(&deps.as_ref(), &mock_env(), &ask_order, &bid_order, &None).expect(
             "expected match validation to pass when ask and bid order used a multi-coin quote",
         );
     }
 
+    #[test]
+    fn test_scope_spec_trade_validation() {
+        let mut deps = mock_dependencies(&[]);
+        deps.querier
+            .with_scope(MockScope::new_with_owner(MOCK_CONTRACT_ADDR));
+        let ask_order = mock_ask_order(AskCollateral::scope_trade(
+            DEFAULT_SCOPE_ADDR,
+            &coins(100, NHASH),
+        ));
+        let get_bid_order = |scope_spec_id: &str,
+                             quote: &[Coin],
+                             requirement: Option<AttributeRequirement>,
+                             require_value_ownership: Option<bool>| {
+            mock_bid_order(BidCollateral::scope_spec_trade(
+                scope_spec_id,
+                quote,
+                requirement,
+                require_value_ownership,
+            ))
+        };
+        let identifiers =
+            "SCOPE SPEC TRADE Match Validation for AskOrder [ask_id] and BidOrder [bid_id]:";
+        validate_match(
+            &deps.as_ref(),
+            &mock_env(),
+            &ask_order,
+            &get_bid_order(DEFAULT_SCOPE_SPEC_ID, &coins(150, NHASH), None, Some(true)),
+            &None,
+        )
+        .expect("a scope spec bid with a quote higher than the ask quote should be accepted");
+        let err = validate_match(
+            &deps.as_ref(),
+            &mock_env(),
+            &ask_order,
+            &get_bid_order("other-spec", &coins(100, NHASH), None, None),
+            &None,
+        )
+        .expect_err("an error should occur when the scope does not use the bid's spec");
+        assert_validation_error_message(
+            err,
+            format!(
+                "{} Ask scope [{}] has scope spec id [{}], which does not match bid scope spec id [other-spec]",
+                identifiers, DEFAULT_SCOPE_ADDR, DEFAULT_SCOPE_SPEC_ID,
+            ),
+        );
+        let err = validate_match(
+            &deps.as_ref(),
+            &mock_env(),
+            &ask_order,
+            &get_bid_order(DEFAULT_SCOPE_SPEC_ID, &coins(99, NHASH), None, None),
+            &None,
+        )
+        .expect_err("an error should occur when the bid quote does not cover the ask quote");
+        assert_validation_error_message(
+            err,
+            format!(
+                "{} Ask quote [100nhash] exceeds bid quote [99nhash]",
+                identifiers
+            ),
+        );
+        validate_match(
+            &deps.as_ref(),
+            &mock_env(),
+            &ask_order,
+            &get_bid_order(DEFAULT_SCOPE_SPEC_ID, &coins(99, NHASH), None, None),
+            &Some(AdminMatchOptions::scope_trade_options(true)),
+        )
+        .expect("a lower bid quote should be accepted when the admin accepts mismatched bids");
+        let err = validate_match(
+            &deps.as_ref(),
+            &mock_env(),
+            &ask_order,
+            &get_bid_order(
+                DEFAULT_SCOPE_SPEC_ID,
+                &coins(100, NHASH),
+                Some(AttributeRequirement::all(&["loan.pb"])),
+                None,
+            ),
+            &None,
+        )
+        .expect_err("an error should occur when the scope does not have the required attributes");
+        assert_validation_error_message(
+            err,
+            format!(
+                "{} the [scope account] is required to have all of the following attributes: [\"loan.pb\"]",
+                identifiers
+            ),
+        );
+        deps.querier
+            .with_attributes(DEFAULT_SCOPE_ADDR, &[("loan.pb", "value", "string")]);
+        validate_match(
+            &deps.as_ref(),
+            &mock_env(),
+            &ask_order,
+            &get_bid_order(
+                DEFAULT_SCOPE_SPEC_ID,
+                &coins(100, NHASH),
+                Some(AttributeRequirement::all(&["loan.pb"])),
+                None,
+            ),
+            &None,
+        )
+        .expect("the match should be accepted when the scope has the required attributes");
+        deps.querier.with_scope(
+            MockScope {
+                value_owner_address: Addr::unchecked("co_owner"),
+                ..MockScope::new_mock_scope_with_owner(MOCK_CONTRACT_ADDR)
+            }
+            .to_scope(),
+        );
+        let err = validate_match(
+            &deps.as_ref(),
+            &mock_env(),
+            &ask_order,
+            &get_bid_order(DEFAULT_SCOPE_SPEC_ID, &coins(100, NHASH), None, Some(true)),
+            &None,
+        )
+        .expect_err("an error should occur when value ownership is required but not escrowed");
+        assert_validation_error_message(
+            err,
+            format!(
+                "{} Ask scope [{}] does not have its value ownership held by the contract",
+                identifiers, DEFAULT_SCOPE_ADDR,
+            ),
+        );
+    }
+
     #[test]
     fn test_successful_scope_trade_validation() {
         let mut deps = mock_dependencies(&[]);
@@ -871,13 +1593,15 @@ mod tests {
                 ("c.pb", "value", "string"),
             ],
         );
-        validate_match(&deps.as_ref(), &ask_order, &bid_order, &None)
+        validate_match(&deps.as_ref(), &mock_env(), &ask_order, &bid_order, &None)
             .expect("expected match validation to pass for correct scope trade parameters");
         replace_ask_quote(&mut ask_order, &[coin(100, "acoin"), coin(100, "bcoin")]);
-        validate_ask_order(&ask_order).expect("multi coin ask order should pass validation");
+        validate_ask_order(&ask_order, &QuoteDenomAllowlist::default())
+            .expect("multi coin ask order should pass validation");
         replace_bid_quote(&mut bid_order, &[coin(100, "acoin"), coin(100, "bcoin")]);
-        validate_bid_order(&bid_order).expect("multi coin bid order should pass validation");
-        validate_match(&deps.as_ref(), &ask_order, &bid_order, &None).expect(
+        validate_bid_order(&bid_order, &QuoteDenomAllowlist::default())
+            .expect("multi coin bid order should pass validation");
+        validate_match(&deps.as_ref(), &mock_env(), &ask_order, &bid_order, &None).expect(
             "expected match validation to pass when ask and bid order used a multi-coin quote",
         );
     }
@@ -892,6 +1616,7 @@ mod tests {
                 owner: Addr::unchecked("ask_addr"),
                 collateral: AskCollateral::coin_trade(&[], &[]),
                 descriptor: None,
+                created_time: None,
             };
             RequestType::iterator().for_each(|bid_request_type| {
                 // Skip duplicate types - they obviously will match
@@ -904,6 +1629,7 @@ mod tests {
                     owner: Addr::unchecked("bid_addr"),
                     collateral: BidCollateral::coin_trade(&[], &[]),
                     descriptor: None,
+                    created_time: None,
                 };
                 assert_validation_failure_with_mismatch_test(
                     format!(
@@ -957,6 +1683,107 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_bidder_not_in_allowed_bidders() {
+        let deps = mock_dependencies(&[]);
+        assert_validation_failure(
+            "Bidder is not an allowed bidder for a private ask",
+            &deps.as_ref(),
+            &mock_ask_order_with_descriptor(
+                AskCollateral::coin_trade(&[], &[]),
+                RequestDescriptor::basic("private").with_allowed_bidders(&["buyer"]),
+            ),
+            &mock_bid_order(BidCollateral::coin_trade(&[], &[])),
+            "Match Validation for AskOrder [ask_id] and BidOrder [bid_id]: BidOrder owner [bidder] is not an allowed bidder for the AskOrder",
+        );
+        validate_match(
+            &deps.as_ref(),
+            &mock_env(),
+            &mock_ask_order_with_descriptor(
+                AskCollateral::coin_trade(&[], &[]),
+                RequestDescriptor::basic("private").with_allowed_bidders(&["buyer", "bidder"]),
+            ),
+            &mock_bid_order(BidCollateral::coin_trade(&[], &[])),
+            &None,
+        )
+        .expect("an allowed bidder should be able to match with a private ask");
+    }
+
+    #[test]
+    fn test_orders_before_effective_time() {
+        let deps = mock_dependencies(&[]);
+        let effective_time = mock_env().block.time.plus_nanos(1);
+        assert_validation_failure(
+            "Ask order is not yet effective",
+            &deps.as_ref(),
+            &mock_ask_order_with_descriptor(
+                AskCollateral::coin_trade(&[], &[]),
+                RequestDescriptor::basic("description").with_effective_time(effective_time),
+            ),
+            &mock_bid_order(BidCollateral::coin_trade(&[], &[])),
+            format!(
+                "Match Validation for AskOrder [ask_id] and BidOrder [bid_id]: AskOrder is not effective until [{}]",
+                effective_time,
+            ),
+        );
+        assert_validation_failure(
+            "Bid order is not yet effective",
+            &deps.as_ref(),
+            &mock_ask_order(AskCollateral::coin_trade(&[], &[])),
+            &mock_bid_with_descriptor(
+                BidCollateral::coin_trade(&[], &[]),
+                RequestDescriptor::basic("description").with_effective_time(effective_time),
+            ),
+            format!(
+                "Match Validation for AskOrder [ask_id] and BidOrder [bid_id]: BidOrder is not effective until [{}]",
+                effective_time,
+            ),
+        );
+        validate_match(
+            &deps.as_ref(),
+            &mock_env(),
+            &mock_ask_order_with_descriptor(
+                AskCollateral::coin_trade(&[], &[]),
+                RequestDescriptor::basic("description").with_effective_time(mock_env().block.time),
+            ),
+            &mock_bid_order(BidCollateral::coin_trade(&[], &[])),
+            &None,
+        )
+        .expect("an ask whose effective time is the current block time should be matchable");
+    }
+
+    #[test]
+    fn test_expired_orders() {
+        let deps = mock_dependencies(&[]);
+        let expiration_time = mock_env().block.time;
+        assert_validation_failure(
+            "Ask order is expired",
+            &deps.as_ref(),
+            &mock_ask_order_with_descriptor(
+                AskCollateral::coin_trade(&[], &[]),
+                RequestDescriptor::basic("description").with_expiration_time(expiration_time),
+            ),
+            &mock_bid_order(BidCollateral::coin_trade(&[], &[])),
+            format!(
+                "Match Validation for AskOrder [ask_id] and BidOrder [bid_id]: AskOrder expired at [{}]",
+                expiration_time,
+            ),
+        );
+        assert_validation_failure(
+            "Bid order is expired",
+            &deps.as_ref(),
+            &mock_ask_order(AskCollateral::coin_trade(&[], &[])),
+            &mock_bid_with_descriptor(
+                BidCollateral::coin_trade(&[], &[]),
+                RequestDescriptor::basic("description").with_expiration_time(expiration_time),
+            ),
+            format!(
+                "Match Validation for AskOrder [ask_id] and BidOrder [bid_id]: BidOrder expired at [{}]",
+                expiration_time,
+            ),
+        );
+    }
+
     #[test]
     fn test_get_required_attributes_error_none_scenarios() {
         let deps = mock_dependencies(&[]);
@@ -992,6 +1819,100 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_get_required_attributes_error_value_requirement_scenarios() {
+        let mut deps = mock_dependencies(&[]);
+        let address = Addr::unchecked("bidder");
+        let descriptor = Some(RequestDescriptor::new_populated_attributes(
+            "desc",
+            AttributeRequirement::all(&["kyc.pb"]).with_value_requirements(&[
+                AttributeValueRequirement::new(
+                    "jurisdiction.pb",
+                    AttributeValueCondition::one_of(&["US", "CA"]),
+                ),
+                AttributeValueRequirement::new(
+                    "kyc_level.pb",
+                    AttributeValueCondition::GreaterThanOrEqual { value: 2 },
+                ),
+            ]),
+        ));
+        deps.querier.with_attributes(
+            "bidder",
+            &[
+                ("kyc.pb", "true", "string"),
+                ("jurisdiction.pb", "MX", "string"),
+                ("kyc_level.pb", "1", "int"),
+            ],
+        );
+        assert_eq!(
+            "the [bidder account] is required to have attributes with the following values: [jurisdiction.pb] one of [\"US\", \"CA\"], [kyc_level.pb] greater than or equal to 2",
+            get_required_attributes_error(&deps.as_ref(), &descriptor, &address, "bidder")
+                .expect("expected an error when the attribute values do not meet the requirements"),
+            "expected the unmet value requirements to be described",
+        );
+        deps.querier.with_attributes(
+            "bidder",
+            &[
+                ("jurisdiction.pb", "CA", "string"),
+                ("kyc_level.pb", "2", "int"),
+            ],
+        );
+        assert_eq!(
+            "the [bidder account] is required to have all of the following attributes: [\"kyc.pb\"]",
+            get_required_attributes_error(&deps.as_ref(), &descriptor, &address, "bidder")
+                .expect("expected an error when the attribute names do not meet the requirements"),
+            "expected only the attribute name requirement to be described",
+        );
+        deps.querier.with_attributes(
+            "bidder",
+            &[
+                ("kyc.pb", "true", "string"),
+                ("jurisdiction.pb", "CA", "string"),
+                ("kyc_level.pb", "2", "int"),
+            ],
+        );
+        assert_eq!(
+            None,
+            get_required_attributes_error(&deps.as_ref(), &descriptor, &address, "bidder"),
+            "expected None when all names and values meet the requirements",
+        );
+    }
+
+    #[test]
+    fn test_get_required_attributes_error_attribute_expression_scenarios() {
+        let mut deps = mock_dependencies(&[]);
+        let address = Addr::unchecked("bidder");
+        let descriptor = Some(RequestDescriptor::new_none().with_attribute_expression(
+            AttributeExpression::or(&[
+                AttributeExpression::and(&[
+                    AttributeExpression::has_attribute("accredited.pb"),
+                    AttributeExpression::has_attribute("us_resident.pb"),
+                ]),
+                AttributeExpression::has_attribute("institutional.pb"),
+            ]),
+        ));
+        deps.querier
+            .with_attributes("bidder", &[("accredited.pb", "true", "string")]);
+        assert_eq!(
+            "the [bidder account] did not satisfy the attribute expression: ([accredited.pb] AND [us_resident.pb]) OR [institutional.pb]",
+            get_required_attributes_error(&deps.as_ref(), &descriptor, &address, "bidder")
+                .expect("expected an error when the expression is not satisfied"),
+            "expected the expression to be described in the error",
+        );
+        deps.querier.with_attributes(
+            "bidder",
+            &[
+                ("accredited.pb", "true", "string"),
+                ("us_resident.pb", "true", "string"),
+            ],
+        );
+        assert_eq!(
+            None,
+            get_required_attributes_error(&deps.as_ref(), &descriptor, &address, "bidder"),
+            "expected None when the expression is satisfied",
+        );
+    }
+
     #[test]
     fn test_get_required_attributes_error_all_type_scenarios() {
         let mut deps = mock_dependencies(&[]);
@@ -1391,6 +2312,7 @@ mod tests {
         );
         validate_match(
             &deps.as_ref(),
+            &mock_env(),
             &ask_order,
             &bid_order,
             &Some(AdminMatchOptions::coin_trade_options(true)),
@@ -1849,7 +2771,7 @@ mod tests {
     ) {
         let test_name = test_name.into();
         let message = expected_error_message.into();
-        let result = validate_match(deps, ask_order, bid_order, &options);
+        let result = validate_match(deps, &mock_env(), ask_order, bid_order, &options);
         let messages = match result {
             Err(e) => match e {
                 ContractError::ValidationError { messages } => messages,
@@ -1895,11 +2817,15 @@ mod tests {
                 RequestType::ScopeTrade => Some(AdminMatchOptions::scope_trade_options(
                     accept_mismatched_bids,
                 )),
+                RequestType::ScopeBundleTrade => Some(
+                    AdminMatchOptions::scope_bundle_trade_options(accept_mismatched_bids),
+                ),
             }
         };
         let test = |accept_mismatched_bids: bool| {
             let result = validate_match(
                 deps,
+                &mock_env(),
                 ask_order,
                 bid_order,
                 &get_admin_options(accept_mismatched_bids),
@@ -1955,4 +2881,103 @@ mod tests {
             suffix.into(),
         )
     }
+
+    #[test]
+    fn test_scope_bundle_trade_scope_mismatch() {
+        let deps = mock_dependencies(&[]);
+        let ask_order = mock_ask_order(AskCollateral::scope_bundle_trade(
+            &["scope1", "scope2"],
+            &coins(100, "quote"),
+        ));
+        assert_validation_failure_with_mismatch_test(
+            "mismatched bundle scopes",
+            &deps.as_ref(),
+            &ask_order,
+            &mock_bid_order(BidCollateral::scope_bundle_trade(
+                &["scope1"],
+                &coins(100, "quote"),
+            )),
+            "SCOPE BUNDLE TRADE Match Validation for AskOrder [ask_id] and BidOrder [bid_id]: Ask scope addresses [scope1, scope2] do not match bid scope addresses [scope1]",
+            true,
+        );
+        validate_match(
+            &deps.as_ref(),
+            &mock_env(),
+            &ask_order,
+            &mock_bid_order(BidCollateral::scope_bundle_trade(
+                &["scope2", "scope1"],
+                &coins(100, "quote"),
+            )),
+            &None,
+        )
+        .expect("a bid listing the same scopes in a different order should be valid");
+    }
+
+    #[test]
+    fn test_match_validation_failures_are_structured() {
+        let deps = mock_dependencies(&[]);
+        let ask_order = mock_ask_order(AskCollateral::coin_trade(
+            &coins(100, "base"),
+            &coins(100, "quote"),
+        ));
+        let bid_order = mock_bid_order(BidCollateral::coin_trade(
+            &coins(50, "base"),
+            &coins(90, "quote"),
+        ));
+        let failures = get_match_validation_failures(
+            &deps.as_ref(),
+            &mock_env(),
+            &ask_order,
+            &bid_order,
+            &None,
+        );
+        assert_eq!(
+            vec![
+                MatchValidationFailure::new(
+                    MatchFailureCode::BaseMismatch,
+                    MatchSide::Both,
+                    "collateral.base",
+                    "Ask base [100base] does not match bid base [50base]",
+                ),
+                MatchValidationFailure::new(
+                    MatchFailureCode::QuoteMismatch,
+                    MatchSide::Both,
+                    "collateral.quote",
+                    "Ask quote [100quote] does not match bid quote [90quote]",
+                ),
+            ],
+            failures,
+            "each collateral mismatch should be reported as a separate failure",
+        );
+        match validate_match(&deps.as_ref(), &mock_env(), &ask_order, &bid_order, &None)
+            .expect_err("validation should fail for the same orders")
+        {
+            ContractError::ValidationError { messages } => assert_eq!(
+                failures
+                    .iter()
+                    .map(|failure| format!(
+                        "COIN TRADE Match Validation for AskOrder [ask_id] and BidOrder [bid_id]: {}",
+                        failure.message,
+                    ))
+                    .collect::<Vec<String>>(),
+                messages,
+                "the validation error should contain each failure's message",
+            ),
+            e => panic!("unexpected error encountered: {:?}", e),
+        };
+        assert!(
+            get_match_validation_failures(
+                &deps.as_ref(),
+                &mock_env(),
+                &ask_order,
+                &mock_bid_order(BidCollateral::coin_trade(
+                    &coins(100, "base"),
+                    &coins(100, "quote"),
+                )),
+                &None,
+            )
+            .is_empty(),
+            "no failures should be produced for matching orders",
+        );
+    }
 }
@@ -1,5 +1,6 @@
 use crate::types::core::error::ContractError;
 use crate::types::core::msg::InstantiateMsg;
+use crate::validation::settlement_fees_validation::validate_settlement_fees;
 use crate::validation::validation_handler::ValidationHandler;
 
 pub fn validate_instantiate_msg(msg: &InstantiateMsg) -> Result<(), ContractError> {
@@ -10,6 +11,17 @@ pub fn validate_instantiate_msg(msg: &InstantiateMsg) -> Result<(), ContractErro
     if msg.contract_name.is_empty() {
         handler.push("contract_name value was empty");
     }
+    if let Some(ref fee_collector_address) = msg.fee_collector_address {
+        if fee_collector_address.is_empty() {
+            handler.push("fee_collector_address value was empty");
+        }
+    }
+    if let Some(ref settlement_fees) = msg.settlement_fees {
+        handler.append(&validate_settlement_fees(
+            "settlement_fees",
+            settlement_fees,
+        ));
+    }
     handler.handle()
 }
 
@@ -17,6 +29,7 @@ pub fn validate_instantiate_msg(msg: &InstantiateMsg) -> Result<(), ContractErro
 mod tests {
     use crate::types::core::error::ContractError;
     use crate::types::core::msg::InstantiateMsg;
+    use crate::types::request::settlement_fees::SettlementFees;
     use crate::validation::instantiation_validation::validate_instantiate_msg;
     use cosmwasm_std::Uint128;
 
@@ -28,6 +41,8 @@ mod tests {
                 contract_name: "some name".to_string(),
                 create_ask_nhash_fee: None,
                 create_bid_nhash_fee: None,
+                settlement_fees: None,
+                fee_collector_address: None,
             },
             "Empty bind_name provided",
             "bind_name value was empty",
@@ -42,12 +57,49 @@ mod tests {
                 contract_name: String::new(),
                 create_ask_nhash_fee: None,
                 create_bid_nhash_fee: None,
+                settlement_fees: None,
+                fee_collector_address: None,
             },
             "Empty contract_name provided",
             "contract_name value was empty",
         );
     }
 
+    #[test]
+    fn test_invalid_fee_collector_address() {
+        assert_expected_validation_error(
+            InstantiateMsg {
+                bind_name: "somename.pb".to_string(),
+                contract_name: "some name".to_string(),
+                create_ask_nhash_fee: None,
+                create_bid_nhash_fee: None,
+                settlement_fees: None,
+                fee_collector_address: Some(String::new()),
+            },
+            "Empty fee_collector_address provided",
+            "fee_collector_address value was empty",
+        );
+    }
+
+    #[test]
+    fn test_invalid_settlement_fees() {
+        assert_expected_validation_error(
+            InstantiateMsg {
+                bind_name: "somename.pb".to_string(),
+                contract_name: "some name".to_string(),
+                create_ask_nhash_fee: None,
+                create_bid_nhash_fee: None,
+                settlement_fees: Some(SettlementFees {
+                    marker_share_sale_bps: Some(Uint128::new(10_001)),
+                    ..SettlementFees::default()
+                }),
+                fee_collector_address: None,
+            },
+            "Excessive settlement fee provided",
+            "settlement_fees.marker_share_sale_bps must be no more than [10000] basis points, but was [10001]",
+        );
+    }
+
     #[test]
     fn test_valid_input() {
         validate_instantiate_msg(&InstantiateMsg {
@@ -55,6 +107,14 @@ mod tests {
             contract_name: "some contract".to_string(),
             create_ask_nhash_fee: Some(Uint128::new(10000)),
             create_bid_nhash_fee: Some(Uint128::new(109009000)),
+            settlement_fees: Some(SettlementFees {
+                coin_trade_bps: Some(Uint128::new(50)),
+                marker_trade_bps: Some(Uint128::new(100)),
+                marker_share_sale_bps: Some(Uint128::new(150)),
+                scope_trade_bps: Some(Uint128::new(10_000)),
+                scope_bundle_trade_bps: None,
+            }),
+            fee_collector_address: Some("fee_collector".to_string()),
         })
         .expect("fully populated instantiate message should pass validation");
     }
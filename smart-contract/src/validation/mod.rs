@@ -1,7 +1,14 @@
 pub mod ask_order_validation;
+pub mod attribute_requirement_validation;
 pub mod bid_order_validation;
+pub mod contract_status_validation;
+pub mod counterparty_attribute_validation;
 pub mod execute_match_validation;
 pub mod instantiation_validation;
 pub mod marker_exchange_validation;
+pub mod quote_denom_validation;
+pub mod request_descriptor_validation;
+pub mod search_validation;
 pub mod settings_update_validation;
+pub mod settlement_fees_validation;
 pub mod validation_handler;
@@ -0,0 +1,61 @@
+use crate::types::core::quote_denom_allowlist::QuoteDenomAllowlist;
+use crate::types::request::request_type::RequestType;
+use cosmwasm_std::Coin;
+
+/// Produces a message for each quote coin whose denom is not allowed for the request type.
+pub fn validate_quote_denoms<S: Into<String>>(
+    order_description: S,
+    request_type: &RequestType,
+    quote: &[Coin],
+    allowlist: &QuoteDenomAllowlist,
+) -> Vec<String> {
+    let order_description = order_description.into();
+    quote
+        .iter()
+        .filter(|coin| !allowlist.is_denom_allowed(request_type, &coin.denom))
+        .map(|coin| {
+            format!(
+                "{} quote denom [{}] is not allowed for request type [{}]",
+                order_description,
+                coin.denom,
+                request_type.get_name(),
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::core::quote_denom_allowlist::QuoteDenomAllowlist;
+    use crate::types::request::request_type::RequestType;
+    use crate::validation::quote_denom_validation::validate_quote_denoms;
+    use cosmwasm_std::coin;
+
+    #[test]
+    fn test_validate_quote_denoms() {
+        let allowlist = QuoteDenomAllowlist {
+            all_request_types: vec!["nhash".to_string()],
+            ..QuoteDenomAllowlist::default()
+        };
+        assert!(
+            validate_quote_denoms(
+                "AskOrder [ask_id]",
+                &RequestType::CoinTrade,
+                &[coin(100, "nhash")],
+                &allowlist,
+            )
+            .is_empty(),
+            "an allowed denom should produce no messages",
+        );
+        assert_eq!(
+            vec!["AskOrder [ask_id] quote denom [nhasb] is not allowed for request type [coin_trade]".to_string()],
+            validate_quote_denoms(
+                "AskOrder [ask_id]",
+                &RequestType::CoinTrade,
+                &[coin(100, "nhash"), coin(100, "nhasb")],
+                &allowlist,
+            ),
+            "a message should be produced for the disallowed denom",
+        );
+    }
+}
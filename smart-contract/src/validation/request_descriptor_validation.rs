@@ -0,0 +1,90 @@
+use crate::types::core::error::ContractError;
+use crate::types::request::request_descriptor::RequestDescriptor;
+use crate::validation::validation_handler::ValidationHandler;
+use cosmwasm_std::Env;
+
+/// Verifies that the time-related values in a request descriptor are sane relative to the current
+/// block time.  This should be run any time an ask or bid order is created or updated.
+pub fn validate_request_descriptor_times<S: Into<String>>(
+    order_identifier: S,
+    descriptor: &Option<RequestDescriptor>,
+    env: &Env,
+) -> Result<(), ContractError> {
+    let handler = ValidationHandler::new();
+    let order_identifier = order_identifier.into();
+    if let Some(expiration_time) = descriptor.as_ref().and_then(|d| d.expiration_time) {
+        if expiration_time <= env.block.time {
+            handler.push(format!(
+                "{} specified expiration time [{}], which is not after the current block time [{}]",
+                order_identifier, expiration_time, env.block.time,
+            ));
+        }
+    }
+    handler.handle()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::error_helpers::assert_validation_error_message;
+    use crate::types::request::request_descriptor::RequestDescriptor;
+    use crate::validation::request_descriptor_validation::validate_request_descriptor_times;
+    use cosmwasm_std::testing::mock_env;
+
+    #[test]
+    fn test_missing_descriptor_passes_validation() {
+        validate_request_descriptor_times("AskOrder [ask_id]", &None, &mock_env())
+            .expect("a missing descriptor should pass validation");
+        validate_request_descriptor_times(
+            "AskOrder [ask_id]",
+            &Some(RequestDescriptor::new_none()),
+            &mock_env(),
+        )
+        .expect("a descriptor without any times should pass validation");
+    }
+
+    #[test]
+    fn test_expiration_time_in_the_future_passes_validation() {
+        let env = mock_env();
+        validate_request_descriptor_times(
+            "BidOrder [bid_id]",
+            &Some(
+                RequestDescriptor::basic("desc")
+                    .with_expiration_time(env.block.time.plus_seconds(1)),
+            ),
+            &env,
+        )
+        .expect("an expiration time after the current block time should pass validation");
+    }
+
+    #[test]
+    fn test_expiration_time_at_or_before_block_time_fails_validation() {
+        let env = mock_env();
+        let err = validate_request_descriptor_times(
+            "AskOrder [ask_id]",
+            &Some(RequestDescriptor::basic("desc").with_expiration_time(env.block.time)),
+            &env,
+        )
+        .expect_err("an expiration time equal to the block time should fail validation");
+        assert_validation_error_message(
+            err,
+            format!(
+                "AskOrder [ask_id] specified expiration time [{}], which is not after the current block time [{}]",
+                env.block.time, env.block.time,
+            ),
+        );
+        let expiration_time = env.block.time.minus_seconds(100);
+        let err = validate_request_descriptor_times(
+            "BidOrder [bid_id]",
+            &Some(RequestDescriptor::basic("desc").with_expiration_time(expiration_time)),
+            &env,
+        )
+        .expect_err("an expiration time before the block time should fail validation");
+        assert_validation_error_message(
+            err,
+            format!(
+                "BidOrder [bid_id] specified expiration time [{}], which is not after the current block time [{}]",
+                expiration_time, env.block.time,
+            ),
+        );
+    }
+}
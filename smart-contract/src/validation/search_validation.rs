@@ -0,0 +1,140 @@
+use crate::types::core::error::ContractError;
+use crate::types::request::search::{Search, SearchSort, SearchType};
+use crate::validation::validation_handler::ValidationHandler;
+
+pub fn validate_search(search: &Search) -> Result<(), ContractError> {
+    let handler = ValidationHandler::new();
+    if let Some(ref start_after) = search.start_after {
+        if start_after.is_empty() {
+            handler.push("start_after was empty");
+        }
+    }
+    if let SearchType::Filter { filter } = &search.search_type {
+        // The indexed criteria find the orders that the filter checks, so without one of them
+        // every order in the contract would need to be checked
+        if filter.owner.is_none() && filter.request_type.is_none() && filter.quote_denom.is_none() {
+            handler.push("filter requires an owner, request_type, or quote_denom");
+        }
+        if let Some(ref owner) = filter.owner {
+            if owner.is_empty() {
+                handler.push("filter owner was empty");
+            }
+        }
+        match filter.quote_denom {
+            Some(ref quote_denom) if quote_denom.is_empty() => {
+                handler.push("filter quote_denom was empty");
+            }
+            None => {
+                if filter.min_price.is_some() || filter.max_price.is_some() {
+                    handler.push("filter price range requires a quote_denom");
+                }
+                if filter.get_sort() == SearchSort::Price {
+                    handler.push("filter price sort requires a quote_denom");
+                }
+            }
+            _ => {}
+        }
+        if let (Some(min_price), Some(max_price)) = (filter.min_price, filter.max_price) {
+            if min_price > max_price {
+                handler.push(format!(
+                    "filter min_price [{}] exceeds max_price [{}]",
+                    min_price, max_price,
+                ));
+            }
+        }
+        if let (Some(created_after), Some(created_before)) =
+            (filter.created_after, filter.created_before)
+        {
+            if created_after > created_before {
+                handler.push(format!(
+                    "filter created_after [{}] is later than created_before [{}]",
+                    created_after, created_before,
+                ));
+            }
+        }
+    }
+    handler.handle()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::error_helpers::assert_validation_error_message;
+    use crate::types::request::request_type::RequestType;
+    use crate::types::request::search::{OrderStatus, Search, SearchFilter, SearchSort};
+    use crate::validation::search_validation::validate_search;
+    use cosmwasm_std::Timestamp;
+
+    #[test]
+    fn test_valid_searches() {
+        validate_search(&Search::all(None, None).start_after("ask_id"))
+            .expect("a search with a cursor should be valid");
+        validate_search(&Search::filter(
+            SearchFilter::new()
+                .with_owner("owner")
+                .with_quote_denom("nhash")
+                .with_price_range(Some(1), Some(1))
+                .with_creation_range(
+                    Some(Timestamp::from_seconds(1)),
+                    Some(Timestamp::from_seconds(1)),
+                )
+                .sorted_by(SearchSort::Price),
+            None,
+            None,
+        ))
+        .expect("a filter with all criteria populated should be valid");
+        validate_search(&Search::filter(
+            SearchFilter::new().with_request_type(RequestType::CoinTrade),
+            None,
+            None,
+        ))
+        .expect("a filter with a single indexed criterion should be valid");
+    }
+
+    #[test]
+    fn test_invalid_searches() {
+        assert_validation_error_message(
+            validate_search(&Search::all(None, None).start_after("")).unwrap_err(),
+            "start_after was empty",
+        );
+        let assert_invalid_filter = |filter: SearchFilter, expected_message: &str| {
+            assert_validation_error_message(
+                validate_search(&Search::filter(filter, None, None)).unwrap_err(),
+                expected_message,
+            );
+        };
+        assert_invalid_filter(
+            SearchFilter::new().with_status(OrderStatus::Active),
+            "filter requires an owner, request_type, or quote_denom",
+        );
+        assert_invalid_filter(SearchFilter::new().with_owner(""), "filter owner was empty");
+        assert_invalid_filter(
+            SearchFilter::new().with_quote_denom(""),
+            "filter quote_denom was empty",
+        );
+        assert_invalid_filter(
+            SearchFilter::new()
+                .with_owner("owner")
+                .with_price_range(None, Some(100)),
+            "filter price range requires a quote_denom",
+        );
+        assert_invalid_filter(
+            SearchFilter::new()
+                .with_owner("owner")
+                .sorted_by(SearchSort::Price),
+            "filter price sort requires a quote_denom",
+        );
+        assert_invalid_filter(
+            SearchFilter::new()
+                .with_quote_denom("nhash")
+                .with_price_range(Some(2), Some(1)),
+            "filter min_price [2] exceeds max_price [1]",
+        );
+        assert_invalid_filter(
+            SearchFilter::new().with_owner("owner").with_creation_range(
+                Some(Timestamp::from_seconds(2)),
+                Some(Timestamp::from_seconds(1)),
+            ),
+            "filter created_after [2.000000000] is later than created_before [1.000000000]",
+        );
+    }
+}
@@ -1,14 +1,32 @@
 use crate::types::core::error::ContractError;
 use crate::types::request::settings_update::SettingsUpdate;
+use crate::validation::attribute_requirement_validation::validate_attribute_requirement;
+use crate::validation::settlement_fees_validation::validate_settlement_fees;
 use crate::validation::validation_handler::ValidationHandler;
 
 pub fn validate_settings_update(update: &SettingsUpdate) -> Result<(), ContractError> {
     let handler = ValidationHandler::new();
-    if let Some(ref new_admin_address) = update.new_admin_address {
-        if new_admin_address.is_empty() {
-            handler.push("new_admin_address was empty");
+    if let Some(ref new_fee_collector_address) = update.new_fee_collector_address {
+        if new_fee_collector_address.is_empty() {
+            handler.push("new_fee_collector_address was empty");
         }
     }
+    if let Some(ref new_settlement_fees) = update.new_settlement_fees {
+        handler.append(&validate_settlement_fees(
+            "new_settlement_fees",
+            new_settlement_fees,
+        ));
+    }
+    if let Some(new_required_trade_attributes) = update
+        .new_required_trade_attributes
+        .as_ref()
+        .filter(|requirement| requirement.has_checks())
+    {
+        handler.append(&validate_attribute_requirement(
+            "new_required_trade_attributes",
+            new_required_trade_attributes,
+        ));
+    }
     handler.handle()
 }
 
@@ -16,17 +34,41 @@ pub fn validate_settings_update(update: &SettingsUpdate) -> Result<(), ContractE
 mod tests {
     use crate::types::core::error::ContractError;
     use crate::types::request::settings_update::SettingsUpdate;
+    use crate::types::request::settlement_fees::SettlementFees;
     use crate::validation::settings_update_validation::validate_settings_update;
+    use cosmwasm_std::Uint128;
+
+    #[test]
+    fn test_empty_fee_collector_address() {
+        assert_single_error_message(
+            "blank fee collector address provided",
+            "new_fee_collector_address was empty",
+            SettingsUpdate {
+                new_create_ask_nhash_fee: None,
+                new_create_bid_nhash_fee: None,
+                new_settlement_fees: None,
+                new_fee_collector_address: Some(String::new()),
+                new_contract_status: None,
+                new_required_trade_attributes: None,
+            },
+        );
+    }
 
     #[test]
-    fn test_empty_admin_address() {
+    fn test_excessive_settlement_fee() {
         assert_single_error_message(
-            "blank admin address provided",
-            "new_admin_address was empty",
+            "excessive settlement fee provided",
+            "new_settlement_fees.coin_trade_bps must be no more than [10000] basis points, but was [20000]",
             SettingsUpdate {
-                new_admin_address: Some("".to_string()),
                 new_create_ask_nhash_fee: None,
                 new_create_bid_nhash_fee: None,
+                new_settlement_fees: Some(SettlementFees {
+                    coin_trade_bps: Some(Uint128::new(20_000)),
+                    ..SettlementFees::default()
+                }),
+                new_fee_collector_address: None,
+                new_contract_status: None,
+                new_required_trade_attributes: None,
             },
         );
     }
@@ -0,0 +1,74 @@
+use crate::types::core::constants::MAX_SETTLEMENT_FEE_BPS;
+use crate::types::request::settlement_fees::SettlementFees;
+
+/// Produces an error message for each fee rate that exceeds the maximum allowed value.
+pub fn validate_settlement_fees<S: Into<String>>(
+    field_name: S,
+    settlement_fees: &SettlementFees,
+) -> Vec<String> {
+    let field_name = field_name.into();
+    settlement_fees
+        .get_named_fee_bps()
+        .into_iter()
+        .filter_map(|(name, fee_bps)| match fee_bps {
+            Some(fee_bps) if fee_bps.u128() > MAX_SETTLEMENT_FEE_BPS => Some(format!(
+                "{}.{} must be no more than [{}] basis points, but was [{}]",
+                &field_name,
+                name,
+                MAX_SETTLEMENT_FEE_BPS,
+                fee_bps.u128(),
+            )),
+            _ => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::request::settlement_fees::SettlementFees;
+    use crate::validation::settlement_fees_validation::validate_settlement_fees;
+    use cosmwasm_std::Uint128;
+
+    #[test]
+    fn test_valid_settlement_fees() {
+        assert!(
+            validate_settlement_fees("settlement_fees", &SettlementFees::default()).is_empty(),
+            "omitted fee rates should be valid",
+        );
+        assert!(
+            validate_settlement_fees(
+                "settlement_fees",
+                &SettlementFees {
+                    coin_trade_bps: Some(Uint128::zero()),
+                    marker_trade_bps: Some(Uint128::new(25)),
+                    marker_share_sale_bps: Some(Uint128::new(150)),
+                    scope_trade_bps: Some(Uint128::new(10_000)),
+                    scope_bundle_trade_bps: None,
+                },
+            )
+            .is_empty(),
+            "fee rates from zero to the maximum should be valid",
+        );
+    }
+
+    #[test]
+    fn test_settlement_fees_exceeding_maximum() {
+        assert_eq!(
+            vec![
+                "settlement_fees.coin_trade_bps must be no more than [10000] basis points, but was [10001]".to_string(),
+                "settlement_fees.scope_trade_bps must be no more than [10000] basis points, but was [50000]".to_string(),
+            ],
+            validate_settlement_fees(
+                "settlement_fees",
+                &SettlementFees {
+                    coin_trade_bps: Some(Uint128::new(10_001)),
+                    marker_trade_bps: None,
+                    marker_share_sale_bps: Some(Uint128::new(100)),
+                    scope_trade_bps: Some(Uint128::new(50_000)),
+                    scope_bundle_trade_bps: None,
+                },
+            ),
+            "each fee rate above the maximum should produce an error message",
+        );
+    }
+}